@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -26,14 +28,16 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -61,6 +65,8 @@ export type Cfg = {
   nonce: bigint;
   /** Guardian pubkey authorized to update configuration */
   guardian: Address;
+  /** Guardian proposed by the current guardian, pending acceptance */
+  pendingGuardian: Option<Address>;
   /** EIP-1559 state and configuration for dynamic pricing. */
   eip1559: Eip1559;
   /** Gas configuration */
@@ -72,18 +78,21 @@ export type CfgArgs = {
   nonce: number | bigint;
   /** Guardian pubkey authorized to update configuration */
   guardian: Address;
+  /** Guardian proposed by the current guardian, pending acceptance */
+  pendingGuardian: OptionOrNullable<Address>;
   /** EIP-1559 state and configuration for dynamic pricing. */
   eip1559: Eip1559Args;
   /** Gas configuration */
   gasConfig: GasConfigArgs;
 };
 
-export function getCfgEncoder(): FixedSizeEncoder<CfgArgs> {
+export function getCfgEncoder(): Encoder<CfgArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['nonce', getU64Encoder()],
       ['guardian', getAddressEncoder()],
+      ['pendingGuardian', getOptionEncoder(getAddressEncoder())],
       ['eip1559', getEip1559Encoder()],
       ['gasConfig', getGasConfigEncoder()],
     ]),
@@ -91,17 +100,18 @@ export function getCfgEncoder(): FixedSizeEncoder<CfgArgs> {
   );
 }
 
-export function getCfgDecoder(): FixedSizeDecoder<Cfg> {
+export function getCfgDecoder(): Decoder<Cfg> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['nonce', getU64Decoder()],
     ['guardian', getAddressDecoder()],
+    ['pendingGuardian', getOptionDecoder(getAddressDecoder())],
     ['eip1559', getEip1559Decoder()],
     ['gasConfig', getGasConfigDecoder()],
   ]);
 }
 
-export function getCfgCodec(): FixedSizeCodec<CfgArgs, Cfg> {
+export function getCfgCodec(): Codec<CfgArgs, Cfg> {
   return combineCodec(getCfgEncoder(), getCfgDecoder());
 }
 
@@ -157,7 +167,3 @@ export async function fetchAllMaybeCfg(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeCfg(maybeAccount));
 }
-
-export function getCfgSize(): number {
-  return 168;
-}
@@ -18,31 +18,67 @@ import { BASE_RELAYER_PROGRAM_ADDRESS } from '../programs';
 export const BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION = 0x2ee0; // 12000
 /** IncorrectRelayerProgram: Incorrect relayer program */
 export const BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM = 0x2ee1; // 12001
+/** AlreadyMigrated: Account is already migrated or does not have the legacy layout */
+export const BASE_RELAYER_ERROR__ALREADY_MIGRATED = 0x2ee2; // 12002
 /** UnauthorizedConfigUpdate: Unauthorized to update configuration */
 export const BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE = 0x2f44; // 12100
+/** InvalidDenominator: Invalid denominator */
+export const BASE_RELAYER_ERROR__INVALID_DENOMINATOR = 0x2f45; // 12101
+/** InvalidWindowDurationSeconds: Invalid window duration seconds */
+export const BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION_SECONDS = 0x2f46; // 12102
+/** InvalidGasCostScalerDp: Invalid gas cost scaler dp */
+export const BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP = 0x2f47; // 12103
+/** InvalidGasLimitRange: Invalid gas limit range */
+export const BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_RANGE = 0x2f48; // 12104
+/** UnauthorizedGuardianAcceptance: Only the pending guardian can accept the guardian role */
+export const BASE_RELAYER_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE = 0x2f49; // 12105
 /** GasLimitTooLow: Gas limit too low */
 export const BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW = 0x2fa8; // 12200
 /** GasLimitExceeded: Gas limit exceeded */
 export const BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED = 0x2fa9; // 12201
+/** GasCostOverflow: Gas cost does not fit in a u64 */
+export const BASE_RELAYER_ERROR__GAS_COST_OVERFLOW = 0x2faa; // 12202
 /** IncorrectGasFeeReceiver: Incorrect gas fee receiver */
 export const BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER = 0x300c; // 12300
+/** InvalidOutgoingMessage: Account is not a bridge outgoing message */
+export const BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE = 0x300d; // 12301
+/** PayerIsNotSender: Only the sender of the outgoing message can pay for its relay */
+export const BASE_RELAYER_ERROR__PAYER_IS_NOT_SENDER = 0x300e; // 12302
 
 export type BaseRelayerError =
+  | typeof BASE_RELAYER_ERROR__ALREADY_MIGRATED
+  | typeof BASE_RELAYER_ERROR__GAS_COST_OVERFLOW
   | typeof BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED
   | typeof BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW
   | typeof BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER
   | typeof BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM
+  | typeof BASE_RELAYER_ERROR__INVALID_DENOMINATOR
+  | typeof BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP
+  | typeof BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_RANGE
+  | typeof BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE
+  | typeof BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION_SECONDS
+  | typeof BASE_RELAYER_ERROR__PAYER_IS_NOT_SENDER
   | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE
+  | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE
   | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION;
 
 let baseRelayerErrorMessages: Record<BaseRelayerError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   baseRelayerErrorMessages = {
+    [BASE_RELAYER_ERROR__ALREADY_MIGRATED]: `Account is already migrated or does not have the legacy layout`,
+    [BASE_RELAYER_ERROR__GAS_COST_OVERFLOW]: `Gas cost does not fit in a u64`,
     [BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED]: `Gas limit exceeded`,
     [BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW]: `Gas limit too low`,
     [BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER]: `Incorrect gas fee receiver`,
     [BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM]: `Incorrect relayer program`,
+    [BASE_RELAYER_ERROR__INVALID_DENOMINATOR]: `Invalid denominator`,
+    [BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP]: `Invalid gas cost scaler dp`,
+    [BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_RANGE]: `Invalid gas limit range`,
+    [BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE]: `Account is not a bridge outgoing message`,
+    [BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION_SECONDS]: `Invalid window duration seconds`,
+    [BASE_RELAYER_ERROR__PAYER_IS_NOT_SENDER]: `Only the sender of the outgoing message can pay for its relay`,
     [BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE]: `Unauthorized to update configuration`,
+    [BASE_RELAYER_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE]: `Only the pending guardian can accept the guardian role`,
     [BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION]: `Only the upgrade authority can initialize the relayer`,
   };
 }
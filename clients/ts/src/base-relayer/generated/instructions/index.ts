@@ -6,8 +6,12 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptGuardian';
+export * from './cancelGuardianTransfer';
+export * from './getBaseFee';
 export * from './initialize';
+export * from './migrateCfg';
 export * from './payForRelay';
+export * from './proposeGuardian';
 export * from './setEip1559Config';
 export * from './setGasConfig';
-export * from './setGuardian';
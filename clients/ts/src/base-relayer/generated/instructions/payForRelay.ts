@@ -10,8 +10,6 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressDecoder,
-  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -52,6 +50,7 @@ export type PayForRelayInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountCfg extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountMessageToRelay extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -69,6 +68,9 @@ export type PayForRelayInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountOutgoingMessage extends string
+        ? ReadonlyAccount<TAccountOutgoingMessage>
+        : TAccountOutgoingMessage,
       TAccountMessageToRelay extends string
         ? WritableAccount<TAccountMessageToRelay>
         : TAccountMessageToRelay,
@@ -81,23 +83,15 @@ export type PayForRelayInstruction<
 
 export type PayForRelayInstructionData = {
   discriminator: ReadonlyUint8Array;
-  mtrSalt: ReadonlyUint8Array;
-  outgoingMessage: Address;
   gasLimit: bigint;
 };
 
-export type PayForRelayInstructionDataArgs = {
-  mtrSalt: ReadonlyUint8Array;
-  outgoingMessage: Address;
-  gasLimit: number | bigint;
-};
+export type PayForRelayInstructionDataArgs = { gasLimit: number | bigint };
 
 export function getPayForRelayInstructionDataEncoder(): FixedSizeEncoder<PayForRelayInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['mtrSalt', fixEncoderSize(getBytesEncoder(), 32)],
-      ['outgoingMessage', getAddressEncoder()],
       ['gasLimit', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: PAY_FOR_RELAY_DISCRIMINATOR })
@@ -107,8 +101,6 @@ export function getPayForRelayInstructionDataEncoder(): FixedSizeEncoder<PayForR
 export function getPayForRelayInstructionDataDecoder(): FixedSizeDecoder<PayForRelayInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['mtrSalt', fixDecoderSize(getBytesDecoder(), 32)],
-    ['outgoingMessage', getAddressDecoder()],
     ['gasLimit', getU64Decoder()],
   ]);
 }
@@ -127,12 +119,14 @@ export type PayForRelayInput<
   TAccountPayer extends string = string,
   TAccountCfg extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountOutgoingMessage extends string = string,
   TAccountMessageToRelay extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /**
    * The account that pays for transaction fees and account creation.
    * Must be mutable to deduct lamports for account rent and gas fees.
+   * Must be the sender of `outgoing_message`.
    */
   payer: TransactionSigner<TAccountPayer>;
   /**
@@ -143,14 +137,21 @@ export type PayForRelayInput<
   cfg: Address<TAccountCfg>;
   /** The account that receives payment for the gas costs of bridging SOL to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /**
+   * The bridge `OutgoingMessage` account whose relay is paid for.
+   * A forged account can only reserve the relay of its own address, never of a real message.
+   */
+  outgoingMessage: Address<TAccountOutgoingMessage>;
+  /**
+   * The relay request for `outgoing_message`.
+   * - Uses PDA with MTR_SEED and the outgoing message so each message can only be paid for once
+   */
   messageToRelay: Address<TAccountMessageToRelay>;
   /**
    * System program required for creating new accounts.
    * Used internally by Anchor for account initialization.
    */
   systemProgram?: Address<TAccountSystemProgram>;
-  mtrSalt: PayForRelayInstructionDataArgs['mtrSalt'];
-  outgoingMessage: PayForRelayInstructionDataArgs['outgoingMessage'];
   gasLimit: PayForRelayInstructionDataArgs['gasLimit'];
 };
 
@@ -158,6 +159,7 @@ export function getPayForRelayInstruction<
   TAccountPayer extends string,
   TAccountCfg extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountOutgoingMessage extends string,
   TAccountMessageToRelay extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BASE_RELAYER_PROGRAM_ADDRESS,
@@ -166,6 +168,7 @@ export function getPayForRelayInstruction<
     TAccountPayer,
     TAccountCfg,
     TAccountGasFeeReceiver,
+    TAccountOutgoingMessage,
     TAccountMessageToRelay,
     TAccountSystemProgram
   >,
@@ -175,6 +178,7 @@ export function getPayForRelayInstruction<
   TAccountPayer,
   TAccountCfg,
   TAccountGasFeeReceiver,
+  TAccountOutgoingMessage,
   TAccountMessageToRelay,
   TAccountSystemProgram
 > {
@@ -186,6 +190,10 @@ export function getPayForRelayInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     cfg: { value: input.cfg ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    outgoingMessage: {
+      value: input.outgoingMessage ?? null,
+      isWritable: false,
+    },
     messageToRelay: { value: input.messageToRelay ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -209,6 +217,7 @@ export function getPayForRelayInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.cfg),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.messageToRelay),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -221,6 +230,7 @@ export function getPayForRelayInstruction<
     TAccountPayer,
     TAccountCfg,
     TAccountGasFeeReceiver,
+    TAccountOutgoingMessage,
     TAccountMessageToRelay,
     TAccountSystemProgram
   >);
@@ -235,6 +245,7 @@ export type ParsedPayForRelayInstruction<
     /**
      * The account that pays for transaction fees and account creation.
      * Must be mutable to deduct lamports for account rent and gas fees.
+     * Must be the sender of `outgoing_message`.
      */
     payer: TAccountMetas[0];
     /**
@@ -245,12 +256,21 @@ export type ParsedPayForRelayInstruction<
     cfg: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging SOL to Base. */
     gasFeeReceiver: TAccountMetas[2];
-    messageToRelay: TAccountMetas[3];
+    /**
+     * The bridge `OutgoingMessage` account whose relay is paid for.
+     * A forged account can only reserve the relay of its own address, never of a real message.
+     */
+    outgoingMessage: TAccountMetas[3];
+    /**
+     * The relay request for `outgoing_message`.
+     * - Uses PDA with MTR_SEED and the outgoing message so each message can only be paid for once
+     */
+    messageToRelay: TAccountMetas[4];
     /**
      * System program required for creating new accounts.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: PayForRelayInstructionData;
 };
@@ -263,7 +283,7 @@ export function parsePayForRelayInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedPayForRelayInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -279,6 +299,7 @@ export function parsePayForRelayInstruction<
       payer: getNextAccount(),
       cfg: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      outgoingMessage: getNextAccount(),
       messageToRelay: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -34,17 +34,17 @@ import {
 import { BASE_RELAYER_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 
-export const SET_GUARDIAN_DISCRIMINATOR = new Uint8Array([
-  147, 243, 50, 121, 154, 164, 50, 30,
+export const PROPOSE_GUARDIAN_DISCRIMINATOR = new Uint8Array([
+  39, 11, 248, 174, 73, 240, 0, 211,
 ]);
 
-export function getSetGuardianDiscriminatorBytes() {
+export function getProposeGuardianDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 8).encode(
-    SET_GUARDIAN_DISCRIMINATOR
+    PROPOSE_GUARDIAN_DISCRIMINATOR
   );
 }
 
-export type SetGuardianInstruction<
+export type ProposeGuardianInstruction<
   TProgram extends string = typeof BASE_RELAYER_PROGRAM_ADDRESS,
   TAccountCfg extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
@@ -62,41 +62,41 @@ export type SetGuardianInstruction<
     ]
   >;
 
-export type SetGuardianInstructionData = {
+export type ProposeGuardianInstructionData = {
   discriminator: ReadonlyUint8Array;
   newGuardian: Address;
 };
 
-export type SetGuardianInstructionDataArgs = { newGuardian: Address };
+export type ProposeGuardianInstructionDataArgs = { newGuardian: Address };
 
-export function getSetGuardianInstructionDataEncoder(): FixedSizeEncoder<SetGuardianInstructionDataArgs> {
+export function getProposeGuardianInstructionDataEncoder(): FixedSizeEncoder<ProposeGuardianInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['newGuardian', getAddressEncoder()],
     ]),
-    (value) => ({ ...value, discriminator: SET_GUARDIAN_DISCRIMINATOR })
+    (value) => ({ ...value, discriminator: PROPOSE_GUARDIAN_DISCRIMINATOR })
   );
 }
 
-export function getSetGuardianInstructionDataDecoder(): FixedSizeDecoder<SetGuardianInstructionData> {
+export function getProposeGuardianInstructionDataDecoder(): FixedSizeDecoder<ProposeGuardianInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['newGuardian', getAddressDecoder()],
   ]);
 }
 
-export function getSetGuardianInstructionDataCodec(): FixedSizeCodec<
-  SetGuardianInstructionDataArgs,
-  SetGuardianInstructionData
+export function getProposeGuardianInstructionDataCodec(): FixedSizeCodec<
+  ProposeGuardianInstructionDataArgs,
+  ProposeGuardianInstructionData
 > {
   return combineCodec(
-    getSetGuardianInstructionDataEncoder(),
-    getSetGuardianInstructionDataDecoder()
+    getProposeGuardianInstructionDataEncoder(),
+    getProposeGuardianInstructionDataDecoder()
   );
 }
 
-export type SetGuardianInput<
+export type ProposeGuardianInput<
   TAccountCfg extends string = string,
   TAccountGuardian extends string = string,
 > = {
@@ -104,17 +104,17 @@ export type SetGuardianInput<
   cfg: Address<TAccountCfg>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
-  newGuardian: SetGuardianInstructionDataArgs['newGuardian'];
+  newGuardian: ProposeGuardianInstructionDataArgs['newGuardian'];
 };
 
-export function getSetGuardianInstruction<
+export function getProposeGuardianInstruction<
   TAccountCfg extends string,
   TAccountGuardian extends string,
   TProgramAddress extends Address = typeof BASE_RELAYER_PROGRAM_ADDRESS,
 >(
-  input: SetGuardianInput<TAccountCfg, TAccountGuardian>,
+  input: ProposeGuardianInput<TAccountCfg, TAccountGuardian>,
   config?: { programAddress?: TProgramAddress }
-): SetGuardianInstruction<TProgramAddress, TAccountCfg, TAccountGuardian> {
+): ProposeGuardianInstruction<TProgramAddress, TAccountCfg, TAccountGuardian> {
   // Program address.
   const programAddress = config?.programAddress ?? BASE_RELAYER_PROGRAM_ADDRESS;
 
@@ -134,14 +134,18 @@ export function getSetGuardianInstruction<
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
     accounts: [getAccountMeta(accounts.cfg), getAccountMeta(accounts.guardian)],
-    data: getSetGuardianInstructionDataEncoder().encode(
-      args as SetGuardianInstructionDataArgs
+    data: getProposeGuardianInstructionDataEncoder().encode(
+      args as ProposeGuardianInstructionDataArgs
     ),
     programAddress,
-  } as SetGuardianInstruction<TProgramAddress, TAccountCfg, TAccountGuardian>);
+  } as ProposeGuardianInstruction<
+    TProgramAddress,
+    TAccountCfg,
+    TAccountGuardian
+  >);
 }
 
-export type ParsedSetGuardianInstruction<
+export type ParsedProposeGuardianInstruction<
   TProgram extends string = typeof BASE_RELAYER_PROGRAM_ADDRESS,
   TAccountMetas extends readonly AccountMeta[] = readonly AccountMeta[],
 > = {
@@ -152,17 +156,17 @@ export type ParsedSetGuardianInstruction<
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
   };
-  data: SetGuardianInstructionData;
+  data: ProposeGuardianInstructionData;
 };
 
-export function parseSetGuardianInstruction<
+export function parseProposeGuardianInstruction<
   TProgram extends string,
   TAccountMetas extends readonly AccountMeta[],
 >(
   instruction: Instruction<TProgram> &
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
-): ParsedSetGuardianInstruction<TProgram, TAccountMetas> {
+): ParsedProposeGuardianInstruction<TProgram, TAccountMetas> {
   if (instruction.accounts.length < 2) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
@@ -176,6 +180,6 @@ export function parseSetGuardianInstruction<
   return {
     programAddress: instruction.programAddress,
     accounts: { cfg: getNextAccount(), guardian: getNextAccount() },
-    data: getSetGuardianInstructionDataDecoder().decode(instruction.data),
+    data: getProposeGuardianInstructionDataDecoder().decode(instruction.data),
   };
 }
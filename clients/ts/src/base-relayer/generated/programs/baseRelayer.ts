@@ -14,11 +14,15 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptGuardianInstruction,
+  type ParsedCancelGuardianTransferInstruction,
+  type ParsedGetBaseFeeInstruction,
   type ParsedInitializeInstruction,
+  type ParsedMigrateCfgInstruction,
   type ParsedPayForRelayInstruction,
+  type ParsedProposeGuardianInstruction,
   type ParsedSetEip1559ConfigInstruction,
   type ParsedSetGasConfigInstruction,
-  type ParsedSetGuardianInstruction,
 } from '../instructions';
 
 export const BASE_RELAYER_PROGRAM_ADDRESS = '' as Address<''>;
@@ -60,17 +64,54 @@ export function identifyBaseRelayerAccount(
 }
 
 export enum BaseRelayerInstruction {
+  AcceptGuardian,
+  CancelGuardianTransfer,
+  GetBaseFee,
   Initialize,
+  MigrateCfg,
   PayForRelay,
+  ProposeGuardian,
   SetEip1559Config,
   SetGasConfig,
-  SetGuardian,
 }
 
 export function identifyBaseRelayerInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): BaseRelayerInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([130, 141, 66, 69, 80, 183, 54, 186])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.AcceptGuardian;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([127, 223, 83, 67, 130, 211, 168, 227])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.CancelGuardianTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([26, 163, 183, 165, 90, 187, 191, 166])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.GetBaseFee;
+  }
   if (
     containsBytes(
       data,
@@ -82,6 +123,17 @@ export function identifyBaseRelayerInstruction(
   ) {
     return BaseRelayerInstruction.Initialize;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([61, 19, 130, 183, 107, 189, 39, 205])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.MigrateCfg;
+  }
   if (
     containsBytes(
       data,
@@ -97,34 +149,34 @@ export function identifyBaseRelayerInstruction(
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([197, 222, 225, 215, 15, 3, 82, 102])
+        new Uint8Array([39, 11, 248, 174, 73, 240, 0, 211])
       ),
       0
     )
   ) {
-    return BaseRelayerInstruction.SetEip1559Config;
+    return BaseRelayerInstruction.ProposeGuardian;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([244, 68, 172, 138, 187, 145, 160, 192])
+        new Uint8Array([197, 222, 225, 215, 15, 3, 82, 102])
       ),
       0
     )
   ) {
-    return BaseRelayerInstruction.SetGasConfig;
+    return BaseRelayerInstruction.SetEip1559Config;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([147, 243, 50, 121, 154, 164, 50, 30])
+        new Uint8Array([244, 68, 172, 138, 187, 145, 160, 192])
       ),
       0
     )
   ) {
-    return BaseRelayerInstruction.SetGuardian;
+    return BaseRelayerInstruction.SetGasConfig;
   }
   throw new Error(
     'The provided instruction could not be identified as a baseRelayer instruction.'
@@ -132,18 +184,30 @@ export function identifyBaseRelayerInstruction(
 }
 
 export type ParsedBaseRelayerInstruction<TProgram extends string = ''> =
+  | ({
+      instructionType: BaseRelayerInstruction.AcceptGuardian;
+    } & ParsedAcceptGuardianInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.CancelGuardianTransfer;
+    } & ParsedCancelGuardianTransferInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.GetBaseFee;
+    } & ParsedGetBaseFeeInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.MigrateCfg;
+    } & ParsedMigrateCfgInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.PayForRelay;
     } & ParsedPayForRelayInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.ProposeGuardian;
+    } & ParsedProposeGuardianInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.SetEip1559Config;
     } & ParsedSetEip1559ConfigInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.SetGasConfig;
-    } & ParsedSetGasConfigInstruction<TProgram>)
-  | ({
-      instructionType: BaseRelayerInstruction.SetGuardian;
-    } & ParsedSetGuardianInstruction<TProgram>);
+    } & ParsedSetGasConfigInstruction<TProgram>);
@@ -25,6 +25,8 @@ export type GasConfig = {
   minGasLimitPerMessage: bigint;
   /** Maximum gas limit per cross-chain message */
   maxGasLimitPerMessage: bigint;
+  /** Gas limit applied when a relay request passes a gas limit of zero */
+  defaultGasLimitPerMessage: bigint;
   /** Scaling factor for gas cost calculations */
   gasCostScaler: bigint;
   /** Decimal precision for gas cost calculations */
@@ -38,6 +40,8 @@ export type GasConfigArgs = {
   minGasLimitPerMessage: number | bigint;
   /** Maximum gas limit per cross-chain message */
   maxGasLimitPerMessage: number | bigint;
+  /** Gas limit applied when a relay request passes a gas limit of zero */
+  defaultGasLimitPerMessage: number | bigint;
   /** Scaling factor for gas cost calculations */
   gasCostScaler: number | bigint;
   /** Decimal precision for gas cost calculations */
@@ -50,6 +54,7 @@ export function getGasConfigEncoder(): FixedSizeEncoder<GasConfigArgs> {
   return getStructEncoder([
     ['minGasLimitPerMessage', getU64Encoder()],
     ['maxGasLimitPerMessage', getU64Encoder()],
+    ['defaultGasLimitPerMessage', getU64Encoder()],
     ['gasCostScaler', getU64Encoder()],
     ['gasCostScalerDp', getU64Encoder()],
     ['gasFeeReceiver', getAddressEncoder()],
@@ -60,6 +65,7 @@ export function getGasConfigDecoder(): FixedSizeDecoder<GasConfig> {
   return getStructDecoder([
     ['minGasLimitPerMessage', getU64Decoder()],
     ['maxGasLimitPerMessage', getU64Decoder()],
+    ['defaultGasLimitPerMessage', getU64Decoder()],
     ['gasCostScaler', getU64Decoder()],
     ['gasCostScalerDp', getU64Decoder()],
     ['gasFeeReceiver', getAddressDecoder()],
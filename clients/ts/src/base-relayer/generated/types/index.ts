@@ -6,6 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './baseFee';
 export * from './eip1559';
 export * from './eip1559Config';
 export * from './gasConfig';
+export * from './guardianTransferAccepted';
+export * from './guardianTransferCancelled';
+export * from './guardianTransferProposed';
@@ -21,21 +21,29 @@ import {
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -51,6 +59,10 @@ import {
   getPartnerOracleConfigEncoder,
   getProtocolConfigDecoder,
   getProtocolConfigEncoder,
+  getRegistrationBucketDecoder,
+  getRegistrationBucketEncoder,
+  getVolumeWindowDecoder,
+  getVolumeWindowEncoder,
   type BaseOracleConfig,
   type BaseOracleConfigArgs,
   type BufferConfig,
@@ -63,6 +75,10 @@ import {
   type PartnerOracleConfigArgs,
   type ProtocolConfig,
   type ProtocolConfigArgs,
+  type RegistrationBucket,
+  type RegistrationBucketArgs,
+  type VolumeWindow,
+  type VolumeWindowArgs,
 } from '../types';
 
 export const BRIDGE_DISCRIMINATOR = new Uint8Array([
@@ -77,12 +93,43 @@ export type Bridge = {
   discriminator: ReadonlyUint8Array;
   /** The Base block number associated with the latest registered output root. */
   baseBlockNumber: bigint;
+  /** Unix timestamp of the latest output root registration. */
+  lastRegistrationTime: bigint;
+  /** Output root of the latest registration. */
+  lastOutputRoot: ReadonlyUint8Array;
+  /** Leaky bucket limiting the rate of output root registrations. */
+  registrationBucket: RegistrationBucket;
   /** Incremental nonce assigned to each outgoing message. */
   nonce: bigint;
+  /**
+   * Highest Base nonce among the incoming messages relayed so far (`None` before the first
+   * relay).
+   */
+  highestRelayedNonce: Option<bigint>;
+  /** Lamports locked in the SOL vault by `bridge_sol` and not yet released by a relayed transfer. */
+  lockedSol: bigint;
+  /** Number of wrapped mints created by `wrap_token`. */
+  wrappedTokenCount: bigint;
+  /** Volume of SOL bridged from Solana to Base in the current fixed daily window. */
+  outboundSolVolume: VolumeWindow;
+  /** Volume of SOL finalized from Base to Solana in the current fixed daily window. */
+  inboundSolVolume: VolumeWindow;
   /** Guardian pubkey authorized to update bridge configuration parameters */
   guardian: Address;
-  /** Whether the bridge is paused (emergency stop mechanism) */
+  /** Guardian proposed by the current guardian, pending acceptance */
+  pendingGuardian: Option<Address>;
+  /**
+   * Second guardian that must co-sign critical configuration changes (the default pubkey
+   * leaves those changes to the guardian alone)
+   */
+  coGuardian: Address;
+  /**
+   * Whether the bridge is paused (emergency stop mechanism). Acts as a master switch over
+   * `paused_ops`.
+   */
   paused: boolean;
+  /** Bitmask of individually paused operations (`PAUSE_*` flags) */
+  pausedOps: number;
   /** EIP-1559 state and configuration for dynamic pricing. */
   eip1559: Eip1559;
   /** Configuration parameters for outgoing message pricing */
@@ -95,17 +142,53 @@ export type Bridge = {
   partnerOracleConfig: PartnerOracleConfig;
   /** Configuration parameters for Base oracle signers */
   baseOracleConfig: BaseOracleConfig;
+  /**
+   * Incremented on every change to `base_oracle_config`. Output root re-validations sign it,
+   * so approvals given to one signer set cannot be replayed under another.
+   */
+  baseOracleEpoch: bigint;
 };
 
 export type BridgeArgs = {
   /** The Base block number associated with the latest registered output root. */
   baseBlockNumber: number | bigint;
+  /** Unix timestamp of the latest output root registration. */
+  lastRegistrationTime: number | bigint;
+  /** Output root of the latest registration. */
+  lastOutputRoot: ReadonlyUint8Array;
+  /** Leaky bucket limiting the rate of output root registrations. */
+  registrationBucket: RegistrationBucketArgs;
   /** Incremental nonce assigned to each outgoing message. */
   nonce: number | bigint;
+  /**
+   * Highest Base nonce among the incoming messages relayed so far (`None` before the first
+   * relay).
+   */
+  highestRelayedNonce: OptionOrNullable<number | bigint>;
+  /** Lamports locked in the SOL vault by `bridge_sol` and not yet released by a relayed transfer. */
+  lockedSol: number | bigint;
+  /** Number of wrapped mints created by `wrap_token`. */
+  wrappedTokenCount: number | bigint;
+  /** Volume of SOL bridged from Solana to Base in the current fixed daily window. */
+  outboundSolVolume: VolumeWindowArgs;
+  /** Volume of SOL finalized from Base to Solana in the current fixed daily window. */
+  inboundSolVolume: VolumeWindowArgs;
   /** Guardian pubkey authorized to update bridge configuration parameters */
   guardian: Address;
-  /** Whether the bridge is paused (emergency stop mechanism) */
+  /** Guardian proposed by the current guardian, pending acceptance */
+  pendingGuardian: OptionOrNullable<Address>;
+  /**
+   * Second guardian that must co-sign critical configuration changes (the default pubkey
+   * leaves those changes to the guardian alone)
+   */
+  coGuardian: Address;
+  /**
+   * Whether the bridge is paused (emergency stop mechanism). Acts as a master switch over
+   * `paused_ops`.
+   */
   paused: boolean;
+  /** Bitmask of individually paused operations (`PAUSE_*` flags) */
+  pausedOps: number;
   /** EIP-1559 state and configuration for dynamic pricing. */
   eip1559: Eip1559Args;
   /** Configuration parameters for outgoing message pricing */
@@ -118,44 +201,73 @@ export type BridgeArgs = {
   partnerOracleConfig: PartnerOracleConfigArgs;
   /** Configuration parameters for Base oracle signers */
   baseOracleConfig: BaseOracleConfigArgs;
+  /**
+   * Incremented on every change to `base_oracle_config`. Output root re-validations sign it,
+   * so approvals given to one signer set cannot be replayed under another.
+   */
+  baseOracleEpoch: number | bigint;
 };
 
-export function getBridgeEncoder(): FixedSizeEncoder<BridgeArgs> {
+export function getBridgeEncoder(): Encoder<BridgeArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['baseBlockNumber', getU64Encoder()],
+      ['lastRegistrationTime', getI64Encoder()],
+      ['lastOutputRoot', fixEncoderSize(getBytesEncoder(), 32)],
+      ['registrationBucket', getRegistrationBucketEncoder()],
       ['nonce', getU64Encoder()],
+      ['highestRelayedNonce', getOptionEncoder(getU64Encoder())],
+      ['lockedSol', getU64Encoder()],
+      ['wrappedTokenCount', getU64Encoder()],
+      ['outboundSolVolume', getVolumeWindowEncoder()],
+      ['inboundSolVolume', getVolumeWindowEncoder()],
       ['guardian', getAddressEncoder()],
+      ['pendingGuardian', getOptionEncoder(getAddressEncoder())],
+      ['coGuardian', getAddressEncoder()],
       ['paused', getBooleanEncoder()],
+      ['pausedOps', getU16Encoder()],
       ['eip1559', getEip1559Encoder()],
       ['gasConfig', getGasConfigEncoder()],
       ['protocolConfig', getProtocolConfigEncoder()],
       ['bufferConfig', getBufferConfigEncoder()],
       ['partnerOracleConfig', getPartnerOracleConfigEncoder()],
       ['baseOracleConfig', getBaseOracleConfigEncoder()],
+      ['baseOracleEpoch', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_DISCRIMINATOR })
   );
 }
 
-export function getBridgeDecoder(): FixedSizeDecoder<Bridge> {
+export function getBridgeDecoder(): Decoder<Bridge> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['baseBlockNumber', getU64Decoder()],
+    ['lastRegistrationTime', getI64Decoder()],
+    ['lastOutputRoot', fixDecoderSize(getBytesDecoder(), 32)],
+    ['registrationBucket', getRegistrationBucketDecoder()],
     ['nonce', getU64Decoder()],
+    ['highestRelayedNonce', getOptionDecoder(getU64Decoder())],
+    ['lockedSol', getU64Decoder()],
+    ['wrappedTokenCount', getU64Decoder()],
+    ['outboundSolVolume', getVolumeWindowDecoder()],
+    ['inboundSolVolume', getVolumeWindowDecoder()],
     ['guardian', getAddressDecoder()],
+    ['pendingGuardian', getOptionDecoder(getAddressDecoder())],
+    ['coGuardian', getAddressDecoder()],
     ['paused', getBooleanDecoder()],
+    ['pausedOps', getU16Decoder()],
     ['eip1559', getEip1559Decoder()],
     ['gasConfig', getGasConfigDecoder()],
     ['protocolConfig', getProtocolConfigDecoder()],
     ['bufferConfig', getBufferConfigDecoder()],
     ['partnerOracleConfig', getPartnerOracleConfigDecoder()],
     ['baseOracleConfig', getBaseOracleConfigDecoder()],
+    ['baseOracleEpoch', getU64Decoder()],
   ]);
 }
 
-export function getBridgeCodec(): FixedSizeCodec<BridgeArgs, Bridge> {
+export function getBridgeCodec(): Codec<BridgeArgs, Bridge> {
   return combineCodec(getBridgeEncoder(), getBridgeDecoder());
 }
 
@@ -211,7 +323,3 @@ export async function fetchAllMaybeBridge(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeBridge(maybeAccount));
 }
-
-export function getBridgeSize(): number {
-  return 528;
-}
@@ -21,12 +21,16 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -38,6 +42,8 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
@@ -71,6 +77,24 @@ export type CallBuffer = {
   to: ReadonlyUint8Array;
   /** The amount of Base native currency (ETH) to send with this call, in wei. */
   value: bigint;
+  /**
+   * Maximum number of appends allowed on this buffer (0 means unlimited), copied from
+   * `bridge.buffer_config.max_call_buffer_appends` at initialization.
+   */
+  maxAppends: bigint;
+  /** Number of appends made to this buffer so far. */
+  appendCount: bigint;
+  /**
+   * Keccak-256 hash `data` must have when the buffer is bridged, committed at initialization
+   * so a dropped or reordered append fails on Solana instead of on Base.
+   */
+  expectedHash: Option<ReadonlyUint8Array>;
+  /**
+   * Keccak-256 hash chain over the segments written to `data`, folded as
+   * `keccak(running_hash || segment)` starting from zero with `initial_data`. Clients recompute
+   * it after each append to catch a dropped or reordered append before bridging.
+   */
+  runningHash: ReadonlyUint8Array;
   /**
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
@@ -94,6 +118,24 @@ export type CallBufferArgs = {
   to: ReadonlyUint8Array;
   /** The amount of Base native currency (ETH) to send with this call, in wei. */
   value: number | bigint;
+  /**
+   * Maximum number of appends allowed on this buffer (0 means unlimited), copied from
+   * `bridge.buffer_config.max_call_buffer_appends` at initialization.
+   */
+  maxAppends: number | bigint;
+  /** Number of appends made to this buffer so far. */
+  appendCount: number | bigint;
+  /**
+   * Keccak-256 hash `data` must have when the buffer is bridged, committed at initialization
+   * so a dropped or reordered append fails on Solana instead of on Base.
+   */
+  expectedHash: OptionOrNullable<ReadonlyUint8Array>;
+  /**
+   * Keccak-256 hash chain over the segments written to `data`, folded as
+   * `keccak(running_hash || segment)` starting from zero with `initial_data`. Clients recompute
+   * it after each append to catch a dropped or reordered append before bridging.
+   */
+  runningHash: ReadonlyUint8Array;
   /**
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
@@ -110,6 +152,10 @@ export function getCallBufferEncoder(): Encoder<CallBufferArgs> {
       ['ty', getCallTypeEncoder()],
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['value', getU128Encoder()],
+      ['maxAppends', getU64Encoder()],
+      ['appendCount', getU64Encoder()],
+      ['expectedHash', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+      ['runningHash', fixEncoderSize(getBytesEncoder(), 32)],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
     (value) => ({ ...value, discriminator: CALL_BUFFER_DISCRIMINATOR })
@@ -123,6 +169,10 @@ export function getCallBufferDecoder(): Decoder<CallBuffer> {
     ['ty', getCallTypeDecoder()],
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['value', getU128Decoder()],
+    ['maxAppends', getU64Decoder()],
+    ['appendCount', getU64Decoder()],
+    ['expectedHash', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    ['runningHash', fixDecoderSize(getBytesDecoder(), 32)],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
 }
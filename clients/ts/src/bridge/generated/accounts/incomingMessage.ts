@@ -19,8 +19,14 @@ import {
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -53,6 +59,13 @@ export function getIncomingMessageDiscriminatorBytes() {
 
 export type IncomingMessage = {
   discriminator: ReadonlyUint8Array;
+  /**
+   * Layout version of this account. Checked on relay so that accounts written with a
+   * different layout are rejected instead of being silently mis-parsed.
+   */
+  version: number;
+  /** The nonce assigned to this message on Base. */
+  nonce: bigint;
   /**
    * The 20-byte EVM address of the sender on Base who initiated this bridge operation.
    * Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.
@@ -69,9 +82,21 @@ export type IncomingMessage = {
    * Once set to true, the message cannot be executed again, preventing replay attacks.
    */
   executed: boolean;
+  /**
+   * Unix timestamp at which the message was proven. Relays are rejected until the configured
+   * `min_prove_to_relay_delay_seconds` has elapsed since then.
+   */
+  provenAt: bigint;
 };
 
 export type IncomingMessageArgs = {
+  /**
+   * Layout version of this account. Checked on relay so that accounts written with a
+   * different layout are rejected instead of being silently mis-parsed.
+   */
+  version: number;
+  /** The nonce assigned to this message on Base. */
+  nonce: number | bigint;
   /**
    * The 20-byte EVM address of the sender on Base who initiated this bridge operation.
    * Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.
@@ -88,15 +113,23 @@ export type IncomingMessageArgs = {
    * Once set to true, the message cannot be executed again, preventing replay attacks.
    */
   executed: boolean;
+  /**
+   * Unix timestamp at which the message was proven. Relays are rejected until the configured
+   * `min_prove_to_relay_delay_seconds` has elapsed since then.
+   */
+  provenAt: number | bigint;
 };
 
 export function getIncomingMessageEncoder(): Encoder<IncomingMessageArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
+      ['nonce', getU64Encoder()],
       ['sender', fixEncoderSize(getBytesEncoder(), 20)],
       ['message', getBridgeBaseToSolanaStateIncomingMessageMessageEncoder()],
       ['executed', getBooleanEncoder()],
+      ['provenAt', getI64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: INCOMING_MESSAGE_DISCRIMINATOR })
   );
@@ -105,9 +138,12 @@ export function getIncomingMessageEncoder(): Encoder<IncomingMessageArgs> {
 export function getIncomingMessageDecoder(): Decoder<IncomingMessage> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
+    ['nonce', getU64Decoder()],
     ['sender', fixDecoderSize(getBytesDecoder(), 20)],
     ['message', getBridgeBaseToSolanaStateIncomingMessageMessageDecoder()],
     ['executed', getBooleanDecoder()],
+    ['provenAt', getI64Decoder()],
   ]);
 }
 
@@ -8,7 +8,11 @@
 
 export * from './bridge';
 export * from './callBuffer';
+export * from './deniedRemoteToken';
 export * from './incomingMessage';
 export * from './outgoingMessage';
 export * from './outputRoot';
 export * from './proveBuffer';
+export * from './relayCursor';
+export * from './remoteTokenRateLimit';
+export * from './wrappedTokenInfo';
@@ -17,6 +17,8 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -56,6 +58,16 @@ export type OutputRoot = {
    * mountain configuration at the time of proof validation.
    */
   totalLeafCount: bigint;
+  /** Solana unix timestamp at which this root was registered. */
+  registeredAt: bigint;
+  /** The Base block number this root was registered for. */
+  baseBlockNumber: bigint;
+  /**
+   * The `base_oracle_epoch` in which the oracle set co-signed this root again through
+   * `revalidate_output_root`, or `NOT_REVALIDATED`. Rotating the oracle set starts a new epoch,
+   * so the root must then be re-validated by the new set.
+   */
+  revalidatedEpoch: bigint;
 };
 
 export type OutputRootArgs = {
@@ -70,6 +82,16 @@ export type OutputRootArgs = {
    * mountain configuration at the time of proof validation.
    */
   totalLeafCount: number | bigint;
+  /** Solana unix timestamp at which this root was registered. */
+  registeredAt: number | bigint;
+  /** The Base block number this root was registered for. */
+  baseBlockNumber: number | bigint;
+  /**
+   * The `base_oracle_epoch` in which the oracle set co-signed this root again through
+   * `revalidate_output_root`, or `NOT_REVALIDATED`. Rotating the oracle set starts a new epoch,
+   * so the root must then be re-validated by the new set.
+   */
+  revalidatedEpoch: number | bigint;
 };
 
 export function getOutputRootEncoder(): FixedSizeEncoder<OutputRootArgs> {
@@ -78,6 +100,9 @@ export function getOutputRootEncoder(): FixedSizeEncoder<OutputRootArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['root', fixEncoderSize(getBytesEncoder(), 32)],
       ['totalLeafCount', getU64Encoder()],
+      ['registeredAt', getI64Encoder()],
+      ['baseBlockNumber', getU64Encoder()],
+      ['revalidatedEpoch', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: OUTPUT_ROOT_DISCRIMINATOR })
   );
@@ -88,6 +113,9 @@ export function getOutputRootDecoder(): FixedSizeDecoder<OutputRoot> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['root', fixDecoderSize(getBytesDecoder(), 32)],
     ['totalLeafCount', getU64Decoder()],
+    ['registeredAt', getI64Decoder()],
+    ['baseBlockNumber', getU64Decoder()],
+    ['revalidatedEpoch', getU64Decoder()],
   ]);
 }
 
@@ -152,5 +180,5 @@ export async function fetchAllMaybeOutputRoot(
 }
 
 export function getOutputRootSize(): number {
-  return 48;
+  return 72;
 }
@@ -27,6 +27,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -55,6 +57,10 @@ export type ProveBuffer = {
   discriminator: ReadonlyUint8Array;
   /** The owner who can modify and eventually consume this buffer */
   owner: Address;
+  /** Maximum number of `data` bytes this buffer was allocated for */
+  maxDataLen: bigint;
+  /** Maximum number of `proof` nodes this buffer was allocated for */
+  maxProofLen: bigint;
   /** Serialized `Message` data (Anchor-serialized) */
   data: ReadonlyUint8Array;
   /** MMR proof nodes used to validate inclusion against an OutputRoot */
@@ -64,6 +70,10 @@ export type ProveBuffer = {
 export type ProveBufferArgs = {
   /** The owner who can modify and eventually consume this buffer */
   owner: Address;
+  /** Maximum number of `data` bytes this buffer was allocated for */
+  maxDataLen: number | bigint;
+  /** Maximum number of `proof` nodes this buffer was allocated for */
+  maxProofLen: number | bigint;
   /** Serialized `Message` data (Anchor-serialized) */
   data: ReadonlyUint8Array;
   /** MMR proof nodes used to validate inclusion against an OutputRoot */
@@ -75,6 +85,8 @@ export function getProveBufferEncoder(): Encoder<ProveBufferArgs> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['owner', getAddressEncoder()],
+      ['maxDataLen', getU64Encoder()],
+      ['maxProofLen', getU64Encoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['proof', getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32))],
     ]),
@@ -86,6 +98,8 @@ export function getProveBufferDecoder(): Decoder<ProveBuffer> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['owner', getAddressDecoder()],
+    ['maxDataLen', getU64Decoder()],
+    ['maxProofLen', getU64Decoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['proof', getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32))],
   ]);
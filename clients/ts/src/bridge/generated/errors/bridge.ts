@@ -20,16 +20,46 @@ export const BRIDGE_ERROR__BRIDGE_PAUSED = 0x2ee0; // 12000
 export const BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM = 0x2ee1; // 12001
 /** IncorrectGasFeeReceiver: Incorrect gas fee receiver */
 export const BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER = 0x2ee2; // 12002
+/** GasCostOverflow: Gas cost does not fit in a u64 */
+export const BRIDGE_ERROR__GAS_COST_OVERFLOW = 0x2ee3; // 12003
+/** QuoteOverflow: Quoted total cost does not fit in a u64 */
+export const BRIDGE_ERROR__QUOTE_OVERFLOW = 0x2ee4; // 12004
+/** AlreadyMigrated: Account is already migrated or does not have the legacy layout */
+export const BRIDGE_ERROR__ALREADY_MIGRATED = 0x2ee5; // 12005
 /** UnauthorizedInitialization: Only the upgrade authority can initialize the bridge */
 export const BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION = 0x2f44; // 12100
 /** UnauthorizedConfigUpdate: Unauthorized to update configuration */
 export const BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE = 0x2f45; // 12101
+/** GuardianApprovalRequired: Guardian approval is required for this finalization */
+export const BRIDGE_ERROR__GUARDIAN_APPROVAL_REQUIRED = 0x2f46; // 12102
+/** SponsorNotAllowed: Sender must also be the payer */
+export const BRIDGE_ERROR__SPONSOR_NOT_ALLOWED = 0x2f47; // 12103
+/** UnauthorizedGuardianAcceptance: Only the pending guardian can accept the guardian role */
+export const BRIDGE_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE = 0x2f48; // 12104
+/** InvalidPartnerConfigOwner: Partner config account is not owned by the partner program */
+export const BRIDGE_ERROR__INVALID_PARTNER_CONFIG_OWNER = 0x2f49; // 12105
+/** CoGuardianSignatureRequired: The co-guardian must also sign this configuration change */
+export const BRIDGE_ERROR__CO_GUARDIAN_SIGNATURE_REQUIRED = 0x2f4a; // 12106
+/** InvalidCoGuardian: The co-guardian must differ from the guardian */
+export const BRIDGE_ERROR__INVALID_CO_GUARDIAN = 0x2f4b; // 12107
 /** BufferUnauthorizedClose: Only the owner can close this buffer */
 export const BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE = 0x2fa8; // 12200
 /** BufferUnauthorizedAppend: Only the owner can append to this buffer */
 export const BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND = 0x2fa9; // 12201
 /** BufferMaxSizeExceeded: Call buffer size exceeds maximum allowed size */
 export const BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED = 0x2faa; // 12202
+/** ProveBufferMaxProofLenExceeded: Prove buffer proof length exceeds maximum allowed length */
+export const BRIDGE_ERROR__PROVE_BUFFER_MAX_PROOF_LEN_EXCEEDED = 0x2fab; // 12203
+/** ProveBufferCapacityExceeded: Append exceeds the prove buffer's allocated capacity */
+export const BRIDGE_ERROR__PROVE_BUFFER_CAPACITY_EXCEEDED = 0x2fac; // 12204
+/** TooManyAppends: Call buffer has reached its maximum number of appends */
+export const BRIDGE_ERROR__TOO_MANY_APPENDS = 0x2fad; // 12205
+/** BufferUnauthorizedTransfer: Only the owner can transfer this buffer */
+export const BRIDGE_ERROR__BUFFER_UNAUTHORIZED_TRANSFER = 0x2fae; // 12206
+/** BufferChecksumMismatch: Call buffer data does not match the committed hash */
+export const BRIDGE_ERROR__BUFFER_CHECKSUM_MISMATCH = 0x2faf; // 12207
+/** BufferOverflow: Append would grow the call buffer past its capacity */
+export const BRIDGE_ERROR__BUFFER_OVERFLOW = 0x2fb0; // 12208
 /** InvalidRecoveryId: Invalid recovery ID */
 export const BRIDGE_ERROR__INVALID_RECOVERY_ID = 0x300c; // 12300
 /** SignatureVerificationFailed: Signature verification failed */
@@ -38,6 +68,8 @@ export const BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED = 0x300d; // 12301
 export const BRIDGE_ERROR__INSUFFICIENT_BASE_SIGNATURES = 0x300e; // 12302
 /** InsufficientPartnerSignatures: Insufficient partner oracle signatures to meet threshold */
 export const BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES = 0x300f; // 12303
+/** OverlappingSignerSets: Signer approved as both a Base and a partner oracle */
+export const BRIDGE_ERROR__OVERLAPPING_SIGNER_SETS = 0x3010; // 12304
 /** InvalidProof: Invalid proof */
 export const BRIDGE_ERROR__INVALID_PROOF = 0x3070; // 12400
 /** MmrShouldBeEmpty: MMR should be empty */
@@ -54,12 +86,50 @@ export const BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS
 export const BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING = 0x3076; // 12406
 /** NoPeaksFoundForNonEmptyMmr: No peaks found for non-empty MMR */
 export const BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR = 0x3077; // 12407
+/** ProofTooLong: Proof is longer than any valid proof for this MMR */
+export const BRIDGE_ERROR__PROOF_TOO_LONG = 0x3078; // 12408
 /** InvalidMessageHash: Invalid message hash */
 export const BRIDGE_ERROR__INVALID_MESSAGE_HASH = 0x30d4; // 12500
 /** AlreadyExecuted: Message already executed */
 export const BRIDGE_ERROR__ALREADY_EXECUTED = 0x30d5; // 12501
 /** IncorrectBlockNumber: Incorrect block number */
 export const BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER = 0x30d6; // 12502
+/** UnsupportedMessageVersion: Unsupported message version */
+export const BRIDGE_ERROR__UNSUPPORTED_MESSAGE_VERSION = 0x30d7; // 12503
+/** RegistrationTooSoon: Output root registration is too soon after the previous one */
+export const BRIDGE_ERROR__REGISTRATION_TOO_SOON = 0x30d8; // 12504
+/** OutputRootTooOld: Output root is older than the maximum allowed age */
+export const BRIDGE_ERROR__OUTPUT_ROOT_TOO_OLD = 0x30d9; // 12505
+/** RegistrationRateLimited: Output root registrations are rate limited */
+export const BRIDGE_ERROR__REGISTRATION_RATE_LIMITED = 0x30da; // 12506
+/** DuplicateOutputRoot: Output root is identical to the previously registered root */
+export const BRIDGE_ERROR__DUPLICATE_OUTPUT_ROOT = 0x30db; // 12507
+/** ProtectedAccountMutation: Relayed instruction targets a protected bridge account */
+export const BRIDGE_ERROR__PROTECTED_ACCOUNT_MUTATION = 0x30dc; // 12508
+/** RootNotRegistered: Output root is not within the registered range */
+export const BRIDGE_ERROR__ROOT_NOT_REGISTERED = 0x30dd; // 12509
+/** InvalidRelaySlice: Relay slice is empty or extends past the message instructions */
+export const BRIDGE_ERROR__INVALID_RELAY_SLICE = 0x30de; // 12510
+/** CannotCloseLatestOutputRoot: The latest output root cannot be closed */
+export const BRIDGE_ERROR__CANNOT_CLOSE_LATEST_OUTPUT_ROOT = 0x30df; // 12511
+/** OutputRootCloseTimelocked: Output root is too recent to be closed */
+export const BRIDGE_ERROR__OUTPUT_ROOT_CLOSE_TIMELOCKED = 0x30e0; // 12512
+/** OutputRootWithinCloseMargin: Output root is within the close margin of the latest registered block */
+export const BRIDGE_ERROR__OUTPUT_ROOT_WITHIN_CLOSE_MARGIN = 0x30e1; // 12513
+/** OutputRootRequiresRevalidation: Output root must be re-validated by the current oracle set */
+export const BRIDGE_ERROR__OUTPUT_ROOT_REQUIRES_REVALIDATION = 0x30e2; // 12514
+/** BlockNumberTooFarAhead: Base block number is too far ahead of the latest registered block */
+export const BRIDGE_ERROR__BLOCK_NUMBER_TOO_FAR_AHEAD = 0x30e3; // 12515
+/** InvalidOutputRootBatch: Output root batch is empty, too large or does not match the provided root accounts */
+export const BRIDGE_ERROR__INVALID_OUTPUT_ROOT_BATCH = 0x30e4; // 12516
+/** RelayTooSoon: Message was proven too recently to be relayed */
+export const BRIDGE_ERROR__RELAY_TOO_SOON = 0x30e5; // 12517
+/** DuplicateRelayedInstruction: Message contains duplicate instructions */
+export const BRIDGE_ERROR__DUPLICATE_RELAYED_INSTRUCTION = 0x30e6; // 12518
+/** RelayNonceOutOfOrder: Message nonce is not above the highest relayed nonce */
+export const BRIDGE_ERROR__RELAY_NONCE_OUT_OF_ORDER = 0x30e7; // 12519
+/** NoInstructionsToRelay: Message has no instructions to relay in slices, use relay_message instead */
+export const BRIDGE_ERROR__NO_INSTRUCTIONS_TO_RELAY = 0x30e8; // 12520
 /** MintDoesNotMatchLocalToken: Mint does not match local token */
 export const BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN = 0x3138; // 12600
 /** TokenAccountDoesNotMatchTo: Token account does not match to address */
@@ -72,6 +142,24 @@ export const BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN = 0x313b; // 12603
 export const BRIDGE_ERROR__INCORRECT_TO = 0x313c; // 12604
 /** IncorrectSolVault: Incorrect sol vault */
 export const BRIDGE_ERROR__INCORRECT_SOL_VAULT = 0x313d; // 12605
+/** SolReleaseExceedsLocked: SOL release exceeds locked amount */
+export const BRIDGE_ERROR__SOL_RELEASE_EXCEEDS_LOCKED = 0x313e; // 12606
+/** WouldDrainPayer: Bridge would leave the sender below the requested reserve */
+export const BRIDGE_ERROR__WOULD_DRAIN_PAYER = 0x313f; // 12607
+/** WrappedTokenLimitReached: Maximum number of wrapped tokens reached */
+export const BRIDGE_ERROR__WRAPPED_TOKEN_LIMIT_REACHED = 0x3140; // 12608
+/** RemoteTokenDenied: Remote token is denied */
+export const BRIDGE_ERROR__REMOTE_TOKEN_DENIED = 0x3141; // 12609
+/** IncorrectDeniedRemoteToken: Incorrect denied remote token account */
+export const BRIDGE_ERROR__INCORRECT_DENIED_REMOTE_TOKEN = 0x3142; // 12610
+/** InvalidBridgePayload: Invalid bridge transfer payload */
+export const BRIDGE_ERROR__INVALID_BRIDGE_PAYLOAD = 0x3143; // 12611
+/** DailyLimitExceeded: Daily bridged volume limit exceeded */
+export const BRIDGE_ERROR__DAILY_LIMIT_EXCEEDED = 0x3144; // 12612
+/** RateLimitExceeded: Remote token rate limit exceeded */
+export const BRIDGE_ERROR__RATE_LIMIT_EXCEEDED = 0x3145; // 12613
+/** IncorrectRateLimit: Incorrect rate limit account */
+export const BRIDGE_ERROR__INCORRECT_RATE_LIMIT = 0x3146; // 12614
 /** RemoteTokenNotFound: Remote token not found */
 export const BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND = 0x319c; // 12700
 /** ScalerExponentNotFound: Scaler exponent not found */
@@ -84,6 +172,8 @@ export const BRIDGE_ERROR__INVALID_SCALER_EXPONENT = 0x319f; // 12703
 export const BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022 = 0x31a0; // 12704
 /** MintIsNotWrappedTokenPda: Mint is not a valid wrapped token PDA */
 export const BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA = 0x31a1; // 12705
+/** MetadataTooLong: Token name or symbol exceeds the maximum length */
+export const BRIDGE_ERROR__METADATA_TOO_LONG = 0x31a2; // 12706
 /** InvalidThreshold: Threshold must be <= number of signers */
 export const BRIDGE_ERROR__INVALID_THRESHOLD = 0x3200; // 12800
 /** TooManySigners: Too many signers (max 32) */
@@ -100,6 +190,22 @@ export const BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS = 0x3205; // 12805
 export const BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP = 0x3206; // 12806
 /** InvalidBlockIntervalRequirement: Invalid block interval requirement */
 export const BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT = 0x3207; // 12807
+/** IncompatibleBlockInterval: Block interval requirement is incompatible with the latest registered block */
+export const BRIDGE_ERROR__INCOMPATIBLE_BLOCK_INTERVAL = 0x3208; // 12808
+/** InvalidRegistrationRateLimit: Registration rate limit requires a non-zero refill interval */
+export const BRIDGE_ERROR__INVALID_REGISTRATION_RATE_LIMIT = 0x3209; // 12809
+/** InvalidBaseFee: Base fee is outside [minimum_base_fee, MAX_BASE_FEE] */
+export const BRIDGE_ERROR__INVALID_BASE_FEE = 0x320a; // 12810
+/** SignerNotFound: Signer is not part of the oracle set */
+export const BRIDGE_ERROR__SIGNER_NOT_FOUND = 0x320b; // 12811
+/** InvalidRateLimitWindow: Rate limit window must be non-zero */
+export const BRIDGE_ERROR__INVALID_RATE_LIMIT_WINDOW = 0x320c; // 12812
+/** InvalidPausedOps: Paused operations mask contains unknown flags */
+export const BRIDGE_ERROR__INVALID_PAUSED_OPS = 0x320d; // 12813
+/** InvalidFeeBounds: Maximum fee must be zero or at least the minimum fee */
+export const BRIDGE_ERROR__INVALID_FEE_BOUNDS = 0x320e; // 12814
+/** InvalidGasMultiplier: Gas multiplier must be non-zero and at most MAX_GAS_MULTIPLIER_BPS */
+export const BRIDGE_ERROR__INVALID_GAS_MULTIPLIER = 0x320f; // 12815
 /** CreationWithNonZeroTarget: Creation with non-zero target */
 export const BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET = 0x3264; // 12900
 /** ZeroAddress: Zero address */
@@ -107,16 +213,31 @@ export const BRIDGE_ERROR__ZERO_ADDRESS = 0x3265; // 12901
 
 export type BridgeError =
   | typeof BRIDGE_ERROR__ALREADY_EXECUTED
+  | typeof BRIDGE_ERROR__ALREADY_MIGRATED
+  | typeof BRIDGE_ERROR__BLOCK_NUMBER_TOO_FAR_AHEAD
   | typeof BRIDGE_ERROR__BRIDGE_PAUSED
+  | typeof BRIDGE_ERROR__BUFFER_CHECKSUM_MISMATCH
   | typeof BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED
+  | typeof BRIDGE_ERROR__BUFFER_OVERFLOW
   | typeof BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND
   | typeof BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE
+  | typeof BRIDGE_ERROR__BUFFER_UNAUTHORIZED_TRANSFER
+  | typeof BRIDGE_ERROR__CANNOT_CLOSE_LATEST_OUTPUT_ROOT
+  | typeof BRIDGE_ERROR__CO_GUARDIAN_SIGNATURE_REQUIRED
   | typeof BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET
+  | typeof BRIDGE_ERROR__DAILY_LIMIT_EXCEEDED
+  | typeof BRIDGE_ERROR__DUPLICATE_OUTPUT_ROOT
+  | typeof BRIDGE_ERROR__DUPLICATE_RELAYED_INSTRUCTION
   | typeof BRIDGE_ERROR__DUPLICATE_SIGNER
   | typeof BRIDGE_ERROR__EMPTY_MMR
+  | typeof BRIDGE_ERROR__GAS_COST_OVERFLOW
+  | typeof BRIDGE_ERROR__GUARDIAN_APPROVAL_REQUIRED
+  | typeof BRIDGE_ERROR__INCOMPATIBLE_BLOCK_INTERVAL
   | typeof BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER
   | typeof BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM
+  | typeof BRIDGE_ERROR__INCORRECT_DENIED_REMOTE_TOKEN
   | typeof BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER
+  | typeof BRIDGE_ERROR__INCORRECT_RATE_LIMIT
   | typeof BRIDGE_ERROR__INCORRECT_SOL_VAULT
   | typeof BRIDGE_ERROR__INCORRECT_TO
   | typeof BRIDGE_ERROR__INCORRECT_TOKEN_VAULT
@@ -124,48 +245,101 @@ export type BridgeError =
   | typeof BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES
   | typeof BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_INTRA_MOUNTAIN_PATH
   | typeof BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS
+  | typeof BRIDGE_ERROR__INVALID_BASE_FEE
   | typeof BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT
+  | typeof BRIDGE_ERROR__INVALID_BRIDGE_PAYLOAD
+  | typeof BRIDGE_ERROR__INVALID_CO_GUARDIAN
   | typeof BRIDGE_ERROR__INVALID_DENOMINATOR
+  | typeof BRIDGE_ERROR__INVALID_FEE_BOUNDS
   | typeof BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP
+  | typeof BRIDGE_ERROR__INVALID_GAS_MULTIPLIER
   | typeof BRIDGE_ERROR__INVALID_MESSAGE_HASH
+  | typeof BRIDGE_ERROR__INVALID_OUTPUT_ROOT_BATCH
+  | typeof BRIDGE_ERROR__INVALID_PARTNER_CONFIG_OWNER
   | typeof BRIDGE_ERROR__INVALID_PARTNER_THRESHOLD
+  | typeof BRIDGE_ERROR__INVALID_PAUSED_OPS
   | typeof BRIDGE_ERROR__INVALID_PROOF
+  | typeof BRIDGE_ERROR__INVALID_RATE_LIMIT_WINDOW
   | typeof BRIDGE_ERROR__INVALID_RECOVERY_ID
+  | typeof BRIDGE_ERROR__INVALID_REGISTRATION_RATE_LIMIT
+  | typeof BRIDGE_ERROR__INVALID_RELAY_SLICE
   | typeof BRIDGE_ERROR__INVALID_REMOTE_TOKEN
   | typeof BRIDGE_ERROR__INVALID_SCALER_EXPONENT
   | typeof BRIDGE_ERROR__INVALID_THRESHOLD
   | typeof BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS
   | typeof BRIDGE_ERROR__LEAF_MOUNTAIN_NOT_FOUND
+  | typeof BRIDGE_ERROR__METADATA_TOO_LONG
   | typeof BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN
   | typeof BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022
   | typeof BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA
   | typeof BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN
   | typeof BRIDGE_ERROR__MMR_SHOULD_BE_EMPTY
+  | typeof BRIDGE_ERROR__NO_INSTRUCTIONS_TO_RELAY
   | typeof BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_CLOSE_TIMELOCKED
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_REQUIRES_REVALIDATION
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_TOO_OLD
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_WITHIN_CLOSE_MARGIN
+  | typeof BRIDGE_ERROR__OVERLAPPING_SIGNER_SETS
+  | typeof BRIDGE_ERROR__PROOF_TOO_LONG
+  | typeof BRIDGE_ERROR__PROTECTED_ACCOUNT_MUTATION
+  | typeof BRIDGE_ERROR__PROVE_BUFFER_CAPACITY_EXCEEDED
+  | typeof BRIDGE_ERROR__PROVE_BUFFER_MAX_PROOF_LEN_EXCEEDED
+  | typeof BRIDGE_ERROR__QUOTE_OVERFLOW
+  | typeof BRIDGE_ERROR__RATE_LIMIT_EXCEEDED
+  | typeof BRIDGE_ERROR__REGISTRATION_RATE_LIMITED
+  | typeof BRIDGE_ERROR__REGISTRATION_TOO_SOON
+  | typeof BRIDGE_ERROR__RELAY_NONCE_OUT_OF_ORDER
+  | typeof BRIDGE_ERROR__RELAY_TOO_SOON
+  | typeof BRIDGE_ERROR__REMOTE_TOKEN_DENIED
   | typeof BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND
+  | typeof BRIDGE_ERROR__ROOT_NOT_REGISTERED
   | typeof BRIDGE_ERROR__SCALER_EXPONENT_NOT_FOUND
   | typeof BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED
+  | typeof BRIDGE_ERROR__SIGNER_NOT_FOUND
+  | typeof BRIDGE_ERROR__SOL_RELEASE_EXCEEDS_LOCKED
+  | typeof BRIDGE_ERROR__SPONSOR_NOT_ALLOWED
   | typeof BRIDGE_ERROR__TOKEN_ACCOUNT_DOES_NOT_MATCH_TO
+  | typeof BRIDGE_ERROR__TOO_MANY_APPENDS
   | typeof BRIDGE_ERROR__TOO_MANY_SIGNERS
   | typeof BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE
   | typeof BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION
+  | typeof BRIDGE_ERROR__UNSUPPORTED_MESSAGE_VERSION
   | typeof BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING
+  | typeof BRIDGE_ERROR__WOULD_DRAIN_PAYER
+  | typeof BRIDGE_ERROR__WRAPPED_TOKEN_LIMIT_REACHED
   | typeof BRIDGE_ERROR__ZERO_ADDRESS;
 
 let bridgeErrorMessages: Record<BridgeError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   bridgeErrorMessages = {
     [BRIDGE_ERROR__ALREADY_EXECUTED]: `Message already executed`,
+    [BRIDGE_ERROR__ALREADY_MIGRATED]: `Account is already migrated or does not have the legacy layout`,
+    [BRIDGE_ERROR__BLOCK_NUMBER_TOO_FAR_AHEAD]: `Base block number is too far ahead of the latest registered block`,
     [BRIDGE_ERROR__BRIDGE_PAUSED]: `Bridge is currently paused`,
+    [BRIDGE_ERROR__BUFFER_CHECKSUM_MISMATCH]: `Call buffer data does not match the committed hash`,
     [BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED]: `Call buffer size exceeds maximum allowed size`,
+    [BRIDGE_ERROR__BUFFER_OVERFLOW]: `Append would grow the call buffer past its capacity`,
     [BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND]: `Only the owner can append to this buffer`,
     [BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE]: `Only the owner can close this buffer`,
+    [BRIDGE_ERROR__BUFFER_UNAUTHORIZED_TRANSFER]: `Only the owner can transfer this buffer`,
+    [BRIDGE_ERROR__CANNOT_CLOSE_LATEST_OUTPUT_ROOT]: `The latest output root cannot be closed`,
+    [BRIDGE_ERROR__CO_GUARDIAN_SIGNATURE_REQUIRED]: `The co-guardian must also sign this configuration change`,
     [BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET]: `Creation with non-zero target`,
+    [BRIDGE_ERROR__DAILY_LIMIT_EXCEEDED]: `Daily bridged volume limit exceeded`,
+    [BRIDGE_ERROR__DUPLICATE_OUTPUT_ROOT]: `Output root is identical to the previously registered root`,
+    [BRIDGE_ERROR__DUPLICATE_RELAYED_INSTRUCTION]: `Message contains duplicate instructions`,
     [BRIDGE_ERROR__DUPLICATE_SIGNER]: `Duplicate signer found`,
     [BRIDGE_ERROR__EMPTY_MMR]: `MMR is empty`,
+    [BRIDGE_ERROR__GAS_COST_OVERFLOW]: `Gas cost does not fit in a u64`,
+    [BRIDGE_ERROR__GUARDIAN_APPROVAL_REQUIRED]: `Guardian approval is required for this finalization`,
+    [BRIDGE_ERROR__INCOMPATIBLE_BLOCK_INTERVAL]: `Block interval requirement is incompatible with the latest registered block`,
     [BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER]: `Incorrect block number`,
     [BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM]: `Incorrect bridge program`,
+    [BRIDGE_ERROR__INCORRECT_DENIED_REMOTE_TOKEN]: `Incorrect denied remote token account`,
     [BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER]: `Incorrect gas fee receiver`,
+    [BRIDGE_ERROR__INCORRECT_RATE_LIMIT]: `Incorrect rate limit account`,
     [BRIDGE_ERROR__INCORRECT_SOL_VAULT]: `Incorrect sol vault`,
     [BRIDGE_ERROR__INCORRECT_TO]: `Incorrect to`,
     [BRIDGE_ERROR__INCORRECT_TOKEN_VAULT]: `Incorrect token vault`,
@@ -173,32 +347,70 @@ if (process.env.NODE_ENV !== 'production') {
     [BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES]: `Insufficient partner oracle signatures to meet threshold`,
     [BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_INTRA_MOUNTAIN_PATH]: `Insufficient proof elements for intra-mountain path`,
     [BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS]: `Insufficient proof elements for other mountain peaks`,
+    [BRIDGE_ERROR__INVALID_BASE_FEE]: `Base fee is outside [minimum_base_fee, MAX_BASE_FEE]`,
     [BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT]: `Invalid block interval requirement`,
+    [BRIDGE_ERROR__INVALID_BRIDGE_PAYLOAD]: `Invalid bridge transfer payload`,
+    [BRIDGE_ERROR__INVALID_CO_GUARDIAN]: `The co-guardian must differ from the guardian`,
     [BRIDGE_ERROR__INVALID_DENOMINATOR]: `Invalid denominator`,
+    [BRIDGE_ERROR__INVALID_FEE_BOUNDS]: `Maximum fee must be zero or at least the minimum fee`,
     [BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP]: `Invalid gas cost scaler dp`,
+    [BRIDGE_ERROR__INVALID_GAS_MULTIPLIER]: `Gas multiplier must be non-zero and at most MAX_GAS_MULTIPLIER_BPS`,
     [BRIDGE_ERROR__INVALID_MESSAGE_HASH]: `Invalid message hash`,
+    [BRIDGE_ERROR__INVALID_OUTPUT_ROOT_BATCH]: `Output root batch is empty, too large or does not match the provided root accounts`,
+    [BRIDGE_ERROR__INVALID_PARTNER_CONFIG_OWNER]: `Partner config account is not owned by the partner program`,
     [BRIDGE_ERROR__INVALID_PARTNER_THRESHOLD]: `Invalid partner threshold`,
+    [BRIDGE_ERROR__INVALID_PAUSED_OPS]: `Paused operations mask contains unknown flags`,
     [BRIDGE_ERROR__INVALID_PROOF]: `Invalid proof`,
+    [BRIDGE_ERROR__INVALID_RATE_LIMIT_WINDOW]: `Rate limit window must be non-zero`,
     [BRIDGE_ERROR__INVALID_RECOVERY_ID]: `Invalid recovery ID`,
+    [BRIDGE_ERROR__INVALID_REGISTRATION_RATE_LIMIT]: `Registration rate limit requires a non-zero refill interval`,
+    [BRIDGE_ERROR__INVALID_RELAY_SLICE]: `Relay slice is empty or extends past the message instructions`,
     [BRIDGE_ERROR__INVALID_REMOTE_TOKEN]: `Invalid remote token`,
     [BRIDGE_ERROR__INVALID_SCALER_EXPONENT]: `Invalid scaler exponent`,
     [BRIDGE_ERROR__INVALID_THRESHOLD]: `Threshold must be <= number of signers`,
     [BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS]: `Invalid window duration seconds`,
     [BRIDGE_ERROR__LEAF_MOUNTAIN_NOT_FOUND]: `Leaf's mountain not found`,
+    [BRIDGE_ERROR__METADATA_TOO_LONG]: `Token name or symbol exceeds the maximum length`,
     [BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN]: `Mint does not match local token`,
     [BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022]: `Mint is not a token 2022 mint`,
     [BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA]: `Mint is not a valid wrapped token PDA`,
     [BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN]: `Mint is a wrapped token`,
     [BRIDGE_ERROR__MMR_SHOULD_BE_EMPTY]: `MMR should be empty`,
+    [BRIDGE_ERROR__NO_INSTRUCTIONS_TO_RELAY]: `Message has no instructions to relay in slices, use relay_message instead`,
     [BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR]: `No peaks found for non-empty MMR`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_CLOSE_TIMELOCKED]: `Output root is too recent to be closed`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_REQUIRES_REVALIDATION]: `Output root must be re-validated by the current oracle set`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_TOO_OLD]: `Output root is older than the maximum allowed age`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_WITHIN_CLOSE_MARGIN]: `Output root is within the close margin of the latest registered block`,
+    [BRIDGE_ERROR__OVERLAPPING_SIGNER_SETS]: `Signer approved as both a Base and a partner oracle`,
+    [BRIDGE_ERROR__PROOF_TOO_LONG]: `Proof is longer than any valid proof for this MMR`,
+    [BRIDGE_ERROR__PROTECTED_ACCOUNT_MUTATION]: `Relayed instruction targets a protected bridge account`,
+    [BRIDGE_ERROR__PROVE_BUFFER_CAPACITY_EXCEEDED]: `Append exceeds the prove buffer's allocated capacity`,
+    [BRIDGE_ERROR__PROVE_BUFFER_MAX_PROOF_LEN_EXCEEDED]: `Prove buffer proof length exceeds maximum allowed length`,
+    [BRIDGE_ERROR__QUOTE_OVERFLOW]: `Quoted total cost does not fit in a u64`,
+    [BRIDGE_ERROR__RATE_LIMIT_EXCEEDED]: `Remote token rate limit exceeded`,
+    [BRIDGE_ERROR__REGISTRATION_RATE_LIMITED]: `Output root registrations are rate limited`,
+    [BRIDGE_ERROR__REGISTRATION_TOO_SOON]: `Output root registration is too soon after the previous one`,
+    [BRIDGE_ERROR__RELAY_NONCE_OUT_OF_ORDER]: `Message nonce is not above the highest relayed nonce`,
+    [BRIDGE_ERROR__RELAY_TOO_SOON]: `Message was proven too recently to be relayed`,
+    [BRIDGE_ERROR__REMOTE_TOKEN_DENIED]: `Remote token is denied`,
     [BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND]: `Remote token not found`,
+    [BRIDGE_ERROR__ROOT_NOT_REGISTERED]: `Output root is not within the registered range`,
     [BRIDGE_ERROR__SCALER_EXPONENT_NOT_FOUND]: `Scaler exponent not found`,
     [BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED]: `Signature verification failed`,
+    [BRIDGE_ERROR__SIGNER_NOT_FOUND]: `Signer is not part of the oracle set`,
+    [BRIDGE_ERROR__SOL_RELEASE_EXCEEDS_LOCKED]: `SOL release exceeds locked amount`,
+    [BRIDGE_ERROR__SPONSOR_NOT_ALLOWED]: `Sender must also be the payer`,
     [BRIDGE_ERROR__TOKEN_ACCOUNT_DOES_NOT_MATCH_TO]: `Token account does not match to address`,
+    [BRIDGE_ERROR__TOO_MANY_APPENDS]: `Call buffer has reached its maximum number of appends`,
     [BRIDGE_ERROR__TOO_MANY_SIGNERS]: `Too many signers (max 32)`,
     [BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE]: `Unauthorized to update configuration`,
+    [BRIDGE_ERROR__UNAUTHORIZED_GUARDIAN_ACCEPTANCE]: `Only the pending guardian can accept the guardian role`,
     [BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION]: `Only the upgrade authority can initialize the bridge`,
+    [BRIDGE_ERROR__UNSUPPORTED_MESSAGE_VERSION]: `Unsupported message version`,
     [BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING]: `Unused proof elements remaining`,
+    [BRIDGE_ERROR__WOULD_DRAIN_PAYER]: `Bridge would leave the sender below the requested reserve`,
+    [BRIDGE_ERROR__WRAPPED_TOKEN_LIMIT_REACHED]: `Maximum number of wrapped tokens reached`,
     [BRIDGE_ERROR__ZERO_ADDRESS]: `Zero address`,
   };
 }
@@ -28,6 +28,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -50,6 +51,7 @@ export type AppendToCallBufferInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
+  TAccountBridge extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -62,6 +64,9 @@ export type AppendToCallBufferInstruction<
       TAccountCallBuffer extends string
         ? WritableAccount<TAccountCallBuffer>
         : TAccountCallBuffer,
+      TAccountBridge extends string
+        ? ReadonlyAccount<TAccountBridge>
+        : TAccountBridge,
       ...TRemainingAccounts,
     ]
   >;
@@ -108,6 +113,7 @@ export function getAppendToCallBufferInstructionDataCodec(): Codec<
 export type AppendToCallBufferInput<
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
+  TAccountBridge extends string = string,
 > = {
   /**
    * The signer authorized to modify this call buffer.
@@ -117,24 +123,31 @@ export type AppendToCallBufferInput<
   /**
    * The call buffer account to append data to.
    * Must have been initialized with enough space to hold the resulting
-   * data; this instruction does not reallocate and will revert if
-   * serialization would exceed the account's allocated size.
+   * data; this instruction does not reallocate.
    */
   callBuffer: Address<TAccountCallBuffer>;
+  /** The bridge account providing `buffer_config.max_call_buffer_size` */
+  bridge: Address<TAccountBridge>;
   data: AppendToCallBufferInstructionDataArgs['data'];
 };
 
 export function getAppendToCallBufferInstruction<
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
+  TAccountBridge extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: AppendToCallBufferInput<TAccountOwner, TAccountCallBuffer>,
+  input: AppendToCallBufferInput<
+    TAccountOwner,
+    TAccountCallBuffer,
+    TAccountBridge
+  >,
   config?: { programAddress?: TProgramAddress }
 ): AppendToCallBufferInstruction<
   TProgramAddress,
   TAccountOwner,
-  TAccountCallBuffer
+  TAccountCallBuffer,
+  TAccountBridge
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -143,6 +156,7 @@ export function getAppendToCallBufferInstruction<
   const originalAccounts = {
     owner: { value: input.owner ?? null, isWritable: false },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
+    bridge: { value: input.bridge ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -157,6 +171,7 @@ export function getAppendToCallBufferInstruction<
     accounts: [
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
+      getAccountMeta(accounts.bridge),
     ],
     data: getAppendToCallBufferInstructionDataEncoder().encode(
       args as AppendToCallBufferInstructionDataArgs
@@ -165,7 +180,8 @@ export function getAppendToCallBufferInstruction<
   } as AppendToCallBufferInstruction<
     TProgramAddress,
     TAccountOwner,
-    TAccountCallBuffer
+    TAccountCallBuffer,
+    TAccountBridge
   >);
 }
 
@@ -183,10 +199,11 @@ export type ParsedAppendToCallBufferInstruction<
     /**
      * The call buffer account to append data to.
      * Must have been initialized with enough space to hold the resulting
-     * data; this instruction does not reallocate and will revert if
-     * serialization would exceed the account's allocated size.
+     * data; this instruction does not reallocate.
      */
     callBuffer: TAccountMetas[1];
+    /** The bridge account providing `buffer_config.max_call_buffer_size` */
+    bridge: TAccountMetas[2];
   };
   data: AppendToCallBufferInstructionData;
 };
@@ -199,7 +216,7 @@ export function parseAppendToCallBufferInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedAppendToCallBufferInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -211,7 +228,11 @@ export function parseAppendToCallBufferInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { owner: getNextAccount(), callBuffer: getNextAccount() },
+    accounts: {
+      owner: getNextAccount(),
+      callBuffer: getNextAccount(),
+      bridge: getNextAccount(),
+    },
     data: getAppendToCallBufferInstructionDataDecoder().decode(
       instruction.data
     ),
@@ -60,6 +60,8 @@ export type BridgeSolInstruction<
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
   TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
@@ -85,6 +87,12 @@ export type BridgeSolInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
@@ -101,6 +109,7 @@ export type BridgeSolInstructionData = {
   to: ReadonlyUint8Array;
   amount: bigint;
   call: Option<Call>;
+  reserveLamports: Option<bigint>;
 };
 
 export type BridgeSolInstructionDataArgs = {
@@ -108,6 +117,7 @@ export type BridgeSolInstructionDataArgs = {
   to: ReadonlyUint8Array;
   amount: number | bigint;
   call: OptionOrNullable<CallArgs>;
+  reserveLamports: OptionOrNullable<number | bigint>;
 };
 
 export function getBridgeSolInstructionDataEncoder(): Encoder<BridgeSolInstructionDataArgs> {
@@ -118,6 +128,7 @@ export function getBridgeSolInstructionDataEncoder(): Encoder<BridgeSolInstructi
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
       ['call', getOptionEncoder(getCallEncoder())],
+      ['reserveLamports', getOptionEncoder(getU64Encoder())],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_SOL_DISCRIMINATOR })
   );
@@ -130,6 +141,7 @@ export function getBridgeSolInstructionDataDecoder(): Decoder<BridgeSolInstructi
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
     ['call', getOptionDecoder(getCallDecoder())],
+    ['reserveLamports', getOptionDecoder(getU64Decoder())],
   ]);
 }
 
@@ -149,6 +161,8 @@ export type BridgeSolInput<
   TAccountGasFeeReceiver extends string = string,
   TAccountSolVault extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
@@ -178,6 +192,18 @@ export type BridgeSolInput<
    * - Mutable to increment nonce and update EIP1559 fee data
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+   * if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+   * exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /**
    * The outgoing message account that stores cross-chain transfer details.
    * - Created fresh for each bridge operation
@@ -194,6 +220,7 @@ export type BridgeSolInput<
   to: BridgeSolInstructionDataArgs['to'];
   amount: BridgeSolInstructionDataArgs['amount'];
   call: BridgeSolInstructionDataArgs['call'];
+  reserveLamports: BridgeSolInstructionDataArgs['reserveLamports'];
 };
 
 export function getBridgeSolInstruction<
@@ -202,6 +229,8 @@ export function getBridgeSolInstruction<
   TAccountGasFeeReceiver extends string,
   TAccountSolVault extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountOutgoingMessage extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
@@ -212,6 +241,8 @@ export function getBridgeSolInstruction<
     TAccountGasFeeReceiver,
     TAccountSolVault,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOutgoingMessage,
     TAccountSystemProgram
   >,
@@ -223,6 +254,8 @@ export function getBridgeSolInstruction<
   TAccountGasFeeReceiver,
   TAccountSolVault,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountOutgoingMessage,
   TAccountSystemProgram
 > {
@@ -236,6 +269,11 @@ export function getBridgeSolInstruction<
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
     solVault: { value: input.solVault ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
@@ -261,6 +299,8 @@ export function getBridgeSolInstruction<
       getAccountMeta(accounts.gasFeeReceiver),
       getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.systemProgram),
     ],
@@ -275,6 +315,8 @@ export function getBridgeSolInstruction<
     TAccountGasFeeReceiver,
     TAccountSolVault,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOutgoingMessage,
     TAccountSystemProgram
   >);
@@ -312,18 +354,30 @@ export type ParsedBridgeSolInstruction<
      * - Mutable to increment nonce and update EIP1559 fee data
      */
     bridge: TAccountMetas[4];
+    /**
+     * The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+     * if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[5];
+    /**
+     * The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+     * exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[6];
     /**
      * The outgoing message account that stores cross-chain transfer details.
      * - Created fresh for each bridge operation
      * - Payer funds the account creation
      * - Space allocated dynamically based on optional call data size
      */
-    outgoingMessage: TAccountMetas[5];
+    outgoingMessage: TAccountMetas[7];
     /**
      * System program required for SOL transfers and account creation.
      * Used for transferring SOL from user to vault and creating outgoing message accounts.
      */
-    systemProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[8];
   };
   data: BridgeSolInstructionData;
 };
@@ -336,7 +390,7 @@ export function parseBridgeSolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -354,6 +408,8 @@ export function parseBridgeSolInstruction<
       gasFeeReceiver: getNextAccount(),
       solVault: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       outgoingMessage: getNextAccount(),
       systemProgram: getNextAccount(),
     },
@@ -52,6 +52,8 @@ export type BridgeSolWithBufferedCallInstruction<
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
   TAccountSolVault extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
@@ -79,6 +81,12 @@ export type BridgeSolWithBufferedCallInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountOwner extends string
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
@@ -149,6 +157,8 @@ export type BridgeSolWithBufferedCallInput<
   TAccountGasFeeReceiver extends string = string,
   TAccountSolVault extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
@@ -180,6 +190,18 @@ export type BridgeSolWithBufferedCallInput<
    * - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+   * if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+   * exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /** The owner of the call buffer who will receive the rent refund. */
   owner: TransactionSigner<TAccountOwner>;
   /**
@@ -208,6 +230,8 @@ export function getBridgeSolWithBufferedCallInstruction<
   TAccountGasFeeReceiver extends string,
   TAccountSolVault extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
@@ -220,6 +244,8 @@ export function getBridgeSolWithBufferedCallInstruction<
     TAccountGasFeeReceiver,
     TAccountSolVault,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
@@ -233,6 +259,8 @@ export function getBridgeSolWithBufferedCallInstruction<
   TAccountGasFeeReceiver,
   TAccountSolVault,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
@@ -248,6 +276,11 @@ export function getBridgeSolWithBufferedCallInstruction<
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
     solVault: { value: input.solVault ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
@@ -275,6 +308,8 @@ export function getBridgeSolWithBufferedCallInstruction<
       getAccountMeta(accounts.gasFeeReceiver),
       getAccountMeta(accounts.solVault),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
@@ -291,6 +326,8 @@ export function getBridgeSolWithBufferedCallInstruction<
     TAccountGasFeeReceiver,
     TAccountSolVault,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
@@ -330,23 +367,35 @@ export type ParsedBridgeSolWithBufferedCallInstruction<
      * - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
      */
     bridge: TAccountMetas[4];
+    /**
+     * The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+     * if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[5];
+    /**
+     * The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+     * exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[6];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[5];
+    owner: TAccountMetas[7];
     /**
      * The call buffer account that stores the call parameters and data.
      * Its contents are copied into the outgoing message, then the account is closed
      * (rent refunded to `owner`).
      */
-    callBuffer: TAccountMetas[6];
+    callBuffer: TAccountMetas[8];
     /**
      * The outgoing message account that stores the cross-chain transfer details.
      * - Created fresh for each bridge; address determined by the provided keypair
      * - Funded by `payer`
      * - Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`
      */
-    outgoingMessage: TAccountMetas[7];
+    outgoingMessage: TAccountMetas[9];
     /** System program required for account creation and the SOL transfer CPI. */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[10];
   };
   data: BridgeSolWithBufferedCallInstructionData;
 };
@@ -359,7 +408,7 @@ export function parseBridgeSolWithBufferedCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSolWithBufferedCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -377,6 +426,8 @@ export function parseBridgeSolWithBufferedCallInstruction<
       gasFeeReceiver: getNextAccount(),
       solVault: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
@@ -61,6 +61,8 @@ export type BridgeSplInstruction<
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountTokenVault extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
@@ -93,6 +95,12 @@ export type BridgeSplInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountTokenVault extends string
         ? WritableAccount<TAccountTokenVault>
         : TAccountTokenVault,
@@ -168,6 +176,8 @@ export type BridgeSplInput<
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountTokenVault extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
@@ -204,6 +214,16 @@ export type BridgeSplInput<
    * - Nonce is incremented after successful bridge operations
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of the bridged remote token; the instruction fails if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of the bridged remote token, recorded against when it exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /**
    * The token vault account that holds locked SPL tokens during the bridge process.
    * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -244,6 +264,8 @@ export function getBridgeSplInstruction<
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountTokenVault extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
@@ -257,6 +279,8 @@ export function getBridgeSplInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountTokenVault,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
@@ -271,6 +295,8 @@ export function getBridgeSplInstruction<
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountTokenVault,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
@@ -290,6 +316,11 @@ export function getBridgeSplInstruction<
       isWritable: true,
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     tokenVault: { value: input.tokenVault ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
@@ -322,6 +353,8 @@ export function getBridgeSplInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.tokenVault),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
@@ -339,6 +372,8 @@ export function getBridgeSplInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountTokenVault,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
@@ -383,6 +418,16 @@ export type ParsedBridgeSplInstruction<
      * - Nonce is incremented after successful bridge operations
      */
     bridge: TAccountMetas[5];
+    /**
+     * The denylist entry of the bridged remote token; the instruction fails if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[6];
+    /**
+     * The rate limit of the bridged remote token, recorded against when it exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[7];
     /**
      * The token vault account that holds locked SPL tokens during the bridge process.
      * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -390,7 +435,7 @@ export type ParsedBridgeSplInstruction<
      * - Token account authority is set to this vault PDA; the program signs using the PDA seeds
      * - Acts as the custody account for tokens being bridged to Base
      */
-    tokenVault: TAccountMetas[6];
+    tokenVault: TAccountMetas[8];
     /**
      * The outgoing message account that represents this bridge operation.
      * - Contains transfer details and optional call data for the destination chain
@@ -398,17 +443,17 @@ export type ParsedBridgeSplInstruction<
      * - Used by relayers to execute the bridge operation on Base
      * - The recorded transfer amount equals the net increase in `token_vault` balance
      */
-    outgoingMessage: TAccountMetas[7];
+    outgoingMessage: TAccountMetas[9];
     /**
      * The SPL Token program interface for executing token transfers.
      * Used for the transfer_checked operation to move tokens to the vault.
      */
-    tokenProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[10];
     /**
      * System program required for creating the outgoing message account and
      * initializing the token vault when needed.
      */
-    systemProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[11];
   };
   data: BridgeSplInstructionData;
 };
@@ -421,7 +466,7 @@ export function parseBridgeSplInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSplInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -440,6 +485,8 @@ export function parseBridgeSplInstruction<
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       tokenVault: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
@@ -53,6 +53,8 @@ export type BridgeSplWithBufferedCallInstruction<
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountTokenVault extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
@@ -87,6 +89,12 @@ export type BridgeSplWithBufferedCallInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountTokenVault extends string
         ? WritableAccount<TAccountTokenVault>
         : TAccountTokenVault,
@@ -168,6 +176,8 @@ export type BridgeSplWithBufferedCallInput<
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountTokenVault extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
@@ -206,6 +216,16 @@ export type BridgeSplWithBufferedCallInput<
    * - Nonce is incremented after successful bridge operations
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of the bridged remote token; the instruction fails if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of the bridged remote token, recorded against when it exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /**
    * The token vault account that holds locked SPL tokens during the bridge process.
    * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -246,6 +266,8 @@ export function getBridgeSplWithBufferedCallInstruction<
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountTokenVault extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
@@ -261,6 +283,8 @@ export function getBridgeSplWithBufferedCallInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountTokenVault,
     TAccountOwner,
     TAccountCallBuffer,
@@ -277,6 +301,8 @@ export function getBridgeSplWithBufferedCallInstruction<
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountTokenVault,
   TAccountOwner,
   TAccountCallBuffer,
@@ -298,6 +324,11 @@ export function getBridgeSplWithBufferedCallInstruction<
       isWritable: true,
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     tokenVault: { value: input.tokenVault ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
@@ -332,6 +363,8 @@ export function getBridgeSplWithBufferedCallInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.tokenVault),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
@@ -351,6 +384,8 @@ export function getBridgeSplWithBufferedCallInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountTokenVault,
     TAccountOwner,
     TAccountCallBuffer,
@@ -397,6 +432,16 @@ export type ParsedBridgeSplWithBufferedCallInstruction<
      * - Nonce is incremented after successful bridge operations
      */
     bridge: TAccountMetas[5];
+    /**
+     * The denylist entry of the bridged remote token; the instruction fails if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[6];
+    /**
+     * The rate limit of the bridged remote token, recorded against when it exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[7];
     /**
      * The token vault account that holds locked SPL tokens during the bridge process.
      * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -404,26 +449,26 @@ export type ParsedBridgeSplWithBufferedCallInstruction<
      * - Token account authority is set to this vault PDA; the program signs using the PDA seeds
      * - Acts as the custody account for tokens being bridged to Base
      */
-    tokenVault: TAccountMetas[6];
+    tokenVault: TAccountMetas[8];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[7];
+    owner: TAccountMetas[9];
     /**
      * The call buffer account that stores the call data.
      * This account will be closed and rent returned to the owner.
      */
-    callBuffer: TAccountMetas[8];
+    callBuffer: TAccountMetas[10];
     /** The outgoing message account that stores the cross-chain transfer details. */
-    outgoingMessage: TAccountMetas[9];
+    outgoingMessage: TAccountMetas[11];
     /**
      * The SPL Token program interface for executing token transfers.
      * Used for the transfer_checked operation to move tokens to the vault.
      */
-    tokenProgram: TAccountMetas[10];
+    tokenProgram: TAccountMetas[12];
     /**
      * System program required for creating the outgoing message account and
      * initializing the token vault when needed.
      */
-    systemProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[13];
   };
   data: BridgeSplWithBufferedCallInstructionData;
 };
@@ -436,7 +481,7 @@ export function parseBridgeSplWithBufferedCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSplWithBufferedCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -455,6 +500,8 @@ export function parseBridgeSplWithBufferedCallInstruction<
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       tokenVault: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
@@ -64,6 +64,8 @@ export type BridgeWrappedTokenInstruction<
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
@@ -95,6 +97,12 @@ export type BridgeWrappedTokenInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
@@ -163,6 +171,8 @@ export type BridgeWrappedTokenInput<
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -197,6 +207,16 @@ export type BridgeWrappedTokenInput<
    * - Tracks nonce for message ordering and EIP-1559 gas pricing
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of the remote token of `mint`, recorded against when it exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /**
    * The outgoing message account being created to store bridge transfer data.
    * - Contains transfer details and optional call data for Base execution
@@ -227,6 +247,8 @@ export function getBridgeWrappedTokenInstruction<
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -239,6 +261,8 @@ export function getBridgeWrappedTokenInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -252,6 +276,8 @@ export function getBridgeWrappedTokenInstruction<
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
   TAccountSystemProgram
@@ -270,6 +296,11 @@ export function getBridgeWrappedTokenInstruction<
       isWritable: true,
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -301,6 +332,8 @@ export function getBridgeWrappedTokenInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -317,6 +350,8 @@ export function getBridgeWrappedTokenInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -359,23 +394,33 @@ export type ParsedBridgeWrappedTokenInstruction<
      * - Tracks nonce for message ordering and EIP-1559 gas pricing
      */
     bridge: TAccountMetas[5];
+    /**
+     * The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[6];
+    /**
+     * The rate limit of the remote token of `mint`, recorded against when it exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[7];
     /**
      * The outgoing message account being created to store bridge transfer data.
      * - Contains transfer details and optional call data for Base execution
      * - Space allocated based on call data size
      * - Will be read by Base relayers to complete the bridge operation
      */
-    outgoingMessage: TAccountMetas[6];
+    outgoingMessage: TAccountMetas[8];
     /**
      * Token2022 program used for burning the wrapped tokens.
      * Required for all token operations including burn_checked.
      */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[9];
     /**
      * System program required for creating the outgoing message account
      * and transferring the gas payment to the `gas_fee_receiver`.
      */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[10];
   };
   data: BridgeWrappedTokenInstructionData;
 };
@@ -388,7 +433,7 @@ export function parseBridgeWrappedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeWrappedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -407,6 +452,8 @@ export function parseBridgeWrappedTokenInstruction<
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -53,6 +53,8 @@ export type BridgeWrappedTokenWithBufferedCallInstruction<
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
+  TAccountRateLimit extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
@@ -86,6 +88,12 @@ export type BridgeWrappedTokenWithBufferedCallInstruction<
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
+      TAccountRateLimit extends string
+        ? WritableAccount<TAccountRateLimit>
+        : TAccountRateLimit,
       TAccountOwner extends string
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
@@ -160,6 +168,8 @@ export type BridgeWrappedTokenWithBufferedCallInput<
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
+  TAccountRateLimit extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
@@ -200,6 +210,16 @@ export type BridgeWrappedTokenWithBufferedCallInput<
    * - Tracks `nonce` for message ordering and maintains EIP-1559 fee state
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
+  /**
+   * The rate limit of the remote token of `mint`, recorded against when it exists.
+   * and deserialized if created
+   */
+  rateLimit: Address<TAccountRateLimit>;
   /** The owner of the call buffer who will receive the rent refund. */
   owner: TransactionSigner<TAccountOwner>;
   /**
@@ -228,6 +248,8 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
+  TAccountRateLimit extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
@@ -242,6 +264,8 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
@@ -257,6 +281,8 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
+  TAccountRateLimit,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
@@ -277,6 +303,11 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
       isWritable: true,
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
+    rateLimit: { value: input.rateLimit ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
@@ -310,6 +341,8 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
+      getAccountMeta(accounts.rateLimit),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
@@ -328,6 +361,8 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
+    TAccountRateLimit,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
@@ -376,22 +411,32 @@ export type ParsedBridgeWrappedTokenWithBufferedCallInstruction<
      * - Tracks `nonce` for message ordering and maintains EIP-1559 fee state
      */
     bridge: TAccountMetas[5];
+    /**
+     * The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[6];
+    /**
+     * The rate limit of the remote token of `mint`, recorded against when it exists.
+     * and deserialized if created
+     */
+    rateLimit: TAccountMetas[7];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[6];
+    owner: TAccountMetas[8];
     /**
      * The call buffer account that stores the call data.
      * This account will be closed and rent returned to the owner.
      */
-    callBuffer: TAccountMetas[7];
+    callBuffer: TAccountMetas[9];
     /**
      * The outgoing message account that stores the cross-chain transfer details.
      * Space is sized based on the current call buffer length so the call data fits.
      */
-    outgoingMessage: TAccountMetas[8];
+    outgoingMessage: TAccountMetas[10];
     /** Token2022 program used for burning the wrapped tokens (burn_checked). */
-    tokenProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[11];
     /** System program required for creating the outgoing message account and transferring gas fees. */
-    systemProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[12];
   };
   data: BridgeWrappedTokenWithBufferedCallInstructionData;
 };
@@ -407,7 +452,7 @@ export function parseBridgeWrappedTokenWithBufferedCallInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -426,6 +471,8 @@ export function parseBridgeWrappedTokenWithBufferedCallInstruction<
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
+      rateLimit: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
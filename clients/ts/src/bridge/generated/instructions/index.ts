@@ -6,9 +6,14 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptGuardian';
+export * from './addDeniedRemoteToken';
+export * from './addOracleSigner';
+export * from './appendChunksToCallBuffer';
 export * from './appendToCallBuffer';
 export * from './appendToProveBufferData';
 export * from './appendToProveBufferProof';
+export * from './beginRelay';
 export * from './bridgeCall';
 export * from './bridgeCallBuffered';
 export * from './bridgeSol';
@@ -17,27 +22,74 @@ export * from './bridgeSpl';
 export * from './bridgeSplWithBufferedCall';
 export * from './bridgeWrappedToken';
 export * from './bridgeWrappedTokenWithBufferedCall';
+export * from './cancelGuardianTransfer';
 export * from './closeCallBuffer';
+export * from './closeOutputRoot';
 export * from './closeProveBuffer';
+export * from './continueRelay';
+export * from './freezeWrappedAccount';
+export * from './getBaseFee';
+export * from './getFeeBounds';
+export * from './getHighestRelayedNonce';
+export * from './getOracleSigners';
+export * from './getProgramAuthorities';
+export * from './getProvenMessage';
+export * from './getTotalLockedSol';
 export * from './initialize';
 export * from './initializeCallBuffer';
 export * from './initializeProveBuffer';
+export * from './migrateBridge';
+export * from './migrateIncomingMessage';
+export * from './migrateOutputRoot';
 export * from './proveMessage';
 export * from './proveMessageBuffered';
+export * from './quoteTotalCost';
 export * from './registerOutputRoot';
+export * from './registerOutputRootsBatch';
 export * from './relayMessage';
+export * from './removeDeniedRemoteToken';
+export * from './removeOracleSigner';
+export * from './resetBaseFee';
+export * from './revalidateOutputRoot';
 export * from './setAdjustmentDenominator';
 export * from './setBlockIntervalRequirement';
+export * from './setCallTypeGasMultiplier';
+export * from './setCoGuardian';
+export * from './setDailySolVolumeLimits';
+export * from './setDecayGraceWindows';
+export * from './setDisjointSignerSets';
+export * from './setEip1559Params';
+export * from './setEnforceRelayNonceOrder';
 export * from './setGasCostScaler';
 export * from './setGasCostScalerDp';
 export * from './setGasFeeReceiver';
 export * from './setGasPerCall';
 export * from './setGasTarget';
+export * from './setLargeFinalizeThreshold';
+export * from './setMaxBlockAdvance';
+export * from './setMaxCallBufferAppends';
 export * from './setMaxCallBufferSize';
+export * from './setMaxProveBufferProofLen';
+export * from './setMaxRootAge';
+export * from './setMaxWrappedTokens';
+export * from './setMaximumFeeLamports';
+export * from './setMinProveToRelayDelay';
+export * from './setMinRegistrationInterval';
 export * from './setMinimumBaseFee';
+export * from './setMinimumFeeLamports';
 export * from './setOracleSigners';
+export * from './setOracleThreshold';
 export * from './setPartnerOracleConfig';
 export * from './setPauseStatus';
+export * from './setPausedOps';
+export * from './setRateLimit';
+export * from './setRegistrationRateLimit';
+export * from './setRejectDuplicateIxs';
+export * from './setRejectDuplicateRoots';
+export * from './setRequireSelfCall';
+export * from './setRevalidateOldRootsAfterBlocks';
 export * from './setWindowDuration';
+export * from './thawWrappedAccount';
+export * from './transferCallBuffer';
 export * from './transferGuardian';
 export * from './wrapToken';
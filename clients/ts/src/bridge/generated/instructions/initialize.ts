@@ -36,30 +36,10 @@ import {
 import { BRIDGE_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
-  getBaseOracleConfigDecoder,
-  getBaseOracleConfigEncoder,
-  getBufferConfigDecoder,
-  getBufferConfigEncoder,
-  getEip1559ConfigDecoder,
-  getEip1559ConfigEncoder,
-  getGasConfigDecoder,
-  getGasConfigEncoder,
-  getPartnerOracleConfigDecoder,
-  getPartnerOracleConfigEncoder,
-  getProtocolConfigDecoder,
-  getProtocolConfigEncoder,
-  type BaseOracleConfig,
-  type BaseOracleConfigArgs,
-  type BufferConfig,
-  type BufferConfigArgs,
-  type Eip1559Config,
-  type Eip1559ConfigArgs,
-  type GasConfig,
-  type GasConfigArgs,
-  type PartnerOracleConfig,
-  type PartnerOracleConfigArgs,
-  type ProtocolConfig,
-  type ProtocolConfigArgs,
+  getConfigDecoder,
+  getConfigEncoder,
+  type Config,
+  type ConfigArgs,
 } from '../types';
 
 export const INITIALIZE_DISCRIMINATOR = new Uint8Array([
@@ -112,34 +92,12 @@ export type InitializeInstruction<
 export type InitializeInstructionData = {
   discriminator: ReadonlyUint8Array;
   guardian: Address;
-  /** Configuration parameters for EIP-1559-inspired fee calculations */
-  eip1559Config: Eip1559Config;
-  /** Configuration parameters for outgoing message pricing */
-  gasConfig: GasConfig;
-  /** Configuration parameters for bridge protocol */
-  protocolConfig: ProtocolConfig;
-  /** Configuration parameters for pre-loading Solana --> Base messages in buffer accounts */
-  bufferConfig: BufferConfig;
-  /** Partner oracle configuration containing the required signature threshold */
-  partnerOracleConfig: PartnerOracleConfig;
-  /** Configuration parameters for Base oracle signers */
-  baseOracleConfig: BaseOracleConfig;
+  cfg: Config;
 };
 
 export type InitializeInstructionDataArgs = {
   guardian: Address;
-  /** Configuration parameters for EIP-1559-inspired fee calculations */
-  eip1559Config: Eip1559ConfigArgs;
-  /** Configuration parameters for outgoing message pricing */
-  gasConfig: GasConfigArgs;
-  /** Configuration parameters for bridge protocol */
-  protocolConfig: ProtocolConfigArgs;
-  /** Configuration parameters for pre-loading Solana --> Base messages in buffer accounts */
-  bufferConfig: BufferConfigArgs;
-  /** Partner oracle configuration containing the required signature threshold */
-  partnerOracleConfig: PartnerOracleConfigArgs;
-  /** Configuration parameters for Base oracle signers */
-  baseOracleConfig: BaseOracleConfigArgs;
+  cfg: ConfigArgs;
 };
 
 export function getInitializeInstructionDataEncoder(): FixedSizeEncoder<InitializeInstructionDataArgs> {
@@ -147,12 +105,7 @@ export function getInitializeInstructionDataEncoder(): FixedSizeEncoder<Initiali
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['guardian', getAddressEncoder()],
-      ['eip1559Config', getEip1559ConfigEncoder()],
-      ['gasConfig', getGasConfigEncoder()],
-      ['protocolConfig', getProtocolConfigEncoder()],
-      ['bufferConfig', getBufferConfigEncoder()],
-      ['partnerOracleConfig', getPartnerOracleConfigEncoder()],
-      ['baseOracleConfig', getBaseOracleConfigEncoder()],
+      ['cfg', getConfigEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INITIALIZE_DISCRIMINATOR })
   );
@@ -162,12 +115,7 @@ export function getInitializeInstructionDataDecoder(): FixedSizeDecoder<Initiali
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['guardian', getAddressDecoder()],
-    ['eip1559Config', getEip1559ConfigDecoder()],
-    ['gasConfig', getGasConfigDecoder()],
-    ['protocolConfig', getProtocolConfigDecoder()],
-    ['bufferConfig', getBufferConfigDecoder()],
-    ['partnerOracleConfig', getPartnerOracleConfigDecoder()],
-    ['baseOracleConfig', getBaseOracleConfigDecoder()],
+    ['cfg', getConfigDecoder()],
   ]);
 }
 
@@ -223,12 +171,7 @@ export type InitializeInput<
    */
   systemProgram?: Address<TAccountSystemProgram>;
   guardian: InitializeInstructionDataArgs['guardian'];
-  eip1559Config: InitializeInstructionDataArgs['eip1559Config'];
-  gasConfig: InitializeInstructionDataArgs['gasConfig'];
-  protocolConfig: InitializeInstructionDataArgs['protocolConfig'];
-  bufferConfig: InitializeInstructionDataArgs['bufferConfig'];
-  partnerOracleConfig: InitializeInstructionDataArgs['partnerOracleConfig'];
-  baseOracleConfig: InitializeInstructionDataArgs['baseOracleConfig'];
+  cfg: InitializeInstructionDataArgs['cfg'];
 };
 
 export function getInitializeInstruction<
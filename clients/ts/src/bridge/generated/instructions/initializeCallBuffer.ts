@@ -14,6 +14,8 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
@@ -32,6 +34,8 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -94,6 +98,7 @@ export type InitializeCallBufferInstructionData = {
   value: bigint;
   initialData: ReadonlyUint8Array;
   maxDataLen: bigint;
+  expectedHash: Option<ReadonlyUint8Array>;
 };
 
 export type InitializeCallBufferInstructionDataArgs = {
@@ -102,6 +107,7 @@ export type InitializeCallBufferInstructionDataArgs = {
   value: number | bigint;
   initialData: ReadonlyUint8Array;
   maxDataLen: number | bigint;
+  expectedHash: OptionOrNullable<ReadonlyUint8Array>;
 };
 
 export function getInitializeCallBufferInstructionDataEncoder(): Encoder<InitializeCallBufferInstructionDataArgs> {
@@ -113,6 +119,7 @@ export function getInitializeCallBufferInstructionDataEncoder(): Encoder<Initial
       ['value', getU128Encoder()],
       ['initialData', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['maxDataLen', getU64Encoder()],
+      ['expectedHash', getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32))],
     ]),
     (value) => ({
       ...value,
@@ -129,6 +136,7 @@ export function getInitializeCallBufferInstructionDataDecoder(): Decoder<Initial
     ['value', getU128Decoder()],
     ['initialData', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['maxDataLen', getU64Decoder()],
+    ['expectedHash', getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32))],
   ]);
 }
 
@@ -168,6 +176,7 @@ export type InitializeCallBufferInput<
   value: InitializeCallBufferInstructionDataArgs['value'];
   initialData: InitializeCallBufferInstructionDataArgs['initialData'];
   maxDataLen: InitializeCallBufferInstructionDataArgs['maxDataLen'];
+  expectedHash: InitializeCallBufferInstructionDataArgs['expectedHash'];
 };
 
 export function getInitializeCallBufferInstruction<
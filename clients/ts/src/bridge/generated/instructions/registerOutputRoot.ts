@@ -155,7 +155,8 @@ export type RegisterOutputRootInput<
   bridge: Address<TAccountBridge>;
   /**
    * Partner `Config` account (PDA with seed "config") owned by partner program.
-   * Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+   * Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in
+   * the handler when partner approvals are required.
    */
   partnerConfig: Address<TAccountPartnerConfig>;
   /**
@@ -266,7 +267,8 @@ export type ParsedRegisterOutputRootInstruction<
     bridge: TAccountMetas[2];
     /**
      * Partner `Config` account (PDA with seed "config") owned by partner program.
-     * Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+     * Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in
+     * the handler when partner approvals are required.
      */
     partnerConfig: TAccountMetas[3];
     /**
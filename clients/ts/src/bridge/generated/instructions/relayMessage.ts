@@ -16,6 +16,7 @@ import {
   getStructEncoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -23,8 +24,9 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
-  type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
 } from '@solana/kit';
 import { BRIDGE_PROGRAM_ADDRESS } from '../programs';
@@ -44,6 +46,7 @@ export type RelayMessageInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountMessage extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountGuardian extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -53,8 +56,12 @@ export type RelayMessageInstruction<
         ? WritableAccount<TAccountMessage>
         : TAccountMessage,
       TAccountBridge extends string
-        ? ReadonlyAccount<TAccountBridge>
+        ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountGuardian extends string
+        ? ReadonlySignerAccount<TAccountGuardian> &
+            AccountSignerMeta<TAccountGuardian>
+        : TAccountGuardian,
       ...TRemainingAccounts,
     ]
   >;
@@ -89,6 +96,7 @@ export function getRelayMessageInstructionDataCodec(): FixedSizeCodec<
 export type RelayMessageInput<
   TAccountMessage extends string = string,
   TAccountBridge extends string = string,
+  TAccountGuardian extends string = string,
 > = {
   /**
    * The incoming message account containing the cross-chain message to be executed.
@@ -100,25 +108,39 @@ export type RelayMessageInput<
   /**
    * The main bridge state account used to check pause status
    * - Uses PDA with BRIDGE_SEED for deterministic address
+   * - Mutable to track the SOL released from the vault
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * Optional guardian co-signer, required for SOL finalizations above
+   * `bridge.protocol_config.large_finalize_threshold`.
+   * - Pass the bridge program ID when the guardian does not co-sign
+   */
+  guardian?: TransactionSigner<TAccountGuardian>;
 };
 
 export function getRelayMessageInstruction<
   TAccountMessage extends string,
   TAccountBridge extends string,
+  TAccountGuardian extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: RelayMessageInput<TAccountMessage, TAccountBridge>,
+  input: RelayMessageInput<TAccountMessage, TAccountBridge, TAccountGuardian>,
   config?: { programAddress?: TProgramAddress }
-): RelayMessageInstruction<TProgramAddress, TAccountMessage, TAccountBridge> {
+): RelayMessageInstruction<
+  TProgramAddress,
+  TAccountMessage,
+  TAccountBridge,
+  TAccountGuardian
+> {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
     message: { value: input.message ?? null, isWritable: true },
-    bridge: { value: input.bridge ?? null, isWritable: false },
+    bridge: { value: input.bridge ?? null, isWritable: true },
+    guardian: { value: input.guardian ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -130,13 +152,15 @@ export function getRelayMessageInstruction<
     accounts: [
       getAccountMeta(accounts.message),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.guardian),
     ],
     data: getRelayMessageInstructionDataEncoder().encode({}),
     programAddress,
   } as RelayMessageInstruction<
     TProgramAddress,
     TAccountMessage,
-    TAccountBridge
+    TAccountBridge,
+    TAccountGuardian
   >);
 }
 
@@ -156,8 +180,15 @@ export type ParsedRelayMessageInstruction<
     /**
      * The main bridge state account used to check pause status
      * - Uses PDA with BRIDGE_SEED for deterministic address
+     * - Mutable to track the SOL released from the vault
      */
     bridge: TAccountMetas[1];
+    /**
+     * Optional guardian co-signer, required for SOL finalizations above
+     * `bridge.protocol_config.large_finalize_threshold`.
+     * - Pass the bridge program ID when the guardian does not co-sign
+     */
+    guardian?: TAccountMetas[2] | undefined;
   };
   data: RelayMessageInstructionData;
 };
@@ -170,7 +201,7 @@ export function parseRelayMessageInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRelayMessageInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -180,9 +211,19 @@ export function parseRelayMessageInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { message: getNextAccount(), bridge: getNextAccount() },
+    accounts: {
+      message: getNextAccount(),
+      bridge: getNextAccount(),
+      guardian: getNextOptionalAccount(),
+    },
     data: getRelayMessageInstructionDataDecoder().decode(instruction.data),
   };
 }
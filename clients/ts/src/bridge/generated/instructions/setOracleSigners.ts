@@ -55,6 +55,7 @@ export type SetOracleSignersInstruction<
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountProgramData extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
+  TAccountCoGuardian extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +74,10 @@ export type SetOracleSignersInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountCoGuardian extends string
+        ? ReadonlySignerAccount<TAccountCoGuardian> &
+            AccountSignerMeta<TAccountCoGuardian>
+        : TAccountCoGuardian,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +121,7 @@ export type SetOracleSignersInput<
   TAccountBridge extends string = string,
   TAccountProgramData extends string = string,
   TAccountProgram extends string = string,
+  TAccountCoGuardian extends string = string,
 > = {
   /** The upgrade authority account */
   upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
@@ -123,6 +129,8 @@ export type SetOracleSignersInput<
   bridge: Address<TAccountBridge>;
   programData: Address<TAccountProgramData>;
   program: Address<TAccountProgram>;
+  /** The co-guardian, required when `bridge.co_guardian` is set */
+  coGuardian?: TransactionSigner<TAccountCoGuardian>;
   cfg: SetOracleSignersInstructionDataArgs['cfg'];
 };
 
@@ -131,13 +139,15 @@ export function getSetOracleSignersInstruction<
   TAccountBridge extends string,
   TAccountProgramData extends string,
   TAccountProgram extends string,
+  TAccountCoGuardian extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: SetOracleSignersInput<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountCoGuardian
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetOracleSignersInstruction<
@@ -145,7 +155,8 @@ export function getSetOracleSignersInstruction<
   TAccountUpgradeAuthority,
   TAccountBridge,
   TAccountProgramData,
-  TAccountProgram
+  TAccountProgram,
+  TAccountCoGuardian
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -159,6 +170,7 @@ export function getSetOracleSignersInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     programData: { value: input.programData ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    coGuardian: { value: input.coGuardian ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -175,6 +187,7 @@ export function getSetOracleSignersInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.programData),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.coGuardian),
     ],
     data: getSetOracleSignersInstructionDataEncoder().encode(
       args as SetOracleSignersInstructionDataArgs
@@ -185,7 +198,8 @@ export function getSetOracleSignersInstruction<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountCoGuardian
   >);
 }
 
@@ -201,6 +215,8 @@ export type ParsedSetOracleSignersInstruction<
     bridge: TAccountMetas[1];
     programData: TAccountMetas[2];
     program: TAccountMetas[3];
+    /** The co-guardian, required when `bridge.co_guardian` is set */
+    coGuardian?: TAccountMetas[4] | undefined;
   };
   data: SetOracleSignersInstructionData;
 };
@@ -213,7 +229,7 @@ export function parseSetOracleSignersInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetOracleSignersInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -223,6 +239,12 @@ export function parseSetOracleSignersInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -230,6 +252,7 @@ export function parseSetOracleSignersInstruction<
       bridge: getNextAccount(),
       programData: getNextAccount(),
       program: getNextAccount(),
+      coGuardian: getNextOptionalAccount(),
     },
     data: getSetOracleSignersInstructionDataDecoder().decode(instruction.data),
   };
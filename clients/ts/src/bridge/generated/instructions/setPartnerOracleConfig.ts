@@ -55,6 +55,7 @@ export type SetPartnerOracleConfigInstruction<
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountProgramData extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
+  TAccountCoGuardian extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +74,10 @@ export type SetPartnerOracleConfigInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountCoGuardian extends string
+        ? ReadonlySignerAccount<TAccountCoGuardian> &
+            AccountSignerMeta<TAccountCoGuardian>
+        : TAccountCoGuardian,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,6 +126,7 @@ export type SetPartnerOracleConfigInput<
   TAccountBridge extends string = string,
   TAccountProgramData extends string = string,
   TAccountProgram extends string = string,
+  TAccountCoGuardian extends string = string,
 > = {
   /** The upgrade authority account */
   upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
@@ -128,6 +134,8 @@ export type SetPartnerOracleConfigInput<
   bridge: Address<TAccountBridge>;
   programData: Address<TAccountProgramData>;
   program: Address<TAccountProgram>;
+  /** The co-guardian, required when `bridge.co_guardian` is set */
+  coGuardian?: TransactionSigner<TAccountCoGuardian>;
   newConfig: SetPartnerOracleConfigInstructionDataArgs['newConfig'];
 };
 
@@ -136,13 +144,15 @@ export function getSetPartnerOracleConfigInstruction<
   TAccountBridge extends string,
   TAccountProgramData extends string,
   TAccountProgram extends string,
+  TAccountCoGuardian extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: SetPartnerOracleConfigInput<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountCoGuardian
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetPartnerOracleConfigInstruction<
@@ -150,7 +160,8 @@ export function getSetPartnerOracleConfigInstruction<
   TAccountUpgradeAuthority,
   TAccountBridge,
   TAccountProgramData,
-  TAccountProgram
+  TAccountProgram,
+  TAccountCoGuardian
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -164,6 +175,7 @@ export function getSetPartnerOracleConfigInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     programData: { value: input.programData ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    coGuardian: { value: input.coGuardian ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -180,6 +192,7 @@ export function getSetPartnerOracleConfigInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.programData),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.coGuardian),
     ],
     data: getSetPartnerOracleConfigInstructionDataEncoder().encode(
       args as SetPartnerOracleConfigInstructionDataArgs
@@ -190,7 +203,8 @@ export function getSetPartnerOracleConfigInstruction<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountCoGuardian
   >);
 }
 
@@ -206,6 +220,8 @@ export type ParsedSetPartnerOracleConfigInstruction<
     bridge: TAccountMetas[1];
     programData: TAccountMetas[2];
     program: TAccountMetas[3];
+    /** The co-guardian, required when `bridge.co_guardian` is set */
+    coGuardian?: TAccountMetas[4] | undefined;
   };
   data: SetPartnerOracleConfigInstructionData;
 };
@@ -218,7 +234,7 @@ export function parseSetPartnerOracleConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetPartnerOracleConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -228,6 +244,12 @@ export function parseSetPartnerOracleConfigInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -235,6 +257,7 @@ export function parseSetPartnerOracleConfigInstruction<
       bridge: getNextAccount(),
       programData: getNextAccount(),
       program: getNextAccount(),
+      coGuardian: getNextOptionalAccount(),
     },
     data: getSetPartnerOracleConfigInstructionDataDecoder().decode(
       instruction.data
@@ -48,6 +48,7 @@ export type SetPauseStatusInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountCoGuardian extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,10 @@ export type SetPauseStatusInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountCoGuardian extends string
+        ? ReadonlySignerAccount<TAccountCoGuardian> &
+            AccountSignerMeta<TAccountCoGuardian>
+        : TAccountCoGuardian,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,25 +106,34 @@ export function getSetPauseStatusInstructionDataCodec(): FixedSizeCodec<
 export type SetPauseStatusInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountCoGuardian extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** The co-guardian, required when `bridge.co_guardian` is set */
+  coGuardian?: TransactionSigner<TAccountCoGuardian>;
   newPaused: SetPauseStatusInstructionDataArgs['newPaused'];
 };
 
 export function getSetPauseStatusInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountCoGuardian extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetPauseStatusInput<TAccountBridge, TAccountGuardian>,
+  input: SetPauseStatusInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountCoGuardian
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetPauseStatusInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountCoGuardian
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -128,6 +142,7 @@ export function getSetPauseStatusInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    coGuardian: { value: input.coGuardian ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -142,6 +157,7 @@ export function getSetPauseStatusInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.coGuardian),
     ],
     data: getSetPauseStatusInstructionDataEncoder().encode(
       args as SetPauseStatusInstructionDataArgs
@@ -150,7 +166,8 @@ export function getSetPauseStatusInstruction<
   } as SetPauseStatusInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountCoGuardian
   >);
 }
 
@@ -164,6 +181,8 @@ export type ParsedSetPauseStatusInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** The co-guardian, required when `bridge.co_guardian` is set */
+    coGuardian?: TAccountMetas[2] | undefined;
   };
   data: SetPauseStatusInstructionData;
 };
@@ -176,7 +195,7 @@ export function parseSetPauseStatusInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetPauseStatusInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -186,9 +205,19 @@ export function parseSetPauseStatusInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      coGuardian: getNextOptionalAccount(),
+    },
     data: getSetPauseStatusInstructionDataDecoder().decode(instruction.data),
   };
 }
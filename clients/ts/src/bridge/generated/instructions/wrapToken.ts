@@ -54,7 +54,9 @@ export type WrapTokenInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
+  TAccountWrappedTokenInfo extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountDeniedRemoteToken extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
@@ -77,9 +79,15 @@ export type WrapTokenInstruction<
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountWrappedTokenInfo extends string
+        ? WritableAccount<TAccountWrappedTokenInfo>
+        : TAccountWrappedTokenInfo,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountDeniedRemoteToken extends string
+        ? ReadonlyAccount<TAccountDeniedRemoteToken>
+        : TAccountDeniedRemoteToken,
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
@@ -181,7 +189,9 @@ export type WrapTokenInput<
   TAccountPayer extends string = string,
   TAccountGasFeeReceiver extends string = string,
   TAccountMint extends string = string,
+  TAccountWrappedTokenInfo extends string = string,
   TAccountBridge extends string = string,
+  TAccountDeniedRemoteToken extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -196,16 +206,28 @@ export type WrapTokenInput<
   /**
    * The new SPL Token-2022 mint being created for the wrapped token.
    * - Uses PDA with token metadata hash and decimals for deterministic address
-   * - Mint authority set to itself (mint account) for controlled minting
+   * - Mint and freeze authority set to itself (mint account) for controlled minting and
+   * guardian-initiated freezes
    * - Includes metadata pointer extension to store token information onchain
    */
   mint: Address<TAccountMint>;
+  /**
+   * Record of the wrapped mint's creation, queryable without replaying events.
+   * - Uses PDA with WRAPPED_TOKEN_INFO_SEED and the mint address
+   * - Payer funds the account creation
+   */
+  wrappedTokenInfo: Address<TAccountWrappedTokenInfo>;
   /**
    * The main bridge state account that tracks cross-chain operations.
    * Used to increment the nonce counter and manage EIP-1559 gas pricing.
    * Must be mutable to update the nonce after creating the outgoing message.
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The denylist entry of the remote token being wrapped; the instruction fails if it exists.
+   * token; only its existence is read
+   */
+  deniedRemoteToken: Address<TAccountDeniedRemoteToken>;
   /**
    * The outgoing message account that stores the cross-chain call to register
    * the wrapped token on the Base blockchain. Contains the encoded function call
@@ -234,7 +256,9 @@ export function getWrapTokenInstruction<
   TAccountPayer extends string,
   TAccountGasFeeReceiver extends string,
   TAccountMint extends string,
+  TAccountWrappedTokenInfo extends string,
   TAccountBridge extends string,
+  TAccountDeniedRemoteToken extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -244,7 +268,9 @@ export function getWrapTokenInstruction<
     TAccountPayer,
     TAccountGasFeeReceiver,
     TAccountMint,
+    TAccountWrappedTokenInfo,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -255,7 +281,9 @@ export function getWrapTokenInstruction<
   TAccountPayer,
   TAccountGasFeeReceiver,
   TAccountMint,
+  TAccountWrappedTokenInfo,
   TAccountBridge,
+  TAccountDeniedRemoteToken,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
   TAccountSystemProgram
@@ -268,7 +296,15 @@ export function getWrapTokenInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
+    wrappedTokenInfo: {
+      value: input.wrappedTokenInfo ?? null,
+      isWritable: true,
+    },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    deniedRemoteToken: {
+      value: input.deniedRemoteToken ?? null,
+      isWritable: false,
+    },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -297,7 +333,9 @@ export function getWrapTokenInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.gasFeeReceiver),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.wrappedTokenInfo),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.deniedRemoteToken),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -311,7 +349,9 @@ export function getWrapTokenInstruction<
     TAccountPayer,
     TAccountGasFeeReceiver,
     TAccountMint,
+    TAccountWrappedTokenInfo,
     TAccountBridge,
+    TAccountDeniedRemoteToken,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
     TAccountSystemProgram
@@ -334,32 +374,44 @@ export type ParsedWrapTokenInstruction<
     /**
      * The new SPL Token-2022 mint being created for the wrapped token.
      * - Uses PDA with token metadata hash and decimals for deterministic address
-     * - Mint authority set to itself (mint account) for controlled minting
+     * - Mint and freeze authority set to itself (mint account) for controlled minting and
+     * guardian-initiated freezes
      * - Includes metadata pointer extension to store token information onchain
      */
     mint: TAccountMetas[2];
+    /**
+     * Record of the wrapped mint's creation, queryable without replaying events.
+     * - Uses PDA with WRAPPED_TOKEN_INFO_SEED and the mint address
+     * - Payer funds the account creation
+     */
+    wrappedTokenInfo: TAccountMetas[3];
     /**
      * The main bridge state account that tracks cross-chain operations.
      * Used to increment the nonce counter and manage EIP-1559 gas pricing.
      * Must be mutable to update the nonce after creating the outgoing message.
      */
-    bridge: TAccountMetas[3];
+    bridge: TAccountMetas[4];
+    /**
+     * The denylist entry of the remote token being wrapped; the instruction fails if it exists.
+     * token; only its existence is read
+     */
+    deniedRemoteToken: TAccountMetas[5];
     /**
      * The outgoing message account that stores the cross-chain call to register
      * the wrapped token on the Base blockchain. Contains the encoded function call
      * with token address, local mint address, and scaling parameters.
      */
-    outgoingMessage: TAccountMetas[4];
+    outgoingMessage: TAccountMetas[6];
     /**
      * SPL Token-2022 program for creating the mint with metadata extensions.
      * Required for initializing tokens with advanced features like metadata pointers.
      */
-    tokenProgram: TAccountMetas[5];
+    tokenProgram: TAccountMetas[7];
     /**
      * System program required for creating new accounts and transferring lamports.
      * Used internally by Anchor for account initialization and rent payments.
      */
-    systemProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[8];
   };
   data: WrapTokenInstructionData;
 };
@@ -372,7 +424,7 @@ export function parseWrapTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWrapTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -388,7 +440,9 @@ export function parseWrapTokenInstruction<
       payer: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
       mint: getNextAccount(),
+      wrappedTokenInfo: getNextAccount(),
       bridge: getNextAccount(),
+      deniedRemoteToken: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -14,9 +14,14 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptGuardianInstruction,
+  type ParsedAddDeniedRemoteTokenInstruction,
+  type ParsedAddOracleSignerInstruction,
+  type ParsedAppendChunksToCallBufferInstruction,
   type ParsedAppendToCallBufferInstruction,
   type ParsedAppendToProveBufferDataInstruction,
   type ParsedAppendToProveBufferProofInstruction,
+  type ParsedBeginRelayInstruction,
   type ParsedBridgeCallBufferedInstruction,
   type ParsedBridgeCallInstruction,
   type ParsedBridgeSolInstruction,
@@ -25,28 +30,75 @@ import {
   type ParsedBridgeSplWithBufferedCallInstruction,
   type ParsedBridgeWrappedTokenInstruction,
   type ParsedBridgeWrappedTokenWithBufferedCallInstruction,
+  type ParsedCancelGuardianTransferInstruction,
   type ParsedCloseCallBufferInstruction,
+  type ParsedCloseOutputRootInstruction,
   type ParsedCloseProveBufferInstruction,
+  type ParsedContinueRelayInstruction,
+  type ParsedFreezeWrappedAccountInstruction,
+  type ParsedGetBaseFeeInstruction,
+  type ParsedGetFeeBoundsInstruction,
+  type ParsedGetHighestRelayedNonceInstruction,
+  type ParsedGetOracleSignersInstruction,
+  type ParsedGetProgramAuthoritiesInstruction,
+  type ParsedGetProvenMessageInstruction,
+  type ParsedGetTotalLockedSolInstruction,
   type ParsedInitializeCallBufferInstruction,
   type ParsedInitializeInstruction,
   type ParsedInitializeProveBufferInstruction,
+  type ParsedMigrateBridgeInstruction,
+  type ParsedMigrateIncomingMessageInstruction,
+  type ParsedMigrateOutputRootInstruction,
   type ParsedProveMessageBufferedInstruction,
   type ParsedProveMessageInstruction,
+  type ParsedQuoteTotalCostInstruction,
   type ParsedRegisterOutputRootInstruction,
+  type ParsedRegisterOutputRootsBatchInstruction,
   type ParsedRelayMessageInstruction,
+  type ParsedRemoveDeniedRemoteTokenInstruction,
+  type ParsedRemoveOracleSignerInstruction,
+  type ParsedResetBaseFeeInstruction,
+  type ParsedRevalidateOutputRootInstruction,
   type ParsedSetAdjustmentDenominatorInstruction,
   type ParsedSetBlockIntervalRequirementInstruction,
+  type ParsedSetCallTypeGasMultiplierInstruction,
+  type ParsedSetCoGuardianInstruction,
+  type ParsedSetDailySolVolumeLimitsInstruction,
+  type ParsedSetDecayGraceWindowsInstruction,
+  type ParsedSetDisjointSignerSetsInstruction,
+  type ParsedSetEip1559ParamsInstruction,
+  type ParsedSetEnforceRelayNonceOrderInstruction,
   type ParsedSetGasCostScalerDpInstruction,
   type ParsedSetGasCostScalerInstruction,
   type ParsedSetGasFeeReceiverInstruction,
   type ParsedSetGasPerCallInstruction,
   type ParsedSetGasTargetInstruction,
+  type ParsedSetLargeFinalizeThresholdInstruction,
+  type ParsedSetMaxBlockAdvanceInstruction,
+  type ParsedSetMaxCallBufferAppendsInstruction,
   type ParsedSetMaxCallBufferSizeInstruction,
+  type ParsedSetMaxProveBufferProofLenInstruction,
+  type ParsedSetMaxRootAgeInstruction,
+  type ParsedSetMaxWrappedTokensInstruction,
+  type ParsedSetMaximumFeeLamportsInstruction,
+  type ParsedSetMinProveToRelayDelayInstruction,
+  type ParsedSetMinRegistrationIntervalInstruction,
   type ParsedSetMinimumBaseFeeInstruction,
+  type ParsedSetMinimumFeeLamportsInstruction,
   type ParsedSetOracleSignersInstruction,
+  type ParsedSetOracleThresholdInstruction,
   type ParsedSetPartnerOracleConfigInstruction,
   type ParsedSetPauseStatusInstruction,
+  type ParsedSetPausedOpsInstruction,
+  type ParsedSetRateLimitInstruction,
+  type ParsedSetRegistrationRateLimitInstruction,
+  type ParsedSetRejectDuplicateIxsInstruction,
+  type ParsedSetRejectDuplicateRootsInstruction,
+  type ParsedSetRequireSelfCallInstruction,
+  type ParsedSetRevalidateOldRootsAfterBlocksInstruction,
   type ParsedSetWindowDurationInstruction,
+  type ParsedThawWrappedAccountInstruction,
+  type ParsedTransferCallBufferInstruction,
   type ParsedTransferGuardianInstruction,
   type ParsedWrapTokenInstruction,
 } from '../instructions';
@@ -56,10 +108,14 @@ export const BRIDGE_PROGRAM_ADDRESS = '' as Address<''>;
 export enum BridgeAccount {
   Bridge,
   CallBuffer,
+  DeniedRemoteToken,
   IncomingMessage,
   OutgoingMessage,
   OutputRoot,
   ProveBuffer,
+  RelayCursor,
+  RemoteTokenRateLimit,
+  WrappedTokenInfo,
 }
 
 export function identifyBridgeAccount(
@@ -88,6 +144,17 @@ export function identifyBridgeAccount(
   ) {
     return BridgeAccount.CallBuffer;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([67, 35, 173, 230, 19, 38, 101, 185])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.DeniedRemoteToken;
+  }
   if (
     containsBytes(
       data,
@@ -97,439 +164,1074 @@ export function identifyBridgeAccount(
       0
     )
   ) {
-    return BridgeAccount.IncomingMessage;
+    return BridgeAccount.IncomingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 255, 197, 226, 200, 215, 31, 29])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.OutgoingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 31, 168, 201, 229, 8, 180, 198])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.OutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 160, 227, 163, 97, 140, 40, 149])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.ProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([1, 250, 94, 184, 169, 144, 34, 37])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RelayCursor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([68, 122, 242, 217, 88, 235, 251, 61])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RemoteTokenRateLimit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 203, 221, 206, 239, 75, 120, 236])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.WrappedTokenInfo;
+  }
+  throw new Error(
+    'The provided account could not be identified as a bridge account.'
+  );
+}
+
+export enum BridgeInstruction {
+  AcceptGuardian,
+  AddDeniedRemoteToken,
+  AddOracleSigner,
+  AppendChunksToCallBuffer,
+  AppendToCallBuffer,
+  AppendToProveBufferData,
+  AppendToProveBufferProof,
+  BeginRelay,
+  BridgeCall,
+  BridgeCallBuffered,
+  BridgeSol,
+  BridgeSolWithBufferedCall,
+  BridgeSpl,
+  BridgeSplWithBufferedCall,
+  BridgeWrappedToken,
+  BridgeWrappedTokenWithBufferedCall,
+  CancelGuardianTransfer,
+  CloseCallBuffer,
+  CloseOutputRoot,
+  CloseProveBuffer,
+  ContinueRelay,
+  FreezeWrappedAccount,
+  GetBaseFee,
+  GetFeeBounds,
+  GetHighestRelayedNonce,
+  GetOracleSigners,
+  GetProgramAuthorities,
+  GetProvenMessage,
+  GetTotalLockedSol,
+  Initialize,
+  InitializeCallBuffer,
+  InitializeProveBuffer,
+  MigrateBridge,
+  MigrateIncomingMessage,
+  MigrateOutputRoot,
+  ProveMessage,
+  ProveMessageBuffered,
+  QuoteTotalCost,
+  RegisterOutputRoot,
+  RegisterOutputRootsBatch,
+  RelayMessage,
+  RemoveDeniedRemoteToken,
+  RemoveOracleSigner,
+  ResetBaseFee,
+  RevalidateOutputRoot,
+  SetAdjustmentDenominator,
+  SetBlockIntervalRequirement,
+  SetCallTypeGasMultiplier,
+  SetCoGuardian,
+  SetDailySolVolumeLimits,
+  SetDecayGraceWindows,
+  SetDisjointSignerSets,
+  SetEip1559Params,
+  SetEnforceRelayNonceOrder,
+  SetGasCostScaler,
+  SetGasCostScalerDp,
+  SetGasFeeReceiver,
+  SetGasPerCall,
+  SetGasTarget,
+  SetLargeFinalizeThreshold,
+  SetMaxBlockAdvance,
+  SetMaxCallBufferAppends,
+  SetMaxCallBufferSize,
+  SetMaxProveBufferProofLen,
+  SetMaxRootAge,
+  SetMaxWrappedTokens,
+  SetMaximumFeeLamports,
+  SetMinProveToRelayDelay,
+  SetMinRegistrationInterval,
+  SetMinimumBaseFee,
+  SetMinimumFeeLamports,
+  SetOracleSigners,
+  SetOracleThreshold,
+  SetPartnerOracleConfig,
+  SetPauseStatus,
+  SetPausedOps,
+  SetRateLimit,
+  SetRegistrationRateLimit,
+  SetRejectDuplicateIxs,
+  SetRejectDuplicateRoots,
+  SetRequireSelfCall,
+  SetRevalidateOldRootsAfterBlocks,
+  SetWindowDuration,
+  ThawWrappedAccount,
+  TransferCallBuffer,
+  TransferGuardian,
+  WrapToken,
+}
+
+export function identifyBridgeInstruction(
+  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): BridgeInstruction {
+  const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([130, 141, 66, 69, 80, 183, 54, 186])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AcceptGuardian;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 92, 223, 8, 146, 137, 13, 76])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AddDeniedRemoteToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 217, 139, 89, 215, 220, 193, 3])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AddOracleSigner;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([225, 225, 90, 247, 108, 140, 44, 60])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendChunksToCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 115, 232, 194, 248, 32, 39, 21])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 153, 157, 132, 144, 253, 235, 44])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToProveBufferData;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([234, 13, 59, 98, 112, 207, 250, 184])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToProveBufferProof;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([177, 249, 121, 49, 15, 114, 41, 19])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BeginRelay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 23, 83, 238, 200, 18, 111, 95])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 112, 52, 204, 33, 68, 62, 85])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeCallBuffered;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 190, 32, 158, 75, 153, 32, 86])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSol;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 106, 74, 190, 246, 31, 157, 12])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSolWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 109, 172, 103, 8, 187, 223, 126])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSpl;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 187, 229, 4, 110, 8, 116, 153])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSplWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([55, 201, 139, 188, 226, 16, 136, 143])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeWrappedToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 175, 150, 237, 216, 76, 56, 5])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([127, 223, 83, 67, 130, 211, 168, 227])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CancelGuardianTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 188, 7, 198, 64, 178, 62, 29])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([249, 227, 155, 186, 2, 131, 237, 62])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseOutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 14, 144, 49, 3, 124, 210, 74])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([165, 214, 165, 56, 0, 16, 105, 142])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ContinueRelay;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 232, 106, 193, 134, 107, 55, 34])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.FreezeWrappedAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([26, 163, 183, 165, 90, 187, 191, 166])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetBaseFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([177, 45, 151, 240, 251, 140, 158, 165])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetFeeBounds;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 60, 239, 143, 57, 229, 197, 116])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetHighestRelayedNonce;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([22, 95, 66, 222, 27, 157, 75, 181])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetOracleSigners;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 58, 180, 185, 73, 134, 153, 135])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetProgramAuthorities;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([197, 54, 55, 216, 62, 184, 214, 134])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetProvenMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([5, 123, 182, 53, 55, 206, 238, 234])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetTotalLockedSol;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([175, 175, 109, 31, 13, 152, 155, 237])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.Initialize;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([85, 68, 100, 234, 255, 226, 95, 72])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitializeCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 200, 91, 188, 138, 211, 253, 222])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitializeProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 63, 64, 21, 180, 183, 197, 236])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.MigrateBridge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 193, 235, 74, 86, 211, 96, 187])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.MigrateIncomingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 10, 140, 182, 64, 96, 83, 230])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.MigrateOutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 66, 78, 136, 158, 187, 47, 115])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ProveMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([211, 48, 193, 183, 2, 51, 102, 159])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ProveMessageBuffered;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([93, 130, 225, 79, 91, 86, 71, 124])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.QuoteTotalCost;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 66, 12, 154, 4, 123, 196, 66])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RegisterOutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([75, 145, 129, 168, 219, 158, 241, 47])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RegisterOutputRootsBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 90, 182, 138, 51, 248, 175, 98])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RelayMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([179, 177, 160, 61, 154, 16, 67, 15])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RemoveDeniedRemoteToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([103, 205, 177, 215, 115, 60, 121, 113])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RemoveOracleSigner;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([150, 255, 197, 226, 200, 215, 31, 29])
+        new Uint8Array([63, 245, 100, 235, 40, 163, 196, 148])
       ),
       0
     )
   ) {
-    return BridgeAccount.OutgoingMessage;
+    return BridgeInstruction.ResetBaseFee;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([11, 31, 168, 201, 229, 8, 180, 198])
+        new Uint8Array([87, 71, 25, 111, 69, 30, 209, 166])
       ),
       0
     )
   ) {
-    return BridgeAccount.OutputRoot;
+    return BridgeInstruction.RevalidateOutputRoot;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([78, 160, 227, 163, 97, 140, 40, 149])
+        new Uint8Array([31, 91, 190, 63, 164, 7, 31, 150])
       ),
       0
     )
   ) {
-    return BridgeAccount.ProveBuffer;
+    return BridgeInstruction.SetAdjustmentDenominator;
   }
-  throw new Error(
-    'The provided account could not be identified as a bridge account.'
-  );
-}
-
-export enum BridgeInstruction {
-  AppendToCallBuffer,
-  AppendToProveBufferData,
-  AppendToProveBufferProof,
-  BridgeCall,
-  BridgeCallBuffered,
-  BridgeSol,
-  BridgeSolWithBufferedCall,
-  BridgeSpl,
-  BridgeSplWithBufferedCall,
-  BridgeWrappedToken,
-  BridgeWrappedTokenWithBufferedCall,
-  CloseCallBuffer,
-  CloseProveBuffer,
-  Initialize,
-  InitializeCallBuffer,
-  InitializeProveBuffer,
-  ProveMessage,
-  ProveMessageBuffered,
-  RegisterOutputRoot,
-  RelayMessage,
-  SetAdjustmentDenominator,
-  SetBlockIntervalRequirement,
-  SetGasCostScaler,
-  SetGasCostScalerDp,
-  SetGasFeeReceiver,
-  SetGasPerCall,
-  SetGasTarget,
-  SetMaxCallBufferSize,
-  SetMinimumBaseFee,
-  SetOracleSigners,
-  SetPartnerOracleConfig,
-  SetPauseStatus,
-  SetWindowDuration,
-  TransferGuardian,
-  WrapToken,
-}
-
-export function identifyBridgeInstruction(
-  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
-): BridgeInstruction {
-  const data = 'data' in instruction ? instruction.data : instruction;
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([113, 115, 232, 194, 248, 32, 39, 21])
+        new Uint8Array([76, 70, 237, 100, 33, 108, 19, 42])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToCallBuffer;
+    return BridgeInstruction.SetBlockIntervalRequirement;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([4, 153, 157, 132, 144, 253, 235, 44])
+        new Uint8Array([156, 30, 130, 11, 194, 179, 93, 147])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToProveBufferData;
+    return BridgeInstruction.SetCallTypeGasMultiplier;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([234, 13, 59, 98, 112, 207, 250, 184])
+        new Uint8Array([34, 82, 107, 63, 38, 242, 38, 200])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToProveBufferProof;
+    return BridgeInstruction.SetCoGuardian;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([90, 23, 83, 238, 200, 18, 111, 95])
+        new Uint8Array([58, 68, 104, 149, 165, 67, 122, 47])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeCall;
+    return BridgeInstruction.SetDailySolVolumeLimits;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([138, 112, 52, 204, 33, 68, 62, 85])
+        new Uint8Array([36, 127, 69, 75, 237, 19, 193, 14])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeCallBuffered;
+    return BridgeInstruction.SetDecayGraceWindows;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([190, 190, 32, 158, 75, 153, 32, 86])
+        new Uint8Array([243, 44, 133, 127, 206, 150, 187, 169])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSol;
+    return BridgeInstruction.SetDisjointSignerSets;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([52, 106, 74, 190, 246, 31, 157, 12])
+        new Uint8Array([210, 68, 187, 152, 195, 171, 25, 213])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSolWithBufferedCall;
+    return BridgeInstruction.SetEip1559Params;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([87, 109, 172, 103, 8, 187, 223, 126])
+        new Uint8Array([195, 11, 158, 104, 200, 201, 165, 204])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSpl;
+    return BridgeInstruction.SetEnforceRelayNonceOrder;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([86, 187, 229, 4, 110, 8, 116, 153])
+        new Uint8Array([148, 146, 101, 170, 5, 6, 222, 119])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSplWithBufferedCall;
+    return BridgeInstruction.SetGasCostScaler;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([55, 201, 139, 188, 226, 16, 136, 143])
+        new Uint8Array([198, 111, 160, 55, 172, 138, 99, 164])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeWrappedToken;
+    return BridgeInstruction.SetGasCostScalerDp;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([117, 175, 150, 237, 216, 76, 56, 5])
+        new Uint8Array([58, 188, 230, 188, 47, 188, 79, 154])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
+    return BridgeInstruction.SetGasFeeReceiver;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([132, 188, 7, 198, 64, 178, 62, 29])
+        new Uint8Array([164, 95, 213, 130, 26, 69, 82, 127])
       ),
       0
     )
   ) {
-    return BridgeInstruction.CloseCallBuffer;
+    return BridgeInstruction.SetGasPerCall;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([79, 14, 144, 49, 3, 124, 210, 74])
+        new Uint8Array([132, 25, 19, 13, 118, 63, 167, 102])
       ),
       0
     )
   ) {
-    return BridgeInstruction.CloseProveBuffer;
+    return BridgeInstruction.SetGasTarget;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([175, 175, 109, 31, 13, 152, 155, 237])
+        new Uint8Array([8, 218, 107, 94, 219, 134, 19, 243])
       ),
       0
     )
   ) {
-    return BridgeInstruction.Initialize;
+    return BridgeInstruction.SetLargeFinalizeThreshold;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([85, 68, 100, 234, 255, 226, 95, 72])
+        new Uint8Array([46, 181, 211, 117, 53, 92, 70, 105])
       ),
       0
     )
   ) {
-    return BridgeInstruction.InitializeCallBuffer;
+    return BridgeInstruction.SetMaxBlockAdvance;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([20, 200, 91, 188, 138, 211, 253, 222])
+        new Uint8Array([125, 131, 164, 125, 47, 251, 254, 21])
       ),
       0
     )
   ) {
-    return BridgeInstruction.InitializeProveBuffer;
+    return BridgeInstruction.SetMaxCallBufferAppends;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([172, 66, 78, 136, 158, 187, 47, 115])
+        new Uint8Array([140, 178, 4, 238, 245, 66, 117, 189])
       ),
       0
     )
   ) {
-    return BridgeInstruction.ProveMessage;
+    return BridgeInstruction.SetMaxCallBufferSize;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([211, 48, 193, 183, 2, 51, 102, 159])
+        new Uint8Array([7, 44, 231, 9, 148, 131, 215, 116])
       ),
       0
     )
   ) {
-    return BridgeInstruction.ProveMessageBuffered;
+    return BridgeInstruction.SetMaxProveBufferProofLen;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([215, 66, 12, 154, 4, 123, 196, 66])
+        new Uint8Array([148, 167, 52, 173, 213, 42, 191, 21])
       ),
       0
     )
   ) {
-    return BridgeInstruction.RegisterOutputRoot;
+    return BridgeInstruction.SetMaxRootAge;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([187, 90, 182, 138, 51, 248, 175, 98])
+        new Uint8Array([9, 195, 210, 57, 133, 188, 32, 229])
       ),
       0
     )
   ) {
-    return BridgeInstruction.RelayMessage;
+    return BridgeInstruction.SetMaxWrappedTokens;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([31, 91, 190, 63, 164, 7, 31, 150])
+        new Uint8Array([182, 144, 106, 14, 49, 118, 137, 37])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetAdjustmentDenominator;
+    return BridgeInstruction.SetMaximumFeeLamports;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([76, 70, 237, 100, 33, 108, 19, 42])
+        new Uint8Array([161, 227, 199, 73, 250, 197, 75, 195])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetBlockIntervalRequirement;
+    return BridgeInstruction.SetMinProveToRelayDelay;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([148, 146, 101, 170, 5, 6, 222, 119])
+        new Uint8Array([251, 224, 73, 2, 86, 176, 123, 242])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasCostScaler;
+    return BridgeInstruction.SetMinRegistrationInterval;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([198, 111, 160, 55, 172, 138, 99, 164])
+        new Uint8Array([56, 95, 58, 94, 221, 136, 138, 156])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasCostScalerDp;
+    return BridgeInstruction.SetMinimumBaseFee;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([58, 188, 230, 188, 47, 188, 79, 154])
+        new Uint8Array([51, 34, 17, 148, 45, 212, 58, 154])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasFeeReceiver;
+    return BridgeInstruction.SetMinimumFeeLamports;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([164, 95, 213, 130, 26, 69, 82, 127])
+        new Uint8Array([38, 183, 212, 67, 28, 241, 25, 160])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasPerCall;
+    return BridgeInstruction.SetOracleSigners;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([132, 25, 19, 13, 118, 63, 167, 102])
+        new Uint8Array([181, 85, 21, 30, 120, 168, 37, 233])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasTarget;
+    return BridgeInstruction.SetOracleThreshold;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([140, 178, 4, 238, 245, 66, 117, 189])
+        new Uint8Array([34, 48, 231, 135, 42, 113, 217, 157])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetMaxCallBufferSize;
+    return BridgeInstruction.SetPartnerOracleConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([56, 95, 58, 94, 221, 136, 138, 156])
+        new Uint8Array([118, 25, 145, 217, 114, 209, 236, 145])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetMinimumBaseFee;
+    return BridgeInstruction.SetPauseStatus;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([38, 183, 212, 67, 28, 241, 25, 160])
+        new Uint8Array([79, 93, 42, 237, 25, 205, 77, 108])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetOracleSigners;
+    return BridgeInstruction.SetPausedOps;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([34, 48, 231, 135, 42, 113, 217, 157])
+        new Uint8Array([42, 212, 44, 91, 198, 58, 60, 239])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetPartnerOracleConfig;
+    return BridgeInstruction.SetRateLimit;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([118, 25, 145, 217, 114, 209, 236, 145])
+        new Uint8Array([235, 40, 35, 145, 157, 190, 133, 145])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetPauseStatus;
+    return BridgeInstruction.SetRegistrationRateLimit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 4, 123, 206, 56, 212, 71, 24])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetRejectDuplicateIxs;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 127, 44, 128, 192, 1, 19, 245])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetRejectDuplicateRoots;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 4, 190, 19, 207, 162, 47, 120])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetRequireSelfCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 172, 88, 61, 96, 230, 105, 30])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetRevalidateOldRootsAfterBlocks;
   }
   if (
     containsBytes(
@@ -542,6 +1244,28 @@ export function identifyBridgeInstruction(
   ) {
     return BridgeInstruction.SetWindowDuration;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([254, 163, 11, 9, 189, 227, 47, 198])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ThawWrappedAccount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([51, 82, 120, 74, 102, 224, 222, 174])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.TransferCallBuffer;
+  }
   if (
     containsBytes(
       data,
@@ -570,6 +1294,18 @@ export function identifyBridgeInstruction(
 }
 
 export type ParsedBridgeInstruction<TProgram extends string = ''> =
+  | ({
+      instructionType: BridgeInstruction.AcceptGuardian;
+    } & ParsedAcceptGuardianInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.AddDeniedRemoteToken;
+    } & ParsedAddDeniedRemoteTokenInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.AddOracleSigner;
+    } & ParsedAddOracleSignerInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.AppendChunksToCallBuffer;
+    } & ParsedAppendChunksToCallBufferInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.AppendToCallBuffer;
     } & ParsedAppendToCallBufferInstruction<TProgram>)
@@ -579,6 +1315,9 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.AppendToProveBufferProof;
     } & ParsedAppendToProveBufferProofInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.BeginRelay;
+    } & ParsedBeginRelayInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.BridgeCall;
     } & ParsedBridgeCallInstruction<TProgram>)
@@ -603,12 +1342,45 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
     } & ParsedBridgeWrappedTokenWithBufferedCallInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CancelGuardianTransfer;
+    } & ParsedCancelGuardianTransferInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.CloseCallBuffer;
     } & ParsedCloseCallBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CloseOutputRoot;
+    } & ParsedCloseOutputRootInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.CloseProveBuffer;
     } & ParsedCloseProveBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ContinueRelay;
+    } & ParsedContinueRelayInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.FreezeWrappedAccount;
+    } & ParsedFreezeWrappedAccountInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetBaseFee;
+    } & ParsedGetBaseFeeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetFeeBounds;
+    } & ParsedGetFeeBoundsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetHighestRelayedNonce;
+    } & ParsedGetHighestRelayedNonceInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetOracleSigners;
+    } & ParsedGetOracleSignersInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetProgramAuthorities;
+    } & ParsedGetProgramAuthoritiesInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetProvenMessage;
+    } & ParsedGetProvenMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetTotalLockedSol;
+    } & ParsedGetTotalLockedSolInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
@@ -618,24 +1390,72 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.InitializeProveBuffer;
     } & ParsedInitializeProveBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.MigrateBridge;
+    } & ParsedMigrateBridgeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.MigrateIncomingMessage;
+    } & ParsedMigrateIncomingMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.MigrateOutputRoot;
+    } & ParsedMigrateOutputRootInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.ProveMessage;
     } & ParsedProveMessageInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.ProveMessageBuffered;
     } & ParsedProveMessageBufferedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.QuoteTotalCost;
+    } & ParsedQuoteTotalCostInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.RegisterOutputRoot;
     } & ParsedRegisterOutputRootInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RegisterOutputRootsBatch;
+    } & ParsedRegisterOutputRootsBatchInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.RelayMessage;
     } & ParsedRelayMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RemoveDeniedRemoteToken;
+    } & ParsedRemoveDeniedRemoteTokenInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RemoveOracleSigner;
+    } & ParsedRemoveOracleSignerInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ResetBaseFee;
+    } & ParsedResetBaseFeeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RevalidateOutputRoot;
+    } & ParsedRevalidateOutputRootInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetAdjustmentDenominator;
     } & ParsedSetAdjustmentDenominatorInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetBlockIntervalRequirement;
     } & ParsedSetBlockIntervalRequirementInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetCallTypeGasMultiplier;
+    } & ParsedSetCallTypeGasMultiplierInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetCoGuardian;
+    } & ParsedSetCoGuardianInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetDailySolVolumeLimits;
+    } & ParsedSetDailySolVolumeLimitsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetDecayGraceWindows;
+    } & ParsedSetDecayGraceWindowsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetDisjointSignerSets;
+    } & ParsedSetDisjointSignerSetsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetEip1559Params;
+    } & ParsedSetEip1559ParamsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetEnforceRelayNonceOrder;
+    } & ParsedSetEnforceRelayNonceOrderInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetGasCostScaler;
     } & ParsedSetGasCostScalerInstruction<TProgram>)
@@ -651,24 +1471,84 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.SetGasTarget;
     } & ParsedSetGasTargetInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetLargeFinalizeThreshold;
+    } & ParsedSetLargeFinalizeThresholdInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxBlockAdvance;
+    } & ParsedSetMaxBlockAdvanceInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxCallBufferAppends;
+    } & ParsedSetMaxCallBufferAppendsInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetMaxCallBufferSize;
     } & ParsedSetMaxCallBufferSizeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxProveBufferProofLen;
+    } & ParsedSetMaxProveBufferProofLenInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxRootAge;
+    } & ParsedSetMaxRootAgeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxWrappedTokens;
+    } & ParsedSetMaxWrappedTokensInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaximumFeeLamports;
+    } & ParsedSetMaximumFeeLamportsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMinProveToRelayDelay;
+    } & ParsedSetMinProveToRelayDelayInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMinRegistrationInterval;
+    } & ParsedSetMinRegistrationIntervalInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetMinimumBaseFee;
     } & ParsedSetMinimumBaseFeeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMinimumFeeLamports;
+    } & ParsedSetMinimumFeeLamportsInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetOracleSigners;
     } & ParsedSetOracleSignersInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetOracleThreshold;
+    } & ParsedSetOracleThresholdInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetPartnerOracleConfig;
     } & ParsedSetPartnerOracleConfigInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetPauseStatus;
     } & ParsedSetPauseStatusInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetPausedOps;
+    } & ParsedSetPausedOpsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRateLimit;
+    } & ParsedSetRateLimitInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRegistrationRateLimit;
+    } & ParsedSetRegistrationRateLimitInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRejectDuplicateIxs;
+    } & ParsedSetRejectDuplicateIxsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRejectDuplicateRoots;
+    } & ParsedSetRejectDuplicateRootsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRequireSelfCall;
+    } & ParsedSetRequireSelfCallInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRevalidateOldRootsAfterBlocks;
+    } & ParsedSetRevalidateOldRootsAfterBlocksInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetWindowDuration;
     } & ParsedSetWindowDurationInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ThawWrappedAccount;
+    } & ParsedThawWrappedAccountInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.TransferCallBuffer;
+    } & ParsedTransferCallBufferInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.TransferGuardian;
     } & ParsedTransferGuardianInstruction<TProgram>)
@@ -25,12 +25,14 @@ import {
 } from '@solana/kit';
 
 export type BaseOracleConfig = {
-  /** Number of required valid unique signatures */
+  /** Required approval weight of valid unique signatures */
   threshold: number;
   /** Number of signers in `signers` array */
   signerCount: number;
   /** Static list of authorized signer addresses */
   signers: Array<ReadonlyUint8Array>;
+  /** Approval weight of each signer in `signers` (a weight of 0 counts as 1) */
+  weights: ReadonlyUint8Array;
 };
 
 export type BaseOracleConfigArgs = BaseOracleConfig;
@@ -43,6 +45,7 @@ export function getBaseOracleConfigEncoder(): FixedSizeEncoder<BaseOracleConfigA
       'signers',
       getArrayEncoder(fixEncoderSize(getBytesEncoder(), 20), { size: 16 }),
     ],
+    ['weights', fixEncoderSize(getBytesEncoder(), 16)],
   ]);
 }
 
@@ -54,6 +57,7 @@ export function getBaseOracleConfigDecoder(): FixedSizeDecoder<BaseOracleConfig>
       'signers',
       getArrayDecoder(fixDecoderSize(getBytesDecoder(), 20), { size: 16 }),
     ],
+    ['weights', fixDecoderSize(getBytesDecoder(), 16)],
   ]);
 }
 
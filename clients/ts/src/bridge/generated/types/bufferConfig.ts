@@ -20,19 +20,41 @@ import {
 export type BufferConfig = {
   /** Maximum call buffer size. This caps the max size of a Solana → Base message. */
   maxCallBufferSize: bigint;
+  /** Maximum number of MMR proof nodes a prove buffer can be allocated for. */
+  maxProveBufferProofLen: bigint;
+  /**
+   * Maximum number of appends to a single call buffer (0 disables the limit). Captured by
+   * each call buffer at initialization.
+   */
+  maxCallBufferAppends: bigint;
 };
 
 export type BufferConfigArgs = {
   /** Maximum call buffer size. This caps the max size of a Solana → Base message. */
   maxCallBufferSize: number | bigint;
+  /** Maximum number of MMR proof nodes a prove buffer can be allocated for. */
+  maxProveBufferProofLen: number | bigint;
+  /**
+   * Maximum number of appends to a single call buffer (0 disables the limit). Captured by
+   * each call buffer at initialization.
+   */
+  maxCallBufferAppends: number | bigint;
 };
 
 export function getBufferConfigEncoder(): FixedSizeEncoder<BufferConfigArgs> {
-  return getStructEncoder([['maxCallBufferSize', getU64Encoder()]]);
+  return getStructEncoder([
+    ['maxCallBufferSize', getU64Encoder()],
+    ['maxProveBufferProofLen', getU64Encoder()],
+    ['maxCallBufferAppends', getU64Encoder()],
+  ]);
 }
 
 export function getBufferConfigDecoder(): FixedSizeDecoder<BufferConfig> {
-  return getStructDecoder([['maxCallBufferSize', getU64Decoder()]]);
+  return getStructDecoder([
+    ['maxCallBufferSize', getU64Decoder()],
+    ['maxProveBufferProofLen', getU64Decoder()],
+    ['maxCallBufferAppends', getU64Decoder()],
+  ]);
 }
 
 export function getBufferConfigCodec(): FixedSizeCodec<
@@ -37,6 +37,8 @@ export type Eip1559 = {
   currentWindowGasUsed: bigint;
   /** Unix timestamp when the current window started (runtime state) */
   windowStartTime: bigint;
+  /** Number of consecutive expired windows without gas usage (runtime state) */
+  emptyWindowStreak: bigint;
 };
 
 export type Eip1559Args = {
@@ -51,6 +53,8 @@ export type Eip1559Args = {
   currentWindowGasUsed: number | bigint;
   /** Unix timestamp when the current window started (runtime state) */
   windowStartTime: number | bigint;
+  /** Number of consecutive expired windows without gas usage (runtime state) */
+  emptyWindowStreak: number | bigint;
 };
 
 export function getEip1559Encoder(): FixedSizeEncoder<Eip1559Args> {
@@ -59,6 +63,7 @@ export function getEip1559Encoder(): FixedSizeEncoder<Eip1559Args> {
     ['currentBaseFee', getU64Encoder()],
     ['currentWindowGasUsed', getU64Encoder()],
     ['windowStartTime', getI64Encoder()],
+    ['emptyWindowStreak', getU64Encoder()],
   ]);
 }
 
@@ -68,6 +73,7 @@ export function getEip1559Decoder(): FixedSizeDecoder<Eip1559> {
     ['currentBaseFee', getU64Decoder()],
     ['currentWindowGasUsed', getU64Decoder()],
     ['windowStartTime', getI64Decoder()],
+    ['emptyWindowStreak', getU64Decoder()],
   ]);
 }
 
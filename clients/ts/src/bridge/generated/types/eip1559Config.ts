@@ -30,6 +30,11 @@ export type Eip1559Config = {
    * on every step.
    */
   minimumBaseFee: bigint;
+  /**
+   * Number of consecutive empty windows during which the base fee holds before it starts
+   * to decay (0 decays from the first empty window)
+   */
+  decayGraceWindows: bigint;
 };
 
 export type Eip1559ConfigArgs = {
@@ -45,6 +50,11 @@ export type Eip1559ConfigArgs = {
    * on every step.
    */
   minimumBaseFee: number | bigint;
+  /**
+   * Number of consecutive empty windows during which the base fee holds before it starts
+   * to decay (0 decays from the first empty window)
+   */
+  decayGraceWindows: number | bigint;
 };
 
 export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
@@ -53,6 +63,7 @@ export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
     ['denominator', getU64Encoder()],
     ['windowDurationSeconds', getU64Encoder()],
     ['minimumBaseFee', getU64Encoder()],
+    ['decayGraceWindows', getU64Encoder()],
   ]);
 }
 
@@ -62,6 +73,7 @@ export function getEip1559ConfigDecoder(): FixedSizeDecoder<Eip1559Config> {
     ['denominator', getU64Decoder()],
     ['windowDurationSeconds', getU64Decoder()],
     ['minimumBaseFee', getU64Decoder()],
+    ['decayGraceWindows', getU64Decoder()],
   ]);
 }
 
@@ -10,8 +10,12 @@ import {
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   type Address,
@@ -29,6 +33,16 @@ export type GasConfig = {
   gasFeeReceiver: Address;
   /** Amount of gas per Solana --> Base message */
   gasPerCall: bigint;
+  /** Minimum fee in lamports charged per message, applied after scaling */
+  minimumFeeLamports: bigint;
+  /** Maximum fee in lamports charged per message, applied after scaling (0 = no ceiling) */
+  maximumFeeLamports: bigint;
+  /**
+   * Fee multiplier in basis points for each `CallType` (indexed by variant), applied to messages
+   * carrying a call. 10_000 charges the base fee unchanged. Each multiplier must be in
+   * `1..=MAX_GAS_MULTIPLIER_BPS`.
+   */
+  callTypeGasMultipliersBps: Array<number>;
 };
 
 export type GasConfigArgs = {
@@ -40,6 +54,16 @@ export type GasConfigArgs = {
   gasFeeReceiver: Address;
   /** Amount of gas per Solana --> Base message */
   gasPerCall: number | bigint;
+  /** Minimum fee in lamports charged per message, applied after scaling */
+  minimumFeeLamports: number | bigint;
+  /** Maximum fee in lamports charged per message, applied after scaling (0 = no ceiling) */
+  maximumFeeLamports: number | bigint;
+  /**
+   * Fee multiplier in basis points for each `CallType` (indexed by variant), applied to messages
+   * carrying a call. 10_000 charges the base fee unchanged. Each multiplier must be in
+   * `1..=MAX_GAS_MULTIPLIER_BPS`.
+   */
+  callTypeGasMultipliersBps: Array<number>;
 };
 
 export function getGasConfigEncoder(): FixedSizeEncoder<GasConfigArgs> {
@@ -48,6 +72,12 @@ export function getGasConfigEncoder(): FixedSizeEncoder<GasConfigArgs> {
     ['gasCostScalerDp', getU64Encoder()],
     ['gasFeeReceiver', getAddressEncoder()],
     ['gasPerCall', getU64Encoder()],
+    ['minimumFeeLamports', getU64Encoder()],
+    ['maximumFeeLamports', getU64Encoder()],
+    [
+      'callTypeGasMultipliersBps',
+      getArrayEncoder(getU32Encoder(), { size: 4 }),
+    ],
   ]);
 }
 
@@ -57,6 +87,12 @@ export function getGasConfigDecoder(): FixedSizeDecoder<GasConfig> {
     ['gasCostScalerDp', getU64Decoder()],
     ['gasFeeReceiver', getAddressDecoder()],
     ['gasPerCall', getU64Decoder()],
+    ['minimumFeeLamports', getU64Decoder()],
+    ['maximumFeeLamports', getU64Decoder()],
+    [
+      'callTypeGasMultipliersBps',
+      getArrayDecoder(getU32Decoder(), { size: 4 }),
+    ],
   ]);
 }
 
@@ -6,7 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './baseFee';
+export * from './baseFeeReset';
 export * from './baseOracleConfig';
+export * from './blockIntervalRequirementUpdated';
 export * from './bridgeBaseToSolanaStateIncomingMessageMessage';
 export * from './bridgeBaseToSolanaStateIncomingMessageTransfer';
 export * from './bridgeSolanaToBaseStateOutgoingMessageMessage';
@@ -14,13 +17,39 @@ export * from './bridgeSolanaToBaseStateOutgoingMessageTransfer';
 export * from './bufferConfig';
 export * from './call';
 export * from './callType';
+export * from './coGuardianUpdated';
+export * from './config';
+export * from './duplicateRootRegistered';
 export * from './eip1559';
 export * from './eip1559Config';
+export * from './eip1559ConfigChanged';
+export * from './eip1559ConfigField';
+export * from './feeBounds';
 export * from './finalizeBridgeSol';
 export * from './finalizeBridgeSpl';
 export * from './finalizeBridgeWrappedToken';
 export * from './gasConfig';
+export * from './guardianTransferAccepted';
+export * from './guardianTransferCancelled';
+export * from './guardianTransferProposed';
 export * from './ix';
 export * from './ixAccount';
+export * from './oracleSigners';
+export * from './oracleSignersUpdated';
+export * from './outgoingMessageReceipt';
+export * from './outputRootEntry';
+export * from './outputRootRegistered';
+export * from './overlappingSignersApproved';
 export * from './partnerOracleConfig';
+export * from './programAuthorities';
+export * from './programAuthority';
 export * from './protocolConfig';
+export * from './provenMessage';
+export * from './quotedCall';
+export * from './quotedOperation';
+export * from './registrationBucket';
+export * from './totalCostQuote';
+export * from './totalLockedSol';
+export * from './volumeWindow';
+export * from './wrappedAccountFreezeUpdated';
+export * from './wrappedTokenCreated';
@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -30,6 +32,70 @@ export type ProtocolConfig = {
   blockIntervalRequirement: bigint;
   /** The Base evm address of SOL */
   remoteSolAddress: ReadonlyUint8Array;
+  /** Minimum number of seconds between two output root registrations (0 disables the check) */
+  minRegistrationIntervalSeconds: bigint;
+  /** Maximum age in seconds of an output root that messages can be proven against (0 disables the check) */
+  maxRootAgeSeconds: bigint;
+  /** Maximum number of output root registrations that can be made in a burst (0 disables the limit) */
+  registrationBucketCapacity: bigint;
+  /** Number of seconds after which one registration is returned to the bucket */
+  registrationRefillIntervalSeconds: bigint;
+  /**
+   * Whether registering the same output root as the previous registration is rejected
+   * (otherwise it is only flagged with a `DuplicateRootRegistered` event)
+   */
+  rejectDuplicateRoots: boolean;
+  /**
+   * Whether a registration is rejected when a signer counts toward both the Base and partner
+   * thresholds (otherwise the overlap is only flagged with an `OverlappingSignersApproved`
+   * event)
+   */
+  disjointSignerSets: boolean;
+  /** Whether relaying a message that contains two identical instructions is rejected */
+  rejectDuplicateIxs: boolean;
+  /**
+   * Whether relaying a message with a Base nonce at or below `highest_relayed_nonce` is
+   * rejected
+   */
+  enforceRelayNonceOrder: boolean;
+  /**
+   * Whether `bridge_call` and `bridge_call_buffered` require `from` to also be the payer,
+   * disallowing third-party sponsors
+   */
+  requireSelfCall: boolean;
+  /** Maximum number of wrapped mints that `wrap_token` can create (0 disables the limit) */
+  maxWrappedTokens: bigint;
+  /**
+   * SOL finalizations above this many lamports must be co-signed by the guardian (0 disables
+   * the check). Token transfers are not covered since their amounts have no common unit.
+   */
+  largeFinalizeThreshold: bigint;
+  /**
+   * Maximum lamports bridged from Solana to Base per fixed daily window (0 disables the limit).
+   * Windows do not overlap, so up to twice the limit can pass around a window boundary.
+   */
+  dailyOutboundSolLimit: bigint;
+  /**
+   * Maximum lamports finalized from Base to Solana per fixed daily window (0 disables the
+   * limit). Windows do not overlap, so up to twice the limit can pass around a window boundary.
+   */
+  dailyInboundSolLimit: bigint;
+  /**
+   * Output roots more than this many Base blocks behind the latest registered block must be
+   * re-validated by the current oracle set before messages can be proven against them
+   * (0 disables the check)
+   */
+  revalidateOldRootsAfterBlocks: bigint;
+  /**
+   * Maximum number of Base blocks a registered output root may advance past the latest
+   * registered one (0 disables the check)
+   */
+  maxBlockAdvance: bigint;
+  /**
+   * Minimum number of seconds between a message being proven and it being relayed (0 disables
+   * the check)
+   */
+  minProveToRelayDelaySeconds: bigint;
 };
 
 export type ProtocolConfigArgs = {
@@ -40,12 +106,92 @@ export type ProtocolConfigArgs = {
   blockIntervalRequirement: number | bigint;
   /** The Base evm address of SOL */
   remoteSolAddress: ReadonlyUint8Array;
+  /** Minimum number of seconds between two output root registrations (0 disables the check) */
+  minRegistrationIntervalSeconds: number | bigint;
+  /** Maximum age in seconds of an output root that messages can be proven against (0 disables the check) */
+  maxRootAgeSeconds: number | bigint;
+  /** Maximum number of output root registrations that can be made in a burst (0 disables the limit) */
+  registrationBucketCapacity: number | bigint;
+  /** Number of seconds after which one registration is returned to the bucket */
+  registrationRefillIntervalSeconds: number | bigint;
+  /**
+   * Whether registering the same output root as the previous registration is rejected
+   * (otherwise it is only flagged with a `DuplicateRootRegistered` event)
+   */
+  rejectDuplicateRoots: boolean;
+  /**
+   * Whether a registration is rejected when a signer counts toward both the Base and partner
+   * thresholds (otherwise the overlap is only flagged with an `OverlappingSignersApproved`
+   * event)
+   */
+  disjointSignerSets: boolean;
+  /** Whether relaying a message that contains two identical instructions is rejected */
+  rejectDuplicateIxs: boolean;
+  /**
+   * Whether relaying a message with a Base nonce at or below `highest_relayed_nonce` is
+   * rejected
+   */
+  enforceRelayNonceOrder: boolean;
+  /**
+   * Whether `bridge_call` and `bridge_call_buffered` require `from` to also be the payer,
+   * disallowing third-party sponsors
+   */
+  requireSelfCall: boolean;
+  /** Maximum number of wrapped mints that `wrap_token` can create (0 disables the limit) */
+  maxWrappedTokens: number | bigint;
+  /**
+   * SOL finalizations above this many lamports must be co-signed by the guardian (0 disables
+   * the check). Token transfers are not covered since their amounts have no common unit.
+   */
+  largeFinalizeThreshold: number | bigint;
+  /**
+   * Maximum lamports bridged from Solana to Base per fixed daily window (0 disables the limit).
+   * Windows do not overlap, so up to twice the limit can pass around a window boundary.
+   */
+  dailyOutboundSolLimit: number | bigint;
+  /**
+   * Maximum lamports finalized from Base to Solana per fixed daily window (0 disables the
+   * limit). Windows do not overlap, so up to twice the limit can pass around a window boundary.
+   */
+  dailyInboundSolLimit: number | bigint;
+  /**
+   * Output roots more than this many Base blocks behind the latest registered block must be
+   * re-validated by the current oracle set before messages can be proven against them
+   * (0 disables the check)
+   */
+  revalidateOldRootsAfterBlocks: number | bigint;
+  /**
+   * Maximum number of Base blocks a registered output root may advance past the latest
+   * registered one (0 disables the check)
+   */
+  maxBlockAdvance: number | bigint;
+  /**
+   * Minimum number of seconds between a message being proven and it being relayed (0 disables
+   * the check)
+   */
+  minProveToRelayDelaySeconds: number | bigint;
 };
 
 export function getProtocolConfigEncoder(): FixedSizeEncoder<ProtocolConfigArgs> {
   return getStructEncoder([
     ['blockIntervalRequirement', getU64Encoder()],
     ['remoteSolAddress', fixEncoderSize(getBytesEncoder(), 20)],
+    ['minRegistrationIntervalSeconds', getU64Encoder()],
+    ['maxRootAgeSeconds', getU64Encoder()],
+    ['registrationBucketCapacity', getU64Encoder()],
+    ['registrationRefillIntervalSeconds', getU64Encoder()],
+    ['rejectDuplicateRoots', getBooleanEncoder()],
+    ['disjointSignerSets', getBooleanEncoder()],
+    ['rejectDuplicateIxs', getBooleanEncoder()],
+    ['enforceRelayNonceOrder', getBooleanEncoder()],
+    ['requireSelfCall', getBooleanEncoder()],
+    ['maxWrappedTokens', getU64Encoder()],
+    ['largeFinalizeThreshold', getU64Encoder()],
+    ['dailyOutboundSolLimit', getU64Encoder()],
+    ['dailyInboundSolLimit', getU64Encoder()],
+    ['revalidateOldRootsAfterBlocks', getU64Encoder()],
+    ['maxBlockAdvance', getU64Encoder()],
+    ['minProveToRelayDelaySeconds', getU64Encoder()],
   ]);
 }
 
@@ -53,6 +199,22 @@ export function getProtocolConfigDecoder(): FixedSizeDecoder<ProtocolConfig> {
   return getStructDecoder([
     ['blockIntervalRequirement', getU64Decoder()],
     ['remoteSolAddress', fixDecoderSize(getBytesDecoder(), 20)],
+    ['minRegistrationIntervalSeconds', getU64Decoder()],
+    ['maxRootAgeSeconds', getU64Decoder()],
+    ['registrationBucketCapacity', getU64Decoder()],
+    ['registrationRefillIntervalSeconds', getU64Decoder()],
+    ['rejectDuplicateRoots', getBooleanDecoder()],
+    ['disjointSignerSets', getBooleanDecoder()],
+    ['rejectDuplicateIxs', getBooleanDecoder()],
+    ['enforceRelayNonceOrder', getBooleanDecoder()],
+    ['requireSelfCall', getBooleanDecoder()],
+    ['maxWrappedTokens', getU64Decoder()],
+    ['largeFinalizeThreshold', getU64Decoder()],
+    ['dailyOutboundSolLimit', getU64Decoder()],
+    ['dailyInboundSolLimit', getU64Decoder()],
+    ['revalidateOldRootsAfterBlocks', getU64Decoder()],
+    ['maxBlockAdvance', getU64Decoder()],
+    ['minProveToRelayDelaySeconds', getU64Decoder()],
   ]);
 }
 
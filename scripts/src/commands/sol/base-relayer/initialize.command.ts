@@ -20,6 +20,7 @@ type CommanderOptions = {
   eip1559MinimumBaseFee?: string;
   minGasLimitPerMessage?: string;
   maxGasLimitPerMessage?: string;
+  defaultGasLimitPerMessage?: string;
   gasCostScaler?: string;
   gasCostScalerDp?: string;
   gasFeeReceiver?: string;
@@ -96,6 +97,10 @@ async function collectInteractiveOptions(
     opts.maxGasLimitPerMessage,
     "Enter maximum gas limit per message (bigint)"
   );
+  opts.defaultGasLimitPerMessage = await getOrPromptBigint(
+    opts.defaultGasLimitPerMessage,
+    "Enter default gas limit per message (bigint)"
+  );
   opts.gasCostScaler = await getOrPromptBigint(
     opts.gasCostScaler,
     "Enter gas cost scaler (bigint)"
@@ -145,6 +150,10 @@ export const initializeCommand = new Command("initialize")
     "--max-gas-limit-per-message <uint>",
     "Maximum gas limit per message (bigint)"
   )
+  .option(
+    "--default-gas-limit-per-message <uint>",
+    "Default gas limit per message (bigint)"
+  )
   .option("--gas-cost-scaler <uint>", "Gas cost scaler (bigint)")
   .option(
     "--gas-cost-scaler-dp <uint>",
@@ -46,6 +46,7 @@ const eip1559FlatSchema = z.object({
 const gasFlatSchema = z.object({
   minGasLimitPerMessage: bigintSchema,
   maxGasLimitPerMessage: bigintSchema,
+  defaultGasLimitPerMessage: bigintSchema,
   gasCostScaler: bigintSchema,
   gasCostScalerDp: bigintSchema,
   gasFeeReceiver: solanaAddressSchema.transform((value) =>
@@ -94,6 +95,7 @@ export async function handleInitialize(args: Args): Promise<void> {
     const gasConfig: GasConfig = {
       minGasLimitPerMessage: args.minGasLimitPerMessage,
       maxGasLimitPerMessage: args.maxGasLimitPerMessage,
+      defaultGasLimitPerMessage: args.defaultGasLimitPerMessage,
       gasCostScaler: args.gasCostScaler,
       gasCostScalerDp: args.gasCostScalerDp,
       gasFeeReceiver: args.gasFeeReceiver,
@@ -192,6 +194,12 @@ async function assertInitialized(
   ) {
     throw new Error("Gas config maxGasLimitPerMessage mismatch!");
   }
+  if (
+    cfgData.data.gasConfig.defaultGasLimitPerMessage !==
+    gasConfig.defaultGasLimitPerMessage
+  ) {
+    throw new Error("Gas config defaultGasLimitPerMessage mismatch!");
+  }
   if (cfgData.data.gasConfig.gasCostScaler !== gasConfig.gasCostScaler) {
     throw new Error("Gas config gasCostScaler mismatch!");
   }
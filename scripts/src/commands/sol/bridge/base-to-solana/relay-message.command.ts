@@ -12,6 +12,7 @@ type CommanderOptions = {
   deployEnv?: string;
   messageHash?: string;
   payerKp?: string;
+  guardianKp?: string;
 };
 
 async function collectInteractiveOptions(
@@ -55,6 +56,10 @@ export const relayMessageCommand = new Command("relay-message")
     "--payer-kp <path>",
     "Payer keypair: 'config' or custom payer keypair path"
   )
+  .option(
+    "--guardian-kp <path>",
+    "Guardian keypair co-signing large SOL finalizations (optional)"
+  )
   .action(async (options) => {
     const opts = await collectInteractiveOptions(options);
     await validateAndExecute(argsSchema, opts, handleRelayMessage);
@@ -50,6 +50,7 @@ export const argsSchema = z.object({
   payerKp: z
     .union([z.literal("config"), z.string().brand<"payerKp">()])
     .default("config"),
+  guardianKp: z.string().brand<"guardianKp">().optional(),
 });
 
 type Args = z.infer<typeof argsSchema>;
@@ -128,10 +129,20 @@ export async function handleRelayMessage(args: Args): Promise<void> {
     });
     logger.info(`Bridge account address: ${bridgeAccountAddress}`);
 
+    // The guardian slot always precedes the remaining accounts. Without a guardian keypair the
+    // client fills it with the program ID, which the bridge reads as "no co-signature".
+    const guardian = args.guardianKp
+      ? await getKeypairSignerFromPath(args.guardianKp)
+      : undefined;
+    if (guardian) {
+      logger.info(`Guardian co-signer: ${guardian.address}`);
+    }
+
     const relayMessageIx = getRelayMessageInstruction(
       {
         message: messagePda,
         bridge: bridgeAccountAddress,
+        guardian,
       },
       { programAddress: config.solana.bridgeProgram }
     );
@@ -27,6 +27,7 @@ type CommanderOptions = {
   gasFeeReceiver?: string;
   protocolBlockIntervalRequirement?: string;
   bufferMaxCallBufferSize?: string;
+  bufferMaxProveBufferProofLen?: string;
   baseOracleThreshold?: string;
   baseOracleSignerCount?: string;
   baseOracleSigners?: string;
@@ -127,6 +128,10 @@ async function collectInteractiveOptions(
     opts.bufferMaxCallBufferSize,
     "Enter buffer max call buffer size (bigint)"
   );
+  opts.bufferMaxProveBufferProofLen = await getOrPromptBigint(
+    opts.bufferMaxProveBufferProofLen,
+    "Enter buffer max prove buffer proof length (bigint)"
+  );
 
   opts.baseOracleThreshold = await getOrPromptBigint(
     opts.baseOracleThreshold,
@@ -193,6 +198,10 @@ export const initializeCommand = new Command("initialize")
     "--buffer-max-call-buffer-size <uint>",
     "Buffer max call buffer size (bigint)"
   )
+  .option(
+    "--buffer-max-prove-buffer-proof-len <uint>",
+    "Buffer max prove buffer proof length (bigint)"
+  )
   .option("--base-oracle-threshold <int>", "Base oracle threshold (bigint)")
   .option(
     "--base-oracle-signer-count <int>",
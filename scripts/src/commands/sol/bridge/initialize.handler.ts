@@ -68,6 +68,7 @@ const protocolFlatSchema = z.object({
 
 const bufferFlatSchema = z.object({
   bufferMaxCallBufferSize: bigintSchema,
+  bufferMaxProveBufferProofLen: bigintSchema,
 });
 
 const baseOracleFlatSchema = z.object({
@@ -132,6 +133,7 @@ export async function handleInitialize(args: InitializeArgs): Promise<void> {
       denominator: args.eip1559Denominator,
       windowDurationSeconds: args.eip1559WindowDurationSeconds,
       minimumBaseFee: args.eip1559MinimumBaseFee,
+      decayGraceWindows: 0n,
     };
 
     const gasConfig: GasConfig = {
@@ -139,15 +141,39 @@ export async function handleInitialize(args: InitializeArgs): Promise<void> {
       gasCostScaler: args.gasCostScaler,
       gasCostScalerDp: args.gasCostScalerDp,
       gasFeeReceiver: args.gasFeeReceiver,
+      minimumFeeLamports: 0n,
+      maximumFeeLamports: 0n,
+      callTypeGasMultipliersBps: [10_000, 10_000, 10_000, 10_000],
     };
 
+    // The optional protocol safeguards start disabled; the guardian enables them afterwards
+    // through the dedicated config instructions.
+
     const protocolConfig: ProtocolConfig = {
       blockIntervalRequirement: args.protocolBlockIntervalRequirement,
       remoteSolAddress: toBytes(args.remoteSolAddress),
+      minRegistrationIntervalSeconds: 0n,
+      maxRootAgeSeconds: 0n,
+      registrationBucketCapacity: 0n,
+      registrationRefillIntervalSeconds: 0n,
+      rejectDuplicateRoots: false,
+      disjointSignerSets: false,
+      rejectDuplicateIxs: false,
+      enforceRelayNonceOrder: false,
+      requireSelfCall: false,
+      maxWrappedTokens: 0n,
+      largeFinalizeThreshold: 0n,
+      dailyOutboundSolLimit: 0n,
+      dailyInboundSolLimit: 0n,
+      revalidateOldRootsAfterBlocks: 0n,
+      maxBlockAdvance: 0n,
+      minProveToRelayDelaySeconds: 0n,
     };
 
     const bufferConfig: BufferConfig = {
       maxCallBufferSize: args.bufferMaxCallBufferSize,
+      maxProveBufferProofLen: args.bufferMaxProveBufferProofLen,
+      maxCallBufferAppends: 0n,
     };
 
     const baseOracleSigners = args.baseOracleSigners.map((signer) =>
@@ -176,6 +202,7 @@ export async function handleInitialize(args: InitializeArgs): Promise<void> {
           new Uint8Array(20)
         ),
       ],
+      weights: new Uint8Array(maxSignerCount).fill(1),
     };
 
     const partnerOracleConfig: PartnerOracleConfig = {
@@ -194,12 +221,14 @@ export async function handleInitialize(args: InitializeArgs): Promise<void> {
 
         // Arguments
         guardian: guardianAddress,
-        eip1559Config,
-        gasConfig,
-        protocolConfig,
-        bufferConfig,
-        baseOracleConfig,
-        partnerOracleConfig,
+        cfg: {
+          eip1559Config,
+          gasConfig,
+          protocolConfig,
+          bufferConfig,
+          partnerOracleConfig,
+          baseOracleConfig,
+        },
       },
       { programAddress: args.programId }
     );
@@ -20,6 +20,7 @@ import {
   monitorMessageExecution,
   buildPayForRelayInstruction,
   outgoingMessagePubkey,
+  remoteTokenPolicyPubkeys,
 } from "@internal/sol";
 import { CONFIGS, DEPLOY_ENVS } from "@internal/constants";
 
@@ -77,6 +78,11 @@ export async function handleBridgeSol(args: Args): Promise<void> {
     });
     logger.info(`Sol Vault: ${solVaultAddress}`);
 
+    const { deniedRemoteToken, rateLimit } = await remoteTokenPolicyPubkeys(
+      config.solana.bridgeProgram,
+      bridge.data.protocolConfig.remoteSolAddress
+    );
+
     // Calculate scaled amount (amount * 10^decimals)
     const scaledAmount = BigInt(Math.floor(args.amount * Math.pow(10, 9)));
     logger.info(`Amount: ${args.amount}`);
@@ -96,6 +102,8 @@ export async function handleBridgeSol(args: Args): Promise<void> {
           gasFeeReceiver: bridge.data.gasConfig.gasFeeReceiver,
           solVault: solVaultAddress,
           bridge: bridgeAccountAddress,
+          deniedRemoteToken,
+          rateLimit,
           outgoingMessage,
           systemProgram: SYSTEM_PROGRAM_ADDRESS,
 
@@ -104,6 +112,7 @@ export async function handleBridgeSol(args: Args): Promise<void> {
           to: toBytes(args.to),
           amount: scaledAmount,
           call: null,
+          reserveLamports: null,
         },
         { programAddress: config.solana.bridgeProgram }
       ),
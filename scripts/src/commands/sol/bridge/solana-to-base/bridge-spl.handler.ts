@@ -33,6 +33,7 @@ import {
   monitorMessageExecution,
   buildPayForRelayInstruction,
   outgoingMessagePubkey,
+  remoteTokenPolicyPubkeys,
 } from "@internal/sol";
 import { CONFIGS, DEPLOY_ENVS } from "@internal/constants";
 
@@ -136,6 +137,11 @@ export async function handleBridgeSpl(args: Args): Promise<void> {
     // Fetch bridge state
     const bridge = await fetchBridge(rpc, bridgeAccountAddress);
 
+    const { deniedRemoteToken, rateLimit } = await remoteTokenPolicyPubkeys(
+      config.solana.bridgeProgram,
+      remoteTokenBytes
+    );
+
     // Resolve from token account
     const fromTokenAccountAddress = await resolveFromTokenAccount(
       args.fromTokenAccount,
@@ -156,6 +162,8 @@ export async function handleBridgeSpl(args: Args): Promise<void> {
           fromTokenAccount: fromTokenAccountAddress,
           tokenVault: tokenVaultAddress,
           bridge: bridgeAccountAddress,
+          deniedRemoteToken,
+          rateLimit,
           outgoingMessage,
           tokenProgram: TOKEN_PROGRAM_ADDRESS,
           systemProgram: SYSTEM_PROGRAM_ADDRESS,
@@ -4,6 +4,7 @@ import {
   devnet,
   address,
   createSolanaRpc,
+  getBase58Codec,
   type Account,
   type Address,
   type Instruction,
@@ -20,6 +21,7 @@ import { toBytes, isAddress as isEvmAddress } from "viem";
 
 import {
   fetchBridge,
+  fetchWrappedTokenInfo,
   getBridgeWrappedTokenInstruction,
 } from "@base/bridge/bridge";
 
@@ -34,6 +36,7 @@ import {
   monitorMessageExecution,
   buildPayForRelayInstruction,
   outgoingMessagePubkey,
+  remoteTokenPolicyPubkeys,
 } from "@internal/sol";
 import { CONFIGS, DEPLOY_ENVS } from "@internal/constants";
 
@@ -123,6 +126,23 @@ export async function handleBridgeWrappedToken(args: Args): Promise<void> {
     // Fetch bridge state
     const bridge = await fetchBridge(rpc, bridgeAccountAddress);
 
+    // The remote token of the wrapped mint is recorded in its wrapped token info
+    const [wrappedTokenInfoAddress] = await getProgramDerivedAddress({
+      programAddress: config.solana.bridgeProgram,
+      seeds: [
+        Buffer.from(getIdlConstant("WRAPPED_TOKEN_INFO_SEED")),
+        getBase58Codec().encode(mintAddress),
+      ],
+    });
+    const wrappedTokenInfo = await fetchWrappedTokenInfo(
+      rpc,
+      wrappedTokenInfoAddress
+    );
+    const { deniedRemoteToken, rateLimit } = await remoteTokenPolicyPubkeys(
+      config.solana.bridgeProgram,
+      wrappedTokenInfo.data.remoteToken
+    );
+
     // Resolve from token account
     const fromTokenAccountAddress = await resolveFromTokenAccount(
       args.fromTokenAccount,
@@ -145,6 +165,8 @@ export async function handleBridgeWrappedToken(args: Args): Promise<void> {
           mint: mintAddress,
           fromTokenAccount: fromTokenAccountAddress,
           bridge: bridgeAccountAddress,
+          deniedRemoteToken,
+          rateLimit,
           outgoingMessage,
           tokenProgram,
           systemProgram: SYSTEM_PROGRAM_ADDRESS,
@@ -6,6 +6,7 @@ import {
   devnet,
   type Instruction,
   getU64Encoder,
+  getBase58Codec,
   Endian,
 } from "@solana/kit";
 import { TOKEN_2022_PROGRAM_ADDRESS } from "@solana-program/token-2022";
@@ -28,6 +29,7 @@ import {
   outgoingMessagePubkey,
   relayMessageToBase,
   monitorMessageExecution,
+  remoteTokenPolicyPubkeys,
 } from "@internal/sol";
 import { CONFIGS, DEPLOY_ENVS, ETH } from "@internal/constants";
 
@@ -139,12 +141,26 @@ export async function handleWrapToken(args: Args): Promise<void> {
     });
     logger.info(`Mint: ${mintAddress}`);
 
+    const [wrappedTokenInfoAddress] = await getProgramDerivedAddress({
+      programAddress: config.solana.bridgeProgram,
+      seeds: [
+        Buffer.from(getIdlConstant("WRAPPED_TOKEN_INFO_SEED")),
+        getBase58Codec().encode(mintAddress),
+      ],
+    });
+    logger.info(`Wrapped token info: ${wrappedTokenInfoAddress}`);
+
     const [bridgeAddress] = await getProgramDerivedAddress({
       programAddress: config.solana.bridgeProgram,
       seeds: [Buffer.from(getIdlConstant("BRIDGE_SEED"))],
     });
     logger.info(`Bridge account: ${bridgeAddress}`);
 
+    const { deniedRemoteToken } = await remoteTokenPolicyPubkeys(
+      config.solana.bridgeProgram,
+      instructionArgs.remoteToken
+    );
+
     // Fetch bridge state
     const bridge = await fetchBridge(rpc, bridgeAddress);
 
@@ -156,7 +172,9 @@ export async function handleWrapToken(args: Args): Promise<void> {
           payer,
           gasFeeReceiver: bridge.data.gasConfig.gasFeeReceiver,
           mint: mintAddress,
+          wrappedTokenInfo: wrappedTokenInfoAddress,
           bridge: bridgeAddress,
+          deniedRemoteToken,
           outgoingMessage,
           tokenProgram: TOKEN_2022_PROGRAM_ADDRESS,
           systemProgram: SYSTEM_PROGRAM_ADDRESS,
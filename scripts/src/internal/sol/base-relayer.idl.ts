@@ -9,6 +9,116 @@ export const IDL = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "accept_guardian",
+      "docs": [
+        "Accepts a pending guardian proposal, making the signer the guardian.",
+        "Only the recorded `pending_guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA and the `new_guardian` signer."
+      ],
+      "discriminator": [
+        130,
+        141,
+        66,
+        69,
+        80,
+        183,
+        54,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config holding the pending guardian"
+          ],
+          "writable": true
+        },
+        {
+          "name": "new_guardian",
+          "docs": [
+            "The proposed guardian accepting the role"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_guardian_transfer",
+      "docs": [
+        "Cancels a pending guardian proposal.",
+        "Only the current `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA and the `guardian` signer."
+      ],
+      "discriminator": [
+        127,
+        223,
+        83,
+        67,
+        130,
+        211,
+        168,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "cfg",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "get_base_fee",
+      "docs": [
+        "Returns the EIP-1559 base fee refreshed for the current timestamp, without persisting the",
+        "refresh. The result is written to the transaction return data as a `BaseFee`.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA."
+      ],
+      "discriminator": [
+        26,
+        163,
+        183,
+        165,
+        90,
+        187,
+        191,
+        166
+      ],
+      "accounts": [
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account holding the EIP-1559 state.",
+            "- Uses PDA with CFG_SEED for deterministic address",
+            "- Read-only: the refreshed state is never persisted"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "BaseFee"
+        }
+      }
+    },
     {
       "name": "initialize",
       "docs": [
@@ -109,6 +219,78 @@ export const IDL = {
         }
       ]
     },
+    {
+      "name": "migrate_cfg",
+      "docs": [
+        "Migrates a config account created by a previous version of the program to the current",
+        "layout. Must be called once, right after upgrading a deployed program, before any other",
+        "instruction can load the config. Only the upgrade authority can call this function.",
+        "",
+        "# Arguments",
+        "* `ctx`                           - The context containing the legacy `cfg` PDA and the",
+        "upgrade authority accounts.",
+        "* `default_gas_limit_per_message` - The gas limit applied to relay requests that pass",
+        "zero, which the legacy layout does not record."
+      ],
+      "discriminator": [
+        61,
+        19,
+        130,
+        183,
+        107,
+        189,
+        39,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority that is authorized to migrate the config."
+          ],
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the rent of the grown config account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config account still in the legacy layout."
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The base_relayer program itself."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for funding the additional rent."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "default_gas_limit_per_message",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "pay_for_relay",
       "docs": [
@@ -116,23 +298,23 @@ export const IDL = {
         "Transfers lamports from `payer` to `cfg.gas_config.gas_fee_receiver` using",
         "the current EIP-1559 pricing and the provided `gas_limit`. Also initializes",
         "a new `MessageToRelay` account containing the `outgoing_message` and",
-        "`gas_limit`. The payer is the sole authorization; the guardian is not",
-        "required for this operation.",
+        "`gas_limit`. Only the sender of the outgoing message may pay for its relay;",
+        "the guardian is not required for this operation.",
         "",
         "# Arguments",
-        "* `ctx`              - The context including `payer`, mutable `cfg` PDA",
-        "(for fee window updates), `gas_fee_receiver` (must",
-        "match configured receiver), and a new",
-        "`message_to_relay` account.",
-        "* `mtr_salt`         - 32-byte salt used to derive the `message_to_relay`",
-        "PDA address, enabling unique messages per request.",
-        "* `outgoing_message` - The Base-side message identifier to be executed.",
-        "* `gas_limit`        - Maximum gas units to budget for execution on Base.",
+        "* `ctx`       - The context including `payer` (must be the message sender),",
+        "mutable `cfg` PDA (for fee window updates), `gas_fee_receiver`",
+        "(must match configured receiver), the bridge `outgoing_message`",
+        "account, and a new `message_to_relay` account seeded by it so",
+        "each message can only be paid for once.",
+        "* `gas_limit` - Maximum gas units to budget for execution on Base.",
+        "Passing zero uses the configured default gas limit.",
         "",
         "# Errors",
         "Returns an error if the `gas_fee_receiver` does not match the configured",
-        "receiver or if the payer lacks sufficient lamports to cover the computed",
-        "fee."
+        "receiver, if `payer` is not the sender of `outgoing_message`, if the relay for",
+        "`outgoing_message` was already paid for, or if the payer lacks sufficient",
+        "lamports to cover the computed fee."
       ],
       "discriminator": [
         41,
@@ -149,7 +331,8 @@ export const IDL = {
           "name": "payer",
           "docs": [
             "The account that pays for transaction fees and account creation.",
-            "Must be mutable to deduct lamports for account rent and gas fees."
+            "Must be mutable to deduct lamports for account rent and gas fees.",
+            "Must be the sender of `outgoing_message`."
           ],
           "writable": true,
           "signer": true
@@ -170,8 +353,19 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The bridge `OutgoingMessage` account whose relay is paid for.",
+            "A forged account can only reserve the relay of its own address, never of a real message."
+          ]
+        },
         {
           "name": "message_to_relay",
+          "docs": [
+            "The relay request for `outgoing_message`.",
+            "- Uses PDA with MTR_SEED and the outgoing message so each message can only be paid for once"
+          ],
           "writable": true
         },
         {
@@ -184,21 +378,51 @@ export const IDL = {
       ],
       "args": [
         {
-          "name": "mtr_salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
+          "name": "gas_limit",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "propose_guardian",
+      "docs": [
+        "Proposes a new guardian. The role is only handed over once the proposed key accepts it.",
+        "Only the current `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the `cfg` PDA and the `guardian` signer.",
+        "* `new_guardian` - The proposed guardian, recorded as `pending_guardian`."
+      ],
+      "discriminator": [
+        39,
+        11,
+        248,
+        174,
+        73,
+        240,
+        0,
+        211
+      ],
+      "accounts": [
         {
-          "name": "outgoing_message",
-          "type": "pubkey"
+          "name": "cfg",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
         },
         {
-          "name": "gas_limit",
-          "type": "u64"
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_guardian",
+          "type": "pubkey"
         }
       ]
     },
@@ -297,50 +521,6 @@ export const IDL = {
           }
         }
       ]
-    },
-    {
-      "name": "set_guardian",
-      "docs": [
-        "Updates the configured guardian.",
-        "Only the current `guardian` may call this instruction.",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the `cfg` PDA and the `guardian` signer.",
-        "Authorization is enforced via an Anchor `has_one` constraint.",
-        "* `cfg` - The new guardian with permissions to update other configs."
-      ],
-      "discriminator": [
-        147,
-        243,
-        50,
-        121,
-        154,
-        164,
-        50,
-        30
-      ],
-      "accounts": [
-        {
-          "name": "cfg",
-          "docs": [
-            "The bridge account containing configuration"
-          ],
-          "writable": true
-        },
-        {
-          "name": "guardian",
-          "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_guardian",
-          "type": "pubkey"
-        }
-      ]
     }
   ],
   "accounts": [
@@ -371,6 +551,47 @@ export const IDL = {
       ]
     }
   ],
+  "events": [
+    {
+      "name": "GuardianTransferAccepted",
+      "discriminator": [
+        92,
+        184,
+        35,
+        135,
+        72,
+        181,
+        168,
+        211
+      ]
+    },
+    {
+      "name": "GuardianTransferCancelled",
+      "discriminator": [
+        210,
+        60,
+        91,
+        221,
+        35,
+        131,
+        73,
+        216
+      ]
+    },
+    {
+      "name": "GuardianTransferProposed",
+      "discriminator": [
+        252,
+        165,
+        36,
+        42,
+        189,
+        213,
+        30,
+        85
+      ]
+    }
+  ],
   "errors": [
     {
       "code": 12000,
@@ -382,11 +603,41 @@ export const IDL = {
       "name": "IncorrectRelayerProgram",
       "msg": "Incorrect relayer program"
     },
+    {
+      "code": 12002,
+      "name": "AlreadyMigrated",
+      "msg": "Account is already migrated or does not have the legacy layout"
+    },
     {
       "code": 12100,
       "name": "UnauthorizedConfigUpdate",
       "msg": "Unauthorized to update configuration"
     },
+    {
+      "code": 12101,
+      "name": "InvalidDenominator",
+      "msg": "Invalid denominator"
+    },
+    {
+      "code": 12102,
+      "name": "InvalidWindowDurationSeconds",
+      "msg": "Invalid window duration seconds"
+    },
+    {
+      "code": 12103,
+      "name": "InvalidGasCostScalerDp",
+      "msg": "Invalid gas cost scaler dp"
+    },
+    {
+      "code": 12104,
+      "name": "InvalidGasLimitRange",
+      "msg": "Invalid gas limit range"
+    },
+    {
+      "code": 12105,
+      "name": "UnauthorizedGuardianAcceptance",
+      "msg": "Only the pending guardian can accept the guardian role"
+    },
     {
       "code": 12200,
       "name": "GasLimitTooLow",
@@ -397,13 +648,60 @@ export const IDL = {
       "name": "GasLimitExceeded",
       "msg": "Gas limit exceeded"
     },
+    {
+      "code": 12202,
+      "name": "GasCostOverflow",
+      "msg": "Gas cost does not fit in a u64"
+    },
     {
       "code": 12300,
       "name": "IncorrectGasFeeReceiver",
       "msg": "Incorrect gas fee receiver"
+    },
+    {
+      "code": 12301,
+      "name": "InvalidOutgoingMessage",
+      "msg": "Account is not a bridge outgoing message"
+    },
+    {
+      "code": 12302,
+      "name": "PayerIsNotSender",
+      "msg": "Only the sender of the outgoing message can pay for its relay"
     }
   ],
   "types": [
+    {
+      "name": "BaseFee",
+      "docs": [
+        "EIP-1559 state as of the current timestamp, returned to the caller via return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "current_base_fee",
+            "docs": [
+              "Base fee in gwei that `pay_for_relay` would charge now"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_window_gas_used",
+            "docs": [
+              "Gas used so far in the current window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start_time",
+            "docs": [
+              "Unix timestamp when the current window started"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
     {
       "name": "Cfg",
       "type": {
@@ -423,6 +721,15 @@ export const IDL = {
             ],
             "type": "pubkey"
           },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "Guardian proposed by the current guardian, pending acceptance"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
           {
             "name": "eip1559",
             "docs": [
@@ -540,6 +847,13 @@ export const IDL = {
             ],
             "type": "u64"
           },
+          {
+            "name": "default_gas_limit_per_message",
+            "docs": [
+              "Gas limit applied when a relay request passes a gas limit of zero"
+            ],
+            "type": "u64"
+          },
           {
             "name": "gas_cost_scaler",
             "docs": [
@@ -564,6 +878,83 @@ export const IDL = {
         ]
       }
     },
+    {
+      "name": "GuardianTransferAccepted",
+      "docs": [
+        "Emitted when the pending guardian accepts the guardian role."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previous_guardian",
+            "docs": [
+              "The guardian being replaced"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "new_guardian",
+            "docs": [
+              "The new guardian"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GuardianTransferCancelled",
+      "docs": [
+        "Emitted when the guardian cancels a pending guardian proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "docs": [
+              "The current guardian"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "The proposal that was cancelled, if any"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "GuardianTransferProposed",
+      "docs": [
+        "Emitted when the guardian proposes a new guardian."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "docs": [
+              "The current guardian"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "The proposed guardian, pending acceptance"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
     {
       "name": "MessageToRelay",
       "type": {
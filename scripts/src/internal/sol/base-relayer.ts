@@ -1,6 +1,7 @@
 import {
   createSolanaRpc,
   devnet,
+  getAddressEncoder,
   getProgramDerivedAddress,
   type Address,
   type KeyPairSigner,
@@ -32,8 +33,9 @@ export async function buildPayForRelayInstruction(
 
   const cfg = await fetchCfg(solRpc, cfgAddress);
 
-  const { salt, pubkey: messageToRelay } = await mtrPubkey(
-    config.solana.baseRelayerProgram
+  const messageToRelay = await mtrPubkey(
+    config.solana.baseRelayerProgram,
+    outgoingMessage
   );
   logger.info(`Message To Relay: ${messageToRelay}`);
 
@@ -43,12 +45,11 @@ export async function buildPayForRelayInstruction(
       payer,
       cfg: cfgAddress,
       gasFeeReceiver: cfg.data.gasConfig.gasFeeReceiver,
+      outgoingMessage,
       messageToRelay,
-      mtrSalt: salt,
       systemProgram: SYSTEM_PROGRAM_ADDRESS,
 
       // Arguments
-      outgoingMessage: outgoingMessage,
       gasLimit: 200_000n,
     },
     { programAddress: config.solana.baseRelayerProgram }
@@ -57,15 +58,15 @@ export async function buildPayForRelayInstruction(
 
 export async function mtrPubkey(
   baseRelayerProgram: Address,
-  salt?: Uint8Array
+  outgoingMessage: Address
 ) {
-  const bytes = new Uint8Array(32);
-  const s = salt ?? crypto.getRandomValues(bytes);
-
   const [pubkey] = await getProgramDerivedAddress({
     programAddress: baseRelayerProgram,
-    seeds: [Buffer.from(getRelayerIdlConstant("MTR_SEED")), Buffer.from(s)],
+    seeds: [
+      Buffer.from(getRelayerIdlConstant("MTR_SEED")),
+      getAddressEncoder().encode(outgoingMessage),
+    ],
   });
 
-  return { salt: s, pubkey };
+  return pubkey;
 }
@@ -9,6 +9,221 @@ export const IDL = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "accept_guardian",
+      "docs": [
+        "Accept a pending guardian proposal, making the signer the guardian",
+        "Only the pending guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and the pending guardian"
+      ],
+      "discriminator": [
+        130,
+        141,
+        66,
+        69,
+        80,
+        183,
+        54,
+        186
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the pending guardian"
+          ],
+          "writable": true
+        },
+        {
+          "name": "new_guardian",
+          "docs": [
+            "The proposed guardian accepting the role"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "add_denied_remote_token",
+      "docs": [
+        "Add a Base token address to the remote token denylist",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the bridge account, guardian and denylist entry",
+        "* `remote_token` - The Base token address that can no longer be bridged or wrapped"
+      ],
+      "discriminator": [
+        112,
+        92,
+        223,
+        8,
+        146,
+        137,
+        13,
+        76
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account used to authorize the guardian"
+          ]
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update the denylist"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of `remote_token`, created if it doesn't exist"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the denylist entry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "add_oracle_signer",
+      "docs": [
+        "Adds a single Base oracle signer with unit weight, keeping the rest of the set.",
+        "Only the upgrade authority can call this function, together with the co-guardian when one",
+        "is configured",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account, upgrade authority and co-guardian",
+        "* `new_signer` - The EVM address of the signer to add"
+      ],
+      "discriminator": [
+        66,
+        217,
+        139,
+        89,
+        215,
+        220,
+        193,
+        3
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_signer",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "append_chunks_to_call_buffer",
+      "docs": [
+        "Appends several chunks of data to an existing call buffer account in a single instruction.",
+        "Only the owner of the call buffer can append data to it.",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context containing the call buffer account",
+        "* `chunks` - Chunks of data appended to the buffer in order"
+      ],
+      "discriminator": [
+        225,
+        225,
+        90,
+        247,
+        108,
+        140,
+        44,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The signer authorized to modify this call buffer.",
+            "Must match `call_buffer.owner`."
+          ],
+          "signer": true
+        },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account to append data to.",
+            "Must have been initialized with enough space to hold the resulting",
+            "data; this instruction does not reallocate."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account providing `buffer_config.max_call_buffer_size`"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "chunks",
+          "type": {
+            "vec": "bytes"
+          }
+        }
+      ]
+    },
     {
       "name": "append_to_call_buffer",
       "docs": [
@@ -43,10 +258,15 @@ export const IDL = {
           "docs": [
             "The call buffer account to append data to.",
             "Must have been initialized with enough space to hold the resulting",
-            "data; this instruction does not reallocate and will revert if",
-            "serialization would exceed the account's allocated size."
+            "data; this instruction does not reallocate."
           ],
           "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account providing `buffer_config.max_call_buffer_size`"
+          ]
         }
       ],
       "args": [
@@ -149,6 +369,82 @@ export const IDL = {
         }
       ]
     },
+    {
+      "name": "begin_relay",
+      "docs": [
+        "Begins relaying a proven message whose instructions do not fit in a single transaction.",
+        "Finalizes the message's transfer (if any), marks the message as executed and creates a",
+        "relay cursor from which `continue_relay` executes the instructions in slices. Messages",
+        "without instructions are rejected and must be relayed with `relay_message`.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the message, bridge and relay cursor accounts"
+      ],
+      "discriminator": [
+        177,
+        249,
+        121,
+        49,
+        15,
+        114,
+        41,
+        19
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the relay cursor account creation.",
+            "- Refunded the cursor rent by the `continue_relay` call that executes the last slice"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account to relay.",
+            "- Marked as executed here so it can no longer be relayed through `relay_message`"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Mutable to track the SOL released from the vault"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "Optional guardian co-signer, required for SOL finalizations above",
+            "`bridge.protocol_config.large_finalize_threshold`.",
+            "- Pass the bridge program ID when the guardian does not co-sign"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "relay_cursor",
+          "docs": [
+            "The execution cursor advanced by `continue_relay`.",
+            "- Uses PDA with RELAY_CURSOR_SEED and the message address, so a message can only be",
+            "begun once"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the relay cursor account."
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "bridge_call",
       "docs": [
@@ -244,7 +540,12 @@ export const IDL = {
             }
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_call_buffered",
@@ -351,7 +652,12 @@ export const IDL = {
             ]
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_sol",
@@ -360,12 +666,17 @@ export const IDL = {
         "This function locks SOL on Solana and initiates a message to mint equivalent",
         "tokens on Base for the specified recipient.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the SOL bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
         "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
         "* `amount`                - Amount of SOL to bridge (in lamports)",
-        "* `call`                  - Optional additional contract call to execute with the token transfer"
+        "* `call`                  - Optional additional contract call to execute with the token transfer",
+        "* `reserve_lamports`      - Optional minimum balance `from` must keep after the bridge; a",
+        "sponsoring `payer` is not checked"
       ],
       "discriminator": [
         190,
@@ -423,6 +734,23 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails",
+            "if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it",
+            "exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
         {
           "name": "outgoing_message",
           "docs": [
@@ -473,8 +801,19 @@ export const IDL = {
               }
             }
           }
+        },
+        {
+          "name": "reserve_lamports",
+          "type": {
+            "option": "u64"
+          }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_sol_with_buffered_call",
@@ -483,6 +822,9 @@ export const IDL = {
         "This function locks SOL on Solana and initiates a message to mint equivalent",
         "tokens on Base, then executes a call using data from a call buffer.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the SOL bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
@@ -546,11 +888,28 @@ export const IDL = {
           "writable": true
         },
         {
-          "name": "owner",
+          "name": "denied_remote_token",
           "docs": [
-            "The owner of the call buffer who will receive the rent refund."
-          ],
-          "writable": true,
+            "The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails",
+            "if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it",
+            "exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "The owner of the call buffer who will receive the rent refund."
+          ],
+          "writable": true,
           "signer": true
         },
         {
@@ -602,7 +961,12 @@ export const IDL = {
           "name": "amount",
           "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_spl",
@@ -611,6 +975,9 @@ export const IDL = {
         "This function burns or locks SPL tokens on Solana and initiates a message to mint",
         "equivalent ERC20 tokens on Base for the specified recipient.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
@@ -683,6 +1050,21 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of the bridged remote token; the instruction fails if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of the bridged remote token, recorded against when it exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
         {
           "name": "token_vault",
           "docs": [
@@ -762,7 +1144,12 @@ export const IDL = {
             }
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_spl_with_buffered_call",
@@ -771,6 +1158,9 @@ export const IDL = {
         "This function locks SPL tokens on Solana and initiates a message to mint equivalent",
         "tokens on Base, then executes a call using data from a call buffer.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
@@ -842,6 +1232,21 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of the bridged remote token; the instruction fails if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of the bridged remote token, recorded against when it exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
         {
           "name": "token_vault",
           "docs": [
@@ -923,7 +1328,12 @@ export const IDL = {
           "name": "amount",
           "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_wrapped_token",
@@ -932,6 +1342,9 @@ export const IDL = {
         "This function burns wrapped tokens on Solana and initiates a message to release",
         "or mint the original tokens on Base for the specified recipient.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
@@ -1001,6 +1414,21 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of the remote token of `mint`; the instruction fails if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of the remote token of `mint`, recorded against when it exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
         {
           "name": "outgoing_message",
           "docs": [
@@ -1059,7 +1487,12 @@ export const IDL = {
             }
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_wrapped_token_with_buffered_call",
@@ -1068,6 +1501,9 @@ export const IDL = {
         "This function burns wrapped tokens on Solana and initiates a message to release",
         "the original tokens on Base, then executes a call using data from a call buffer.",
         "",
+        "The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see",
+        "`CHANGELOG.md` for clients built before these accounts.",
+        "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
@@ -1138,6 +1574,21 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of the remote token of `mint`; the instruction fails if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of the remote token of `mint`, recorded against when it exists.",
+            "and deserialized if created"
+          ],
+          "writable": true
+        },
         {
           "name": "owner",
           "docs": [
@@ -1198,7 +1649,49 @@ export const IDL = {
           "name": "amount",
           "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
+    },
+    {
+      "name": "cancel_guardian_transfer",
+      "docs": [
+        "Cancel a pending guardian proposal",
+        "Only the current guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and current guardian"
+      ],
+      "discriminator": [
+        127,
+        223,
+        83,
+        67,
+        130,
+        211,
+        168,
+        227
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": []
     },
     {
       "name": "close_call_buffer",
@@ -1239,6 +1732,75 @@ export const IDL = {
       ],
       "args": []
     },
+    {
+      "name": "close_output_root",
+      "docs": [
+        "Closes an erroneously created or stale output root and returns its rent to a receiver.",
+        "The latest output root can never be closed, and other roots only once they trail the",
+        "latest block by `OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS` block intervals and are older than",
+        "`OUTPUT_ROOT_CLOSE_DELAY_SECONDS`.",
+        "Only the guardian can call this function, co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the guardians, rent receiver, bridge and output root accounts",
+        "* `base_block_number` - The Base block number of the output root to close"
+      ],
+      "discriminator": [
+        249,
+        227,
+        155,
+        186,
+        2,
+        131,
+        237,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to close output roots."
+          ],
+          "signer": true
+        },
+        {
+          "name": "receiver",
+          "docs": [
+            "The account receiving the reclaimed rent."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account that tracks the latest registered Base block number.",
+            "- Uses PDA with BRIDGE_SEED"
+          ]
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "The output root account to close.",
+            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number"
+          ],
+          "writable": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set."
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "base_block_number",
+          "type": "u64"
+        }
+      ]
+    },
     {
       "name": "close_prove_buffer",
       "docs": [
@@ -1279,316 +1841,285 @@ export const IDL = {
       "args": []
     },
     {
-      "name": "initialize",
+      "name": "continue_relay",
       "docs": [
-        "Initializes the bridge program with required state accounts.",
-        "This function sets up the initial bridge configuration and must be called once during deployment.",
+        "Executes the next `ix_count` instructions of a message relayed with `begin_relay`,",
+        "starting at the relay cursor. The relay is complete once the cursor reaches the end",
+        "of the instruction list.",
         "",
         "# Arguments",
-        "* `ctx`      - The context containing all accounts needed for initialization, including the guardian signer",
-        "* `guardian` - The guardian account that will have administrative authority over the bridge",
-        "* `cfg`      - All the configuration parameters needed to initialize the bridge"
+        "* `ctx`      - The context containing the message, bridge and relay cursor accounts",
+        "* `ix_count` - Number of instructions to execute in this slice"
       ],
       "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
+        165,
+        214,
+        165,
+        56,
+        0,
+        16,
+        105,
+        142
       ],
       "accounts": [
         {
-          "name": "upgrade_authority",
-          "docs": [
-            "The upgrade authority that is authorized to initialize the bridge.",
-            "This ensures only the program deployer can set the initial configuration."
-          ],
-          "signer": true
-        },
-        {
-          "name": "payer",
+          "name": "message",
           "docs": [
-            "The account that pays for the transaction and bridge account creation.",
-            "Must be mutable to deduct lamports for account rent.",
-            "Can be different from the upgrade_authority."
-          ],
-          "writable": true,
-          "signer": true
+            "The incoming message being relayed."
+          ]
         },
         {
           "name": "bridge",
           "docs": [
-            "The bridge state account being initialized.",
-            "- Uses PDA with BRIDGE_SEED for deterministic address",
-            "- Payer funds the account creation",
-            "- Space allocated for bridge state (DISCRIMINATOR_LEN + Bridge::INIT_SPACE)"
-          ],
-          "writable": true
-        },
-        {
-          "name": "program_data",
-          "docs": [
-            "Program data account containing the upgrade authority.",
-            "Validates that the signer is indeed the upgrade authority."
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address"
           ]
         },
         {
-          "name": "program",
+          "name": "relay_cursor",
           "docs": [
-            "The bridge program itself.",
-            "Validates that program_data is the correct ProgramData account for this program."
-          ]
+            "The execution cursor created by `begin_relay` for this message.",
+            "- Uses PDA with RELAY_CURSOR_SEED and the message address",
+            "- Mutable to advance past the executed slice",
+            "- Closed to `payer` once the last slice executes"
+          ],
+          "writable": true
         },
         {
-          "name": "system_program",
+          "name": "payer",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for account initialization."
-          ]
+            "The account that paid for the relay cursor, refunded its rent after the last slice."
+          ],
+          "writable": true
         }
       ],
       "args": [
         {
-          "name": "guardian",
-          "type": "pubkey"
-        },
-        {
-          "name": "cfg",
-          "type": {
-            "defined": {
-              "name": "Config"
-            }
-          }
+          "name": "ix_count",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "initialize_call_buffer",
+      "name": "freeze_wrapped_account",
       "docs": [
-        "Initializes a call buffer account that can store large call data.",
-        "This account can be used to build up call data over multiple transactions",
-        "before using it in a bridge operation.",
+        "Freeze a wrapped token account, blocking transfers and bridging back to Base",
+        "Only the guardian can call this function",
         "",
         "# Arguments",
-        "* `ctx`          - The context containing accounts for initialization (including bridge config)",
-        "* `ty`           - The type of call (Call, DelegateCall, Create, Create2)",
-        "* `to`           - The target contract address on Base",
-        "* `value`        - The amount of ETH to send with the call (in wei)",
-        "* `initial_data` - Initial call data to store",
-        "* `max_data_len` - Maximum total length of data that will be stored"
+        "* `ctx` - The context containing the bridge, guardian, wrapped mint and token account"
       ],
       "discriminator": [
-        85,
-        68,
-        100,
-        234,
-        255,
-        226,
-        95,
-        72
+        21,
+        232,
+        106,
+        193,
+        134,
+        107,
+        55,
+        34
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "docs": [
-            "The account that pays for the transaction and call buffer account creation.",
-            "This signer becomes the `CallBuffer.owner`."
-          ],
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration including max buffer size"
+            "The bridge account used to authorize the guardian"
           ]
         },
         {
-          "name": "call_buffer",
+          "name": "guardian",
           "docs": [
-            "The call buffer account being initialized.",
-            "Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).",
-            "The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`."
+            "The guardian account authorized to freeze and thaw wrapped token accounts"
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "system_program",
+          "name": "mint",
           "docs": [
-            "System program required for creating new accounts"
+            "The wrapped mint. Its address must be the wrapped token PDA derived from its metadata and",
+            "decimals, so only mints created by `wrap_token` can be used."
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "ty",
-          "type": {
-            "defined": {
-              "name": "CallType"
-            }
-          }
-        },
-        {
-          "name": "to",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
-        },
-        {
-          "name": "value",
-          "type": "u128"
         },
         {
-          "name": "initial_data",
-          "type": "bytes"
+          "name": "token_account",
+          "docs": [
+            "The token account of `mint` to freeze or thaw"
+          ],
+          "writable": true
         },
         {
-          "name": "max_data_len",
-          "type": "u64"
+          "name": "token_program",
+          "docs": [
+            "SPL Token-2022 program the wrapped mint belongs to"
+          ]
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "initialize_prove_buffer",
+      "name": "get_base_fee",
       "docs": [
-        "Initializes a prove buffer account that can store large prove inputs.",
-        "This account can be used to build up serialized message data and MMR proof nodes",
-        "over multiple transactions before calling `prove_message_buffered`.",
+        "Returns the EIP-1559 base fee refreshed for the current timestamp, without persisting the",
+        "refresh. The result is written to the transaction return data as a `BaseFee`.",
         "",
         "# Arguments",
-        "* `ctx`           - The context containing accounts for initialization (payer, bridge, buffer)",
-        "* `max_data_len`  - Maximum total length of serialized `Message` data that will be stored",
-        "* `max_proof_len` - Maximum number of 32-byte MMR proof nodes that will be stored"
+        "* `ctx` - The context containing the bridge account"
       ],
       "discriminator": [
-        20,
-        200,
-        91,
-        188,
-        138,
-        211,
-        253,
-        222
+        26,
+        163,
+        183,
+        165,
+        90,
+        187,
+        191,
+        166
       ],
       "accounts": [
-        {
-          "name": "payer",
-          "docs": [
-            "Payer funds the buffer account creation"
-          ],
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "bridge",
           "docs": [
-            "Bridge for pause checks (future use); also a consistent pattern like call buffers"
+            "The main bridge state account holding the EIP-1559 state.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Read-only: the refreshed state is never persisted"
           ]
-        },
-        {
-          "name": "prove_buffer",
-          "docs": [
-            "Prove buffer to be created with capacity sized by the provided max lengths"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "system_program"
         }
       ],
-      "args": [
-        {
-          "name": "max_data_len",
-          "type": "u64"
-        },
-        {
-          "name": "max_proof_len",
-          "type": "u64"
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "BaseFee"
         }
-      ]
+      }
     },
     {
-      "name": "prove_message",
+      "name": "get_fee_bounds",
       "docs": [
-        "Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.",
-        "This function verifies the message was included in a previously registered output root",
-        "and stores the proven message state for later relay execution.",
+        "Returns the minimum, current and maximum lamport fee for a standard call under the current",
+        "gas config, without persisting the base fee refresh. The result is written to the",
+        "transaction return data as a `FeeBounds`.",
         "",
         "# Arguments",
-        "* `ctx`          - The transaction context",
-        "* `nonce`        - Unique identifier for the cross-chain message",
-        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
-        "* `data`         - The message payload/calldata to be executed on Solana",
-        "* `proof`        - MMR proof demonstrating message inclusion in the output root",
-        "* `message_hash` - The 32-byte hash of the message for verification"
+        "* `ctx` - The context containing the bridge account"
       ],
       "discriminator": [
-        172,
-        66,
-        78,
-        136,
+        177,
+        45,
+        151,
+        240,
+        251,
+        140,
         158,
-        187,
-        47,
-        115
+        165
       ],
       "accounts": [
         {
-          "name": "payer",
-          "docs": [
-            "The account that pays for the transaction and incoming message account creation.",
-            "Must be mutable to deduct lamports for account rent."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "output_root",
+          "name": "bridge",
           "docs": [
-            "The output root account containing the MMR root from Base.",
-            "Used to verify that the message proof is valid against the committed state.",
-            "This root must have been previously registered via register_output_root instruction."
+            "The main bridge state account holding the EIP-1559 state and gas config.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Read-only: the refreshed state is never persisted"
           ]
-        },
-        {
-          "name": "message",
-          "docs": [
-            "The incoming message account being created to store the proven message.",
-            "- Uses PDA with INCOMING_MESSAGE_SEED and message hash for deterministic address",
-            "- Payer funds the account creation",
-            "- Space dynamically allocated based on message data length",
-            "- Once created, this account can be used by relay instructions to execute the message"
-          ],
-          "writable": true
-        },
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "FeeBounds"
+        }
+      }
+    },
+    {
+      "name": "get_highest_relayed_nonce",
+      "docs": [
+        "Returns the highest Base nonce relayed so far, or `None` before the first relay, so",
+        "monitoring can detect skipped nonces.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account"
+      ],
+      "discriminator": [
+        70,
+        60,
+        239,
+        143,
+        57,
+        229,
+        197,
+        116
+      ],
+      "accounts": [
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account used to check pause status",
+            "The main bridge state account tracking relayed nonces.",
             "- Uses PDA with BRIDGE_SEED for deterministic address"
           ]
-        },
+        }
+      ],
+      "args": [],
+      "returns": {
+        "option": "u64"
+      }
+    },
+    {
+      "name": "get_oracle_signers",
+      "docs": [
+        "Returns the current base oracle signer set and approval threshold.",
+        "The result is written to the transaction return data as an `OracleSigners`.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account"
+      ],
+      "discriminator": [
+        22,
+        95,
+        66,
+        222,
+        27,
+        157,
+        75,
+        181
+      ],
+      "accounts": [
         {
-          "name": "system_program",
+          "name": "bridge",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for account initialization."
+            "The main bridge state account holding the base oracle configuration.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address"
           ]
         }
       ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "OracleSigners"
+        }
+      }
+    },
+    {
+      "name": "get_program_authorities",
+      "docs": [
+        "Returns the bridge's program-derived addresses and their bumps, including the CPI",
+        "authority that signs instructions relayed from `sender`.",
+        "The result is written to the transaction return data as a `ProgramAuthorities`.",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context (no accounts are required)",
+        "* `sender` - The Base sender whose bridge CPI authority is derived"
+      ],
+      "discriminator": [
+        228,
+        58,
+        180,
+        185,
+        73,
+        134,
+        153,
+        135
+      ],
+      "accounts": [],
       "args": [
-        {
-          "name": "nonce",
-          "type": "u64"
-        },
         {
           "name": "sender",
           "type": {
@@ -1597,22 +2128,45 @@ export const IDL = {
               20
             ]
           }
-        },
-        {
-          "name": "data",
-          "type": "bytes"
-        },
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "ProgramAuthorities"
+        }
+      }
+    },
+    {
+      "name": "get_proven_message",
+      "docs": [
+        "Returns the stored data and execution status of a proven message.",
+        "The result is written to the transaction return data as a `ProvenMessage`; the message",
+        "payload is only included inline when it fits, its keccak256 hash is always included.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the incoming message account",
+        "* `message_hash` - The 32-byte hash of the proven message"
+      ],
+      "discriminator": [
+        197,
+        54,
+        55,
+        216,
+        62,
+        184,
+        214,
+        134
+      ],
+      "accounts": [
         {
-          "name": "proof",
-          "type": {
-            "vec": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        },
+          "name": "message",
+          "docs": [
+            "The incoming message account created by `prove_message`.",
+            "- Uses PDA with INCOMING_MESSAGE_SEED and the message hash for deterministic address"
+          ]
+        }
+      ],
+      "args": [
         {
           "name": "message_hash",
           "type": {
@@ -1622,200 +2176,237 @@ export const IDL = {
             ]
           }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "ProvenMessage"
+        }
+      }
     },
     {
-      "name": "prove_message_buffered",
+      "name": "get_total_locked_sol",
       "docs": [
-        "Proves that a cross-chain message exists using buffered data and proof.",
-        "This function reads the serialized message and MMR proof from a `ProveBuffer`,",
-        "verifies inclusion against a previously registered output root, and stores the",
-        "proven message for later relay execution. The prove buffer is closed on success.",
+        "Returns the SOL locked in the bridge alongside the SOL vault's actual balance.",
+        "The result is written to the transaction return data as a `TotalLockedSol`.",
         "",
         "# Arguments",
-        "* `ctx`          - The context containing accounts for verification and message creation",
-        "* `nonce`        - Unique identifier for the cross-chain message",
-        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
-        "* `message_hash` - The 32-byte hash of the message for verification"
+        "* `ctx` - The context containing the bridge and SOL vault accounts"
       ],
       "discriminator": [
-        211,
-        48,
-        193,
-        183,
-        2,
-        51,
-        102,
-        159
+        5,
+        123,
+        182,
+        53,
+        55,
+        206,
+        238,
+        234
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "bridge",
           "docs": [
-            "Payer funds the IncomingMessage account"
-          ],
-          "writable": true,
-          "signer": true
+            "The main bridge state account tracking the SOL locked by `bridge_sol`.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address"
+          ]
         },
         {
-          "name": "output_root",
+          "name": "sol_vault",
           "docs": [
-            "Output root to verify the proof against"
+            "The SOL vault holding the lamports of all bridged SOL."
           ]
-        },
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "TotalLockedSol"
+        }
+      }
+    },
+    {
+      "name": "initialize",
+      "docs": [
+        "Initializes the bridge program with required state accounts.",
+        "This function sets up the initial bridge configuration and must be called once during deployment.",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing all accounts needed for initialization, including the guardian signer",
+        "* `guardian` - The guardian account that will have administrative authority over the bridge",
+        "* `cfg`      - All the configuration parameters needed to initialize the bridge"
+      ],
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
         {
-          "name": "message",
+          "name": "upgrade_authority",
           "docs": [
-            "The incoming message account created if proof verifies"
+            "The upgrade authority that is authorized to initialize the bridge.",
+            "This ensures only the program deployer can set the initial configuration."
           ],
-          "writable": true
-        },
-        {
-          "name": "bridge",
-          "docs": [
-            "Bridge for pause check"
-          ]
+          "signer": true
         },
         {
-          "name": "owner",
+          "name": "payer",
           "docs": [
-            "Owner receives rent when buffer is closed"
+            "The account that pays for the transaction and bridge account creation.",
+            "Must be mutable to deduct lamports for account rent.",
+            "Can be different from the upgrade_authority."
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "prove_buffer",
+          "name": "bridge",
           "docs": [
-            "Prove buffer containing data and proof; closed on success"
+            "The bridge state account being initialized.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Payer funds the account creation",
+            "- Space allocated for bridge state (DISCRIMINATOR_LEN + Bridge::INIT_SPACE)"
           ],
           "writable": true
         },
         {
-          "name": "system_program"
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority.",
+            "Validates that the signer is indeed the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself.",
+            "Validates that program_data is the correct ProgramData account for this program."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
         }
       ],
       "args": [
         {
-          "name": "nonce",
-          "type": "u64"
-        },
-        {
-          "name": "sender",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
+          "name": "guardian",
+          "type": "pubkey"
         },
         {
-          "name": "message_hash",
+          "name": "cfg",
           "type": {
-            "array": [
-              "u8",
-              32
-            ]
+            "defined": {
+              "name": "Config"
+            }
           }
         }
       ]
     },
     {
-      "name": "register_output_root",
+      "name": "initialize_call_buffer",
       "docs": [
-        "Registers an output root from Base to enable message verification.",
-        "This function stores the MMR root of Base message state at a specific block number,",
-        "which is required before any messages from that block can be proven and relayed.",
-        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
-        "signers per configured thresholds; the Solana payer only funds account creation.",
+        "Initializes a call buffer account that can store large call data.",
+        "This account can be used to build up call data over multiple transactions",
+        "before using it in a bridge operation.",
         "",
         "# Arguments",
-        "* `ctx`               - The context containing accounts for storing the output root (payer signs for fees; authorization is provided via EVM signatures)",
-        "* `output_root`       - The 32-byte MMR root of Base messages for the given block",
-        "* `base_block_number` - The Base block number this output root corresponds to",
-        "* `total_leaf_count`  - The total number of leaves in the MMR with this root",
-        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root"
+        "* `ctx`          - The context containing accounts for initialization (including bridge config)",
+        "* `ty`           - The type of call (Call, DelegateCall, Create, Create2)",
+        "* `to`           - The target contract address on Base",
+        "* `value`        - The amount of ETH to send with the call (in wei)",
+        "* `initial_data` - Initial call data to store",
+        "* `max_data_len` - Maximum total length of data that will be stored",
+        "* `expected_hash` - Optional keccak-256 hash the complete data must match when bridged"
       ],
       "discriminator": [
-        215,
-        66,
-        12,
-        154,
-        4,
-        123,
-        196,
-        66
+        85,
+        68,
+        100,
+        234,
+        255,
+        226,
+        95,
+        72
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "Payer funds the account creation. Authorization is enforced via oracle EVM signature."
+            "The account that pays for the transaction and call buffer account creation.",
+            "This signer becomes the `CallBuffer.owner`."
           ],
           "writable": true,
           "signer": true
         },
-        {
-          "name": "root",
-          "docs": [
-            "The output root account being created to store the Base MMR root and total leaf count.",
-            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number for deterministic address",
-            "- Payer funds the account creation (authorization is enforced via EVM signatures)",
-            "- Space allocated for output root state (DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE)",
-            "- Each output root corresponds to a specific Base block number"
-          ],
-          "writable": true
-        },
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account that tracks the latest registered Base block number.",
-            "- Uses PDA with BRIDGE_SEED",
-            "- Must be mutable to update the base_block_number field",
-            "- Enforces registrations are monotonic and aligned to the configured interval"
-          ],
-          "writable": true
+            "The bridge account containing configuration including max buffer size"
+          ]
         },
         {
-          "name": "partner_config",
+          "name": "call_buffer",
           "docs": [
-            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
-            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
-          ]
+            "The call buffer account being initialized.",
+            "Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).",
+            "The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`."
+          ],
+          "writable": true,
+          "signer": true
         },
         {
           "name": "system_program",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for output root account initialization."
+            "System program required for creating new accounts"
           ]
         }
       ],
       "args": [
         {
-          "name": "output_root",
+          "name": "ty",
+          "type": {
+            "defined": {
+              "name": "CallType"
+            }
+          }
+        },
+        {
+          "name": "to",
           "type": {
             "array": [
               "u8",
-              32
+              20
             ]
           }
         },
         {
-          "name": "base_block_number",
-          "type": "u64"
+          "name": "value",
+          "type": "u128"
         },
         {
-          "name": "total_leaf_count",
+          "name": "initial_data",
+          "type": "bytes"
+        },
+        {
+          "name": "max_data_len",
           "type": "u64"
         },
         {
-          "name": "signatures",
+          "name": "expected_hash",
           "type": {
-            "vec": {
+            "option": {
               "array": [
                 "u8",
-                65
+                32
               ]
             }
           }
@@ -1823,646 +2414,865 @@ export const IDL = {
       ]
     },
     {
-      "name": "relay_message",
+      "name": "initialize_prove_buffer",
       "docs": [
-        "Executes a previously proven cross-chain message on Solana.",
-        "This function takes a message that has been proven via `prove_message` and executes",
-        "its payload using a bridge CPI authority derived from the message sender.",
+        "Initializes a prove buffer account that can store large prove inputs.",
+        "This account can be used to build up serialized message data and MMR proof nodes",
+        "over multiple transactions before calling `prove_message_buffered`.",
         "",
         "# Arguments",
-        "* `ctx` - The transaction context"
+        "* `ctx`           - The context containing accounts for initialization (payer, bridge, buffer)",
+        "* `max_data_len`  - Maximum total length of serialized `Message` data that will be stored",
+        "* `max_proof_len` - Maximum number of 32-byte MMR proof nodes that will be stored"
       ],
       "discriminator": [
-        187,
-        90,
-        182,
+        20,
+        200,
+        91,
+        188,
         138,
-        51,
-        248,
-        175,
-        98
+        211,
+        253,
+        222
       ],
       "accounts": [
         {
-          "name": "message",
+          "name": "payer",
           "docs": [
-            "The incoming message account containing the cross-chain message to be executed.",
-            "- Contains either a pure call message or a transfer message with additional instructions",
-            "- Must be mutable to mark the message as executed after processing",
-            "- Prevents replay attacks by tracking execution status"
+            "Payer funds the buffer account creation"
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account used to check pause status",
-            "- Uses PDA with BRIDGE_SEED for deterministic address"
+            "Bridge for pause checks (future use); also a consistent pattern like call buffers"
           ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "set_adjustment_denominator",
-      "docs": [
-        "Set the adjustment denominator for EIP-1559 pricing",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_denominator` - The new adjustment denominator"
-      ],
-      "discriminator": [
-        31,
-        91,
-        190,
-        63,
-        164,
-        7,
-        31,
-        150
-      ],
-      "accounts": [
-        {
-          "name": "bridge",
-          "docs": [
-            "The bridge account containing configuration"
-          ],
-          "writable": true
         },
         {
-          "name": "guardian",
+          "name": "prove_buffer",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Prove buffer to be created with capacity sized by the provided max lengths"
           ],
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "system_program"
         }
       ],
       "args": [
         {
-          "name": "new_denominator",
+          "name": "max_data_len",
+          "type": "u64"
+        },
+        {
+          "name": "max_proof_len",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "set_block_interval_requirement",
+      "name": "migrate_bridge",
       "docs": [
-        "Set the block interval requirement for Protocol Config",
-        "Only the guardian can call this function",
+        "Migrates a bridge account created by a previous version of the program to the current",
+        "layout. Must be called once, right after upgrading a deployed program, before any other",
+        "instruction can load the bridge. Only the upgrade authority can call this function.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_interval` - The new block interval requirement value"
+        "* `ctx` - The context containing the legacy bridge account, the SOL vault seeding",
+        "`locked_sol` and the upgrade authority accounts",
+        "* `cfg` - All the configuration parameters of the migrated bridge"
       ],
       "discriminator": [
-        76,
-        70,
-        237,
-        100,
-        33,
-        108,
-        19,
-        42
+        42,
+        63,
+        64,
+        21,
+        180,
+        183,
+        197,
+        236
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "upgrade_authority",
           "docs": [
-            "The bridge account containing configuration"
+            "The upgrade authority that is authorized to migrate the bridge."
           ],
-          "writable": true
+          "signer": true
         },
         {
-          "name": "guardian",
+          "name": "payer",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The account that pays for the rent of the grown bridge account."
           ],
+          "writable": true,
           "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_interval",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_cost_scaler",
-      "docs": [
-        "Set the gas cost scaler for Gas Cost Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_scaler` - The new gas cost scaler value"
-      ],
-      "discriminator": [
-        148,
-        146,
-        101,
-        170,
-        5,
-        6,
-        222,
-        119
-      ],
-      "accounts": [
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The bridge state account still in the legacy layout."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "sol_vault",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
+            "The SOL vault whose balance seeds `locked_sol`."
+          ]
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for funding the additional rent."
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_scaler",
-          "type": "u64"
+          "name": "cfg",
+          "type": {
+            "defined": {
+              "name": "Config"
+            }
+          }
         }
       ]
     },
     {
-      "name": "set_gas_cost_scaler_dp",
+      "name": "migrate_incoming_message",
       "docs": [
-        "Set the gas cost scaler DP for Gas Cost Config",
-        "Only the guardian can call this function",
+        "Migrates an incoming message proven by a previous version of the program to the current",
+        "layout, so it can be relayed after an upgrade.",
+        "Anyone can call this function; the nonce is checked against the message hash.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_dp` - The new gas cost scaler DP value"
+        "* `ctx`          - The context containing the legacy incoming message account",
+        "* `nonce`        - The nonce the message was proven with",
+        "* `message_hash` - The hash the incoming message account is derived from"
       ],
       "discriminator": [
-        198,
-        111,
-        160,
-        55,
-        172,
-        138,
-        99,
-        164
+        106,
+        193,
+        235,
+        74,
+        86,
+        211,
+        96,
+        187
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "payer",
           "docs": [
-            "The bridge account containing configuration"
+            "The account that pays for the rent of the grown message account."
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "guardian",
+          "name": "message",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The incoming message account still in the legacy layout, at the PDA with",
+            "INCOMING_MESSAGE_SEED and message_hash."
           ],
-          "signer": true
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for funding the additional rent."
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_dp",
+          "name": "nonce",
           "type": "u64"
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "set_gas_fee_receiver",
+      "name": "migrate_output_root",
       "docs": [
-        "Set the gas fee receiver for Gas Cost Config",
-        "Only the guardian can call this function",
+        "Migrates an output root created by a previous version of the program to the current",
+        "layout, so it can be proven against again after an upgrade.",
+        "Only the upgrade authority can call this function",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_receiver` - The new gas fee receiver"
+        "* `ctx`               - The context containing the legacy output root and the upgrade authority accounts",
+        "* `base_block_number` - The Base block number of the output root to migrate"
       ],
       "discriminator": [
-        58,
-        188,
-        230,
-        188,
-        47,
-        188,
-        79,
-        154
+        93,
+        10,
+        140,
+        182,
+        64,
+        96,
+        83,
+        230
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "upgrade_authority",
           "docs": [
-            "The bridge account containing configuration"
+            "The upgrade authority that is authorized to migrate output roots."
           ],
-          "writable": true
+          "signer": true
         },
         {
-          "name": "guardian",
+          "name": "payer",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The account that pays for the rent of the grown output root account."
           ],
+          "writable": true,
           "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_receiver",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_per_call",
-      "docs": [
-        "Set the gas amount per call for Gas Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_val` - The new gas amount per call value"
-      ],
-      "discriminator": [
-        164,
-        95,
-        213,
-        130,
-        26,
-        69,
-        82,
-        127
-      ],
-      "accounts": [
+        },
         {
-          "name": "bridge",
+          "name": "output_root",
           "docs": [
-            "The bridge account containing configuration"
+            "The output root account still in the legacy layout, at the PDA with OUTPUT_ROOT_SEED and",
+            "base_block_number."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "program_data",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for funding the additional rent."
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_val",
+          "name": "base_block_number",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "set_gas_target",
+      "name": "prove_message",
       "docs": [
-        "Set the gas target for EIP-1559 pricing",
-        "Only the guardian can call this function",
+        "Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.",
+        "This function verifies the message was included in a previously registered output root",
+        "and stores the proven message state for later relay execution.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_target` - The new gas target value"
+        "* `ctx`          - The transaction context",
+        "* `nonce`        - Unique identifier for the cross-chain message",
+        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
+        "* `data`         - The message payload/calldata to be executed on Solana",
+        "* `proof`        - MMR proof demonstrating message inclusion in the output root",
+        "* `message_hash` - The 32-byte hash of the message for verification"
       ],
       "discriminator": [
-        132,
-        25,
-        19,
-        13,
-        118,
-        63,
-        167,
-        102
+        172,
+        66,
+        78,
+        136,
+        158,
+        187,
+        47,
+        115
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "payer",
           "docs": [
-            "The bridge account containing configuration"
+            "The account that pays for the transaction and incoming message account creation.",
+            "Must be mutable to deduct lamports for account rent."
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "guardian",
+          "name": "output_root",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The output root account containing the MMR root from Base.",
+            "Used to verify that the message proof is valid against the committed state.",
+            "This root must have been previously registered via register_output_root instruction."
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account being created to store the proven message.",
+            "- Uses PDA with INCOMING_MESSAGE_SEED and message hash for deterministic address",
+            "- Payer funds the account creation",
+            "- Space dynamically allocated based on message data length",
+            "- Once created, this account can be used by relay instructions to execute the message"
           ],
-          "signer": true
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address"
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_target",
+          "name": "nonce",
           "type": "u64"
+        },
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "set_max_call_buffer_size",
+      "name": "prove_message_buffered",
       "docs": [
-        "Set the max call buffer size for Buffer Config",
-        "Only the guardian can call this function",
+        "Proves that a cross-chain message exists using buffered data and proof.",
+        "This function reads the serialized message and MMR proof from a `ProveBuffer`,",
+        "verifies inclusion against a previously registered output root, and stores the",
+        "proven message for later relay execution. The prove buffer is closed on success.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_size` - The new max call buffer size value"
+        "* `ctx`          - The context containing accounts for verification and message creation",
+        "* `nonce`        - Unique identifier for the cross-chain message",
+        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
+        "* `message_hash` - The 32-byte hash of the message for verification"
       ],
       "discriminator": [
-        140,
-        178,
-        4,
-        238,
-        245,
-        66,
-        117,
-        189
+        211,
+        48,
+        193,
+        183,
+        2,
+        51,
+        102,
+        159
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "payer",
           "docs": [
-            "The bridge account containing configuration"
+            "Payer funds the IncomingMessage account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "Output root to verify the proof against"
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account created if proof verifies"
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "bridge",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Bridge for pause check"
+          ]
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner receives rent when buffer is closed"
           ],
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "Prove buffer containing data and proof; closed on success"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program"
         }
       ],
       "args": [
         {
-          "name": "new_size",
+          "name": "nonce",
           "type": "u64"
+        },
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
         }
       ]
     },
     {
-      "name": "set_minimum_base_fee",
+      "name": "quote_total_cost",
       "docs": [
-        "Set the minimum base fee for EIP-1559 pricing",
-        "Only the guardian can call this function",
+        "Quotes the lamports a `bridge_sol`, `bridge_spl` or `bridge_call` would cost right now:",
+        "gas fee at the current base fee, locked SOL value and outgoing message rent.",
+        "The result is written to the transaction return data as a `TotalCostQuote`.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_fee` - The new minimum base fee value"
+        "* `ctx`       - The context containing the bridge account",
+        "* `operation` - The operation to quote, including the shape of its optional call"
       ],
       "discriminator": [
-        56,
-        95,
-        58,
-        94,
-        221,
-        136,
-        138,
-        156
+        93,
+        130,
+        225,
+        79,
+        91,
+        86,
+        71,
+        124
       ],
       "accounts": [
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
-          ],
-          "writable": true
-        },
-        {
-          "name": "guardian",
-          "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
+            "The main bridge state account holding the gas and EIP-1559 configuration.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Read-only: quoting never records gas usage"
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_fee",
-          "type": "u64"
+          "name": "operation",
+          "type": {
+            "defined": {
+              "name": "QuotedOperation"
+            }
+          }
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "TotalCostQuote"
+        }
+      }
     },
     {
-      "name": "set_oracle_signers",
+      "name": "register_output_root",
       "docs": [
-        "Sets the authorized oracle EVM signer addresses and the signature threshold used",
-        "when registering output roots. This function updates the `OracleSigners` account",
-        "and can only be called by the guardian.",
+        "Registers an output root from Base to enable message verification.",
+        "This function stores the MMR root of Base message state at a specific block number,",
+        "which is required before any messages from that block can be proven and relayed.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer only funds account creation.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge, guardian signer, and oracle signers accounts",
-        "* `cfg` - Configuration parameters for Base oracle signers"
+        "* `ctx`               - The context containing accounts for storing the output root (payer signs for fees; authorization is provided via EVM signatures)",
+        "* `output_root`       - The 32-byte MMR root of Base messages for the given block",
+        "* `base_block_number` - The Base block number this output root corresponds to",
+        "* `total_leaf_count`  - The total number of leaves in the MMR with this root",
+        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root"
       ],
       "discriminator": [
-        38,
-        183,
-        212,
-        67,
-        28,
-        241,
-        25,
-        160
-      ],
+        215,
+        66,
+        12,
+        154,
+        4,
+        123,
+        196,
+        66
+      ],
       "accounts": [
         {
-          "name": "upgrade_authority",
+          "name": "payer",
           "docs": [
-            "The upgrade authority account"
+            "Payer funds the account creation. Authorization is enforced via oracle EVM signature."
           ],
+          "writable": true,
           "signer": true
         },
+        {
+          "name": "root",
+          "docs": [
+            "The output root account being created to store the Base MMR root and total leaf count.",
+            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number for deterministic address",
+            "- Payer funds the account creation (authorization is enforced via EVM signatures)",
+            "- Space allocated for output root state (DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE)",
+            "- Each output root corresponds to a specific Base block number"
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The main bridge state account that tracks the latest registered Base block number.",
+            "- Uses PDA with BRIDGE_SEED",
+            "- Must be mutable to update the base_block_number field",
+            "- Enforces registrations are monotonic and aligned to the configured interval"
           ],
           "writable": true
         },
         {
-          "name": "program_data"
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in",
+            "the handler when partner approvals are required."
+          ]
         },
         {
-          "name": "program"
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for output root account initialization."
+          ]
         }
       ],
       "args": [
         {
-          "name": "cfg",
+          "name": "output_root",
           "type": {
-            "defined": {
-              "name": "BaseOracleConfig"
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "base_block_number",
+          "type": "u64"
+        },
+        {
+          "name": "total_leaf_count",
+          "type": "u64"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
             }
           }
         }
       ]
     },
     {
-      "name": "set_partner_oracle_config",
+      "name": "register_output_roots_batch",
       "docs": [
-        "Update the partner oracle configuration containing the required signature threshold",
+        "Registers several consecutive output roots from Base under a single set of oracle",
+        "signatures, e.g. to catch up after registrations were delayed.",
+        "Each entry is checked as if registered with `register_output_root`. The batch counts as one",
+        "registration against the cooldown, but each entry takes its own token from the registration",
+        "rate limit.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_config` - The new partner oracle config"
+        "* `ctx`        - The context containing the bridge account; the output root PDAs are passed as remaining accounts in entry order",
+        "* `entries`    - The output roots to register, in strictly increasing block order",
+        "* `signatures` - A list of ECDSA signatures from authorized oracles over the whole batch"
       ],
       "discriminator": [
-        34,
-        48,
-        231,
-        135,
-        42,
-        113,
-        217,
-        157
+        75,
+        145,
+        129,
+        168,
+        219,
+        158,
+        241,
+        47
       ],
       "accounts": [
         {
-          "name": "upgrade_authority",
+          "name": "payer",
           "docs": [
-            "The upgrade authority account"
+            "Payer funds the output root accounts. Authorization is enforced via oracle EVM signatures."
           ],
+          "writable": true,
           "signer": true
         },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The main bridge state account that tracks the latest registered Base block number.",
+            "- Uses PDA with BRIDGE_SEED",
+            "- Must be mutable to update the base_block_number field",
+            "- Enforces registrations are monotonic and aligned to the configured interval"
           ],
           "writable": true
         },
         {
-          "name": "program_data"
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in",
+            "the handler when partner approvals are required."
+          ]
         },
         {
-          "name": "program"
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the output root accounts."
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_config",
+          "name": "entries",
           "type": {
-            "defined": {
-              "name": "PartnerOracleConfig"
+            "vec": {
+              "defined": {
+                "name": "OutputRootEntry"
+              }
+            }
+          }
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
             }
           }
         }
       ]
     },
     {
-      "name": "set_pause_status",
+      "name": "relay_message",
       "docs": [
-        "Set the pause status for the bridge",
-        "Only the guardian can call this function",
+        "Executes a previously proven cross-chain message on Solana.",
+        "This function takes a message that has been proven via `prove_message` and executes",
+        "its payload using a bridge CPI authority derived from the message sender.",
+        "",
+        "The `guardian` account always precedes the remaining accounts; pass the bridge program ID",
+        "when the guardian does not co-sign. See `CHANGELOG.md` for clients built before this slot.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_paused` - The new pause status (true for paused, false for unpaused)"
+        "* `ctx` - The transaction context"
       ],
       "discriminator": [
-        118,
-        25,
-        145,
-        217,
-        114,
-        209,
-        236,
-        145
+        187,
+        90,
+        182,
+        138,
+        51,
+        248,
+        175,
+        98
       ],
       "accounts": [
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account containing the cross-chain message to be executed.",
+            "- Contains either a pure call message or a transfer message with additional instructions",
+            "- Must be mutable to mark the message as executed after processing",
+            "- Prevents replay attacks by tracking execution status"
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Mutable to track the SOL released from the vault"
           ],
           "writable": true
         },
         {
           "name": "guardian",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Optional guardian co-signer, required for SOL finalizations above",
+            "`bridge.protocol_config.large_finalize_threshold`.",
+            "- Pass the bridge program ID when the guardian does not co-sign"
           ],
-          "signer": true
+          "signer": true,
+          "optional": true
         }
       ],
-      "args": [
-        {
-          "name": "new_paused",
-          "type": "bool"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "set_window_duration",
+      "name": "remove_denied_remote_token",
       "docs": [
-        "Set the window duration for EIP-1559 pricing",
-        "Only the guardian can call this function",
+        "Remove a Base token address from the remote token denylist",
+        "Only the guardian can call this function, co-signed by the co-guardian if set",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_duration` - The new window duration in seconds"
+        "* `ctx`          - The context containing the bridge account, guardians and denylist entry",
+        "* `remote_token` - The Base token address to allow again"
       ],
       "discriminator": [
-        229,
-        2,
-        41,
-        119,
-        55,
-        255,
-        252,
-        205
+        179,
+        177,
+        160,
+        61,
+        154,
+        16,
+        67,
+        15
       ],
       "accounts": [
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
-          ],
-          "writable": true
+            "The bridge account used to authorize the guardians"
+          ]
         },
         {
           "name": "guardian",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The guardian account authorized to update the denylist, refunded the entry's rent"
           ],
+          "writable": true,
           "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of `remote_token`, closed by this instruction"
+          ],
+          "writable": true
         }
       ],
       "args": [
         {
-          "name": "new_duration",
-          "type": "u64"
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
         }
       ]
     },
     {
-      "name": "transfer_guardian",
+      "name": "remove_oracle_signer",
       "docs": [
-        "Transfer guardian authority to a new pubkey",
-        "Only the current guardian can call this function",
+        "Removes a single Base oracle signer. Fails if the remaining signers could no longer",
+        "meet the threshold.",
+        "Only the upgrade authority can call this function, together with the co-guardian when one",
+        "is configured",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and current guardian",
-        "* `new_guardian` - The pubkey of the new guardian"
+        "* `ctx`    - The context containing the bridge account, upgrade authority and co-guardian",
+        "* `signer` - The EVM address of the signer to remove"
       ],
       "discriminator": [
-        118,
-        250,
-        162,
-        85,
-        197,
-        130,
-        116,
-        123
+        103,
+        205,
+        177,
+        215,
+        115,
+        60,
+        121,
+        113
       ],
       "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
         {
           "name": "bridge",
           "docs": [
@@ -2471,470 +3281,4618 @@ export const IDL = {
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "co_guardian",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The co-guardian, required when `bridge.co_guardian` is set"
           ],
-          "signer": true
+          "signer": true,
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "new_guardian",
-          "type": "pubkey"
+          "name": "signer",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
         }
       ]
     },
     {
-      "name": "wrap_token",
+      "name": "reset_base_fee",
       "docs": [
-        "Creates a wrapped version of a Base token.",
-        "This function creates a new SPL mint account on Solana that represents the Base token,",
-        "enabling users to bridge the token between the two chains. It will also trigger a message",
-        "to Base to register the wrapped token in the Base Bridge contract.",
+        "Reset the current EIP-1559 base fee in an emergency",
+        "Only the guardian can call this function",
         "",
         "# Arguments",
-        "* `ctx`                    - The transaction context",
-        "* `outgoing_message_salt`  - The salt for the outgoing message account",
-        "* `decimals`               - Number of decimal places for the token",
-        "* `partial_token_metadata` - Token name, symbol, remote Base token address, and scaler exponent"
+        "* `ctx`     - The context containing the bridge account and guardian",
+        "* `new_fee` - The new base fee, in `[minimum_base_fee, MAX_BASE_FEE]`"
       ],
       "discriminator": [
-        203,
-        83,
-        204,
-        83,
-        225,
-        109,
-        44,
-        6
+        63,
+        245,
+        100,
+        235,
+        40,
+        163,
+        196,
+        148
       ],
       "accounts": [
         {
-          "name": "payer",
-          "docs": [
-            "The account that pays for the transaction and all account creation costs.",
-            "Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "gas_fee_receiver",
+          "name": "bridge",
           "docs": [
-            "The account that receives payment for the gas costs of registering the token on Base."
+            "The bridge account containing configuration"
           ],
           "writable": true
         },
         {
-          "name": "mint",
+          "name": "guardian",
           "docs": [
-            "The new SPL Token-2022 mint being created for the wrapped token.",
-            "- Uses PDA with token metadata hash and decimals for deterministic address",
-            "- Mint authority set to itself (mint account) for controlled minting",
-            "- Includes metadata pointer extension to store token information onchain"
+            "The guardian account authorized to update configuration"
           ],
-          "writable": true
-        },
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revalidate_output_root",
+      "docs": [
+        "Records that the current Base oracle set co-signed an already registered output root.",
+        "Required before proving against roots older than `revalidate_old_roots_after_blocks`.",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the bridge and output root accounts",
+        "* `base_block_number` - The Base block number of the output root to re-validate",
+        "* `signatures`        - Oracle EVM signatures over the root's registration message"
+      ],
+      "discriminator": [
+        87,
+        71,
+        25,
+        111,
+        69,
+        30,
+        209,
+        166
+      ],
+      "accounts": [
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account that tracks cross-chain operations.",
-            "Used to increment the nonce counter and manage EIP-1559 gas pricing.",
-            "Must be mutable to update the nonce after creating the outgoing message."
-          ],
-          "writable": true
+            "The main bridge state account holding the current Base oracle set.",
+            "- Uses PDA with BRIDGE_SEED"
+          ]
         },
         {
-          "name": "outgoing_message",
+          "name": "output_root",
           "docs": [
-            "The outgoing message account that stores the cross-chain call to register",
-            "the wrapped token on the Base blockchain. Contains the encoded function call",
-            "with token address, local mint address, and scaling parameters."
+            "The output root account being re-validated.",
+            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number"
           ],
           "writable": true
         },
         {
-          "name": "token_program",
-          "docs": [
-            "SPL Token-2022 program for creating the mint with metadata extensions.",
-            "Required for initializing tokens with advanced features like metadata pointers."
-          ]
-        },
-        {
-          "name": "system_program",
+          "name": "partner_config",
           "docs": [
-            "System program required for creating new accounts and transferring lamports.",
-            "Used internally by Anchor for account initialization and rent payments."
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in",
+            "the handler when partner approvals are required."
           ]
         }
       ],
       "args": [
         {
-          "name": "outgoing_message_salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "decimals",
-          "type": "u8"
+          "name": "base_block_number",
+          "type": "u64"
         },
         {
-          "name": "partial_token_metadata",
+          "name": "signatures",
           "type": {
-            "defined": {
-              "name": "PartialTokenMetadata"
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
             }
           }
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "Bridge",
+      "name": "set_adjustment_denominator",
+      "docs": [
+        "Set the adjustment denominator for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_denominator` - The new adjustment denominator"
+      ],
       "discriminator": [
-        231,
-        232,
         31,
-        98,
-        110,
-        3,
-        23,
-        59
+        91,
+        190,
+        63,
+        164,
+        7,
+        31,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_denominator",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "CallBuffer",
+      "name": "set_block_interval_requirement",
+      "docs": [
+        "Set the block interval requirement for Protocol Config",
+        "Only the guardian can call this function. The interval only constrains which Base blocks",
+        "can be registered, not how often, so it does not need the co-guardian",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_interval` - The new block interval requirement value"
+      ],
       "discriminator": [
-        134,
-        143,
-        168,
-        251,
-        163,
-        216,
-        180,
-        113
+        76,
+        70,
+        237,
+        100,
+        33,
+        108,
+        19,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_interval",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "IncomingMessage",
+      "name": "set_call_type_gas_multiplier",
+      "docs": [
+        "Set the fee multiplier for a call type in Gas Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `ty` - The call type the multiplier applies to",
+        "* `multiplier_bps` - The new multiplier in basis points (10_000 leaves the fee unchanged),",
+        "in `1..=MAX_GAS_MULTIPLIER_BPS`"
+      ],
       "discriminator": [
+        156,
         30,
-        144,
-        125,
-        111,
-        211,
-        223,
-        91,
-        170
+        130,
+        11,
+        194,
+        179,
+        93,
+        147
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "ty",
+          "type": {
+            "defined": {
+              "name": "CallType"
+            }
+          }
+        },
+        {
+          "name": "multiplier_bps",
+          "type": "u32"
+        }
       ]
     },
     {
-      "name": "OutgoingMessage",
+      "name": "set_co_guardian",
+      "docs": [
+        "Set the co-guardian that must co-sign critical configuration changes (unpausing,",
+        "guardian transfers and co-guardian changes)",
+        "Only the current guardian can call this function, co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`             - The context containing the bridge account and guardians",
+        "* `new_co_guardian` - The new co-guardian (the default pubkey removes it)"
+      ],
       "discriminator": [
-        150,
-        255,
-        197,
-        226,
-        200,
-        215,
-        31,
-        29
+        34,
+        82,
+        107,
+        63,
+        38,
+        242,
+        38,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_co_guardian",
+          "type": "pubkey"
+        }
       ]
     },
     {
-      "name": "OutputRoot",
+      "name": "set_daily_sol_volume_limits",
+      "docs": [
+        "Set the daily limits on SOL bridged in each direction for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling either limit must be",
+        "co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`            - The context containing the bridge account and guardians",
+        "* `outbound_limit` - Maximum lamports bridged to Base per fixed daily window (0 disables",
+        "the limit)",
+        "* `inbound_limit`  - Maximum lamports finalized from Base per fixed daily window (0",
+        "disables the limit)"
+      ],
       "discriminator": [
-        11,
-        31,
-        168,
-        201,
-        229,
-        8,
-        180,
-        198
+        58,
+        68,
+        104,
+        149,
+        165,
+        67,
+        122,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "outbound_limit",
+          "type": "u64"
+        },
+        {
+          "name": "inbound_limit",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "ProveBuffer",
+      "name": "set_decay_grace_windows",
+      "docs": [
+        "Set the number of consecutive empty windows before the base fee decays for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the bridge account and guardian",
+        "* `new_grace_windows` - The new number of empty windows during which the base fee holds"
+      ],
       "discriminator": [
-        78,
-        160,
-        227,
-        163,
-        97,
-        140,
-        40,
-        149
+        36,
+        127,
+        69,
+        75,
+        237,
+        19,
+        193,
+        14
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_grace_windows",
+          "type": "u64"
+        }
       ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 12000,
-      "name": "BridgePaused",
-      "msg": "Bridge is currently paused"
-    },
-    {
-      "code": 12001,
-      "name": "IncorrectBridgeProgram",
-      "msg": "Incorrect bridge program"
-    },
-    {
-      "code": 12002,
-      "name": "IncorrectGasFeeReceiver",
-      "msg": "Incorrect gas fee receiver"
     },
     {
-      "code": 12100,
-      "name": "UnauthorizedInitialization",
-      "msg": "Only the upgrade authority can initialize the bridge"
+      "name": "set_disjoint_signer_sets",
+      "docs": [
+        "Set whether the Base and partner oracle sets must approve output roots independently for",
+        "Protocol Config",
+        "Only the guardian can call this function. Turning the check off must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                  - The context containing the bridge account and guardians",
+        "* `disjoint_signer_sets` - If set, a signer in both sets fails registration instead of only",
+        "emitting an event"
+      ],
+      "discriminator": [
+        243,
+        44,
+        133,
+        127,
+        206,
+        150,
+        187,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "disjoint_signer_sets",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "code": 12101,
-      "name": "UnauthorizedConfigUpdate",
-      "msg": "Unauthorized to update configuration"
+      "name": "set_eip1559_params",
+      "docs": [
+        "Replace all EIP-1559 pricing parameters at once, settling the current fee window under the",
+        "old parameters first",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account and guardian",
+        "* `new_config` - The new EIP-1559 configuration"
+      ],
+      "discriminator": [
+        210,
+        68,
+        187,
+        152,
+        195,
+        171,
+        25,
+        213
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_config",
+          "type": {
+            "defined": {
+              "name": "Eip1559Config"
+            }
+          }
+        }
+      ]
     },
     {
-      "code": 12200,
-      "name": "BufferUnauthorizedClose",
-      "msg": "Only the owner can close this buffer"
+      "name": "set_enforce_relay_nonce_order",
+      "docs": [
+        "Set whether relaying a message at or below the highest relayed nonce is rejected for",
+        "Protocol Config",
+        "Only the guardian can call this function. Turning the check off must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                       - The context containing the bridge account and guardians",
+        "* `enforce_relay_nonce_order` - If set, messages must be relayed in increasing nonce order"
+      ],
+      "discriminator": [
+        195,
+        11,
+        158,
+        104,
+        200,
+        201,
+        165,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enforce_relay_nonce_order",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "code": 12201,
-      "name": "BufferUnauthorizedAppend",
-      "msg": "Only the owner can append to this buffer"
-    },
+      "name": "set_gas_cost_scaler",
+      "docs": [
+        "Set the gas cost scaler for Gas Cost Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_scaler` - The new gas cost scaler value"
+      ],
+      "discriminator": [
+        148,
+        146,
+        101,
+        170,
+        5,
+        6,
+        222,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_scaler",
+          "type": "u64"
+        }
+      ]
+    },
     {
-      "code": 12202,
-      "name": "BufferMaxSizeExceeded",
-      "msg": "Call buffer size exceeds maximum allowed size"
+      "name": "set_gas_cost_scaler_dp",
+      "docs": [
+        "Set the gas cost scaler DP for Gas Cost Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_dp` - The new gas cost scaler DP value"
+      ],
+      "discriminator": [
+        198,
+        111,
+        160,
+        55,
+        172,
+        138,
+        99,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_dp",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12300,
-      "name": "InvalidRecoveryId",
-      "msg": "Invalid recovery ID"
+      "name": "set_gas_fee_receiver",
+      "docs": [
+        "Set the gas fee receiver for Gas Cost Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_receiver` - The new gas fee receiver"
+      ],
+      "discriminator": [
+        58,
+        188,
+        230,
+        188,
+        47,
+        188,
+        79,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_receiver",
+          "type": "pubkey"
+        }
+      ]
     },
     {
-      "code": 12301,
-      "name": "SignatureVerificationFailed",
-      "msg": "Signature verification failed"
+      "name": "set_gas_per_call",
+      "docs": [
+        "Set the gas amount per call for Gas Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_val` - The new gas amount per call value"
+      ],
+      "discriminator": [
+        164,
+        95,
+        213,
+        130,
+        26,
+        69,
+        82,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_val",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12302,
-      "name": "InsufficientBaseSignatures",
-      "msg": "Insufficient base oracle signatures to meet threshold"
+      "name": "set_gas_target",
+      "docs": [
+        "Set the gas target for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_target` - The new gas target value"
+      ],
+      "discriminator": [
+        132,
+        25,
+        19,
+        13,
+        118,
+        63,
+        167,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_target",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12303,
-      "name": "InsufficientPartnerSignatures",
-      "msg": "Insufficient partner oracle signatures to meet threshold"
+      "name": "set_large_finalize_threshold",
+      "docs": [
+        "Set the lamport amount above which SOL finalizations require a guardian co-signature for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling the threshold must be",
+        "co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`           - The context containing the bridge account and guardians",
+        "* `new_threshold` - The new threshold in lamports (0 disables the check)"
+      ],
+      "discriminator": [
+        8,
+        218,
+        107,
+        94,
+        219,
+        134,
+        19,
+        243
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_threshold",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12400,
-      "name": "InvalidProof",
-      "msg": "Invalid proof"
-    },
-    {
-      "code": 12401,
-      "name": "MmrShouldBeEmpty",
-      "msg": "MMR should be empty"
-    },
-    {
-      "code": 12402,
-      "name": "EmptyMmr",
-      "msg": "MMR is empty"
-    },
-    {
-      "code": 12403,
-      "name": "LeafMountainNotFound",
-      "msg": "Leaf's mountain not found"
+      "name": "set_max_block_advance",
+      "docs": [
+        "Set how many Base blocks a new output root may advance past the latest registered one",
+        "for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling the bound must be co-signed",
+        "by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                   - The context containing the bridge account and guardians",
+        "* `new_max_block_advance` - The new maximum advance in blocks (0 disables the check)"
+      ],
+      "discriminator": [
+        46,
+        181,
+        211,
+        117,
+        53,
+        92,
+        70,
+        105
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_max_block_advance",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12404,
-      "name": "InsufficientProofElementsForIntraMountainPath",
-      "msg": "Insufficient proof elements for intra-mountain path"
+      "name": "set_max_call_buffer_appends",
+      "docs": [
+        "Set the max number of appends per call buffer for Buffer Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`     - The context containing the bridge account and guardian",
+        "* `new_max` - The new max number of appends to a single call buffer (0 disables the limit)"
+      ],
+      "discriminator": [
+        125,
+        131,
+        164,
+        125,
+        47,
+        251,
+        254,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_max",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12405,
-      "name": "InsufficientProofElementsForOtherMountainPeaks",
-      "msg": "Insufficient proof elements for other mountain peaks"
+      "name": "set_max_call_buffer_size",
+      "docs": [
+        "Set the max call buffer size for Buffer Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_size` - The new max call buffer size value"
+      ],
+      "discriminator": [
+        140,
+        178,
+        4,
+        238,
+        245,
+        66,
+        117,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_size",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12406,
-      "name": "UnusedProofElementsRemaining",
-      "msg": "Unused proof elements remaining"
+      "name": "set_max_prove_buffer_proof_len",
+      "docs": [
+        "Set the max prove buffer proof length for Buffer Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_len` - The new max number of proof nodes a prove buffer can hold"
+      ],
+      "discriminator": [
+        7,
+        44,
+        231,
+        9,
+        148,
+        131,
+        215,
+        116
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_len",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12407,
-      "name": "NoPeaksFoundForNonEmptyMmr",
-      "msg": "No peaks found for non-empty MMR"
+      "name": "set_max_root_age",
+      "docs": [
+        "Set the maximum age of output roots that messages can be proven against for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling the maximum age must be",
+        "co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                 - The context containing the bridge account and guardians",
+        "* `new_max_age_seconds` - The new maximum root age in seconds (0 disables the check)"
+      ],
+      "discriminator": [
+        148,
+        167,
+        52,
+        173,
+        213,
+        42,
+        191,
+        21
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_max_age_seconds",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12500,
-      "name": "InvalidMessageHash",
-      "msg": "Invalid message hash"
+      "name": "set_max_wrapped_tokens",
+      "docs": [
+        "Set the maximum number of wrapped mints for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling the limit must be co-signed",
+        "by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`     - The context containing the bridge account and guardians",
+        "* `new_max` - The new maximum number of wrapped tokens (0 disables the limit)"
+      ],
+      "discriminator": [
+        9,
+        195,
+        210,
+        57,
+        133,
+        188,
+        32,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_max",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "code": 12501,
-      "name": "AlreadyExecuted",
-      "msg": "Message already executed"
+      "name": "set_maximum_fee_lamports",
+      "docs": [
+        "Set the maximum fee per message for Gas Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_maximum_fee` - The new maximum fee in lamports charged per message (0 removes the",
+        "ceiling)"
+      ],
+      "discriminator": [
+        182,
+        144,
+        106,
+        14,
+        49,
+        118,
+        137,
+        37
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_maximum_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_min_prove_to_relay_delay",
+      "docs": [
+        "Set how long a proven message must wait before it can be relayed for Protocol Config",
+        "Only the guardian can call this function. Shortening or disabling the delay must be",
+        "co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the bridge account and guardians",
+        "* `new_delay_seconds` - The new minimum delay in seconds (0 disables the check)"
+      ],
+      "discriminator": [
+        161,
+        227,
+        199,
+        73,
+        250,
+        197,
+        75,
+        195
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_delay_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_min_registration_interval",
+      "docs": [
+        "Set the minimum wall-clock interval between output root registrations for Protocol Config",
+        "Only the guardian can call this function. Lowering or disabling the interval must be",
+        "co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                  - The context containing the bridge account and guardians",
+        "* `new_interval_seconds` - The new minimum interval in seconds (0 disables the check)"
+      ],
+      "discriminator": [
+        251,
+        224,
+        73,
+        2,
+        86,
+        176,
+        123,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_interval_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_minimum_base_fee",
+      "docs": [
+        "Set the minimum base fee for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_fee` - The new minimum base fee value"
+      ],
+      "discriminator": [
+        56,
+        95,
+        58,
+        94,
+        221,
+        136,
+        138,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_minimum_fee_lamports",
+      "docs": [
+        "Set the minimum fee per message for Gas Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_minimum_fee` - The new minimum fee in lamports charged per message"
+      ],
+      "discriminator": [
+        51,
+        34,
+        17,
+        148,
+        45,
+        212,
+        58,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_minimum_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_oracle_signers",
+      "docs": [
+        "Sets the authorized oracle EVM signer addresses and the signature threshold used",
+        "when registering output roots. Only the upgrade authority can call this function, together",
+        "with the co-guardian when one is configured.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account, upgrade authority and co-guardian",
+        "* `cfg` - Configuration parameters for Base oracle signers"
+      ],
+      "discriminator": [
+        38,
+        183,
+        212,
+        67,
+        28,
+        241,
+        25,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "cfg",
+          "type": {
+            "defined": {
+              "name": "BaseOracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_oracle_threshold",
+      "docs": [
+        "Sets the approval threshold of the Base oracle signer set.",
+        "Only the upgrade authority can call this function, together with the co-guardian when one",
+        "is configured",
+        "",
+        "# Arguments",
+        "* `ctx`       - The context containing the bridge account, upgrade authority and co-guardian",
+        "* `threshold` - The new required approval weight (> 0 and <= total signer weight)"
+      ],
+      "discriminator": [
+        181,
+        85,
+        21,
+        30,
+        120,
+        168,
+        37,
+        233
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "set_partner_oracle_config",
+      "docs": [
+        "Update the partner oracle configuration containing the required signature threshold",
+        "Only the upgrade authority can call this function, together with the co-guardian when one",
+        "is configured",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account, upgrade authority and co-guardian",
+        "* `new_config` - The new partner oracle config"
+      ],
+      "discriminator": [
+        34,
+        48,
+        231,
+        135,
+        42,
+        113,
+        217,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_config",
+          "type": {
+            "defined": {
+              "name": "PartnerOracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_pause_status",
+      "docs": [
+        "Set the pause status for the bridge",
+        "Only the guardian can call this function. Unpausing must be co-signed by the co-guardian if",
+        "set",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_paused` - The new pause status (true for paused, false for unpaused)"
+      ],
+      "discriminator": [
+        118,
+        25,
+        145,
+        217,
+        114,
+        209,
+        236,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_paused_ops",
+      "docs": [
+        "Pause individual bridge operations without stopping the whole bridge",
+        "Only the guardian can call this function. Clearing a flag must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account and guardian",
+        "* `paused_ops` - Bitmask of paused operations (`PAUSE_PROVE`, `PAUSE_RELAY`,",
+        "`PAUSE_BRIDGE_TOKEN`, `PAUSE_BRIDGE_CALL`, `PAUSE_REGISTER_ROOT`)"
+      ],
+      "discriminator": [
+        79,
+        93,
+        42,
+        237,
+        25,
+        205,
+        77,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "paused_ops",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "set_rate_limit",
+      "docs": [
+        "Set the outflow limit of a Base token address, checked by every bridge of that token",
+        "Only the guardian can call this function. Removing a limit, raising its maximum or",
+        "shortening its window must be co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`            - The context containing the bridge account, guardians and rate limit",
+        "* `remote_token`   - The Base token address to limit",
+        "* `max_per_window` - The maximum amount, in local token units, bridged per window (0 removes the limit)",
+        "* `window_seconds` - The window duration in seconds"
+      ],
+      "discriminator": [
+        42,
+        212,
+        44,
+        91,
+        198,
+        58,
+        60,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account used to authorize the guardians"
+          ]
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to set rate limits; it pays the rent of a new limit and is",
+            "refunded when the limit is removed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set and the change loosens the limit"
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "rate_limit",
+          "docs": [
+            "The rate limit of `remote_token`, created if it doesn't exist"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the rate limit"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "max_per_window",
+          "type": "u64"
+        },
+        {
+          "name": "window_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_registration_rate_limit",
+      "docs": [
+        "Set the leaky bucket rate limit on output root registrations for Protocol Config",
+        "Only the guardian can call this function. Raising or disabling the capacity, or shortening",
+        "the refill interval, must be co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                     - The context containing the bridge account and guardians",
+        "* `capacity`                - Maximum registrations in a burst (0 disables the limit)",
+        "* `refill_interval_seconds` - Seconds after which one registration is returned to the bucket"
+      ],
+      "discriminator": [
+        235,
+        40,
+        35,
+        145,
+        157,
+        190,
+        133,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "capacity",
+          "type": "u64"
+        },
+        {
+          "name": "refill_interval_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_reject_duplicate_ixs",
+      "docs": [
+        "Set whether relaying a message with identical instructions is rejected for Protocol Config",
+        "Only the guardian can call this function. Turning the check off must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                  - The context containing the bridge account and guardians",
+        "* `reject_duplicate_ixs` - If set, messages containing two identical instructions fail to relay"
+      ],
+      "discriminator": [
+        172,
+        4,
+        123,
+        206,
+        56,
+        212,
+        71,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reject_duplicate_ixs",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_reject_duplicate_roots",
+      "docs": [
+        "Set whether registering an output root identical to the previous one is rejected for Protocol Config",
+        "Only the guardian can call this function. Turning the check off must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`                    - The context containing the bridge account and guardians",
+        "* `reject_duplicate_roots` - If set, duplicate roots fail instead of only emitting an event"
+      ],
+      "discriminator": [
+        21,
+        127,
+        44,
+        128,
+        192,
+        1,
+        19,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reject_duplicate_roots",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_require_self_call",
+      "docs": [
+        "Set whether `bridge_call` and `bridge_call_buffered` require `from` to be the payer for",
+        "Protocol Config",
+        "Only the guardian can call this function. Turning the check off must be co-signed by the",
+        "co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the bridge account and guardians",
+        "* `require_self_call` - If set, calls paid for by a third-party sponsor are rejected"
+      ],
+      "discriminator": [
+        27,
+        4,
+        190,
+        19,
+        207,
+        162,
+        47,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "require_self_call",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_revalidate_old_roots_after_blocks",
+      "docs": [
+        "Set how many Base blocks an output root may fall behind before it must be re-validated",
+        "for Protocol Config",
+        "Only the guardian can call this function. Raising the threshold or disabling re-validation",
+        "must be co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account and guardians",
+        "* `new_blocks` - The new block threshold (0 disables re-validation)"
+      ],
+      "discriminator": [
+        215,
+        172,
+        88,
+        61,
+        96,
+        230,
+        105,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_blocks",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_window_duration",
+      "docs": [
+        "Set the window duration for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_duration` - The new window duration in seconds"
+      ],
+      "discriminator": [
+        229,
+        2,
+        41,
+        119,
+        55,
+        255,
+        252,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_duration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "thaw_wrapped_account",
+      "docs": [
+        "Thaw a wrapped token account frozen by `freeze_wrapped_account`",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge, guardian, wrapped mint and token account"
+      ],
+      "discriminator": [
+        254,
+        163,
+        11,
+        9,
+        189,
+        227,
+        47,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account used to authorize the guardian"
+          ]
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to freeze and thaw wrapped token accounts"
+          ],
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The wrapped mint. Its address must be the wrapped token PDA derived from its metadata and",
+            "decimals, so only mints created by `wrap_token` can be used."
+          ]
+        },
+        {
+          "name": "token_account",
+          "docs": [
+            "The token account of `mint` to freeze or thaw"
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "SPL Token-2022 program the wrapped mint belongs to"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transfer_call_buffer",
+      "docs": [
+        "Transfers ownership of a call buffer account to a new owner.",
+        "Only the current owner of the call buffer can transfer it. This lets a service build",
+        "large call data and hand the buffer to the wallet that will bridge it.",
+        "",
+        "# Arguments",
+        "* `ctx`       - The context containing the call buffer and its current owner",
+        "* `new_owner` - The account that will own the call buffer (must not be the zero pubkey)"
+      ],
+      "discriminator": [
+        51,
+        82,
+        120,
+        74,
+        102,
+        224,
+        222,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The current owner of the call buffer.",
+            "Must match `call_buffer.owner`."
+          ],
+          "signer": true
+        },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account being transferred"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_owner",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "transfer_guardian",
+      "docs": [
+        "Propose a new guardian. The role is only handed over once the proposed key accepts it",
+        "with `accept_guardian`.",
+        "Only the current guardian can call this function, co-signed by the co-guardian if set",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and current guardian",
+        "* `new_guardian` - The pubkey of the proposed guardian, recorded as `pending_guardian`"
+      ],
+      "discriminator": [
+        118,
+        250,
+        162,
+        85,
+        197,
+        130,
+        116,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "co_guardian",
+          "docs": [
+            "The co-guardian, required when `bridge.co_guardian` is set"
+          ],
+          "signer": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_guardian",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "wrap_token",
+      "docs": [
+        "Creates a wrapped version of a Base token.",
+        "This function creates a new SPL mint account on Solana that represents the Base token,",
+        "enabling users to bridge the token between the two chains. It will also trigger a message",
+        "to Base to register the wrapped token in the Base Bridge contract.",
+        "",
+        "The `denied_remote_token` PDA of the remote token follows `bridge`; see `CHANGELOG.md` for",
+        "clients built before this account.",
+        "",
+        "# Arguments",
+        "* `ctx`                    - The transaction context",
+        "* `outgoing_message_salt`  - The salt for the outgoing message account",
+        "* `decimals`               - Number of decimal places for the token",
+        "* `partial_token_metadata` - Token name, symbol, remote Base token address, and scaler exponent"
+      ],
+      "discriminator": [
+        203,
+        83,
+        204,
+        83,
+        225,
+        109,
+        44,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the transaction and all account creation costs.",
+            "Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The account that receives payment for the gas costs of registering the token on Base."
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The new SPL Token-2022 mint being created for the wrapped token.",
+            "- Uses PDA with token metadata hash and decimals for deterministic address",
+            "- Mint and freeze authority set to itself (mint account) for controlled minting and",
+            "guardian-initiated freezes",
+            "- Includes metadata pointer extension to store token information onchain"
+          ],
+          "writable": true
+        },
+        {
+          "name": "wrapped_token_info",
+          "docs": [
+            "Record of the wrapped mint's creation, queryable without replaying events.",
+            "- Uses PDA with WRAPPED_TOKEN_INFO_SEED and the mint address",
+            "- Payer funds the account creation"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account that tracks cross-chain operations.",
+            "Used to increment the nonce counter and manage EIP-1559 gas pricing.",
+            "Must be mutable to update the nonce after creating the outgoing message."
+          ],
+          "writable": true
+        },
+        {
+          "name": "denied_remote_token",
+          "docs": [
+            "The denylist entry of the remote token being wrapped; the instruction fails if it exists.",
+            "token; only its existence is read"
+          ]
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message account that stores the cross-chain call to register",
+            "the wrapped token on the Base blockchain. Contains the encoded function call",
+            "with token address, local mint address, and scaling parameters."
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "SPL Token-2022 program for creating the mint with metadata extensions.",
+            "Required for initializing tokens with advanced features like metadata pointers."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts and transferring lamports.",
+            "Used internally by Anchor for account initialization and rent payments."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "outgoing_message_salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "partial_token_metadata",
+          "type": {
+            "defined": {
+              "name": "PartialTokenMetadata"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "Bridge",
+      "discriminator": [
+        231,
+        232,
+        31,
+        98,
+        110,
+        3,
+        23,
+        59
+      ]
+    },
+    {
+      "name": "CallBuffer",
+      "discriminator": [
+        134,
+        143,
+        168,
+        251,
+        163,
+        216,
+        180,
+        113
+      ]
+    },
+    {
+      "name": "DeniedRemoteToken",
+      "discriminator": [
+        67,
+        35,
+        173,
+        230,
+        19,
+        38,
+        101,
+        185
+      ]
+    },
+    {
+      "name": "IncomingMessage",
+      "discriminator": [
+        30,
+        144,
+        125,
+        111,
+        211,
+        223,
+        91,
+        170
+      ]
+    },
+    {
+      "name": "OutgoingMessage",
+      "discriminator": [
+        150,
+        255,
+        197,
+        226,
+        200,
+        215,
+        31,
+        29
+      ]
+    },
+    {
+      "name": "OutputRoot",
+      "discriminator": [
+        11,
+        31,
+        168,
+        201,
+        229,
+        8,
+        180,
+        198
+      ]
+    },
+    {
+      "name": "ProveBuffer",
+      "discriminator": [
+        78,
+        160,
+        227,
+        163,
+        97,
+        140,
+        40,
+        149
+      ]
+    },
+    {
+      "name": "RelayCursor",
+      "discriminator": [
+        1,
+        250,
+        94,
+        184,
+        169,
+        144,
+        34,
+        37
+      ]
+    },
+    {
+      "name": "RemoteTokenRateLimit",
+      "discriminator": [
+        68,
+        122,
+        242,
+        217,
+        88,
+        235,
+        251,
+        61
+      ]
+    },
+    {
+      "name": "WrappedTokenInfo",
+      "discriminator": [
+        187,
+        203,
+        221,
+        206,
+        239,
+        75,
+        120,
+        236
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "BaseFeeReset",
+      "discriminator": [
+        0,
+        185,
+        13,
+        76,
+        79,
+        164,
+        168,
+        228
+      ]
+    },
+    {
+      "name": "BlockIntervalRequirementUpdated",
+      "discriminator": [
+        245,
+        159,
+        5,
+        237,
+        171,
+        147,
+        143,
+        40
+      ]
+    },
+    {
+      "name": "CoGuardianUpdated",
+      "discriminator": [
+        225,
+        236,
+        100,
+        87,
+        25,
+        127,
+        190,
+        229
+      ]
+    },
+    {
+      "name": "DuplicateRootRegistered",
+      "discriminator": [
+        229,
+        2,
+        145,
+        153,
+        70,
+        86,
+        207,
+        201
+      ]
+    },
+    {
+      "name": "Eip1559ConfigChanged",
+      "discriminator": [
+        27,
+        177,
+        137,
+        100,
+        198,
+        247,
+        52,
+        33
+      ]
+    },
+    {
+      "name": "GuardianTransferAccepted",
+      "discriminator": [
+        92,
+        184,
+        35,
+        135,
+        72,
+        181,
+        168,
+        211
+      ]
+    },
+    {
+      "name": "GuardianTransferCancelled",
+      "discriminator": [
+        210,
+        60,
+        91,
+        221,
+        35,
+        131,
+        73,
+        216
+      ]
+    },
+    {
+      "name": "GuardianTransferProposed",
+      "discriminator": [
+        252,
+        165,
+        36,
+        42,
+        189,
+        213,
+        30,
+        85
+      ]
+    },
+    {
+      "name": "OracleSignersUpdated",
+      "discriminator": [
+        202,
+        141,
+        15,
+        59,
+        33,
+        104,
+        137,
+        159
+      ]
+    },
+    {
+      "name": "OutputRootRegistered",
+      "discriminator": [
+        180,
+        195,
+        46,
+        194,
+        24,
+        131,
+        72,
+        43
+      ]
+    },
+    {
+      "name": "OverlappingSignersApproved",
+      "discriminator": [
+        41,
+        132,
+        197,
+        222,
+        244,
+        66,
+        75,
+        56
+      ]
+    },
+    {
+      "name": "WrappedAccountFreezeUpdated",
+      "discriminator": [
+        41,
+        45,
+        237,
+        208,
+        31,
+        13,
+        45,
+        145
+      ]
+    },
+    {
+      "name": "WrappedTokenCreated",
+      "discriminator": [
+        126,
+        49,
+        246,
+        64,
+        175,
+        45,
+        82,
+        231
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 12000,
+      "name": "BridgePaused",
+      "msg": "Bridge is currently paused"
+    },
+    {
+      "code": 12001,
+      "name": "IncorrectBridgeProgram",
+      "msg": "Incorrect bridge program"
+    },
+    {
+      "code": 12002,
+      "name": "IncorrectGasFeeReceiver",
+      "msg": "Incorrect gas fee receiver"
+    },
+    {
+      "code": 12003,
+      "name": "GasCostOverflow",
+      "msg": "Gas cost does not fit in a u64"
+    },
+    {
+      "code": 12004,
+      "name": "QuoteOverflow",
+      "msg": "Quoted total cost does not fit in a u64"
+    },
+    {
+      "code": 12005,
+      "name": "AlreadyMigrated",
+      "msg": "Account is already migrated or does not have the legacy layout"
+    },
+    {
+      "code": 12100,
+      "name": "UnauthorizedInitialization",
+      "msg": "Only the upgrade authority can initialize the bridge"
+    },
+    {
+      "code": 12101,
+      "name": "UnauthorizedConfigUpdate",
+      "msg": "Unauthorized to update configuration"
+    },
+    {
+      "code": 12102,
+      "name": "GuardianApprovalRequired",
+      "msg": "Guardian approval is required for this finalization"
+    },
+    {
+      "code": 12103,
+      "name": "SponsorNotAllowed",
+      "msg": "Sender must also be the payer"
+    },
+    {
+      "code": 12104,
+      "name": "UnauthorizedGuardianAcceptance",
+      "msg": "Only the pending guardian can accept the guardian role"
+    },
+    {
+      "code": 12105,
+      "name": "InvalidPartnerConfigOwner",
+      "msg": "Partner config account is not owned by the partner program"
+    },
+    {
+      "code": 12106,
+      "name": "CoGuardianSignatureRequired",
+      "msg": "The co-guardian must also sign this configuration change"
+    },
+    {
+      "code": 12107,
+      "name": "InvalidCoGuardian",
+      "msg": "The co-guardian must differ from the guardian"
+    },
+    {
+      "code": 12200,
+      "name": "BufferUnauthorizedClose",
+      "msg": "Only the owner can close this buffer"
+    },
+    {
+      "code": 12201,
+      "name": "BufferUnauthorizedAppend",
+      "msg": "Only the owner can append to this buffer"
+    },
+    {
+      "code": 12202,
+      "name": "BufferMaxSizeExceeded",
+      "msg": "Call buffer size exceeds maximum allowed size"
+    },
+    {
+      "code": 12203,
+      "name": "ProveBufferMaxProofLenExceeded",
+      "msg": "Prove buffer proof length exceeds maximum allowed length"
+    },
+    {
+      "code": 12204,
+      "name": "ProveBufferCapacityExceeded",
+      "msg": "Append exceeds the prove buffer's allocated capacity"
+    },
+    {
+      "code": 12205,
+      "name": "TooManyAppends",
+      "msg": "Call buffer has reached its maximum number of appends"
+    },
+    {
+      "code": 12206,
+      "name": "BufferUnauthorizedTransfer",
+      "msg": "Only the owner can transfer this buffer"
+    },
+    {
+      "code": 12207,
+      "name": "BufferChecksumMismatch",
+      "msg": "Call buffer data does not match the committed hash"
+    },
+    {
+      "code": 12208,
+      "name": "BufferOverflow",
+      "msg": "Append would grow the call buffer past its capacity"
+    },
+    {
+      "code": 12300,
+      "name": "InvalidRecoveryId",
+      "msg": "Invalid recovery ID"
+    },
+    {
+      "code": 12301,
+      "name": "SignatureVerificationFailed",
+      "msg": "Signature verification failed"
+    },
+    {
+      "code": 12302,
+      "name": "InsufficientBaseSignatures",
+      "msg": "Insufficient base oracle signatures to meet threshold"
+    },
+    {
+      "code": 12303,
+      "name": "InsufficientPartnerSignatures",
+      "msg": "Insufficient partner oracle signatures to meet threshold"
+    },
+    {
+      "code": 12304,
+      "name": "OverlappingSignerSets",
+      "msg": "Signer approved as both a Base and a partner oracle"
+    },
+    {
+      "code": 12400,
+      "name": "InvalidProof",
+      "msg": "Invalid proof"
+    },
+    {
+      "code": 12401,
+      "name": "MmrShouldBeEmpty",
+      "msg": "MMR should be empty"
+    },
+    {
+      "code": 12402,
+      "name": "EmptyMmr",
+      "msg": "MMR is empty"
+    },
+    {
+      "code": 12403,
+      "name": "LeafMountainNotFound",
+      "msg": "Leaf's mountain not found"
+    },
+    {
+      "code": 12404,
+      "name": "InsufficientProofElementsForIntraMountainPath",
+      "msg": "Insufficient proof elements for intra-mountain path"
+    },
+    {
+      "code": 12405,
+      "name": "InsufficientProofElementsForOtherMountainPeaks",
+      "msg": "Insufficient proof elements for other mountain peaks"
+    },
+    {
+      "code": 12406,
+      "name": "UnusedProofElementsRemaining",
+      "msg": "Unused proof elements remaining"
+    },
+    {
+      "code": 12407,
+      "name": "NoPeaksFoundForNonEmptyMmr",
+      "msg": "No peaks found for non-empty MMR"
+    },
+    {
+      "code": 12408,
+      "name": "ProofTooLong",
+      "msg": "Proof is longer than any valid proof for this MMR"
+    },
+    {
+      "code": 12500,
+      "name": "InvalidMessageHash",
+      "msg": "Invalid message hash"
+    },
+    {
+      "code": 12501,
+      "name": "AlreadyExecuted",
+      "msg": "Message already executed"
+    },
+    {
+      "code": 12502,
+      "name": "IncorrectBlockNumber",
+      "msg": "Incorrect block number"
+    },
+    {
+      "code": 12503,
+      "name": "UnsupportedMessageVersion",
+      "msg": "Unsupported message version"
+    },
+    {
+      "code": 12504,
+      "name": "RegistrationTooSoon",
+      "msg": "Output root registration is too soon after the previous one"
+    },
+    {
+      "code": 12505,
+      "name": "OutputRootTooOld",
+      "msg": "Output root is older than the maximum allowed age"
+    },
+    {
+      "code": 12506,
+      "name": "RegistrationRateLimited",
+      "msg": "Output root registrations are rate limited"
+    },
+    {
+      "code": 12507,
+      "name": "DuplicateOutputRoot",
+      "msg": "Output root is identical to the previously registered root"
+    },
+    {
+      "code": 12508,
+      "name": "ProtectedAccountMutation",
+      "msg": "Relayed instruction targets a protected bridge account"
+    },
+    {
+      "code": 12509,
+      "name": "RootNotRegistered",
+      "msg": "Output root is not within the registered range"
+    },
+    {
+      "code": 12510,
+      "name": "InvalidRelaySlice",
+      "msg": "Relay slice is empty or extends past the message instructions"
+    },
+    {
+      "code": 12511,
+      "name": "CannotCloseLatestOutputRoot",
+      "msg": "The latest output root cannot be closed"
+    },
+    {
+      "code": 12512,
+      "name": "OutputRootCloseTimelocked",
+      "msg": "Output root is too recent to be closed"
+    },
+    {
+      "code": 12513,
+      "name": "OutputRootWithinCloseMargin",
+      "msg": "Output root is within the close margin of the latest registered block"
+    },
+    {
+      "code": 12514,
+      "name": "OutputRootRequiresRevalidation",
+      "msg": "Output root must be re-validated by the current oracle set"
+    },
+    {
+      "code": 12515,
+      "name": "BlockNumberTooFarAhead",
+      "msg": "Base block number is too far ahead of the latest registered block"
+    },
+    {
+      "code": 12516,
+      "name": "InvalidOutputRootBatch",
+      "msg": "Output root batch is empty, too large or does not match the provided root accounts"
+    },
+    {
+      "code": 12517,
+      "name": "RelayTooSoon",
+      "msg": "Message was proven too recently to be relayed"
+    },
+    {
+      "code": 12518,
+      "name": "DuplicateRelayedInstruction",
+      "msg": "Message contains duplicate instructions"
+    },
+    {
+      "code": 12519,
+      "name": "RelayNonceOutOfOrder",
+      "msg": "Message nonce is not above the highest relayed nonce"
+    },
+    {
+      "code": 12520,
+      "name": "NoInstructionsToRelay",
+      "msg": "Message has no instructions to relay in slices, use relay_message instead"
+    },
+    {
+      "code": 12600,
+      "name": "MintDoesNotMatchLocalToken",
+      "msg": "Mint does not match local token"
+    },
+    {
+      "code": 12601,
+      "name": "TokenAccountDoesNotMatchTo",
+      "msg": "Token account does not match to address"
+    },
+    {
+      "code": 12602,
+      "name": "IncorrectTokenVault",
+      "msg": "Incorrect token vault"
+    },
+    {
+      "code": 12603,
+      "name": "MintIsWrappedToken",
+      "msg": "Mint is a wrapped token"
+    },
+    {
+      "code": 12604,
+      "name": "IncorrectTo",
+      "msg": "Incorrect to"
+    },
+    {
+      "code": 12605,
+      "name": "IncorrectSolVault",
+      "msg": "Incorrect sol vault"
+    },
+    {
+      "code": 12606,
+      "name": "SolReleaseExceedsLocked",
+      "msg": "SOL release exceeds locked amount"
+    },
+    {
+      "code": 12607,
+      "name": "WouldDrainPayer",
+      "msg": "Bridge would leave the sender below the requested reserve"
+    },
+    {
+      "code": 12608,
+      "name": "WrappedTokenLimitReached",
+      "msg": "Maximum number of wrapped tokens reached"
+    },
+    {
+      "code": 12609,
+      "name": "RemoteTokenDenied",
+      "msg": "Remote token is denied"
+    },
+    {
+      "code": 12610,
+      "name": "IncorrectDeniedRemoteToken",
+      "msg": "Incorrect denied remote token account"
+    },
+    {
+      "code": 12611,
+      "name": "InvalidBridgePayload",
+      "msg": "Invalid bridge transfer payload"
+    },
+    {
+      "code": 12612,
+      "name": "DailyLimitExceeded",
+      "msg": "Daily bridged volume limit exceeded"
+    },
+    {
+      "code": 12613,
+      "name": "RateLimitExceeded",
+      "msg": "Remote token rate limit exceeded"
+    },
+    {
+      "code": 12614,
+      "name": "IncorrectRateLimit",
+      "msg": "Incorrect rate limit account"
+    },
+    {
+      "code": 12700,
+      "name": "RemoteTokenNotFound",
+      "msg": "Remote token not found"
+    },
+    {
+      "code": 12701,
+      "name": "ScalerExponentNotFound",
+      "msg": "Scaler exponent not found"
+    },
+    {
+      "code": 12702,
+      "name": "InvalidRemoteToken",
+      "msg": "Invalid remote token"
+    },
+    {
+      "code": 12703,
+      "name": "InvalidScalerExponent",
+      "msg": "Invalid scaler exponent"
+    },
+    {
+      "code": 12704,
+      "name": "MintIsNotFromToken2022",
+      "msg": "Mint is not a token 2022 mint"
+    },
+    {
+      "code": 12705,
+      "name": "MintIsNotWrappedTokenPda",
+      "msg": "Mint is not a valid wrapped token PDA"
+    },
+    {
+      "code": 12706,
+      "name": "MetadataTooLong",
+      "msg": "Token name or symbol exceeds the maximum length"
+    },
+    {
+      "code": 12800,
+      "name": "InvalidThreshold",
+      "msg": "Threshold must be <= number of signers"
+    },
+    {
+      "code": 12801,
+      "name": "TooManySigners",
+      "msg": "Too many signers (max 32)"
+    },
+    {
+      "code": 12802,
+      "name": "DuplicateSigner",
+      "msg": "Duplicate signer found"
+    },
+    {
+      "code": 12803,
+      "name": "InvalidPartnerThreshold",
+      "msg": "Invalid partner threshold"
+    },
+    {
+      "code": 12804,
+      "name": "InvalidDenominator",
+      "msg": "Invalid denominator"
+    },
+    {
+      "code": 12805,
+      "name": "InvalidWindowDurationSeconds",
+      "msg": "Invalid window duration seconds"
+    },
+    {
+      "code": 12806,
+      "name": "InvalidGasCostScalerDp",
+      "msg": "Invalid gas cost scaler dp"
+    },
+    {
+      "code": 12807,
+      "name": "InvalidBlockIntervalRequirement",
+      "msg": "Invalid block interval requirement"
+    },
+    {
+      "code": 12808,
+      "name": "IncompatibleBlockInterval",
+      "msg": "Block interval requirement is incompatible with the latest registered block"
+    },
+    {
+      "code": 12809,
+      "name": "InvalidRegistrationRateLimit",
+      "msg": "Registration rate limit requires a non-zero refill interval"
+    },
+    {
+      "code": 12810,
+      "name": "InvalidBaseFee",
+      "msg": "Base fee is outside [minimum_base_fee, MAX_BASE_FEE]"
+    },
+    {
+      "code": 12811,
+      "name": "SignerNotFound",
+      "msg": "Signer is not part of the oracle set"
+    },
+    {
+      "code": 12812,
+      "name": "InvalidRateLimitWindow",
+      "msg": "Rate limit window must be non-zero"
+    },
+    {
+      "code": 12813,
+      "name": "InvalidPausedOps",
+      "msg": "Paused operations mask contains unknown flags"
+    },
+    {
+      "code": 12814,
+      "name": "InvalidFeeBounds",
+      "msg": "Maximum fee must be zero or at least the minimum fee"
+    },
+    {
+      "code": 12815,
+      "name": "InvalidGasMultiplier",
+      "msg": "Gas multiplier must be non-zero and at most MAX_GAS_MULTIPLIER_BPS"
+    },
+    {
+      "code": 12900,
+      "name": "CreationWithNonZeroTarget",
+      "msg": "Creation with non-zero target"
+    },
+    {
+      "code": 12901,
+      "name": "ZeroAddress",
+      "msg": "Zero address"
+    }
+  ],
+  "types": [
+    {
+      "name": "BaseFee",
+      "docs": [
+        "EIP-1559 state as of the current timestamp, returned to the caller via return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "current_base_fee",
+            "docs": [
+              "Base fee that would be charged by a bridge operation now"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_window_gas_used",
+            "docs": [
+              "Gas used so far in the current window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start_time",
+            "docs": [
+              "Unix timestamp when the current window started"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BaseFeeReset",
+      "docs": [
+        "Emitted when the guardian overrides the EIP-1559 base fee in an emergency."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old_base_fee",
+            "docs": [
+              "The base fee before the reset"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "new_base_fee",
+            "docs": [
+              "The base fee after the reset"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BaseOracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "docs": [
+              "Required approval weight of valid unique signatures"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signer_count",
+            "docs": [
+              "Number of signers in `signers` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "Static list of authorized signer addresses"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "weights",
+            "docs": [
+              "Approval weight of each signer in `signers` (a weight of 0 counts as 1)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BlockIntervalRequirementUpdated",
+      "docs": [
+        "Emitted when the guardian changes the output root block interval requirement."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "old_interval",
+            "docs": [
+              "The previous block interval requirement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "new_interval",
+            "docs": [
+              "The new block interval requirement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The latest registered Base block number at the time of the change"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Bridge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number associated with the latest registered output root."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "last_registration_time",
+            "docs": [
+              "Unix timestamp of the latest output root registration."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "last_output_root",
+            "docs": [
+              "Output root of the latest registration."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "registration_bucket",
+            "docs": [
+              "Leaky bucket limiting the rate of output root registrations."
+            ],
+            "type": {
+              "defined": {
+                "name": "RegistrationBucket"
+              }
+            }
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Incremental nonce assigned to each outgoing message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "highest_relayed_nonce",
+            "docs": [
+              "Highest Base nonce among the incoming messages relayed so far (`None` before the first",
+              "relay)."
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "locked_sol",
+            "docs": [
+              "Lamports locked in the SOL vault by `bridge_sol` and not yet released by a relayed transfer."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "wrapped_token_count",
+            "docs": [
+              "Number of wrapped mints created by `wrap_token`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outbound_sol_volume",
+            "docs": [
+              "Volume of SOL bridged from Solana to Base in the current fixed daily window."
+            ],
+            "type": {
+              "defined": {
+                "name": "VolumeWindow"
+              }
+            }
+          },
+          {
+            "name": "inbound_sol_volume",
+            "docs": [
+              "Volume of SOL finalized from Base to Solana in the current fixed daily window."
+            ],
+            "type": {
+              "defined": {
+                "name": "VolumeWindow"
+              }
+            }
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Guardian pubkey authorized to update bridge configuration parameters"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "Guardian proposed by the current guardian, pending acceptance"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "co_guardian",
+            "docs": [
+              "Second guardian that must co-sign critical configuration changes (the default pubkey",
+              "leaves those changes to the guardian alone)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Whether the bridge is paused (emergency stop mechanism). Acts as a master switch over",
+              "`paused_ops`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "paused_ops",
+            "docs": [
+              "Bitmask of individually paused operations (`PAUSE_*` flags)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "eip1559",
+            "docs": [
+              "EIP-1559 state and configuration for dynamic pricing."
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559"
+              }
+            }
+          },
+          {
+            "name": "gas_config",
+            "docs": [
+              "Configuration parameters for outgoing message pricing"
+            ],
+            "type": {
+              "defined": {
+                "name": "GasConfig"
+              }
+            }
+          },
+          {
+            "name": "protocol_config",
+            "docs": [
+              "Configuration parameters for bridge protocol"
+            ],
+            "type": {
+              "defined": {
+                "name": "ProtocolConfig"
+              }
+            }
+          },
+          {
+            "name": "buffer_config",
+            "docs": [
+              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+            ],
+            "type": {
+              "defined": {
+                "name": "BufferConfig"
+              }
+            }
+          },
+          {
+            "name": "partner_oracle_config",
+            "docs": [
+              "Partner oracle configuration containing the required signature threshold"
+            ],
+            "type": {
+              "defined": {
+                "name": "PartnerOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "base_oracle_config",
+            "docs": [
+              "Configuration parameters for Base oracle signers"
+            ],
+            "type": {
+              "defined": {
+                "name": "BaseOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "base_oracle_epoch",
+            "docs": [
+              "Incremented on every change to `base_oracle_config`. Output root re-validations sign it,",
+              "so approvals given to one signer set cannot be replayed under another."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BufferConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "max_call_buffer_size",
+            "docs": [
+              "Maximum call buffer size. This caps the max size of a Solana → Base message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_prove_buffer_proof_len",
+            "docs": [
+              "Maximum number of MMR proof nodes a prove buffer can be allocated for."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_call_buffer_appends",
+            "docs": [
+              "Maximum number of appends to a single call buffer (0 disables the limit). Captured by",
+              "each call buffer at initialization."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Call",
+      "docs": [
+        "Represents a contract call to be executed on Base.",
+        "Contains all the necessary information to perform various types of contract interactions,",
+        "including regular calls, delegate calls, and contract creation operations."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ty",
+            "docs": [
+              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
+              "Determines how the call will be executed on the Base side."
+            ],
+            "type": {
+              "defined": {
+                "name": "CallType"
+              }
+            }
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The target address on Base (20 bytes for Ethereum-compatible address).",
+              "Must be set to zero for Create and Create2 operations."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "value",
+            "docs": [
+              "Amount of ETH to send with this call on Base, in wei."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The encoded function call data or contract bytecode.",
+              "For regular calls: ABI-encoded function signature and parameters.",
+              "For contract creation: the contract's initialization bytecode."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBuffer",
+      "docs": [
+        "A buffer account that stores call parameters which can be built up over multiple transactions",
+        "to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and",
+        "the account is typically consumed (closed via `close = owner`) by the buffered bridge",
+        "instructions when the call is bridged to Base."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner who can modify this call buffer"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "ty",
+            "docs": [
+              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
+              "Determines how the call will be executed on the Base side."
+            ],
+            "type": {
+              "defined": {
+                "name": "CallType"
+              }
+            }
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The target address on Base (20 bytes for Ethereum-compatible address).",
+              "Must be set to zero for Create and Create2 operations."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "value",
+            "docs": [
+              "The amount of Base native currency (ETH) to send with this call, in wei."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "max_appends",
+            "docs": [
+              "Maximum number of appends allowed on this buffer (0 means unlimited), copied from",
+              "`bridge.buffer_config.max_call_buffer_appends` at initialization."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "append_count",
+            "docs": [
+              "Number of appends made to this buffer so far."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expected_hash",
+            "docs": [
+              "Keccak-256 hash `data` must have when the buffer is bridged, committed at initialization",
+              "so a dropped or reordered append fails on Solana instead of on Base."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "running_hash",
+            "docs": [
+              "Keccak-256 hash chain over the segments written to `data`, folded as",
+              "`keccak(running_hash || segment)` starting from zero with `initial_data`. Clients recompute",
+              "it after each append to catch a dropped or reordered append before bridging."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The encoded function call data or contract bytecode.",
+              "For regular calls: ABI-encoded function signature and parameters.",
+              "For contract creation: the contract's initialization bytecode."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Call"
+          },
+          {
+            "name": "DelegateCall"
+          },
+          {
+            "name": "Create"
+          },
+          {
+            "name": "Create2"
+          }
+        ]
+      }
     },
     {
-      "code": 12502,
-      "name": "IncorrectBlockNumber",
-      "msg": "Incorrect block number"
+      "name": "CoGuardianUpdated",
+      "docs": [
+        "Emitted when the guardians change the co-guardian."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previous_co_guardian",
+            "docs": [
+              "The co-guardian being replaced (the default pubkey if none was set)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "new_co_guardian",
+            "docs": [
+              "The new co-guardian (the default pubkey if it was removed)"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12600,
-      "name": "MintDoesNotMatchLocalToken",
-      "msg": "Mint does not match local token"
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "eip1559_config",
+            "docs": [
+              "Configuration parameters for EIP-1559-inspired fee calculations"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559Config"
+              }
+            }
+          },
+          {
+            "name": "gas_config",
+            "docs": [
+              "Configuration parameters for outgoing message pricing"
+            ],
+            "type": {
+              "defined": {
+                "name": "GasConfig"
+              }
+            }
+          },
+          {
+            "name": "protocol_config",
+            "docs": [
+              "Configuration parameters for bridge protocol"
+            ],
+            "type": {
+              "defined": {
+                "name": "ProtocolConfig"
+              }
+            }
+          },
+          {
+            "name": "buffer_config",
+            "docs": [
+              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+            ],
+            "type": {
+              "defined": {
+                "name": "BufferConfig"
+              }
+            }
+          },
+          {
+            "name": "partner_oracle_config",
+            "docs": [
+              "Partner oracle configuration containing the required signature threshold"
+            ],
+            "type": {
+              "defined": {
+                "name": "PartnerOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "base_oracle_config",
+            "docs": [
+              "Configuration parameters for Base oracle signers"
+            ],
+            "type": {
+              "defined": {
+                "name": "BaseOracleConfig"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12601,
-      "name": "TokenAccountDoesNotMatchTo",
-      "msg": "Token account does not match to address"
+      "name": "DeniedRemoteToken",
+      "docs": [
+        "Guardian-managed denylist entry for a Base token address, at",
+        "`[DENIED_REMOTE_TOKEN_SEED, remote_token]`. Tokens with an entry can no longer be bridged or",
+        "wrapped."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "remote_token",
+            "docs": [
+              "The denied Base token address"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12602,
-      "name": "IncorrectTokenVault",
-      "msg": "Incorrect token vault"
+      "name": "DuplicateRootRegistered",
+      "docs": [
+        "Emitted when an output root is registered with the same root as the previous registration,",
+        "which may indicate a stalled oracle feed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "output_root",
+            "docs": [
+              "The repeated output root"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "previous_base_block_number",
+            "docs": [
+              "The Base block number of the previous registration"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number of the new registration"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12603,
-      "name": "MintIsWrappedToken",
-      "msg": "Mint is a wrapped token"
+      "name": "Eip1559",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Configuration parameters for EIP-1559-inspired fee calculations"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559Config"
+              }
+            }
+          },
+          {
+            "name": "current_base_fee",
+            "docs": [
+              "Current base fee used in fee computation (runtime state).",
+              "Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_window_gas_used",
+            "docs": [
+              "Gas used in the current time window (runtime state)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start_time",
+            "docs": [
+              "Unix timestamp when the current window started (runtime state)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "empty_window_streak",
+            "docs": [
+              "Number of consecutive expired windows without gas usage (runtime state)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12604,
-      "name": "IncorrectTo",
-      "msg": "Incorrect to"
+      "name": "Eip1559Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Gas target per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "Adjustment denominator (controls rate of change)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_duration_seconds",
+            "docs": [
+              "Window duration in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_base_fee",
+            "docs": [
+              "Minimum base fee. Used to seed `current_base_fee` at initialization",
+              "and as an underflow clamp during decreases; not enforced as a strict lower bound",
+              "on every step."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "decay_grace_windows",
+            "docs": [
+              "Number of consecutive empty windows during which the base fee holds before it starts",
+              "to decay (0 decays from the first empty window)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12605,
-      "name": "IncorrectSolVault",
-      "msg": "Incorrect sol vault"
+      "name": "Eip1559ConfigChanged",
+      "docs": [
+        "Emitted when the guardian changes an EIP-1559 configuration parameter, so that past fees can",
+        "be traced back to the configuration they were charged under."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "field",
+            "docs": [
+              "The parameter that changed"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559ConfigField"
+              }
+            }
+          },
+          {
+            "name": "old_value",
+            "docs": [
+              "The value before the change"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "new_value",
+            "docs": [
+              "The value after the change"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix timestamp of the change"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12700,
-      "name": "RemoteTokenNotFound",
-      "msg": "Remote token not found"
+      "name": "Eip1559ConfigField",
+      "docs": [
+        "EIP-1559 configuration parameter changed by the guardian."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Target"
+          },
+          {
+            "name": "Denominator"
+          },
+          {
+            "name": "WindowDurationSeconds"
+          },
+          {
+            "name": "MinimumBaseFee"
+          },
+          {
+            "name": "DecayGraceWindows"
+          }
+        ]
+      }
     },
     {
-      "code": 12701,
-      "name": "ScalerExponentNotFound",
-      "msg": "Scaler exponent not found"
+      "name": "FeeBounds",
+      "docs": [
+        "Lamport fees for a `CallType::Call` message, returned to the caller via return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minimum_fee",
+            "docs": [
+              "Fee charged at the minimum base fee, never below `minimum_fee_lamports`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_fee",
+            "docs": [
+              "Fee that would be charged by a `bridge_call` now"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maximum_fee",
+            "docs": [
+              "`maximum_fee_lamports`, or `None` when the gas config sets no ceiling"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12702,
-      "name": "InvalidRemoteToken",
-      "msg": "Invalid remote token"
+      "name": "FinalizeBridgeSol",
+      "docs": [
+        "Instruction data for finalizing a native SOL transfer from Base to Solana.",
+        "",
+        "Contains the data needed to release escrowed SOL on Solana that corresponds",
+        "to a transfer initiated on Base. SOL is held in a PDA vault and released to",
+        "the recipient when finalized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "to",
+            "docs": [
+              "The Solana public key of the recipient who will receive the SOL.",
+              "This must match the intended recipient specified in the original bridge message."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of SOL to transfer, denominated in lamports (1 SOL = 1_000_000_000 lamports).",
+              "This amount will be transferred from the SOL vault to the recipient."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12703,
-      "name": "InvalidScalerExponent",
-      "msg": "Invalid scaler exponent"
+      "name": "FinalizeBridgeSpl",
+      "docs": [
+        "Instruction data for finalizing a bridged SPL token transfer from Base to Solana.",
+        "",
+        "Releases tokens from a program-controlled vault PDA to the specified recipient",
+        "token account on Solana."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "remote_token",
+            "docs": [
+              "The 20-byte ERC-20 contract address on Base that corresponds to the SPL mint.",
+              "Used, together with the SPL mint, to derive the token-vault PDA for this mapping."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "local_token",
+            "docs": [
+              "The SPL token mint on Solana that mirrors the `remote_token`."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The recipient SPL token account address on Solana that will receive tokens.",
+              "This must be a valid token account for `local_token`.",
+              "Note: this program does not enforce ownership or ATA semantics; the account",
+              "is authenticated by address equality (`self.to`) and `transfer_checked`",
+              "enforces the mint match."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount to transfer, in base units of the mint (respecting mint decimals).",
+              "`transfer_checked` enforces that the destination account's mint matches and",
+              "the decimals are correct."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12704,
-      "name": "MintIsNotFromToken2022",
-      "msg": "Mint is not a token 2022 mint"
+      "name": "FinalizeBridgeWrappedToken",
+      "docs": [
+        "Instruction data for finalizing a wrapped token transfer from Base to Solana.",
+        "",
+        "This struct represents the final step in a cross-chain bridge operation where tokens",
+        "that were originally on Base are being bridged to Solana as wrapped tokens. The",
+        "finalization process mints the appropriate amount of wrapped tokens to the recipient's",
+        "token account on Solana.",
+        "",
+        "The wrapped token mint is derived deterministically from the original token's metadata",
+        "and decimals, ensuring consistency across bridge operations."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The mint address of the wrapped token on Solana.",
+              "This is a PDA that represents the Solana version",
+              "of a token that originally exists on Base. The mint address is derived",
+              "deterministically from the original token's metadata and decimals."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The destination token account that will receive the wrapped tokens.",
+              "This must be a valid token account that is associated with the wrapped",
+              "token mint. It is expected to be controlled by the intended recipient of",
+              "the bridged tokens; recipient ownership is not enforced by this",
+              "instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of wrapped tokens to mint to the recipient.",
+              "The amount is specified in the token's smallest unit."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12705,
-      "name": "MintIsNotWrappedTokenPda",
-      "msg": "Mint is not a valid wrapped token PDA"
+      "name": "GasConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gas_cost_scaler",
+            "docs": [
+              "Scaling factor applied when converting (gas_per_call * base_fee) into lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler_dp",
+            "docs": [
+              "Decimal precision for the gas cost scaler (denominator)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee_receiver",
+            "docs": [
+              "Account that receives gas fees collected on Solana"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "gas_per_call",
+            "docs": [
+              "Amount of gas per Solana --> Base message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_fee_lamports",
+            "docs": [
+              "Minimum fee in lamports charged per message, applied after scaling"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maximum_fee_lamports",
+            "docs": [
+              "Maximum fee in lamports charged per message, applied after scaling (0 = no ceiling)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "call_type_gas_multipliers_bps",
+            "docs": [
+              "Fee multiplier in basis points for each `CallType` (indexed by variant), applied to messages",
+              "carrying a call. 10_000 charges the base fee unchanged. Each multiplier must be in",
+              "`1..=MAX_GAS_MULTIPLIER_BPS`."
+            ],
+            "type": {
+              "array": [
+                "u32",
+                4
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12800,
-      "name": "InvalidThreshold",
-      "msg": "Threshold must be <= number of signers"
+      "name": "GuardianTransferAccepted",
+      "docs": [
+        "Emitted when the pending guardian accepts the guardian role."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previous_guardian",
+            "docs": [
+              "The guardian being replaced"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "new_guardian",
+            "docs": [
+              "The new guardian"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12801,
-      "name": "TooManySigners",
-      "msg": "Too many signers (max 32)"
+      "name": "GuardianTransferCancelled",
+      "docs": [
+        "Emitted when the guardian cancels a pending guardian proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "docs": [
+              "The current guardian"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "The proposal that was cancelled, if any"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12802,
-      "name": "DuplicateSigner",
-      "msg": "Duplicate signer found"
+      "name": "GuardianTransferProposed",
+      "docs": [
+        "Emitted when the guardian proposes a new guardian."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "guardian",
+            "docs": [
+              "The current guardian"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pending_guardian",
+            "docs": [
+              "The proposed guardian, pending acceptance"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12803,
-      "name": "InvalidPartnerThreshold",
-      "msg": "Invalid partner threshold"
+      "name": "IncomingMessage",
+      "docs": [
+        "Represents a cross-chain message sent from Base to Solana",
+        "that is waiting to be processed or has already been executed.",
+        "",
+        "This struct stores the essential information needed to validate and execute",
+        "bridge operations from Base to Solana, including both simple calls and token transfers.",
+        "",
+        "Messages are created by the `prove_message` instruction (after MMR verification)",
+        "and executed by the `relay_message` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version of this account. Checked on relay so that accounts written with a",
+              "different layout are rejected instead of being silently mis-parsed."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "The nonce assigned to this message on Base."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The 20-byte EVM address of the sender on Base who initiated this bridge operation.",
+              "Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.",
+              "This field does not restrict who can call the relay instruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "message",
+            "docs": [
+              "The actual message payload containing either instruction calls or token transfer data.",
+              "This enum determines what type of operation will be executed on Solana."
+            ],
+            "type": {
+              "defined": {
+                "name": "bridge::base_to_solana::state::incoming_message::Message"
+              }
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Flag indicating whether this message has been successfully executed on Solana.",
+              "Once set to true, the message cannot be executed again, preventing replay attacks."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "proven_at",
+            "docs": [
+              "Unix timestamp at which the message was proven. Relays are rejected until the configured",
+              "`min_prove_to_relay_delay_seconds` has elapsed since then."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12804,
-      "name": "InvalidDenominator",
-      "msg": "Invalid denominator"
+      "name": "Ix",
+      "docs": [
+        "Instruction to be executed by the bridge program via signed CPI during message relay.",
+        "Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program_id",
+            "docs": [
+              "Program that will process this instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "accounts",
+            "docs": [
+              "Accounts required for this instruction."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "IxAccount"
+                }
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Instruction data."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 12805,
-      "name": "InvalidWindowDurationSeconds",
-      "msg": "Invalid window duration seconds"
+      "name": "IxAccount",
+      "docs": [
+        "Account used in an instruction.",
+        "Similar to Solana's `AccountMeta`, but serializable with Anchor and supports PDAs via `PubkeyOrPda`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "docs": [
+              "Public key of the account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "is_writable",
+            "docs": [
+              "Whether the account is writable."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "is_signer",
+            "docs": [
+              "Whether the account is a signer."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 12806,
-      "name": "InvalidGasCostScalerDp",
-      "msg": "Invalid gas cost scaler dp"
+      "name": "OracleSigners",
+      "docs": [
+        "Base oracle committee, returned to the caller via return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "docs": [
+              "Required approval weight of valid unique signatures."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "The 20-byte EVM addresses of the active signers."
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  20
+                ]
+              }
+            }
+          },
+          {
+            "name": "weights",
+            "docs": [
+              "Approval weight of each signer in `signers` (a weight of 0 counts as 1)."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
     },
     {
-      "code": 12807,
-      "name": "InvalidBlockIntervalRequirement",
-      "msg": "Invalid block interval requirement"
+      "name": "OracleSignersUpdated",
+      "docs": [
+        "Emitted whenever the Base oracle signer set or its threshold changes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signer_count",
+            "docs": [
+              "Number of signers after the update"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Required approval weight after the update"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "Oracle signer epoch started by the update"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12900,
-      "name": "CreationWithNonZeroTarget",
-      "msg": "Creation with non-zero target"
+      "name": "OutgoingMessage",
+      "docs": [
+        "Represents a message being sent from Solana to Base through the bridge.",
+        "This struct contains all the necessary information to execute a cross-chain operation",
+        "on the Base side, including the message content and execution parameters."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Monotonic message nonce used for ordering and replay protection on Base.",
+              "Starts at 0 and is incremented by the `Bridge` for each new message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The Solana public key of the signer that initiated this cross-chain message.",
+              "Carried to Base for use by destination logic; Solana-side authentication is enforced",
+              "via signer constraints."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "message",
+            "docs": [
+              "The actual message payload that will be executed on Base.",
+              "Can be either a direct contract call or a token transfer (with optional call)."
+            ],
+            "type": {
+              "defined": {
+                "name": "bridge::solana_to_base::state::outgoing_message::Message"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12901,
-      "name": "ZeroAddress",
-      "msg": "Zero address"
-    }
-  ],
-  "types": [
-    {
-      "name": "BaseOracleConfig",
+      "name": "OutgoingMessageReceipt",
+      "docs": [
+        "Identifies an outgoing message created by a `bridge_*` instruction, returned to the caller via",
+        "return data so it does not have to predict the nonce or account address."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "threshold",
+            "name": "nonce",
             "docs": [
-              "Number of required valid unique signatures"
+              "Nonce assigned to the message"
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "signer_count",
+            "name": "outgoing_message",
             "docs": [
-              "Number of signers in `signers` array"
+              "Address of the `OutgoingMessage` account"
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "signers",
+            "name": "message_hash",
             "docs": [
-              "Static list of authorized signer addresses"
+              "Hash Base validators register the message under (see `OutgoingMessage::message_hash`)"
             ],
             "type": {
               "array": [
-                {
-                  "array": [
-                    "u8",
-                    20
-                  ]
-                },
-                16
+                "u8",
+                32
               ]
             }
           }
@@ -2942,211 +7900,366 @@ export const IDL = {
       }
     },
     {
-      "name": "Bridge",
+      "name": "OutputRoot",
+      "docs": [
+        "Represents a cryptographic commitment to the set of Base L2 bridge messages",
+        "at a specific Base block number.",
+        "",
+        "Output roots are registered on Solana by a trusted oracle and serve as",
+        "checkpoints that allow messages from Base to be proven and relayed to",
+        "Solana. Each output root contains an MMR root that commits to all bridge",
+        "messages as of a particular Base block number.",
+        "",
+        "This account is used in the Base → Solana message passing flow, where:",
+        "1. A trusted oracle registers output roots for specific Base blocks",
+        "2. Users prove their messages were included on Base using these roots and an MMR proof",
+        "3. Proven messages are then relayed and executed on Solana"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "base_block_number",
+            "name": "root",
             "docs": [
-              "The Base block number associated with the latest registered output root."
+              "The 32-byte MMR root that commits to all outgoing bridge messages on Base",
+              "as of the specified Base block number."
             ],
-            "type": "u64"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "nonce",
+            "name": "total_leaf_count",
             "docs": [
-              "Incremental nonce assigned to each outgoing message."
+              "The total number of leaves that were present in the MMR when this root",
+              "was generated. This is crucial for determining the MMR structure and",
+              "mountain configuration at the time of proof validation."
             ],
             "type": "u64"
           },
           {
-            "name": "guardian",
+            "name": "registered_at",
             "docs": [
-              "Guardian pubkey authorized to update bridge configuration parameters"
+              "Solana unix timestamp at which this root was registered."
             ],
-            "type": "pubkey"
+            "type": "i64"
           },
           {
-            "name": "paused",
+            "name": "base_block_number",
             "docs": [
-              "Whether the bridge is paused (emergency stop mechanism)"
+              "The Base block number this root was registered for."
             ],
-            "type": "bool"
+            "type": "u64"
           },
           {
-            "name": "eip1559",
+            "name": "revalidated_epoch",
             "docs": [
-              "EIP-1559 state and configuration for dynamic pricing."
+              "The `base_oracle_epoch` in which the oracle set co-signed this root again through",
+              "`revalidate_output_root`, or `NOT_REVALIDATED`. Rotating the oracle set starts a new epoch,",
+              "so the root must then be re-validated by the new set."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutputRootEntry",
+      "docs": [
+        "A single output root within a `register_output_roots_batch` call."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "output_root",
+            "docs": [
+              "The 32-byte MMR root of Base messages for `base_block_number`"
             ],
             "type": {
-              "defined": {
-                "name": "Eip1559"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "gas_config",
+            "name": "base_block_number",
             "docs": [
-              "Configuration parameters for outgoing message pricing"
+              "The Base block number this output root corresponds to"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_leaf_count",
+            "docs": [
+              "The total number of leaves in the MMR with this root"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OutputRootRegistered",
+      "docs": [
+        "Emitted when a new output root is registered, after all approval and rate limit checks pass."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "output_root",
+            "docs": [
+              "The registered Base MMR root"
             ],
             "type": {
-              "defined": {
-                "name": "GasConfig"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "protocol_config",
+            "name": "base_block_number",
             "docs": [
-              "Configuration parameters for bridge protocol"
+              "The Base block number the root was registered for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_leaf_count",
+            "docs": [
+              "The MMR's total leaf count at this checkpoint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "base_signature_count",
+            "docs": [
+              "Number of Base oracle signers that approved the root"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "partner_signature_count",
+            "docs": [
+              "Number of partner oracle signers that approved the root (0 when partner approvals are",
+              "not required)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "The account that paid for the registration"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OverlappingSignersApproved",
+      "docs": [
+        "Emitted when signers approving an output root are part of both the Base and partner oracle",
+        "sets, so each of their signatures counted toward both thresholds."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number of the registration"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "EVM addresses present in both signer sets"
             ],
             "type": {
-              "defined": {
-                "name": "ProtocolConfig"
+              "vec": {
+                "array": [
+                  "u8",
+                  20
+                ]
               }
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "PartialTokenMetadata",
+      "docs": [
+        "Represents token metadata for tokens that are bridged between Base and Solana.",
+        "",
+        "This struct contains metadata needed to represent a token that exists on both",
+        "chains, including information about its remote counterpart and any scaling factors needed",
+        "to handle differences between the chains (such as decimal precision).",
+        "",
+        "The metadata is stored using the SPL Token-2022 metadata interface's",
+        "`additional_metadata` key/value field and can be used to reconstruct the relationship",
+        "between tokens on both sides of the bridge."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "The human-readable name of the token (e.g., \"Wrapped Bitcoin\")"
+            ],
+            "type": "string"
           },
           {
-            "name": "buffer_config",
+            "name": "symbol",
             "docs": [
-              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+              "The symbol/ticker of the token (e.g., \"WBTC\")"
             ],
-            "type": {
-              "defined": {
-                "name": "BufferConfig"
-              }
-            }
+            "type": "string"
           },
           {
-            "name": "partner_oracle_config",
+            "name": "remote_token",
             "docs": [
-              "Partner oracle configuration containing the required signature threshold"
+              "The 20-byte address of the corresponding token contract on Base (EVM address bytes).",
+              "This allows the bridge to identify which Base token this Solana token represents."
             ],
             "type": {
-              "defined": {
-                "name": "PartnerOracleConfig"
-              }
+              "array": [
+                "u8",
+                20
+              ]
             }
           },
           {
-            "name": "base_oracle_config",
+            "name": "scaler_exponent",
             "docs": [
-              "Configuration parameters for Base oracle signers"
+              "The scaling exponent used to convert between token amounts on different chains.",
+              "This handles cases where tokens have differing decimal precision on Base vs Solana.",
+              "For example, when Base token has 18 decimals and the Solana wrapped mint has 9,",
+              "this value conveys the decimal relationship so bridging logic can scale amounts.",
+              "The exact conversion is performed by the EVM-side contract; Solana propagates this",
+              "value but does not apply arithmetic with it."
             ],
-            "type": {
-              "defined": {
-                "name": "BaseOracleConfig"
-              }
-            }
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "BufferConfig",
+      "name": "PartnerOracleConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "max_call_buffer_size",
+            "name": "required_threshold",
             "docs": [
-              "Maximum call buffer size. This caps the max size of a Solana → Base message."
+              "Partner signatures required by our bridge to accept an output root"
             ],
-            "type": "u64"
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "Call",
+      "name": "ProgramAuthorities",
       "docs": [
-        "Represents a contract call to be executed on Base.",
-        "Contains all the necessary information to perform various types of contract interactions,",
-        "including regular calls, delegate calls, and contract creation operations."
+        "PDAs the bridge signs with or checks against, returned to the caller via return data."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "ty",
+            "name": "bridge",
             "docs": [
-              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
-              "Determines how the call will be executed on the Base side."
+              "Main bridge state account (`[BRIDGE_SEED]`)"
             ],
             "type": {
               "defined": {
-                "name": "CallType"
+                "name": "ProgramAuthority"
               }
             }
           },
           {
-            "name": "to",
+            "name": "sol_vault",
             "docs": [
-              "The target address on Base (20 bytes for Ethereum-compatible address).",
-              "Must be set to zero for Create and Create2 operations."
+              "Vault holding bridged SOL, which signs SOL finalizations (`[SOL_VAULT_SEED]`)"
             ],
             "type": {
-              "array": [
-                "u8",
-                20
-              ]
+              "defined": {
+                "name": "ProgramAuthority"
+              }
             }
           },
           {
-            "name": "value",
+            "name": "bridge_cpi_authority",
             "docs": [
-              "Amount of ETH to send with this call on Base, in wei."
+              "Signer of relayed instruction CPIs for `sender` (`[BRIDGE_CPI_AUTHORITY_SEED, sender]`)"
             ],
-            "type": "u128"
+            "type": {
+              "defined": {
+                "name": "ProgramAuthority"
+              }
+            }
           },
           {
-            "name": "data",
+            "name": "partner_signers",
             "docs": [
-              "The encoded function call data or contract bytecode.",
-              "For regular calls: ABI-encoded function signature and parameters.",
-              "For contract creation: the contract's initialization bytecode."
+              "Partner signer set checked during output root registration",
+              "(`[PARTNER_SIGNERS_ACCOUNT_SEED]` under `PARTNER_PROGRAM_ID`)"
             ],
-            "type": "bytes"
+            "type": {
+              "defined": {
+                "name": "ProgramAuthority"
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "CallBuffer",
+      "name": "ProgramAuthority",
       "docs": [
-        "A buffer account that stores call parameters which can be built up over multiple transactions",
-        "to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and",
-        "the account is typically consumed (closed via `close = owner`) by the buffered bridge",
-        "instructions when the call is bridged to Base."
+        "A program-derived address and the bump it was found with."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
-            "docs": [
-              "The owner who can modify this call buffer"
-            ],
+            "name": "address",
             "type": "pubkey"
           },
           {
-            "name": "ty",
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProtocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "block_interval_requirement",
             "docs": [
-              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
-              "Determines how the call will be executed on the Base side."
+              "Block interval requirement for output root registration. Every Base block associated with a",
+              "submitted output root must be a multiple of this number."
             ],
-            "type": {
-              "defined": {
-                "name": "CallType"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "to",
+            "name": "remote_sol_address",
             "docs": [
-              "The target address on Base (20 bytes for Ethereum-compatible address).",
-              "Must be set to zero for Create and Create2 operations."
+              "The Base evm address of SOL"
             ],
             "type": {
               "array": [
@@ -3156,244 +8269,205 @@ export const IDL = {
             }
           },
           {
-            "name": "value",
+            "name": "min_registration_interval_seconds",
             "docs": [
-              "The amount of Base native currency (ETH) to send with this call, in wei."
+              "Minimum number of seconds between two output root registrations (0 disables the check)"
             ],
-            "type": "u128"
+            "type": "u64"
           },
           {
-            "name": "data",
+            "name": "max_root_age_seconds",
             "docs": [
-              "The encoded function call data or contract bytecode.",
-              "For regular calls: ABI-encoded function signature and parameters.",
-              "For contract creation: the contract's initialization bytecode."
+              "Maximum age in seconds of an output root that messages can be proven against (0 disables the check)"
             ],
-            "type": "bytes"
-          }
-        ]
-      }
-    },
-    {
-      "name": "CallType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Call"
-          },
-          {
-            "name": "DelegateCall"
-          },
-          {
-            "name": "Create"
+            "type": "u64"
           },
           {
-            "name": "Create2"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "eip1559_config",
+            "name": "registration_bucket_capacity",
             "docs": [
-              "Configuration parameters for EIP-1559-inspired fee calculations"
+              "Maximum number of output root registrations that can be made in a burst (0 disables the limit)"
             ],
-            "type": {
-              "defined": {
-                "name": "Eip1559Config"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "gas_config",
+            "name": "registration_refill_interval_seconds",
             "docs": [
-              "Configuration parameters for outgoing message pricing"
+              "Number of seconds after which one registration is returned to the bucket"
             ],
-            "type": {
-              "defined": {
-                "name": "GasConfig"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "protocol_config",
+            "name": "reject_duplicate_roots",
             "docs": [
-              "Configuration parameters for bridge protocol"
+              "Whether registering the same output root as the previous registration is rejected",
+              "(otherwise it is only flagged with a `DuplicateRootRegistered` event)"
             ],
-            "type": {
-              "defined": {
-                "name": "ProtocolConfig"
-              }
-            }
+            "type": "bool"
           },
           {
-            "name": "buffer_config",
+            "name": "disjoint_signer_sets",
             "docs": [
-              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+              "Whether a registration is rejected when a signer counts toward both the Base and partner",
+              "thresholds (otherwise the overlap is only flagged with an `OverlappingSignersApproved`",
+              "event)"
             ],
-            "type": {
-              "defined": {
-                "name": "BufferConfig"
-              }
-            }
+            "type": "bool"
           },
           {
-            "name": "partner_oracle_config",
+            "name": "reject_duplicate_ixs",
             "docs": [
-              "Partner oracle configuration containing the required signature threshold"
+              "Whether relaying a message that contains two identical instructions is rejected"
             ],
-            "type": {
-              "defined": {
-                "name": "PartnerOracleConfig"
-              }
-            }
+            "type": "bool"
           },
           {
-            "name": "base_oracle_config",
+            "name": "enforce_relay_nonce_order",
             "docs": [
-              "Configuration parameters for Base oracle signers"
+              "Whether relaying a message with a Base nonce at or below `highest_relayed_nonce` is",
+              "rejected"
             ],
-            "type": {
-              "defined": {
-                "name": "BaseOracleConfig"
-              }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "Eip1559",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "bool"
+          },
           {
-            "name": "config",
+            "name": "require_self_call",
             "docs": [
-              "Configuration parameters for EIP-1559-inspired fee calculations"
+              "Whether `bridge_call` and `bridge_call_buffered` require `from` to also be the payer,",
+              "disallowing third-party sponsors"
             ],
-            "type": {
-              "defined": {
-                "name": "Eip1559Config"
-              }
-            }
+            "type": "bool"
           },
           {
-            "name": "current_base_fee",
+            "name": "max_wrapped_tokens",
             "docs": [
-              "Current base fee used in fee computation (runtime state).",
-              "Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports."
+              "Maximum number of wrapped mints that `wrap_token` can create (0 disables the limit)"
             ],
             "type": "u64"
           },
           {
-            "name": "current_window_gas_used",
+            "name": "large_finalize_threshold",
             "docs": [
-              "Gas used in the current time window (runtime state)"
+              "SOL finalizations above this many lamports must be co-signed by the guardian (0 disables",
+              "the check). Token transfers are not covered since their amounts have no common unit."
             ],
             "type": "u64"
           },
           {
-            "name": "window_start_time",
+            "name": "daily_outbound_sol_limit",
             "docs": [
-              "Unix timestamp when the current window started (runtime state)"
+              "Maximum lamports bridged from Solana to Base per fixed daily window (0 disables the limit).",
+              "Windows do not overlap, so up to twice the limit can pass around a window boundary."
             ],
-            "type": "i64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Eip1559Config",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "u64"
+          },
           {
-            "name": "target",
+            "name": "daily_inbound_sol_limit",
             "docs": [
-              "Gas target per window"
+              "Maximum lamports finalized from Base to Solana per fixed daily window (0 disables the",
+              "limit). Windows do not overlap, so up to twice the limit can pass around a window boundary."
             ],
             "type": "u64"
           },
           {
-            "name": "denominator",
+            "name": "revalidate_old_roots_after_blocks",
             "docs": [
-              "Adjustment denominator (controls rate of change)"
+              "Output roots more than this many Base blocks behind the latest registered block must be",
+              "re-validated by the current oracle set before messages can be proven against them",
+              "(0 disables the check)"
             ],
             "type": "u64"
           },
           {
-            "name": "window_duration_seconds",
+            "name": "max_block_advance",
             "docs": [
-              "Window duration in seconds"
+              "Maximum number of Base blocks a registered output root may advance past the latest",
+              "registered one (0 disables the check)"
             ],
             "type": "u64"
           },
           {
-            "name": "minimum_base_fee",
+            "name": "min_prove_to_relay_delay_seconds",
             "docs": [
-              "Minimum base fee. Used to seed `current_base_fee` at initialization",
-              "and as an underflow clamp during decreases; not enforced as a strict lower bound",
-              "on every step."
+              "Minimum number of seconds between a message being proven and it being relayed (0 disables",
+              "the check)"
             ],
             "type": "u64"
           }
         ]
       }
     },
-    {
-      "name": "FinalizeBridgeSol",
-      "docs": [
-        "Instruction data for finalizing a native SOL transfer from Base to Solana.",
-        "",
-        "Contains the data needed to release escrowed SOL on Solana that corresponds",
-        "to a transfer initiated on Base. SOL is held in a PDA vault and released to",
-        "the recipient when finalized."
+    {
+      "name": "ProveBuffer",
+      "docs": [
+        "A buffer account to stage large Base → Solana prove inputs over multiple transactions.",
+        "Stores the serialized `Message` bytes (`data`) and the MMR `proof` nodes."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "to",
+            "name": "owner",
             "docs": [
-              "The Solana public key of the recipient who will receive the SOL.",
-              "This must match the intended recipient specified in the original bridge message."
+              "The owner who can modify and eventually consume this buffer"
             ],
             "type": "pubkey"
           },
           {
-            "name": "amount",
+            "name": "max_data_len",
             "docs": [
-              "The amount of SOL to transfer, denominated in lamports (1 SOL = 1_000_000_000 lamports).",
-              "This amount will be transferred from the SOL vault to the recipient."
+              "Maximum number of `data` bytes this buffer was allocated for"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_proof_len",
+            "docs": [
+              "Maximum number of `proof` nodes this buffer was allocated for"
             ],
             "type": "u64"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Serialized `Message` data (Anchor-serialized)"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "proof",
+            "docs": [
+              "MMR proof nodes used to validate inclusion against an OutputRoot"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "FinalizeBridgeSpl",
+      "name": "ProvenMessage",
       "docs": [
-        "Instruction data for finalizing a bridged SPL token transfer from Base to Solana.",
-        "",
-        "Releases tokens from a program-controlled vault PDA to the specified recipient",
-        "token account on Solana."
+        "Status of a proven message, returned to the caller via return data."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "remote_token",
+            "name": "nonce",
             "docs": [
-              "The 20-byte ERC-20 contract address on Base that corresponds to the SPL mint.",
-              "Used, together with the SPL mint, to derive the token-vault PDA for this mapping."
+              "The nonce assigned to the message on Base."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The 20-byte EVM address of the sender on Base."
             ],
             "type": {
               "array": [
@@ -3403,323 +8477,303 @@ export const IDL = {
             }
           },
           {
-            "name": "local_token",
+            "name": "executed",
             "docs": [
-              "The SPL token mint on Solana that mirrors the `remote_token`."
+              "Whether the message has been relayed on Solana."
             ],
-            "type": "pubkey"
+            "type": "bool"
           },
           {
-            "name": "to",
+            "name": "data_hash",
             "docs": [
-              "The recipient SPL token account address on Solana that will receive tokens.",
-              "This must be a valid token account for `local_token`.",
-              "Note: this program does not enforce ownership or ATA semantics; the account",
-              "is authenticated by address equality (`self.to`) and `transfer_checked`",
-              "enforces the mint match."
+              "keccak256 of the Borsh-serialized message payload."
             ],
-            "type": "pubkey"
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "amount",
+            "name": "data",
             "docs": [
-              "The amount to transfer, in base units of the mint (respecting mint decimals).",
-              "`transfer_checked` enforces that the destination account's mint matches and",
-              "the decimals are correct."
+              "The Borsh-serialized message payload, omitted when it does not fit in return data."
             ],
-            "type": "u64"
+            "type": {
+              "option": "bytes"
+            }
           }
         ]
       }
     },
     {
-      "name": "FinalizeBridgeWrappedToken",
+      "name": "QuotedCall",
       "docs": [
-        "Instruction data for finalizing a wrapped token transfer from Base to Solana.",
-        "",
-        "This struct represents the final step in a cross-chain bridge operation where tokens",
-        "that were originally on Base are being bridged to Solana as wrapped tokens. The",
-        "finalization process mints the appropriate amount of wrapped tokens to the recipient's",
-        "token account on Solana.",
-        "",
-        "The wrapped token mint is derived deterministically from the original token's metadata",
-        "and decimals, ensuring consistency across bridge operations."
+        "Shape of the optional call attached to a quoted operation."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "local_token",
-            "docs": [
-              "The mint address of the wrapped token on Solana.",
-              "This is a PDA that represents the Solana version",
-              "of a token that originally exists on Base. The mint address is derived",
-              "deterministically from the original token's metadata and decimals."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "to",
+            "name": "ty",
             "docs": [
-              "The destination token account that will receive the wrapped tokens.",
-              "This must be a valid token account that is associated with the wrapped",
-              "token mint. It is expected to be controlled by the intended recipient of",
-              "the bridged tokens; recipient ownership is not enforced by this",
-              "instruction."
+              "The type of the call, which selects its gas multiplier."
             ],
-            "type": "pubkey"
+            "type": {
+              "defined": {
+                "name": "CallType"
+              }
+            }
           },
           {
-            "name": "amount",
+            "name": "data_len",
             "docs": [
-              "The amount of wrapped tokens to mint to the recipient.",
-              "The amount is specified in the token's smallest unit."
+              "Length in bytes of the call data, which sizes the outgoing message account."
             ],
-            "type": "u64"
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "GasConfig",
+      "name": "QuotedOperation",
+      "docs": [
+        "The bridge operation to quote."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "gas_cost_scaler",
-            "docs": [
-              "Scaling factor applied when converting (gas_per_call * base_fee) into lamports"
-            ],
-            "type": "u64"
-          },
+        "kind": "enum",
+        "variants": [
           {
-            "name": "gas_cost_scaler_dp",
-            "docs": [
-              "Decimal precision for the gas cost scaler (denominator)"
-            ],
-            "type": "u64"
+            "name": "Sol",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              },
+              {
+                "name": "call",
+                "type": {
+                  "option": {
+                    "defined": {
+                      "name": "QuotedCall"
+                    }
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "gas_fee_receiver",
-            "docs": [
-              "Account that receives gas fees collected on Solana"
-            ],
-            "type": "pubkey"
+            "name": "Spl",
+            "fields": [
+              {
+                "name": "call",
+                "type": {
+                  "option": {
+                    "defined": {
+                      "name": "QuotedCall"
+                    }
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "gas_per_call",
-            "docs": [
-              "Amount of gas per Solana --> Base message"
-            ],
-            "type": "u64"
+            "name": "Call",
+            "fields": [
+              {
+                "name": "call",
+                "type": {
+                  "defined": {
+                    "name": "QuotedCall"
+                  }
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "IncomingMessage",
+      "name": "RegistrationBucket",
       "docs": [
-        "Represents a cross-chain message sent from Base to Solana",
-        "that is waiting to be processed or has already been executed.",
-        "",
-        "This struct stores the essential information needed to validate and execute",
-        "bridge operations from Base to Solana, including both simple calls and token transfers.",
-        "",
-        "Messages are created by the `prove_message` instruction (after MMR verification)",
-        "and executed by the `relay_message` instruction."
+        "Leaky bucket state for output root registrations."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "sender",
-            "docs": [
-              "The 20-byte EVM address of the sender on Base who initiated this bridge operation.",
-              "Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.",
-              "This field does not restrict who can call the relay instruction."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "message",
+            "name": "tokens",
             "docs": [
-              "The actual message payload containing either instruction calls or token transfer data.",
-              "This enum determines what type of operation will be executed on Solana."
+              "Registrations currently available"
             ],
-            "type": {
-              "defined": {
-                "name": "bridge::base_to_solana::state::incoming_message::Message"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "executed",
+            "name": "last_refill_time",
             "docs": [
-              "Flag indicating whether this message has been successfully executed on Solana.",
-              "Once set to true, the message cannot be executed again, preventing replay attacks."
+              "Unix timestamp from which the next refill is measured"
             ],
-            "type": "bool"
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "Ix",
+      "name": "RelayCursor",
       "docs": [
-        "Instruction to be executed by the bridge program via signed CPI during message relay.",
-        "Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging."
+        "Execution cursor for an incoming message relayed over several transactions.",
+        "",
+        "Created by `begin_relay` and advanced by each `continue_relay` call. Every slice of the",
+        "message's instruction list starts at `next_ix`, so a slice can only ever execute once.",
+        "The final slice closes the cursor and refunds its rent to `payer`."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "program_id",
+            "name": "message",
             "docs": [
-              "Program that will process this instruction."
+              "The incoming message being relayed."
             ],
             "type": "pubkey"
           },
           {
-            "name": "accounts",
+            "name": "payer",
             "docs": [
-              "Accounts required for this instruction."
+              "The account that paid for the cursor and receives its rent back once the relay completes."
             ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "IxAccount"
-                }
-              }
-            }
+            "type": "pubkey"
           },
           {
-            "name": "data",
+            "name": "next_ix",
             "docs": [
-              "Instruction data."
+              "Index of the next instruction of the message to execute."
             ],
-            "type": "bytes"
+            "type": "u32"
+          },
+          {
+            "name": "total_ixs",
+            "docs": [
+              "Total number of instructions in the message."
+            ],
+            "type": "u32"
           }
         ]
       }
     },
     {
-      "name": "IxAccount",
+      "name": "RemoteTokenRateLimit",
       "docs": [
-        "Account used in an instruction.",
-        "Similar to Solana's `AccountMeta`, but serializable with Anchor and supports PDAs via `PubkeyOrPda`."
+        "Guardian-set fixed-window outflow limit for a Base token address, at",
+        "`[RATE_LIMIT_SEED, remote_token]`. Tokens without a limit account are unlimited."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "pubkey",
+            "name": "remote_token",
+            "docs": [
+              "The Base token address the limit applies to"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "max_per_window",
+            "docs": [
+              "Maximum amount, in local token units, bridged out per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_seconds",
             "docs": [
-              "Public key of the account."
+              "Window duration in seconds"
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "is_writable",
+            "name": "window_start_time",
             "docs": [
-              "Whether the account is writable."
+              "Unix timestamp when the current window started"
             ],
-            "type": "bool"
+            "type": "i64"
           },
           {
-            "name": "is_signer",
+            "name": "amount_in_window",
             "docs": [
-              "Whether the account is a signer."
+              "Amount bridged out in the current window"
             ],
-            "type": "bool"
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "OutgoingMessage",
+      "name": "TotalCostQuote",
       "docs": [
-        "Represents a message being sent from Solana to Base through the bridge.",
-        "This struct contains all the necessary information to execute a cross-chain operation",
-        "on the Base side, including the message content and execution parameters."
+        "Lamport cost of a bridge operation, returned to the caller via return data."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nonce",
+            "name": "gas_fee",
             "docs": [
-              "Monotonic message nonce used for ordering and replay protection on Base.",
-              "Starts at 0 and is incremented by the `Bridge` for each new message."
+              "Gas fee paid to the gas fee receiver at the current base fee."
             ],
             "type": "u64"
           },
           {
-            "name": "sender",
+            "name": "value",
             "docs": [
-              "The Solana public key of the signer that initiated this cross-chain message.",
-              "Carried to Base for use by destination logic; Solana-side authentication is enforced",
-              "via signer constraints."
+              "Lamports locked in the SOL vault (only non-zero for `bridge_sol`)."
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "message",
+            "name": "rent",
             "docs": [
-              "The actual message payload that will be executed on Base.",
-              "Can be either a direct contract call or a token transfer (with optional call)."
+              "Rent-exempt balance of the outgoing message account created by the operation."
             ],
-            "type": {
-              "defined": {
-                "name": "bridge::solana_to_base::state::outgoing_message::Message"
-              }
-            }
+            "type": "u64"
+          },
+          {
+            "name": "total",
+            "docs": [
+              "Sum of `gas_fee`, `value` and `rent`. Quoting fails with `QuoteOverflow` if it does not",
+              "fit in a u64."
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "OutputRoot",
+      "name": "TotalLockedSol",
       "docs": [
-        "Represents a cryptographic commitment to the set of Base L2 bridge messages",
-        "at a specific Base block number.",
-        "",
-        "Output roots are registered on Solana by a trusted oracle and serve as",
-        "checkpoints that allow messages from Base to be proven and relayed to",
-        "Solana. Each output root contains an MMR root that commits to all bridge",
-        "messages as of a particular Base block number.",
-        "",
-        "This account is used in the Base → Solana message passing flow, where:",
-        "1. A trusted oracle registers output roots for specific Base blocks",
-        "2. Users prove their messages were included on Base using these roots and an MMR proof",
-        "3. Proven messages are then relayed and executed on Solana"
+        "Locked SOL totals, returned to the caller via return data."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "root",
+            "name": "locked_sol",
             "docs": [
-              "The 32-byte MMR root that commits to all outgoing bridge messages on Base",
-              "as of the specified Base block number."
+              "Lamports accounted as locked: bridged out and not yet finalized back to Solana."
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
+            "type": "u64"
           },
           {
-            "name": "total_leaf_count",
+            "name": "vault_balance",
             "docs": [
-              "The total number of leaves that were present in the MMR when this root",
-              "was generated. This is crucial for determining the MMR structure and",
-              "mountain configuration at the time of proof validation."
+              "Actual lamport balance of the SOL vault. May exceed `locked_sol` (e.g. direct deposits),",
+              "but never be lower for a solvent bridge."
             ],
             "type": "u64"
           }
@@ -3727,95 +8781,86 @@ export const IDL = {
       }
     },
     {
-      "name": "PartialTokenMetadata",
+      "name": "VolumeWindow",
       "docs": [
-        "Represents token metadata for tokens that are bridged between Base and Solana.",
+        "Volume accumulated over a fixed (tumbling) daily window.",
         "",
-        "This struct contains metadata needed to represent a token that exists on both",
-        "chains, including information about its remote counterpart and any scaling factors needed",
-        "to handle differences between the chains (such as decimal precision).",
-        "",
-        "The metadata is stored using the SPL Token-2022 metadata interface's",
-        "`additional_metadata` key/value field and can be used to reconstruct the relationship",
-        "between tokens on both sides of the bridge."
+        "A window starts with the first addition after the previous one elapsed and is reset as a",
+        "whole, rather than sliding with time. The limit therefore bounds each window, not every",
+        "24-hour span: volume added at the end of one window and at the start of the next can reach",
+        "twice the limit within a few seconds."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "name",
-            "docs": [
-              "The human-readable name of the token (e.g., \"Wrapped Bitcoin\")"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "docs": [
-              "The symbol/ticker of the token (e.g., \"WBTC\")"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "remote_token",
+            "name": "volume",
             "docs": [
-              "The 20-byte address of the corresponding token contract on Base (EVM address bytes).",
-              "This allows the bridge to identify which Base token this Solana token represents."
+              "Amount accumulated in the current window"
             ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
+            "type": "u64"
           },
           {
-            "name": "scaler_exponent",
+            "name": "window_start_time",
             "docs": [
-              "The scaling exponent used to convert between token amounts on different chains.",
-              "This handles cases where tokens have differing decimal precision on Base vs Solana.",
-              "For example, when Base token has 18 decimals and the Solana wrapped mint has 9,",
-              "this value conveys the decimal relationship so bridging logic can scale amounts.",
-              "The exact conversion is performed by the EVM-side contract; Solana propagates this",
-              "value but does not apply arithmetic with it."
+              "Unix timestamp when the current window started"
             ],
-            "type": "u8"
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "PartnerOracleConfig",
+      "name": "WrappedAccountFreezeUpdated",
+      "docs": [
+        "Emitted when the guardian freezes or thaws a wrapped token account."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "required_threshold",
+            "name": "mint",
             "docs": [
-              "Partner signatures required by our bridge to accept an output root"
+              "The wrapped mint"
             ],
-            "type": "u8"
+            "type": "pubkey"
+          },
+          {
+            "name": "token_account",
+            "docs": [
+              "The token account that was frozen or thawed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "frozen",
+            "docs": [
+              "Whether the account is now frozen"
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "ProtocolConfig",
+      "name": "WrappedTokenCreated",
+      "docs": [
+        "Emitted when `wrap_token` creates a new wrapped mint for a Base token."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "block_interval_requirement",
+            "name": "mint",
             "docs": [
-              "Block interval requirement for output root registration. Every Base block associated with a",
-              "submitted output root must be a multiple of this number."
+              "The wrapped mint created on Solana"
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "remote_sol_address",
+            "name": "remote_token",
             "docs": [
-              "The Base evm address of SOL"
+              "The 20-byte address of the token on Base"
             ],
             "type": {
               "array": [
@@ -3823,46 +8868,96 @@ export const IDL = {
                 20
               ]
             }
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the wrapped mint"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Token name"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "Token symbol"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "scaler_exponent",
+            "docs": [
+              "Decimal scaling exponent between the Base token and the wrapped mint"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "ProveBuffer",
+      "name": "WrappedTokenInfo",
       "docs": [
-        "A buffer account to stage large Base → Solana prove inputs over multiple transactions.",
-        "Stores the serialized `Message` bytes (`data`) and the MMR `proof` nodes."
+        "Durable record of a wrapped mint created by `wrap_token`.",
+        "",
+        "Created alongside the mint at `[WRAPPED_TOKEN_INFO_SEED, mint]` so integrators can look up",
+        "when, by whom and with which configuration a wrapped token was created without replaying",
+        "`WrappedTokenCreated` events."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "mint",
             "docs": [
-              "The owner who can modify and eventually consume this buffer"
+              "The wrapped Token-2022 mint this record describes."
             ],
             "type": "pubkey"
           },
           {
-            "name": "data",
+            "name": "creator",
             "docs": [
-              "Serialized `Message` data (Anchor-serialized)"
+              "The payer of the `wrap_token` transaction that created the mint."
             ],
-            "type": "bytes"
+            "type": "pubkey"
           },
           {
-            "name": "proof",
+            "name": "created_at",
             "docs": [
-              "MMR proof nodes used to validate inclusion against an OutputRoot"
+              "Solana unix timestamp at which the mint was created."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "remote_token",
+            "docs": [
+              "The 20-byte address of the Base token the mint wraps."
             ],
             "type": {
-              "vec": {
-                "array": [
-                  "u8",
-                  32
-                ]
-              }
+              "array": [
+                "u8",
+                20
+              ]
             }
+          },
+          {
+            "name": "decimals",
+            "docs": [
+              "Decimals of the wrapped mint."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "scaler_exponent",
+            "docs": [
+              "Exponent registered on Base to scale amounts between the mint and the remote token."
+            ],
+            "type": "u8"
           }
         ]
       }
@@ -4081,21 +9176,71 @@ export const IDL = {
       "type": "bytes",
       "value": "[98, 114, 105, 100, 103, 101]"
     },
+    {
+      "name": "DAILY_VOLUME_WINDOW_SECONDS",
+      "type": "u64",
+      "value": "86400"
+    },
+    {
+      "name": "DENIED_REMOTE_TOKEN_SEED",
+      "type": "bytes",
+      "value": "[100, 101, 110, 105, 101, 100, 95, 114, 101, 109, 111, 116, 101, 95, 116, 111, 107, 101, 110]"
+    },
+    {
+      "name": "GAS_MULTIPLIER_BPS_DENOMINATOR",
+      "type": "u64",
+      "value": "10000"
+    },
     {
       "name": "INCOMING_MESSAGE_SEED",
       "type": "bytes",
       "value": "[105, 110, 99, 111, 109, 105, 110, 103, 95, 109, 101, 115, 115, 97, 103, 101]"
     },
+    {
+      "name": "INCOMING_MESSAGE_VERSION",
+      "type": "u8",
+      "value": "2"
+    },
+    {
+      "name": "MAX_BASE_FEE",
+      "type": "u64",
+      "value": "1000000000000"
+    },
+    {
+      "name": "MAX_GAS_MULTIPLIER_BPS",
+      "type": "u32",
+      "value": "100000"
+    },
+    {
+      "name": "MAX_OUTPUT_ROOT_BATCH_SIZE",
+      "type": "u8",
+      "value": "16"
+    },
     {
       "name": "MAX_PARTNER_VALIDATOR_THRESHOLD",
       "type": "u8",
       "value": "5"
     },
+    {
+      "name": "MAX_REMOTE_TOKEN_DECIMALS",
+      "type": "u8",
+      "value": "18"
+    },
     {
       "name": "MAX_SIGNER_COUNT",
       "type": "u8",
       "value": "16"
     },
+    {
+      "name": "MAX_TOKEN_NAME_LEN",
+      "type": "u8",
+      "value": "32"
+    },
+    {
+      "name": "MAX_TOKEN_SYMBOL_LEN",
+      "type": "u8",
+      "value": "10"
+    },
     {
       "name": "NATIVE_SOL_PUBKEY",
       "type": "pubkey",
@@ -4106,6 +9251,16 @@ export const IDL = {
       "type": "bytes",
       "value": "[111, 117, 116, 103, 111, 105, 110, 103, 95, 109, 101, 115, 115, 97, 103, 101]"
     },
+    {
+      "name": "OUTPUT_ROOT_CLOSE_DELAY_SECONDS",
+      "type": "u64",
+      "value": "604800"
+    },
+    {
+      "name": "OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS",
+      "type": "u64",
+      "value": "100"
+    },
     {
       "name": "OUTPUT_ROOT_SEED",
       "type": "bytes",
@@ -4121,6 +9276,46 @@ export const IDL = {
       "type": "bytes",
       "value": "[115, 105, 103, 110, 101, 114, 115]"
     },
+    {
+      "name": "PAUSE_ALL_OPS",
+      "type": "u16",
+      "value": "31"
+    },
+    {
+      "name": "PAUSE_BRIDGE_CALL",
+      "type": "u16",
+      "value": "8"
+    },
+    {
+      "name": "PAUSE_BRIDGE_TOKEN",
+      "type": "u16",
+      "value": "4"
+    },
+    {
+      "name": "PAUSE_PROVE",
+      "type": "u16",
+      "value": "1"
+    },
+    {
+      "name": "PAUSE_REGISTER_ROOT",
+      "type": "u16",
+      "value": "16"
+    },
+    {
+      "name": "PAUSE_RELAY",
+      "type": "u16",
+      "value": "2"
+    },
+    {
+      "name": "RATE_LIMIT_SEED",
+      "type": "bytes",
+      "value": "[114, 97, 116, 101, 95, 108, 105, 109, 105, 116]"
+    },
+    {
+      "name": "RELAY_CURSOR_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 108, 97, 121, 95, 99, 117, 114, 115, 111, 114]"
+    },
     {
       "name": "REMOTE_TOKEN_METADATA_KEY",
       "type": "string",
@@ -4141,6 +9336,11 @@ export const IDL = {
       "type": "bytes",
       "value": "[116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]"
     },
+    {
+      "name": "WRAPPED_TOKEN_INFO_SEED",
+      "type": "bytes",
+      "value": "[119, 114, 97, 112, 112, 101, 100, 95, 116, 111, 107, 101, 110, 95, 105, 110, 102, 111]"
+    },
     {
       "name": "WRAPPED_TOKEN_SEED",
       "type": "bytes",
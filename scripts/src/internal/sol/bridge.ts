@@ -1,6 +1,7 @@
 import {
   getProgramDerivedAddress,
   type Address as SolanaAddress,
+  type ReadonlyUint8Array,
 } from "@solana/kit";
 import { toBytes, type Address as EvmAddress } from "viem";
 
@@ -40,3 +41,28 @@ export async function solVaultPubkey(
 
   return pubkey;
 }
+
+export async function remoteTokenPolicyPubkeys(
+  bridgeProgram: SolanaAddress,
+  remoteToken: EvmAddress | ReadonlyUint8Array
+) {
+  const remoteTokenBytes =
+    typeof remoteToken === "string" ? toBytes(remoteToken) : remoteToken;
+
+  const [deniedRemoteToken] = await getProgramDerivedAddress({
+    programAddress: bridgeProgram,
+    seeds: [
+      Buffer.from(getIdlConstant("DENIED_REMOTE_TOKEN_SEED")),
+      Buffer.from(remoteTokenBytes),
+    ],
+  });
+  const [rateLimit] = await getProgramDerivedAddress({
+    programAddress: bridgeProgram,
+    seeds: [
+      Buffer.from(getIdlConstant("RATE_LIMIT_SEED")),
+      Buffer.from(remoteTokenBytes),
+    ],
+  });
+
+  return { deniedRemoteToken, rateLimit };
+}
@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 pub const DISCRIMINATOR_LEN: usize = 8;
 
+/// Discriminator of the bridge program's `OutgoingMessage` account.
+pub const OUTGOING_MESSAGE_DISCRIMINATOR: [u8; 8] = [150, 255, 197, 226, 200, 215, 31, 29];
+
 #[constant]
 pub const SCALE: u128 = 1_000_000;
 
@@ -9,10 +9,28 @@ pub enum RelayerError {
     #[msg("Incorrect relayer program")]
     IncorrectRelayerProgram,
 
+    #[msg("Account is already migrated or does not have the legacy layout")]
+    AlreadyMigrated,
+
     // Configuration (6100-6199)
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate = 6100,
 
+    #[msg("Invalid denominator")]
+    InvalidDenominator,
+
+    #[msg("Invalid window duration seconds")]
+    InvalidWindowDurationSeconds,
+
+    #[msg("Invalid gas cost scaler dp")]
+    InvalidGasCostScalerDp,
+
+    #[msg("Invalid gas limit range")]
+    InvalidGasLimitRange,
+
+    #[msg("Only the pending guardian can accept the guardian role")]
+    UnauthorizedGuardianAcceptance,
+
     // Gas Validation (6200-6299)
     #[msg("Gas limit too low")]
     GasLimitTooLow = 6200,
@@ -20,7 +38,16 @@ pub enum RelayerError {
     #[msg("Gas limit exceeded")]
     GasLimitExceeded,
 
+    #[msg("Gas cost does not fit in a u64")]
+    GasCostOverflow,
+
     // Payment (6300-6399)
     #[msg("Incorrect gas fee receiver")]
     IncorrectGasFeeReceiver = 6300,
+
+    #[msg("Account is not a bridge outgoing message")]
+    InvalidOutgoingMessage,
+
+    #[msg("Only the sender of the outgoing message can pay for its relay")]
+    PayerIsNotSender,
 }
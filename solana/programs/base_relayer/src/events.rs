@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the guardian proposes a new guardian.
+#[event]
+pub struct GuardianTransferProposed {
+    /// The current guardian
+    pub guardian: Pubkey,
+    /// The proposed guardian, pending acceptance
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the pending guardian accepts the guardian role.
+#[event]
+pub struct GuardianTransferAccepted {
+    /// The guardian being replaced
+    pub previous_guardian: Pubkey,
+    /// The new guardian
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when the guardian cancels a pending guardian proposal.
+#[event]
+pub struct GuardianTransferCancelled {
+    /// The current guardian
+    pub guardian: Pubkey,
+    /// The proposal that was cancelled, if any
+    pub pending_guardian: Option<Pubkey>,
+}
@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::CFG_SEED, instructions::SetConfig, state::Cfg, GuardianTransferAccepted,
+    GuardianTransferCancelled, GuardianTransferProposed, RelayerError,
+};
+
+/// Accounts struct for accepting a pending guardian proposal
+/// Only the proposed guardian can accept
+#[derive(Accounts)]
+pub struct AcceptGuardian<'info> {
+    /// The relayer config holding the pending guardian
+    #[account(
+        mut,
+        seeds = [CFG_SEED],
+        bump,
+        constraint = cfg.pending_guardian == Some(new_guardian.key())
+            @ RelayerError::UnauthorizedGuardianAcceptance
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The proposed guardian accepting the role
+    pub new_guardian: Signer<'info>,
+}
+
+pub fn propose_guardian_handler(ctx: Context<SetConfig>, new_guardian: Pubkey) -> Result<()> {
+    ctx.accounts.cfg.pending_guardian = Some(new_guardian);
+
+    emit!(GuardianTransferProposed {
+        guardian: ctx.accounts.guardian.key(),
+        pending_guardian: new_guardian,
+    });
+
+    Ok(())
+}
+
+pub fn accept_guardian_handler(ctx: Context<AcceptGuardian>) -> Result<()> {
+    let cfg = &mut ctx.accounts.cfg;
+    let previous_guardian = cfg.guardian;
+    cfg.guardian = ctx.accounts.new_guardian.key();
+    cfg.pending_guardian = None;
+
+    emit!(GuardianTransferAccepted {
+        previous_guardian,
+        new_guardian: cfg.guardian,
+    });
+
+    Ok(())
+}
+
+pub fn cancel_guardian_transfer_handler(ctx: Context<SetConfig>) -> Result<()> {
+    let pending_guardian = ctx.accounts.cfg.pending_guardian.take();
+
+    emit!(GuardianTransferCancelled {
+        guardian: ctx.accounts.guardian.key(),
+        pending_guardian,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData, ToAccountMetas};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    fn fetch_cfg(svm: &litesvm::LiteSVM, cfg_pda: &Pubkey) -> Cfg {
+        let cfg_account = svm.get_account(cfg_pda).unwrap();
+        Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap()
+    }
+
+    fn propose(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        guardian: &Keypair,
+        cfg_pda: Pubkey,
+    ) -> Keypair {
+        let new_guardian = Keypair::new();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetConfig {
+                cfg: cfg_pda,
+                guardian: guardian.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ProposeGuardian {
+                new_guardian: new_guardian.pubkey(),
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, guardian],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Current guardian should be able to propose a guardian");
+
+        new_guardian
+    }
+
+    fn accept_tx(
+        svm: &litesvm::LiteSVM,
+        payer: &Keypair,
+        signer: &Keypair,
+        cfg_pda: Pubkey,
+    ) -> Transaction {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::AcceptGuardian {
+                cfg: cfg_pda,
+                new_guardian: signer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::AcceptGuardian {}.data(),
+        };
+
+        Transaction::new(
+            &[payer, signer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        )
+    }
+
+    #[test]
+    fn test_propose_then_accept_transfers_guardian() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_guardian = propose(&mut svm, &payer, &guardian, cfg_pda);
+
+        // Proposing alone does not hand over the role
+        let cfg = fetch_cfg(&svm, &cfg_pda);
+        assert_eq!(cfg.guardian, guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, Some(new_guardian.pubkey()));
+
+        let tx = accept_tx(&svm, &payer, &new_guardian, cfg_pda);
+        svm.send_transaction(tx)
+            .expect("Pending guardian should be able to accept");
+
+        let cfg = fetch_cfg(&svm, &cfg_pda);
+        assert_eq!(cfg.guardian, new_guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, None);
+    }
+
+    #[test]
+    fn test_propose_then_cancel_clears_pending_guardian() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_guardian = propose(&mut svm, &payer, &guardian, cfg_pda);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetConfig {
+                cfg: cfg_pda,
+                guardian: guardian.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::CancelGuardianTransfer {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Current guardian should be able to cancel the transfer");
+
+        let cfg = fetch_cfg(&svm, &cfg_pda);
+        assert_eq!(cfg.guardian, guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, None);
+
+        // The cancelled proposal can no longer be accepted
+        let tx = accept_tx(&svm, &payer, &new_guardian, cfg_pda);
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedGuardianAcceptance"));
+    }
+
+    #[test]
+    fn test_accept_guardian_with_wrong_key_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        propose(&mut svm, &payer, &guardian, cfg_pda);
+
+        let impostor = Keypair::new();
+        let tx = accept_tx(&svm, &payer, &impostor, cfg_pda);
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedGuardianAcceptance"));
+
+        let cfg = fetch_cfg(&svm, &cfg_pda);
+        assert_eq!(cfg.guardian, guardian.pubkey());
+    }
+
+    #[test]
+    fn test_propose_guardian_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetConfig {
+                cfg: cfg_pda,
+                guardian: fake_guardian.pubkey(),
+            }
+            .to_account_metas(None),
+            data: instruction::ProposeGuardian {
+                new_guardian: Pubkey::new_unique(),
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &fake_guardian],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedConfigUpdate"));
+        assert_eq!(fetch_cfg(&svm, &cfg_pda).pending_guardian, None);
+    }
+}
@@ -19,10 +19,10 @@ pub struct SetConfig<'info> {
     pub guardian: Signer<'info>,
 }
 
+pub mod guardian_transfer;
 pub mod set_eip1559_config;
 pub mod set_gas_config;
-pub mod set_guardian;
 
+pub use guardian_transfer::*;
 pub use set_eip1559_config::*;
 pub use set_gas_config::*;
-pub use set_guardian::*;
@@ -6,6 +6,7 @@ pub fn set_eip1559_config_handler(
     ctx: Context<SetConfig>,
     eip1559_config: Eip1559Config,
 ) -> Result<()> {
+    eip1559_config.validate()?;
     ctx.accounts.cfg.eip1559.config = eip1559_config;
     Ok(())
 }
@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{instructions::SetConfig, internal::GasConfig};
 
 pub fn set_gas_config_handler(ctx: Context<SetConfig>, gas_config: GasConfig) -> Result<()> {
+    gas_config.validate()?;
     ctx.accounts.cfg.gas_config = gas_config;
     Ok(())
 }
@@ -31,6 +32,7 @@ mod tests {
         let new_config = GasConfig {
             min_gas_limit_per_message: 200_000,
             max_gas_limit_per_message: 200_000_000,
+            default_gas_limit_per_message: 300_000,
             gas_cost_scaler: 2_000_000,
             gas_cost_scaler_dp: 10u64.pow(7),
             gas_fee_receiver: new_gas_receiver,
@@ -83,6 +85,7 @@ mod tests {
         let new_config = GasConfig {
             min_gas_limit_per_message: 200_000,
             max_gas_limit_per_message: 200_000_000,
+            default_gas_limit_per_message: 300_000,
             gas_cost_scaler: 2_000_000,
             gas_cost_scaler_dp: 10u64.pow(7),
             gas_fee_receiver: new_gas_receiver,
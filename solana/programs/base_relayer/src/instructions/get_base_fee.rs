@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::CFG_SEED, state::Cfg};
+
+/// Accounts for the `get_base_fee` view instruction that reports the live EIP-1559 base fee.
+#[derive(Accounts)]
+pub struct GetBaseFee<'info> {
+    /// The relayer config state account holding the EIP-1559 state.
+    /// - Uses PDA with CFG_SEED for deterministic address
+    /// - Read-only: the refreshed state is never persisted
+    #[account(seeds = [CFG_SEED], bump)]
+    pub cfg: Account<'info, Cfg>,
+}
+
+/// EIP-1559 state as of the current timestamp, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct BaseFee {
+    /// Base fee in gwei that `pay_for_relay` would charge now
+    pub current_base_fee: u64,
+    /// Gas used so far in the current window
+    pub current_window_gas_used: u64,
+    /// Unix timestamp when the current window started
+    pub window_start_time: i64,
+}
+
+/// Refreshes a copy of the config's EIP-1559 state for the current timestamp, exactly as
+/// `pay_for_relay` does, and returns it without writing it back.
+pub fn get_base_fee_handler(ctx: Context<GetBaseFee>) -> Result<BaseFee> {
+    let mut eip1559 = ctx.accounts.cfg.eip1559.clone();
+    let current_base_fee = eip1559.refresh_base_fee(Clock::get()?.unix_timestamp);
+
+    Ok(BaseFee {
+        current_base_fee,
+        current_window_gas_used: eip1559.current_window_gas_used,
+        window_start_time: eip1559.window_start_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts;
+    use crate::test_utils::{mock_clock, setup_relayer, SetupRelayerResult};
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    #[test]
+    fn get_base_fee_refreshes_without_persisting() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        let cfg_before = svm.get_account(&cfg_pda).unwrap().data;
+        let cfg = Cfg::try_deserialize(&mut &cfg_before[..]).unwrap();
+
+        let now =
+            cfg.eip1559.window_start_time + 3 * cfg.eip1559.config.window_duration_seconds as i64;
+        mock_clock(&mut svm, now);
+
+        let mut expected = cfg.eip1559.clone();
+        let expected_base_fee = expected.refresh_base_fee(now);
+
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts: accounts::GetBaseFee { cfg: cfg_pda }.to_account_metas(None),
+            data: crate::instruction::GetBaseFee {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_base_fee should succeed");
+
+        assert_eq!(meta.return_data.program_id, crate::ID);
+        let base_fee = BaseFee::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            base_fee,
+            BaseFee {
+                current_base_fee: expected_base_fee,
+                current_window_gas_used: expected.current_window_gas_used,
+                window_start_time: expected.window_start_time,
+            }
+        );
+        assert_eq!(svm.get_account(&cfg_pda).unwrap().data, cfg_before);
+    }
+}
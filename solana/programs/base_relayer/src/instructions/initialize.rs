@@ -56,11 +56,15 @@ pub fn initialize_handler(
     eip1559_config: Eip1559Config,
     gas_config: GasConfig,
 ) -> Result<()> {
+    eip1559_config.validate()?;
+    gas_config.validate()?;
+
     let current_timestamp = Clock::get()?.unix_timestamp;
     let minimum_base_fee = eip1559_config.minimum_base_fee;
 
     *ctx.accounts.cfg = Cfg {
         guardian,
+        pending_guardian: None,
         eip1559: Eip1559 {
             config: eip1559_config,
             current_base_fee: minimum_base_fee,
@@ -143,6 +147,7 @@ mod tests {
 
         // Verify all fields
         assert_eq!(cfg.guardian, guardian_pk);
+        assert_eq!(cfg.pending_guardian, None);
         assert_eq!(cfg.nonce, 0);
         assert_eq!(cfg.eip1559.config, Eip1559Config::test_new());
         assert_eq!(cfg.eip1559.current_base_fee, 1); // minimum_base_fee from test config
@@ -213,4 +218,76 @@ mod tests {
             error_string
         );
     }
+
+    fn send_initialize(
+        eip1559_config: Eip1559Config,
+        gas_config: GasConfig,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let DeployRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+            program_data_pda,
+        } = deploy_relayer();
+
+        let accounts = accounts::Initialize {
+            upgrade_authority: payer.pubkey(),
+            payer: payer.pubkey(),
+            cfg: cfg_pda,
+            program_data: program_data_pda,
+            program: ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::Initialize {
+                guardian: guardian.pubkey(),
+                eip1559_config,
+                gas_config,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_initialize_rejects_invalid_eip1559_config() {
+        let mut eip1559_config = Eip1559Config::test_new();
+        eip1559_config.denominator = 0;
+
+        let result = send_initialize(eip1559_config, GasConfig::test_new(Pubkey::new_unique()));
+        assert!(result.is_err(), "Expected invalid EIP-1559 config to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidDenominator"),
+            "Expected InvalidDenominator error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_initialize_rejects_invalid_gas_config() {
+        let mut gas_config = GasConfig::test_new(Pubkey::new_unique());
+        gas_config.min_gas_limit_per_message = gas_config.max_gas_limit_per_message + 1;
+
+        let result = send_initialize(Eip1559Config::test_new(), gas_config);
+        assert!(result.is_err(), "Expected invalid gas config to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidGasLimitRange"),
+            "Expected InvalidGasLimitRange error, got: {}",
+            error_string
+        );
+    }
 }
@@ -0,0 +1,304 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN},
+    internal::{resize_account, Eip1559, GasConfig},
+    program::BaseRelayer as BaseRelayerProgram,
+    Cfg, RelayerError,
+};
+
+/// Layout of the `Cfg` account written by the program before `pending_guardian` and
+/// `gas_config.default_gas_limit_per_message` were added.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyCfg {
+    nonce: u64,
+    guardian: Pubkey,
+    eip1559: Eip1559,
+    gas_config: LegacyGasConfig,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyGasConfig {
+    min_gas_limit_per_message: u64,
+    max_gas_limit_per_message: u64,
+    gas_cost_scaler: u64,
+    gas_cost_scaler_dp: u64,
+    gas_fee_receiver: Pubkey,
+}
+
+/// Accounts for the `migrate_cfg` instruction that rewrites a `Cfg` account created by a
+/// previous version of the program into the current layout.
+/// Only the upgrade authority can migrate the config.
+#[derive(Accounts)]
+pub struct MigrateCfg<'info> {
+    /// The upgrade authority that is authorized to migrate the config.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for the rent of the grown config account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The relayer config account still in the legacy layout.
+    /// CHECK: Deserialized manually from the legacy layout in the handler.
+    #[account(mut, owner = crate::ID, seeds = [CFG_SEED], bump)]
+    pub cfg: UncheckedAccount<'info>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ RelayerError::UnauthorizedConfigUpdate
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The base_relayer program itself.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ RelayerError::IncorrectRelayerProgram
+    )]
+    pub program: Program<'info, BaseRelayerProgram>,
+
+    /// System program required for funding the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy `Cfg` account to the current layout.
+///
+/// The nonce, guardian, EIP-1559 state and gas configuration are carried over, with
+/// `default_gas_limit_per_message` filling the gas limit applied to relay requests that pass zero.
+/// The resulting gas configuration must pass `GasConfig::validate`, so the default has to lie
+/// within the legacy minimum and maximum gas limits. No guardian transfer is pending after the
+/// migration.
+pub fn migrate_cfg_handler(
+    ctx: Context<MigrateCfg>,
+    default_gas_limit_per_message: u64,
+) -> Result<()> {
+    let cfg_info = ctx.accounts.cfg.to_account_info();
+    let legacy = {
+        let data = cfg_info.try_borrow_data()?;
+        require!(
+            data.len() == DISCRIMINATOR_LEN + LegacyCfg::INIT_SPACE
+                && data.starts_with(Cfg::DISCRIMINATOR),
+            RelayerError::AlreadyMigrated
+        );
+        LegacyCfg::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+
+    let cfg = Cfg {
+        nonce: legacy.nonce,
+        guardian: legacy.guardian,
+        pending_guardian: None,
+        eip1559: legacy.eip1559,
+        gas_config: GasConfig {
+            min_gas_limit_per_message: legacy.gas_config.min_gas_limit_per_message,
+            max_gas_limit_per_message: legacy.gas_config.max_gas_limit_per_message,
+            default_gas_limit_per_message,
+            gas_cost_scaler: legacy.gas_config.gas_cost_scaler,
+            gas_cost_scaler_dp: legacy.gas_config.gas_cost_scaler_dp,
+            gas_fee_receiver: legacy.gas_config.gas_fee_receiver,
+        },
+    };
+    cfg.gas_config.validate()?;
+
+    resize_account(
+        &cfg_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        DISCRIMINATOR_LEN + Cfg::INIT_SPACE,
+    )?;
+    cfg.try_serialize(&mut &mut cfg_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::MigrateCfg as MigrateCfgIx,
+        internal::Eip1559Config,
+        test_utils::{setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER},
+        ID,
+    };
+
+    /// Overwrites the config account with a legacy-layout config guarded by `guardian`.
+    fn write_legacy_cfg(svm: &mut litesvm::LiteSVM, cfg_pda: Pubkey, guardian: Pubkey) {
+        let legacy = LegacyCfg {
+            nonce: 42,
+            guardian,
+            eip1559: Eip1559 {
+                config: Eip1559Config::test_new(),
+                current_base_fee: 7,
+                current_window_gas_used: 1_000,
+                window_start_time: 1747440000,
+            },
+            gas_config: LegacyGasConfig {
+                min_gas_limit_per_message: 100_000,
+                max_gas_limit_per_message: 100_000_000,
+                gas_cost_scaler: 1_000_000,
+                gas_cost_scaler_dp: 10u64.pow(6),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            },
+        };
+
+        let mut data = Cfg::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), DISCRIMINATOR_LEN + LegacyCfg::INIT_SPACE);
+
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            cfg_pda,
+            Account {
+                lamports,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    fn migrate_cfg(
+        svm: &mut litesvm::LiteSVM,
+        upgrade_authority: &Keypair,
+        cfg_pda: Pubkey,
+        default_gas_limit_per_message: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (program_data, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateCfg {
+                upgrade_authority: upgrade_authority.pubkey(),
+                payer: upgrade_authority.pubkey(),
+                cfg: cfg_pda,
+                program_data,
+                program: ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateCfgIx {
+                default_gas_limit_per_message,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[upgrade_authority],
+            Message::new(&[ix], Some(&upgrade_authority.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_cfg_carries_state_into_current_layout() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        write_legacy_cfg(&mut svm, cfg_pda, guardian.pubkey());
+
+        migrate_cfg(&mut svm, &payer, cfg_pda, 200_000).expect("migration should succeed");
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        assert_eq!(cfg_account.data.len(), DISCRIMINATOR_LEN + Cfg::INIT_SPACE);
+        assert!(
+            cfg_account.lamports >= svm.minimum_balance_for_rent_exemption(cfg_account.data.len())
+        );
+
+        let cfg = Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap();
+        assert_eq!(cfg.nonce, 42);
+        assert_eq!(cfg.guardian, guardian.pubkey());
+        assert_eq!(cfg.pending_guardian, None);
+        assert_eq!(cfg.eip1559.config, Eip1559Config::test_new());
+        assert_eq!(cfg.eip1559.current_base_fee, 7);
+        assert_eq!(cfg.eip1559.current_window_gas_used, 1_000);
+        assert_eq!(cfg.eip1559.window_start_time, 1747440000);
+        assert_eq!(cfg.gas_config, GasConfig::test_new(TEST_GAS_FEE_RECEIVER));
+    }
+
+    #[test]
+    fn test_migrate_cfg_twice_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        write_legacy_cfg(&mut svm, cfg_pda, guardian.pubkey());
+        migrate_cfg(&mut svm, &payer, cfg_pda, 200_000).expect("migration should succeed");
+
+        let result = migrate_cfg(&mut svm, &payer, cfg_pda, 200_000);
+        assert!(result.is_err(), "Expected a second migration to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AlreadyMigrated"),
+            "Expected AlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_cfg_with_default_gas_limit_out_of_range_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        write_legacy_cfg(&mut svm, cfg_pda, guardian.pubkey());
+
+        // Legacy minimum is 100_000
+        let result = migrate_cfg(&mut svm, &payer, cfg_pda, 50_000);
+        assert!(result.is_err(), "Expected an out-of-range default to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidGasLimitRange"),
+            "Expected InvalidGasLimitRange error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_cfg_with_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            guardian,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        write_legacy_cfg(&mut svm, cfg_pda, guardian.pubkey());
+
+        let result = migrate_cfg(&mut svm, &guardian, cfg_pda, 200_000);
+        assert!(result.is_err(), "Expected the guardian to be rejected");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
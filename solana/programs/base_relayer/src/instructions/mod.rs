@@ -1,7 +1,11 @@
 pub mod config;
+pub mod get_base_fee;
 pub mod initialize;
+pub mod migrate_cfg;
 pub mod pay_for_relay;
 
 pub use config::*;
+pub use get_base_fee::*;
 pub use initialize::*;
+pub use migrate_cfg::*;
 pub use pay_for_relay::*;
@@ -1,17 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{CFG_SEED, DISCRIMINATOR_LEN, MTR_SEED},
+    constants::{CFG_SEED, DISCRIMINATOR_LEN, MTR_SEED, OUTGOING_MESSAGE_DISCRIMINATOR},
     internal::check_and_pay_for_gas,
     state::{Cfg, MessageToRelay},
     RelayerError,
 };
 
 #[derive(Accounts)]
-#[instruction(mtr_salt: [u8; 32])]
 pub struct PayForRelay<'info> {
     /// The account that pays for transaction fees and account creation.
     /// Must be mutable to deduct lamports for account rent and gas fees.
+    /// Must be the sender of `outgoing_message`.
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -26,7 +26,14 @@ pub struct PayForRelay<'info> {
     #[account(mut, address = cfg.gas_config.gas_fee_receiver @ RelayerError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
-    #[account(init, payer = payer, seeds = [MTR_SEED, mtr_salt.as_ref()], bump, space = DISCRIMINATOR_LEN + MessageToRelay::INIT_SPACE)]
+    /// The bridge `OutgoingMessage` account whose relay is paid for.
+    /// A forged account can only reserve the relay of its own address, never of a real message.
+    /// CHECK: The handler checks the account discriminator and that its sender is `payer`.
+    pub outgoing_message: UncheckedAccount<'info>,
+
+    /// The relay request for `outgoing_message`.
+    /// - Uses PDA with MTR_SEED and the outgoing message so each message can only be paid for once
+    #[account(init, payer = payer, seeds = [MTR_SEED, outgoing_message.key().as_ref()], bump, space = DISCRIMINATOR_LEN + MessageToRelay::INIT_SPACE)]
     pub message_to_relay: Account<'info, MessageToRelay>,
 
     /// System program required for creating new accounts.
@@ -34,13 +41,14 @@ pub struct PayForRelay<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn pay_for_relay_handler(
-    ctx: Context<PayForRelay>,
-    _mtr_salt: [u8; 32],
-    outgoing_message: Pubkey,
-    gas_limit: u64,
-) -> Result<()> {
-    check_and_pay_for_gas(
+pub fn pay_for_relay_handler(ctx: Context<PayForRelay>, gas_limit: u64) -> Result<()> {
+    require_keys_eq!(
+        outgoing_message_sender(&ctx.accounts.outgoing_message)?,
+        ctx.accounts.payer.key(),
+        RelayerError::PayerIsNotSender
+    );
+
+    let gas_limit = check_and_pay_for_gas(
         &ctx.accounts.system_program,
         &ctx.accounts.payer,
         &ctx.accounts.gas_fee_receiver,
@@ -50,7 +58,7 @@ pub fn pay_for_relay_handler(
 
     *ctx.accounts.message_to_relay = MessageToRelay {
         nonce: ctx.accounts.cfg.nonce,
-        outgoing_message,
+        outgoing_message: ctx.accounts.outgoing_message.key(),
         gas_limit,
     };
     ctx.accounts.cfg.nonce += 1;
@@ -58,15 +66,38 @@ pub fn pay_for_relay_handler(
     Ok(())
 }
 
+/// Reads the `sender` of a bridge `OutgoingMessage` account.
+///
+/// The account is laid out as `discriminator (8) | nonce (8) | sender (32) | message`.
+fn outgoing_message_sender(outgoing_message: &AccountInfo) -> Result<Pubkey> {
+    const SENDER_OFFSET: usize = DISCRIMINATOR_LEN + 8;
+
+    let data = outgoing_message.try_borrow_data()?;
+    require!(
+        data.len() >= SENDER_OFFSET + 32
+            && data[..DISCRIMINATOR_LEN] == OUTGOING_MESSAGE_DISCRIMINATOR,
+        RelayerError::InvalidOutgoingMessage
+    );
+
+    let mut sender = [0u8; 32];
+    sender.copy_from_slice(&data[SENDER_OFFSET..SENDER_OFFSET + 32]);
+    Ok(Pubkey::new_from_array(sender))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER};
-    use crate::{accounts, state::MessageToRelay};
+    use crate::test_utils::{
+        create_outgoing_message, setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER,
+    };
+    use crate::{accounts, internal::GasConfig, state::MessageToRelay};
     use anchor_lang::{
-        solana_program::{instruction::Instruction, system_program},
+        solana_program::{
+            instruction::Instruction, native_token::LAMPORTS_PER_SOL, system_program,
+        },
         InstructionData,
     };
+    use solana_keypair::Keypair;
     use solana_message::Message;
     use solana_signer::Signer;
     use solana_transaction::Transaction;
@@ -85,13 +116,12 @@ mod tests {
         svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
         let initial_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
 
-        let outgoing_message = Pubkey::new_unique();
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
         let gas_limit: u64 = 123_456;
 
-        // Derive PDA for message_to_relay using salt
-        let mtr_salt = Pubkey::new_unique().to_bytes();
+        // Derive PDA for message_to_relay from the outgoing message
         let (message_to_relay, _) = Pubkey::find_program_address(
-            &[crate::constants::MTR_SEED, mtr_salt.as_ref()],
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
             &crate::ID,
         );
 
@@ -99,6 +129,7 @@ mod tests {
             payer: payer_pk,
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            outgoing_message,
             message_to_relay,
             system_program: system_program::ID,
         }
@@ -107,12 +138,7 @@ mod tests {
         let ix = Instruction {
             program_id: crate::ID,
             accounts,
-            data: crate::instruction::PayForRelay {
-                mtr_salt,
-                outgoing_message,
-                gas_limit,
-            }
-            .data(),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
         };
 
         let tx = Transaction::new(
@@ -134,4 +160,268 @@ mod tests {
         let final_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
         assert_eq!(final_receiver_balance - initial_receiver_balance, gas_limit);
     }
+
+    #[test]
+    fn pay_for_relay_uses_default_gas_limit_when_zero() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+        let payer_pk = payer.pubkey();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        let initial_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
+        let (message_to_relay, _) = Pubkey::find_program_address(
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
+            &crate::ID,
+        );
+
+        let accounts = accounts::PayForRelay {
+            payer: payer_pk,
+            cfg: cfg_pda,
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            outgoing_message,
+            message_to_relay,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: crate::instruction::PayForRelay { gas_limit: 0 }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+            .expect("failed to send transaction");
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        let cfg = Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap();
+        let default_gas_limit = cfg.gas_config.default_gas_limit_per_message;
+
+        // The resolved default is recorded and charged
+        let msg_account = svm.get_account(&message_to_relay).unwrap();
+        let msg = MessageToRelay::try_deserialize(&mut &msg_account.data[..]).unwrap();
+        assert_eq!(msg.gas_limit, default_gas_limit);
+
+        let final_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert_eq!(
+            final_receiver_balance - initial_receiver_balance,
+            default_gas_limit
+        );
+    }
+
+    #[test]
+    fn pay_for_relay_rejects_duplicate_payment() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+        let payer_pk = payer.pubkey();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
+        let (message_to_relay, _) = Pubkey::find_program_address(
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
+            &crate::ID,
+        );
+
+        let pay_for_relay_ix = |gas_limit: u64| Instruction {
+            program_id: crate::ID,
+            accounts: accounts::PayForRelay {
+                payer: payer_pk,
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                outgoing_message,
+                message_to_relay,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
+        };
+
+        // First payment succeeds
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[pay_for_relay_ix(123_456)], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("failed to send transaction");
+
+        // Second payment for the same outgoing message is rejected
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[pay_for_relay_ix(654_321)], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected duplicate payment to fail");
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("already in use"),
+            "Expected account already in use error, got: {}",
+            error_string
+        );
+
+        // The original request is left untouched
+        let msg_account = svm.get_account(&message_to_relay).unwrap();
+        let msg = MessageToRelay::try_deserialize(&mut &msg_account.data[..]).unwrap();
+        assert_eq!(msg.gas_limit, 123_456);
+    }
+
+    #[test]
+    fn pay_for_relay_rejects_payer_other_than_sender() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+        let payer_pk = payer.pubkey();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let front_runner = Keypair::new();
+        svm.airdrop(&front_runner.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
+        let (message_to_relay, _) = Pubkey::find_program_address(
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
+            &crate::ID,
+        );
+
+        let pay_for_relay_ix = |payer: Pubkey, gas_limit: u64| Instruction {
+            program_id: crate::ID,
+            accounts: accounts::PayForRelay {
+                payer,
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                outgoing_message,
+                message_to_relay,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
+        };
+
+        // A third party cannot lock in an underfunded relay for someone else's message
+        let min_gas_limit = GasConfig::test_new(TEST_GAS_FEE_RECEIVER).min_gas_limit_per_message;
+        let tx = Transaction::new(
+            &[&front_runner],
+            Message::new(
+                &[pay_for_relay_ix(front_runner.pubkey(), min_gas_limit)],
+                Some(&front_runner.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected non-sender payment to fail");
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("PayerIsNotSender"),
+            "Expected PayerIsNotSender error, got: {}",
+            error_string
+        );
+        assert!(svm.get_account(&message_to_relay).is_none());
+
+        // The sender can still pay for its own message
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[pay_for_relay_ix(payer_pk, 123_456)], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("failed to send transaction");
+
+        let msg_account = svm.get_account(&message_to_relay).unwrap();
+        let msg = MessageToRelay::try_deserialize(&mut &msg_account.data[..]).unwrap();
+        assert_eq!(msg.gas_limit, 123_456);
+    }
+
+    fn send_pay_for_relay(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        cfg_pda: Pubkey,
+        gas_limit: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let outgoing_message = create_outgoing_message(svm, &payer.pubkey());
+        let (message_to_relay, _) = Pubkey::find_program_address(
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
+            &crate::ID,
+        );
+
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts: accounts::PayForRelay {
+                payer: payer.pubkey(),
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                outgoing_message,
+                message_to_relay,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn pay_for_relay_surfaces_specific_gas_limit_errors() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        let cfg = Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap();
+        let min_gas_limit = cfg.gas_config.min_gas_limit_per_message;
+        let max_gas_limit = cfg.gas_config.max_gas_limit_per_message;
+
+        // Below the minimum: the client should raise the gas limit
+        let result = send_pay_for_relay(&mut svm, &payer, cfg_pda, min_gas_limit - 1);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("GasLimitTooLow"),
+            "Expected GasLimitTooLow error, got: {}",
+            error_string
+        );
+
+        // Above the maximum: the client should lower the gas limit
+        let result = send_pay_for_relay(&mut svm, &payer, cfg_pda, max_gas_limit + 1);
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("GasLimitExceeded"),
+            "Expected GasLimitExceeded error, got: {}",
+            error_string
+        );
+    }
 }
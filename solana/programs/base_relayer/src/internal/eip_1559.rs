@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::SCALE, internal::fixed_pow};
+use crate::{constants::SCALE, internal::fixed_pow, RelayerError};
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct Eip1559 {
@@ -25,6 +25,17 @@ pub struct Eip1559Config {
     pub minimum_base_fee: u64,
 }
 
+impl Eip1559Config {
+    pub fn validate(&self) -> Result<()> {
+        require!(self.denominator > 0, RelayerError::InvalidDenominator);
+        require!(
+            self.window_duration_seconds > 0,
+            RelayerError::InvalidWindowDurationSeconds
+        );
+        Ok(())
+    }
+}
+
 impl Eip1559 {
     /// Refresh the base fee if window has expired, reset window tracking
     /// Handles multiple expired windows by processing each empty window
@@ -8,6 +8,8 @@ pub struct GasConfig {
     pub min_gas_limit_per_message: u64,
     /// Maximum gas limit per cross-chain message
     pub max_gas_limit_per_message: u64,
+    /// Gas limit applied when a relay request passes a gas limit of zero
+    pub default_gas_limit_per_message: u64,
     /// Scaling factor for gas cost calculations
     pub gas_cost_scaler: u64,
     /// Decimal precision for gas cost calculations
@@ -16,15 +18,59 @@ pub struct GasConfig {
     pub gas_fee_receiver: Pubkey,
 }
 
+impl GasConfig {
+    /// Ensures the scaler precision is non-zero and that the default gas limit lies within
+    /// `[min_gas_limit_per_message, max_gas_limit_per_message]`.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.gas_cost_scaler_dp > 0,
+            RelayerError::InvalidGasCostScalerDp
+        );
+        require!(
+            self.min_gas_limit_per_message <= self.default_gas_limit_per_message
+                && self.default_gas_limit_per_message <= self.max_gas_limit_per_message,
+            RelayerError::InvalidGasLimitRange
+        );
+        Ok(())
+    }
+
+    /// Lamports charged for `gas_limit` gas at the given `base_fee`.
+    ///
+    /// Intermediate products are computed in `u128`; a cost that does not fit back into `u64`
+    /// fails with `GasCostOverflow`.
+    pub fn gas_cost(&self, gas_limit: u64, base_fee: u64) -> Result<u64> {
+        let gas_cost = (gas_limit as u128)
+            .checked_mul(base_fee as u128)
+            .and_then(|cost| cost.checked_mul(self.gas_cost_scaler as u128))
+            .ok_or(RelayerError::GasCostOverflow)?
+            / self.gas_cost_scaler_dp as u128;
+        Ok(u64::try_from(gas_cost).map_err(|_| RelayerError::GasCostOverflow)?)
+    }
+}
+
+/// Resolves, validates and pays for the requested gas limit.
+/// A `gas_limit` of zero is substituted with the configured default.
+/// Returns the gas limit that was actually charged.
 pub fn check_and_pay_for_gas<'info>(
     system_program: &Program<'info, System>,
     payer: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
     cfg: &mut Cfg,
     gas_limit: u64,
-) -> Result<()> {
+) -> Result<u64> {
+    let gas_limit = resolve_gas_limit(gas_limit, cfg);
     check_gas_limit(gas_limit, cfg)?;
-    pay_for_gas(system_program, payer, gas_fee_receiver, cfg, gas_limit)
+    pay_for_gas(system_program, payer, gas_fee_receiver, cfg, gas_limit)?;
+
+    Ok(gas_limit)
+}
+
+fn resolve_gas_limit(gas_limit: u64, cfg: &Cfg) -> u64 {
+    if gas_limit == 0 {
+        cfg.gas_config.default_gas_limit_per_message
+    } else {
+        gas_limit
+    }
 }
 
 fn check_gas_limit(gas_limit: u64, cfg: &Cfg) -> Result<()> {
@@ -54,8 +100,7 @@ fn pay_for_gas<'info>(
     // Record gas usage for this transaction
     cfg.eip1559.add_gas_usage(gas_limit);
 
-    let gas_cost =
-        gas_limit * base_fee * cfg.gas_config.gas_cost_scaler / cfg.gas_config.gas_cost_scaler_dp;
+    let gas_cost = cfg.gas_config.gas_cost(gas_limit, base_fee)?;
 
     let cpi_ctx = CpiContext::new(
         system_program.to_account_info(),
@@ -75,7 +120,10 @@ mod tests {
     use super::*;
     use crate::internal::{Eip1559, Eip1559Config};
     use crate::state::Cfg;
-    use crate::test_utils::{mock_clock, setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER};
+    use crate::test_utils::{
+        create_outgoing_message, mock_clock, setup_relayer, SetupRelayerResult,
+        TEST_GAS_FEE_RECEIVER,
+    };
     use crate::{accounts, instruction};
     use anchor_lang::solana_program::{instruction::Instruction, system_program};
     use anchor_lang::InstructionData;
@@ -101,6 +149,7 @@ mod tests {
     fn check_gas_limit_allows_equal_limit() {
         let cfg = Cfg {
             guardian: Pubkey::new_unique(),
+            pending_guardian: None,
             eip1559: new_eip(),
             gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
             nonce: 0,
@@ -114,6 +163,7 @@ mod tests {
     fn check_gas_limit_errors_above_limit() {
         let mut cfg = Cfg {
             guardian: Pubkey::new_unique(),
+            pending_guardian: None,
             eip1559: new_eip(),
             gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
             nonce: 0,
@@ -124,6 +174,50 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn resolve_gas_limit_uses_default_for_zero() {
+        let cfg = Cfg {
+            guardian: Pubkey::new_unique(),
+            pending_guardian: None,
+            eip1559: new_eip(),
+            gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
+            nonce: 0,
+        };
+
+        let resolved = super::resolve_gas_limit(0, &cfg);
+        assert_eq!(resolved, cfg.gas_config.default_gas_limit_per_message);
+    }
+
+    #[test]
+    fn resolve_gas_limit_keeps_explicit_value() {
+        let cfg = Cfg {
+            guardian: Pubkey::new_unique(),
+            pending_guardian: None,
+            eip1559: new_eip(),
+            gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
+            nonce: 0,
+        };
+
+        let resolved = super::resolve_gas_limit(250_000, &cfg);
+        assert_eq!(resolved, 250_000);
+    }
+
+    #[test]
+    fn check_gas_limit_rejects_default_below_minimum() {
+        let mut cfg = Cfg {
+            guardian: Pubkey::new_unique(),
+            pending_guardian: None,
+            eip1559: new_eip(),
+            gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
+            nonce: 0,
+        };
+        cfg.gas_config.default_gas_limit_per_message = cfg.gas_config.min_gas_limit_per_message - 1;
+
+        let resolved = super::resolve_gas_limit(0, &cfg);
+        let res = super::check_gas_limit(resolved, &cfg);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn check_and_pay_transfers_scaled_amount() {
         let SetupRelayerResult {
@@ -167,16 +261,16 @@ mod tests {
         svm.send_transaction(tx).unwrap();
 
         // Now pay for relay with gas_limit=123; base_fee=1 => transfer=246
-        let outgoing_message = Pubkey::new_unique();
-        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
         let (message_to_relay, _) = Pubkey::find_program_address(
-            &[crate::constants::MTR_SEED, mtr_salt.as_ref()],
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
             &crate::ID,
         );
         let accounts = accounts::PayForRelay {
             payer: payer_pk,
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            outgoing_message,
             message_to_relay,
             system_program: system_program::ID,
         }
@@ -186,12 +280,7 @@ mod tests {
         let ix = Instruction {
             program_id: crate::ID,
             accounts,
-            data: crate::instruction::PayForRelay {
-                mtr_salt,
-                outgoing_message,
-                gas_limit,
-            }
-            .data(),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
         };
 
         let tx = Transaction::new(
@@ -267,16 +356,16 @@ mod tests {
         mock_clock(&mut svm, start_time + 1);
 
         let gas_limit = 100_000u64;
-        let outgoing_message = Pubkey::new_unique();
-        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let outgoing_message = create_outgoing_message(&mut svm, &payer_pk);
         let (message_to_relay, _) = Pubkey::find_program_address(
-            &[crate::constants::MTR_SEED, mtr_salt.as_ref()],
+            &[crate::constants::MTR_SEED, outgoing_message.as_ref()],
             &crate::ID,
         );
         let accounts = accounts::PayForRelay {
             payer: payer_pk,
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            outgoing_message,
             message_to_relay,
             system_program: system_program::ID,
         }
@@ -285,12 +374,7 @@ mod tests {
         let ix = Instruction {
             program_id: crate::ID,
             accounts,
-            data: crate::instruction::PayForRelay {
-                mtr_salt,
-                outgoing_message,
-                gas_limit,
-            }
-            .data(),
+            data: crate::instruction::PayForRelay { gas_limit }.data(),
         };
 
         let tx = Transaction::new(
@@ -310,4 +394,48 @@ mod tests {
         assert_eq!(updated.eip1559.current_window_gas_used, gas_limit);
         assert_eq!(updated.eip1559.window_start_time, start_time + 1);
     }
+
+    #[test]
+    fn gas_cost_large_multiplicands_do_not_overflow_intermediates() {
+        let mut gas_config = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        gas_config.gas_cost_scaler = 1_000_000_000;
+        gas_config.gas_cost_scaler_dp = 1_000_000_000;
+        let gas_limit = gas_config.max_gas_limit_per_message;
+
+        // gas_limit * base_fee * gas_cost_scaler = 1e8 * 1e9 * 1e9 overflows u64, but the
+        // scaled-down cost fits
+        assert_eq!(
+            gas_config.gas_cost(gas_limit, 1_000_000_000).unwrap(),
+            100_000_000_000_000_000
+        );
+
+        // The scaled-down cost itself no longer fits
+        assert_eq!(
+            gas_config.gas_cost(gas_limit, u64::MAX).unwrap_err(),
+            RelayerError::GasCostOverflow.into()
+        );
+    }
+
+    #[test]
+    fn validate_accepts_test_config() {
+        assert!(GasConfig::test_new(TEST_GAS_FEE_RECEIVER)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_gas_config() {
+        let mut zero_dp = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        zero_dp.gas_cost_scaler_dp = 0;
+        assert!(zero_dp.validate().is_err());
+
+        let mut inverted_range = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        inverted_range.max_gas_limit_per_message = inverted_range.min_gas_limit_per_message - 1;
+        assert!(inverted_range.validate().is_err());
+
+        let mut default_above_max = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        default_above_max.default_gas_limit_per_message =
+            default_above_max.max_gas_limit_per_message + 1;
+        assert!(default_above_max.validate().is_err());
+    }
 }
@@ -1,7 +1,9 @@
 pub mod eip_1559;
 pub mod gas_config;
 pub mod math;
+pub mod resize;
 
 pub use eip_1559::*;
 pub use gas_config::*;
 pub use math::*;
+pub use resize::*;
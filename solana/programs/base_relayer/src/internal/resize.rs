@@ -0,0 +1,32 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+/// Grows `account` to `new_len` bytes, topping its lamports up to the new rent-exempt minimum
+/// from `payer`.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let missing_lamports = required_lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    account.realloc(new_len, true)?;
+
+    Ok(())
+}
@@ -4,11 +4,13 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod events;
 mod instructions;
 mod internal;
 mod state;
 
 pub use errors::*;
+pub use events::*;
 use instructions::*;
 use internal::*;
 use state::*;
@@ -44,6 +46,19 @@ pub mod base_relayer {
         initialize_handler(ctx, guardian, eip1559_config, gas_config)
     }
 
+    /// Migrates a config account created by a previous version of the program to the current
+    /// layout. Must be called once, right after upgrading a deployed program, before any other
+    /// instruction can load the config. Only the upgrade authority can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx`                           - The context containing the legacy `cfg` PDA and the
+    ///                                     upgrade authority accounts.
+    /// * `default_gas_limit_per_message` - The gas limit applied to relay requests that pass
+    ///                                     zero, which the legacy layout does not record.
+    pub fn migrate_cfg(ctx: Context<MigrateCfg>, default_gas_limit_per_message: u64) -> Result<()> {
+        migrate_cfg_handler(ctx, default_gas_limit_per_message)
+    }
+
     /// Updates the EIP1559 configuration.
     /// Only the recorded `guardian` may call this instruction.
     ///
@@ -69,44 +84,65 @@ pub mod base_relayer {
         set_gas_config_handler(ctx, gas_config)
     }
 
-    /// Updates the configured guardian.
+    /// Proposes a new guardian. The role is only handed over once the proposed key accepts it.
+    /// Only the current `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the `cfg` PDA and the `guardian` signer.
+    /// * `new_guardian` - The proposed guardian, recorded as `pending_guardian`.
+    pub fn propose_guardian(ctx: Context<SetConfig>, new_guardian: Pubkey) -> Result<()> {
+        propose_guardian_handler(ctx, new_guardian)
+    }
+
+    /// Accepts a pending guardian proposal, making the signer the guardian.
+    /// Only the recorded `pending_guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA and the `new_guardian` signer.
+    pub fn accept_guardian(ctx: Context<AcceptGuardian>) -> Result<()> {
+        accept_guardian_handler(ctx)
+    }
+
+    /// Cancels a pending guardian proposal.
     /// Only the current `guardian` may call this instruction.
     ///
     /// # Arguments
     /// * `ctx` - The context containing the `cfg` PDA and the `guardian` signer.
-    ///           Authorization is enforced via an Anchor `has_one` constraint.
-    /// * `cfg` - The new guardian with permissions to update other configs.
-    pub fn set_guardian(ctx: Context<SetConfig>, new_guardian: Pubkey) -> Result<()> {
-        set_guardian_handler(ctx, new_guardian)
+    pub fn cancel_guardian_transfer(ctx: Context<SetConfig>) -> Result<()> {
+        cancel_guardian_transfer_handler(ctx)
     }
 
     /// Pays the gas cost for relaying a message to Base and records the request.
     /// Transfers lamports from `payer` to `cfg.gas_config.gas_fee_receiver` using
     /// the current EIP-1559 pricing and the provided `gas_limit`. Also initializes
     /// a new `MessageToRelay` account containing the `outgoing_message` and
-    /// `gas_limit`. The payer is the sole authorization; the guardian is not
-    /// required for this operation.
+    /// `gas_limit`. Only the sender of the outgoing message may pay for its relay;
+    /// the guardian is not required for this operation.
     ///
     /// # Arguments
-    /// * `ctx`              - The context including `payer`, mutable `cfg` PDA
-    ///                         (for fee window updates), `gas_fee_receiver` (must
-    ///                         match configured receiver), and a new
-    ///                         `message_to_relay` account.
-    /// * `mtr_salt`         - 32-byte salt used to derive the `message_to_relay`
-    ///                         PDA address, enabling unique messages per request.
-    /// * `outgoing_message` - The Base-side message identifier to be executed.
-    /// * `gas_limit`        - Maximum gas units to budget for execution on Base.
+    /// * `ctx`       - The context including `payer` (must be the message sender),
+    ///                 mutable `cfg` PDA (for fee window updates), `gas_fee_receiver`
+    ///                 (must match configured receiver), the bridge `outgoing_message`
+    ///                 account, and a new `message_to_relay` account seeded by it so
+    ///                 each message can only be paid for once.
+    /// * `gas_limit` - Maximum gas units to budget for execution on Base.
+    ///                 Passing zero uses the configured default gas limit.
     ///
     /// # Errors
     /// Returns an error if the `gas_fee_receiver` does not match the configured
-    /// receiver or if the payer lacks sufficient lamports to cover the computed
-    /// fee.
-    pub fn pay_for_relay(
-        ctx: Context<PayForRelay>,
-        mtr_salt: [u8; 32],
-        outgoing_message: Pubkey,
-        gas_limit: u64,
-    ) -> Result<()> {
-        pay_for_relay_handler(ctx, mtr_salt, outgoing_message, gas_limit)
+    /// receiver, if `payer` is not the sender of `outgoing_message`, if the relay for
+    /// `outgoing_message` was already paid for, or if the payer lacks sufficient
+    /// lamports to cover the computed fee.
+    pub fn pay_for_relay(ctx: Context<PayForRelay>, gas_limit: u64) -> Result<()> {
+        pay_for_relay_handler(ctx, gas_limit)
+    }
+
+    /// Returns the EIP-1559 base fee refreshed for the current timestamp, without persisting the
+    /// refresh. The result is written to the transaction return data as a `BaseFee`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA.
+    pub fn get_base_fee(ctx: Context<GetBaseFee>) -> Result<BaseFee> {
+        get_base_fee_handler(ctx)
     }
 }
@@ -9,6 +9,8 @@ pub struct Cfg {
     pub nonce: u64,
     /// Guardian pubkey authorized to update configuration
     pub guardian: Pubkey,
+    /// Guardian proposed by the current guardian, pending acceptance
+    pub pending_guardian: Option<Pubkey>,
     /// EIP-1559 state and configuration for dynamic pricing.
     pub eip1559: Eip1559,
     /// Gas configuration
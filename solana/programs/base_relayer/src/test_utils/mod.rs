@@ -15,7 +15,7 @@ use solana_transaction::Transaction;
 
 use crate::{
     accounts,
-    constants::CFG_SEED,
+    constants::{CFG_SEED, OUTGOING_MESSAGE_DISCRIMINATOR},
     instruction::Initialize,
     internal::{Eip1559Config, GasConfig},
     ID,
@@ -39,6 +39,7 @@ impl GasConfig {
         Self {
             min_gas_limit_per_message: 100_000,
             max_gas_limit_per_message: 100_000_000,
+            default_gas_limit_per_message: 200_000,
             gas_cost_scaler: 1_000_000,
             gas_cost_scaler_dp: 10u64.pow(6),
             gas_fee_receiver,
@@ -214,3 +215,28 @@ pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     clock.unix_timestamp = timestamp;
     svm.set_sysvar::<Clock>(&clock);
 }
+
+/// Writes a bridge `OutgoingMessage` account sent by `sender` and returns its address.
+pub fn create_outgoing_message(svm: &mut LiteSVM, sender: &Pubkey) -> Pubkey {
+    let outgoing_message = Pubkey::new_unique();
+
+    // discriminator | nonce | sender | message (left zeroed)
+    let mut data = OUTGOING_MESSAGE_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.extend_from_slice(sender.as_ref());
+    data.extend_from_slice(&[0u8; 64]);
+
+    svm.set_account(
+        outgoing_message,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    outgoing_message
+}
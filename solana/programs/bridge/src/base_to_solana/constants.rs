@@ -1,10 +1,20 @@
 use anchor_lang::prelude::*;
 
+#[constant]
+pub const INCOMING_MESSAGE_VERSION: u8 = 2;
 #[constant]
 pub const INCOMING_MESSAGE_SEED: &[u8] = b"incoming_message";
 #[constant]
 pub const OUTPUT_ROOT_SEED: &[u8] = b"output_root";
 #[constant]
+pub const RELAY_CURSOR_SEED: &[u8] = b"relay_cursor";
+#[constant]
+pub const MAX_OUTPUT_ROOT_BATCH_SIZE: u8 = 16;
+#[constant]
+pub const OUTPUT_ROOT_CLOSE_DELAY_SECONDS: u64 = 604_800;
+#[constant]
+pub const OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS: u64 = 100;
+#[constant]
 pub const BRIDGE_CPI_AUTHORITY_SEED: &[u8] = b"bridge_cpi_authority";
 #[constant]
 pub const PARTNER_SIGNERS_ACCOUNT_SEED: &[u8] = b"signers";
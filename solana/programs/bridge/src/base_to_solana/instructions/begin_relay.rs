@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{INCOMING_MESSAGE_VERSION, RELAY_CURSOR_SEED},
+    ensure_no_duplicate_ixs, ensure_no_protected_mutation, finalize_transfer,
+    state::{IncomingMessage, RelayCursor},
+    Message,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_RELAY};
+use crate::BridgeError;
+
+/// Accounts struct for the `begin_relay` instruction, the first phase of relaying a message
+/// whose instructions do not fit in a single transaction.
+///
+/// The `guardian` slot is always part of the account list: when no co-signature is provided, pass
+/// the bridge program ID in its place. The transfer accounts are passed as `remaining_accounts`
+/// and start right after the system program.
+#[derive(Accounts)]
+pub struct BeginRelay<'info> {
+    /// The account that pays for the relay cursor account creation.
+    /// - Refunded the cursor rent by the `continue_relay` call that executes the last slice
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The incoming message account to relay.
+    /// - Marked as executed here so it can no longer be relayed through `relay_message`
+    #[account(mut)]
+    pub message: Account<'info, IncomingMessage>,
+
+    /// The main bridge state account used to check pause status
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Mutable to track the SOL released from the vault
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Optional guardian co-signer, required for SOL finalizations above
+    /// `bridge.protocol_config.large_finalize_threshold`.
+    /// - Pass the bridge program ID when the guardian does not co-sign
+    #[account(address = bridge.guardian @ BridgeError::GuardianApprovalRequired)]
+    pub guardian: Option<Signer<'info>>,
+
+    /// The execution cursor advanced by `continue_relay`.
+    /// - Uses PDA with RELAY_CURSOR_SEED and the message address, so a message can only be
+    ///   begun once
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + RelayCursor::INIT_SPACE,
+        seeds = [RELAY_CURSOR_SEED, message.key().as_ref()],
+        bump
+    )]
+    pub relay_cursor: Account<'info, RelayCursor>,
+
+    /// System program required for creating the relay cursor account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn begin_relay_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, BeginRelay<'info>>,
+) -> Result<()> {
+    // Check if bridge is paused
+    ctx.accounts.bridge.require_not_paused(PAUSE_RELAY)?;
+
+    require!(
+        ctx.accounts.message.version == INCOMING_MESSAGE_VERSION,
+        BridgeError::UnsupportedMessageVersion
+    );
+
+    require!(!ctx.accounts.message.executed, BridgeError::AlreadyExecuted);
+
+    let nonce = ctx.accounts.message.nonce;
+    ctx.accounts.bridge.record_relayed_nonce(nonce)?;
+
+    // Give proven messages time to settle before anyone can relay them
+    ctx.accounts.message.validate_relay_delay(
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .min_prove_to_relay_delay_seconds,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let message = ctx.accounts.message.message.clone();
+    let (transfer, ixs) = match message {
+        Message::Call(ixs) => (None, ixs),
+        Message::Transfer { transfer, ixs } => (Some(transfer), ixs),
+    };
+
+    // A cursor over zero instructions could never be completed by `continue_relay`, stranding its
+    // rent; such messages fit in a single `relay_message` call
+    require!(!ixs.is_empty(), BridgeError::NoInstructionsToRelay);
+
+    if ctx.accounts.bridge.protocol_config.reject_duplicate_ixs {
+        ensure_no_duplicate_ixs(&ixs)?;
+    }
+
+    // Reject the whole message up front rather than failing on a later slice
+    let protected_accounts = [
+        ctx.accounts.bridge.key(),
+        ctx.accounts.message.key(),
+        ctx.accounts.relay_cursor.key(),
+    ];
+    for ix in &ixs {
+        ensure_no_protected_mutation(ix, ctx.program_id, &protected_accounts)?;
+    }
+
+    // The transfer is finalized in this phase; `continue_relay` only executes instructions
+    if let Some(transfer) = transfer {
+        finalize_transfer(
+            &mut ctx.accounts.bridge,
+            ctx.accounts.guardian.is_some(),
+            transfer,
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    ctx.accounts.message.executed = true;
+
+    *ctx.accounts.relay_cursor = RelayCursor {
+        message: ctx.accounts.message.key(),
+        payer: ctx.accounts.payer.key(),
+        next_ix: 0,
+        total_ixs: ixs.len() as u32,
+    };
+
+    Ok(())
+}
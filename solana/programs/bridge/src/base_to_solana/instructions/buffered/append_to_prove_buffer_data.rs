@@ -22,6 +22,10 @@ pub fn append_to_prove_buffer_data_handler(
     chunk: Vec<u8>,
 ) -> Result<()> {
     let buf = &mut ctx.accounts.prove_buffer;
+    require!(
+        (buf.data.len() + chunk.len()) as u64 <= buf.max_data_len,
+        BridgeError::ProveBufferCapacityExceeded
+    );
     buf.data.extend_from_slice(&chunk);
     Ok(())
 }
@@ -164,4 +168,46 @@ mod tests {
         let err = format!("{:?}", result.unwrap_err());
         assert!(err.contains("Unauthorized"), "Unexpected error: {}", err);
     }
+
+    #[test]
+    fn test_append_to_prove_buffer_data_over_capacity() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        // Spare proof capacity must not be usable for data
+        let prove_buffer = Keypair::new();
+        setup_prove_buffer(&mut svm, &owner, &prove_buffer, 16, 8);
+
+        let accounts = accounts::AppendToProveBufferData {
+            owner: owner.pubkey(),
+            prove_buffer: prove_buffer.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: AppendToProveBufferDataIx {
+                chunk: vec![0xABu8; 17],
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected append over capacity to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("ProveBufferCapacityExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+    }
 }
@@ -22,6 +22,10 @@ pub fn append_to_prove_buffer_proof_handler(
     proof_chunk: Vec<[u8; 32]>,
 ) -> Result<()> {
     let buf = &mut ctx.accounts.prove_buffer;
+    require!(
+        (buf.proof.len() + proof_chunk.len()) as u64 <= buf.max_proof_len,
+        BridgeError::ProveBufferCapacityExceeded
+    );
     buf.proof.extend_from_slice(&proof_chunk);
     Ok(())
 }
@@ -167,4 +171,76 @@ mod tests {
         let err = format!("{:?}", result.unwrap_err());
         assert!(err.contains("Unauthorized"), "Unexpected error: {}", err);
     }
+
+    fn append_proof(
+        svm: &mut litesvm::LiteSVM,
+        owner: &Keypair,
+        prove_buffer: &Keypair,
+        proof_chunk: Vec<[u8; 32]>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let accounts = accounts::AppendToProveBufferProof {
+            owner: owner.pubkey(),
+            prove_buffer: prove_buffer.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: AppendToProveBufferProofIx { proof_chunk }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_to_prove_buffer_proof_up_to_capacity() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let prove_buffer = Keypair::new();
+        setup_prove_buffer(&mut svm, &owner, &prove_buffer, 256, 4);
+
+        append_proof(&mut svm, &owner, &prove_buffer, vec![[1u8; 32]; 3])
+            .expect("Failed to append first proof chunk");
+        append_proof(&mut svm, &owner, &prove_buffer, vec![[2u8; 32]])
+            .expect("Failed to append proof chunk filling capacity");
+
+        let acct = svm.get_account(&prove_buffer.pubkey()).unwrap();
+        let buf = ProveBuffer::try_deserialize(&mut &acct.data[..]).unwrap();
+        assert_eq!(buf.proof.len(), 4);
+    }
+
+    #[test]
+    fn test_append_to_prove_buffer_proof_over_capacity() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        // Plenty of data capacity so the proof overflow is not absorbed by spare account space
+        let prove_buffer = Keypair::new();
+        setup_prove_buffer(&mut svm, &owner, &prove_buffer, 1024, 4);
+
+        append_proof(&mut svm, &owner, &prove_buffer, vec![[1u8; 32]; 4])
+            .expect("Failed to append proof chunk filling capacity");
+
+        let result = append_proof(&mut svm, &owner, &prove_buffer, vec![[2u8; 32]]);
+        assert!(result.is_err(), "Expected append over capacity to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("ProveBufferCapacityExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+    }
 }
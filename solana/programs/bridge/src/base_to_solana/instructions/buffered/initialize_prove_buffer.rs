@@ -3,11 +3,14 @@ use anchor_lang::prelude::*;
 use crate::{
     base_to_solana::ProveBuffer,
     common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    BridgeError,
 };
 
 /// Accounts for initializing a `ProveBuffer` which can hold large prove inputs.
+/// Allocation is sized by `max_data_len` and `max_proof_len`; the latter is capped by
+/// `bridge.buffer_config.max_prove_buffer_proof_len`.
 #[derive(Accounts)]
-#[instruction(_max_data_len: u64, _max_proof_len: u64)]
+#[instruction(max_data_len: u64, max_proof_len: u64)]
 pub struct InitializeProveBuffer<'info> {
     /// Payer funds the buffer account creation
     #[account(mut)]
@@ -24,7 +27,8 @@ pub struct InitializeProveBuffer<'info> {
     #[account(
         init,
         payer = payer,
-        space = DISCRIMINATOR_LEN + ProveBuffer::space(_max_data_len as usize, _max_proof_len as usize),
+        space = DISCRIMINATOR_LEN + ProveBuffer::space(max_data_len as usize, max_proof_len as usize),
+        constraint = bridge.buffer_config.max_prove_buffer_proof_len >= max_proof_len @ BridgeError::ProveBufferMaxProofLenExceeded,
     )]
     pub prove_buffer: Account<'info, ProveBuffer>,
 
@@ -33,11 +37,13 @@ pub struct InitializeProveBuffer<'info> {
 
 pub fn initialize_prove_buffer_handler(
     ctx: Context<InitializeProveBuffer>,
-    _max_data_len: u64,
-    _max_proof_len: u64,
+    max_data_len: u64,
+    max_proof_len: u64,
 ) -> Result<()> {
     *ctx.accounts.prove_buffer = ProveBuffer {
         owner: ctx.accounts.payer.key(),
+        max_data_len,
+        max_proof_len,
         data: Vec::new(),
         proof: Vec::new(),
     };
@@ -57,6 +63,7 @@ mod tests {
 
     use crate::{
         accounts,
+        common::bridge::BufferConfig,
         instruction::InitializeProveBuffer as InitializeProveBufferIx,
         test_utils::{setup_bridge, SetupBridgeResult},
         ID,
@@ -114,6 +121,8 @@ mod tests {
 
         let buf = ProveBuffer::try_deserialize(&mut &acct.data[..]).unwrap();
         assert_eq!(buf.owner, payer.pubkey());
+        assert_eq!(buf.max_data_len, max_data_len);
+        assert_eq!(buf.max_proof_len, max_proof_len);
         assert!(buf.data.is_empty());
         assert!(buf.proof.is_empty());
     }
@@ -169,4 +178,53 @@ mod tests {
             + ProveBuffer::space(max_data_len as usize, max_proof_len as usize);
         assert_eq!(acct.data.len(), expected);
     }
+
+    #[test]
+    fn test_initialize_prove_buffer_rejects_proof_len_above_max() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let prove_buffer = Keypair::new();
+
+        // One node more than the configured maximum
+        let max_data_len: u64 = 512;
+        let max_proof_len: u64 = BufferConfig::test_new().max_prove_buffer_proof_len + 1;
+
+        let accounts = accounts::InitializeProveBuffer {
+            payer: payer.pubkey(),
+            bridge: bridge_pda,
+            prove_buffer: prove_buffer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: InitializeProveBufferIx {
+                max_data_len,
+                max_proof_len,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &prove_buffer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected oversized proof capacity to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("ProveBufferMaxProofLenExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+    }
 }
@@ -3,10 +3,12 @@ use anchor_lang::{prelude::*, solana_program::keccak};
 use crate::BridgeError;
 use crate::{
     base_to_solana::{
-        constants::INCOMING_MESSAGE_SEED, internal::mmr, state::IncomingMessage, Message,
-        OutputRoot, ProveBuffer,
+        constants::{INCOMING_MESSAGE_SEED, INCOMING_MESSAGE_VERSION},
+        internal::mmr,
+        state::IncomingMessage,
+        Message, OutputRoot, ProveBuffer,
     },
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_PROVE},
 };
 
 /// Buffered variant of `prove_message` that reads data/proof from a `ProveBuffer` and closes it.
@@ -56,7 +58,7 @@ pub fn prove_message_buffered_handler(
     message_hash: [u8; 32],
 ) -> Result<()> {
     // Pause
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_PROVE)?;
 
     // Verify hash
     let data = &ctx.accounts.prove_buffer.data;
@@ -66,6 +68,28 @@ pub fn prove_message_buffered_handler(
         BridgeError::InvalidMessageHash
     );
 
+    // Root must be within the registered block range
+    ctx.accounts
+        .output_root
+        .validate_registered(ctx.accounts.bridge.base_block_number)?;
+
+    // Roots far behind the latest block must have been co-signed again by the current oracles
+    ctx.accounts.output_root.validate_revalidated(
+        ctx.accounts.bridge.base_block_number,
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .revalidate_old_roots_after_blocks,
+        ctx.accounts.bridge.base_oracle_epoch,
+    )?;
+
+    // Root freshness
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.output_root.validate_age(
+        ctx.accounts.bridge.protocol_config.max_root_age_seconds,
+        current_timestamp,
+    )?;
+
     // Verify proof
     mmr::verify_proof(
         &ctx.accounts.output_root.root,
@@ -78,9 +102,12 @@ pub fn prove_message_buffered_handler(
     // Deserialize and save
     let message_enum = Message::try_from_slice(data)?;
     *ctx.accounts.message = IncomingMessage {
+        version: INCOMING_MESSAGE_VERSION,
+        nonce,
         executed: false,
         sender,
         message: message_enum,
+        proven_at: current_timestamp,
     };
 
     Ok(())
@@ -102,7 +129,6 @@ mod tests {
     use anchor_lang::solana_program::keccak::hash as keccak_hash;
     use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
     use litesvm::LiteSVM;
-    use solana_account::Account as SvmAccount;
     use solana_keypair::Keypair;
     use solana_message::Message as SolMessage;
     use solana_signer::Signer as _;
@@ -116,35 +142,12 @@ mod tests {
             AppendToProveBufferData, AppendToProveBufferProof, InitializeProveBuffer,
             ProveMessageBuffered as ProveMessageBufferedIx,
         },
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{
+            create_output_root_account, mock_clock, setup_bridge, update_bridge, SetupBridgeResult,
+        },
         ID,
     };
 
-    fn create_output_root_account(
-        svm: &mut LiteSVM,
-        root_pk: Pubkey,
-        root: [u8; 32],
-        total_leaf_count: u64,
-    ) {
-        let output_root = crate::base_to_solana::state::OutputRoot {
-            root,
-            total_leaf_count,
-        };
-        let mut data = Vec::new();
-        output_root.try_serialize(&mut data).unwrap();
-        svm.set_account(
-            root_pk,
-            SvmAccount {
-                lamports: 1_000_000,
-                data,
-                owner: ID,
-                executable: false,
-                rent_epoch: 0,
-            },
-        )
-        .unwrap();
-    }
-
     fn compute_message_hash(nonce: u64, sender: [u8; 20], data: &[u8]) -> [u8; 32] {
         let mut v = Vec::new();
         v.extend_from_slice(&nonce.to_be_bytes());
@@ -241,8 +244,7 @@ mod tests {
         let nonce = 0u64;
         let sender = [7u8; 20];
         let message_hash = compute_message_hash(nonce, sender, &message_bytes);
-        let output_root_pk = Keypair::new().pubkey();
-        create_output_root_account(svm, output_root_pk, message_hash, 1);
+        let output_root_pk = create_output_root_account(svm, message_hash, 1);
 
         (
             message_hash,
@@ -442,8 +444,7 @@ mod tests {
         svm.send_transaction(append_tx).unwrap();
 
         // Create OutputRoot (values won't matter; hash check fails first)
-        let output_root_pk = Keypair::new().pubkey();
-        create_output_root_account(&mut svm, output_root_pk, [9u8; 32], 1);
+        let output_root_pk = create_output_root_account(&mut svm, [9u8; 32], 1);
 
         let bad_message_hash = [0u8; 32];
         let incoming_pda = Pubkey::find_program_address(
@@ -501,13 +502,7 @@ mod tests {
             buffered_message_setup(&mut svm, bridge_pda);
 
         // Pause the bridge
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         let incoming_pda = Pubkey::find_program_address(
             &[
@@ -550,4 +545,256 @@ mod tests {
         let err = format!("{:?}", result.unwrap_err());
         assert!(err.contains("BridgePaused"), "unexpected error: {}", err);
     }
+
+    fn set_max_root_age(svm: &mut LiteSVM, max_root_age_seconds: u64) {
+        update_bridge(svm, |bridge| {
+            bridge.protocol_config.max_root_age_seconds = max_root_age_seconds;
+        });
+    }
+
+    /// Stages a buffered message against a root registered now, advances the clock by
+    /// `root_age_seconds` and proves it.
+    fn send_prove_buffered(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        root_age_seconds: i64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        send_prove_buffered_at_block(svm, payer, bridge_pda, root_age_seconds, 1)
+    }
+
+    /// Same as `send_prove_buffered`, but with the root recorded at `root_base_block_number`
+    /// while the bridge's latest registered block stays at 1.
+    fn send_prove_buffered_at_block(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        root_age_seconds: i64,
+        root_base_block_number: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        send_prove_buffered_with(
+            svm,
+            payer,
+            bridge_pda,
+            root_age_seconds,
+            |_, output_root| {
+                output_root.base_block_number = root_base_block_number;
+            },
+        )
+    }
+
+    /// Same as `send_prove_buffered`, but lets `configure` adjust the bridge and the output root
+    /// after they are staged.
+    fn send_prove_buffered_with(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        root_age_seconds: i64,
+        configure: impl FnOnce(&mut Bridge, &mut OutputRoot),
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (message_hash, output_root_pk, owner, prove_buffer, nonce, sender, _) =
+            buffered_message_setup(svm, bridge_pda);
+
+        let mut root_account = svm.get_account(&output_root_pk).unwrap();
+        let mut output_root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        update_bridge(svm, |bridge| configure(bridge, &mut output_root));
+
+        let mut data = Vec::new();
+        output_root.try_serialize(&mut data).unwrap();
+        root_account.data = data;
+        svm.set_account(output_root_pk, root_account).unwrap();
+
+        let now = svm.get_sysvar::<Clock>().unix_timestamp;
+        mock_clock(svm, now + root_age_seconds);
+
+        let incoming_pda = Pubkey::find_program_address(
+            &[
+                crate::base_to_solana::constants::INCOMING_MESSAGE_SEED,
+                &message_hash,
+            ],
+            &ID,
+        )
+        .0;
+
+        let prove_accounts = accounts::ProveMessageBuffered {
+            payer: payer.pubkey(),
+            output_root: output_root_pk,
+            message: incoming_pda,
+            bridge: bridge_pda,
+            owner: owner.pubkey(),
+            prove_buffer: prove_buffer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let prove_ix = Instruction {
+            program_id: ID,
+            accounts: prove_accounts,
+            data: ProveMessageBufferedIx {
+                nonce,
+                sender,
+                message_hash,
+            }
+            .data(),
+        };
+
+        let prove_tx = Transaction::new(
+            &[payer, &owner],
+            SolMessage::new(&[prove_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(prove_tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_prove_message_buffered_succeeds_with_fresh_root() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_max_root_age(&mut svm, 3_600);
+
+        send_prove_buffered(&mut svm, &payer, bridge_pda, 3_600)
+            .expect("prove against a fresh root should succeed");
+    }
+
+    #[test]
+    fn test_prove_message_buffered_fails_with_aged_root() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_max_root_age(&mut svm, 3_600);
+
+        let result = send_prove_buffered(&mut svm, &payer, bridge_pda, 3_601);
+        assert!(result.is_err(), "Expected prove against aged root to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("OutputRootTooOld"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_prove_message_buffered_fails_with_root_above_registered_block() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_prove_buffered_at_block(&mut svm, &payer, bridge_pda, 0, 2);
+        assert!(
+            result.is_err(),
+            "Expected prove against an unregistered block to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("RootNotRegistered"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_prove_message_buffered_fails_with_unset_root_block() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_prove_buffered_at_block(&mut svm, &payer, bridge_pda, 0, 0);
+        assert!(result.is_err(), "Expected prove against block 0 to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("RootNotRegistered"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_prove_message_buffered_succeeds_with_recent_root_when_revalidation_enabled() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        send_prove_buffered_with(&mut svm, &payer, bridge_pda, 0, |bridge, _| {
+            bridge.base_block_number = 1_001;
+            bridge.protocol_config.revalidate_old_roots_after_blocks = 1_000;
+        })
+        .expect("prove against a recent root should not require re-validation");
+    }
+
+    #[test]
+    fn test_prove_message_buffered_old_root_requires_revalidation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_prove_buffered_with(&mut svm, &payer, bridge_pda, 0, |bridge, _| {
+            bridge.base_block_number = 1_002;
+            bridge.protocol_config.revalidate_old_roots_after_blocks = 1_000;
+        });
+        assert!(result.is_err(), "Expected prove against old root to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("OutputRootRequiresRevalidation"),
+            "Unexpected error: {}",
+            err
+        );
+
+        send_prove_buffered_with(&mut svm, &payer, bridge_pda, 0, |bridge, output_root| {
+            bridge.base_block_number = 1_002;
+            bridge.protocol_config.revalidate_old_roots_after_blocks = 1_000;
+            output_root.revalidated_epoch = bridge.base_oracle_epoch;
+        })
+        .expect("prove against a re-validated old root should succeed");
+    }
+
+    #[test]
+    fn test_prove_message_buffered_old_root_revalidated_by_rotated_out_set_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Re-validated, but the oracle set has rotated since
+        let result =
+            send_prove_buffered_with(&mut svm, &payer, bridge_pda, 0, |bridge, output_root| {
+                bridge.base_block_number = 1_002;
+                bridge.protocol_config.revalidate_old_roots_after_blocks = 1_000;
+                output_root.revalidated_epoch = bridge.base_oracle_epoch;
+                bridge.base_oracle_epoch += 1;
+            });
+        assert!(
+            result.is_err(),
+            "Expected a root re-validated by a previous oracle set to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("OutputRootRequiresRevalidation"),
+            "Unexpected error: {}",
+            err
+        );
+    }
 }
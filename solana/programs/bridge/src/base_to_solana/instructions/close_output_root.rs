@@ -0,0 +1,368 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::{
+        OUTPUT_ROOT_CLOSE_DELAY_SECONDS, OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS, OUTPUT_ROOT_SEED,
+    },
+    state::OutputRoot,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::BridgeError;
+
+/// Accounts struct for the guardian-only `close_output_root` instruction that closes an
+/// erroneously created or stale output root. The co-guardian must co-sign when one is configured.
+#[derive(Accounts)]
+#[instruction(base_block_number: u64)]
+pub struct CloseOutputRoot<'info> {
+    /// The guardian account authorized to close output roots.
+    pub guardian: Signer<'info>,
+
+    /// The account receiving the reclaimed rent.
+    /// CHECK: Any account chosen by the guardian.
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    /// The main bridge state account that tracks the latest registered Base block number.
+    /// - Uses PDA with BRIDGE_SEED
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The output root account to close.
+    /// - Uses PDA with OUTPUT_ROOT_SEED and base_block_number
+    #[account(
+        mut,
+        close = receiver,
+        seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+        bump
+    )]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The co-guardian, required when `bridge.co_guardian` is set.
+    pub co_guardian: Option<Signer<'info>>,
+}
+
+/// Closes a non-latest output root once it is both at least `OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS`
+/// block intervals behind the latest registered block and older than
+/// `OUTPUT_ROOT_CLOSE_DELAY_SECONDS`.
+///
+/// Each output root commits to every Base message up to its block, so any message provable
+/// against a closed root remains provable against the latest one. The block margin and the
+/// timelock leave proofs that are already in flight against the root time to land.
+pub fn close_output_root_handler(
+    ctx: Context<CloseOutputRoot>,
+    base_block_number: u64,
+) -> Result<()> {
+    ctx.accounts.bridge.require_co_guardian(
+        ctx.accounts
+            .co_guardian
+            .as_ref()
+            .map(|co_guardian| co_guardian.key()),
+    )?;
+
+    require!(
+        base_block_number != ctx.accounts.bridge.base_block_number,
+        BridgeError::CannotCloseLatestOutputRoot
+    );
+
+    let bridge = &ctx.accounts.bridge;
+    let margin_blocks = bridge
+        .protocol_config
+        .block_interval_requirement
+        .saturating_mul(OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS);
+    require!(
+        base_block_number.saturating_add(margin_blocks) <= bridge.base_block_number,
+        BridgeError::OutputRootWithinCloseMargin
+    );
+
+    let age = Clock::get()?
+        .unix_timestamp
+        .saturating_sub(ctx.accounts.output_root.registered_at)
+        .max(0) as u64;
+    require!(
+        age >= OUTPUT_ROOT_CLOSE_DELAY_SECONDS,
+        BridgeError::OutputRootCloseTimelocked
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::CloseOutputRoot as CloseOutputRootIx,
+        test_utils::{mock_clock, setup_bridge, update_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const NOW: i64 = 1747440000;
+
+    /// Writes an output root for `base_block_number` registered at `registered_at`.
+    fn write_output_root(
+        svm: &mut litesvm::LiteSVM,
+        base_block_number: u64,
+        registered_at: i64,
+    ) -> Pubkey {
+        let output_root_pda = Pubkey::find_program_address(
+            &[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+            &ID,
+        )
+        .0;
+        let output_root = OutputRoot {
+            root: [1u8; 32],
+            total_leaf_count: 1,
+            registered_at,
+            base_block_number,
+            revalidated_epoch: OutputRoot::NOT_REVALIDATED,
+        };
+        let mut data = Vec::new();
+        output_root.try_serialize(&mut data).unwrap();
+        svm.set_account(
+            output_root_pda,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        output_root_pda
+    }
+
+    fn set_latest_base_block_number(svm: &mut litesvm::LiteSVM, base_block_number: u64) {
+        update_bridge(svm, |bridge| bridge.base_block_number = base_block_number);
+    }
+
+    fn send_close_output_root(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        co_guardian: Option<&Keypair>,
+        receiver: Pubkey,
+        bridge_pda: Pubkey,
+        base_block_number: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let output_root = Pubkey::find_program_address(
+            &[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+            &ID,
+        )
+        .0;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseOutputRoot {
+                guardian: guardian.pubkey(),
+                receiver,
+                bridge: bridge_pda,
+                output_root,
+                co_guardian: co_guardian.map(|co_guardian| co_guardian.pubkey()),
+            }
+            .to_account_metas(None),
+            data: CloseOutputRootIx { base_block_number }.data(),
+        };
+
+        let signers: Vec<&Keypair> = std::iter::once(guardian).chain(co_guardian).collect();
+        let tx = Transaction::new(
+            &signers,
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    /// Blocks a root must trail the latest registered block by, at the test block interval.
+    const MARGIN_BLOCKS: u64 = 300 * OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS;
+
+    #[test]
+    fn test_close_output_root_closes_root_at_margin_boundary() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        mock_clock(&mut svm, NOW);
+        set_latest_base_block_number(&mut svm, 300 + MARGIN_BLOCKS);
+        let stale_root =
+            write_output_root(&mut svm, 300, NOW - OUTPUT_ROOT_CLOSE_DELAY_SECONDS as i64);
+        let receiver = Pubkey::new_unique();
+
+        send_close_output_root(&mut svm, &guardian, None, receiver, bridge_pda, 300)
+            .expect("closing a stale root should succeed");
+
+        assert!(svm
+            .get_account(&stale_root)
+            .is_none_or(|account| account.lamports == 0));
+        assert_eq!(
+            svm.get_account(&receiver).unwrap().lamports,
+            LAMPORTS_PER_SOL
+        );
+    }
+
+    #[test]
+    fn test_close_output_root_refuses_root_within_margin() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        mock_clock(&mut svm, NOW);
+        set_latest_base_block_number(&mut svm, MARGIN_BLOCKS);
+        write_output_root(
+            &mut svm,
+            300,
+            NOW - 2 * OUTPUT_ROOT_CLOSE_DELAY_SECONDS as i64,
+        );
+
+        let result = send_close_output_root(
+            &mut svm,
+            &guardian,
+            None,
+            guardian.pubkey(),
+            bridge_pda,
+            300,
+        );
+        assert!(
+            result.is_err(),
+            "Expected closing a root within the margin to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("OutputRootWithinCloseMargin"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_close_output_root_refuses_latest_root() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        mock_clock(&mut svm, NOW);
+        set_latest_base_block_number(&mut svm, 600);
+        write_output_root(
+            &mut svm,
+            600,
+            NOW - 2 * OUTPUT_ROOT_CLOSE_DELAY_SECONDS as i64,
+        );
+
+        let result = send_close_output_root(
+            &mut svm,
+            &guardian,
+            None,
+            guardian.pubkey(),
+            bridge_pda,
+            600,
+        );
+        assert!(result.is_err(), "Expected closing the latest root to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("CannotCloseLatestOutputRoot"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_close_output_root_refuses_recent_root() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        mock_clock(&mut svm, NOW);
+        set_latest_base_block_number(&mut svm, 300 + MARGIN_BLOCKS);
+        write_output_root(
+            &mut svm,
+            300,
+            NOW - OUTPUT_ROOT_CLOSE_DELAY_SECONDS as i64 + 1,
+        );
+
+        let result = send_close_output_root(
+            &mut svm,
+            &guardian,
+            None,
+            guardian.pubkey(),
+            bridge_pda,
+            300,
+        );
+        assert!(result.is_err(), "Expected closing a recent root to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("OutputRootCloseTimelocked"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_close_output_root_requires_co_guardian_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = Keypair::new();
+        update_bridge(&mut svm, |bridge| bridge.co_guardian = co_guardian.pubkey());
+
+        mock_clock(&mut svm, NOW);
+        set_latest_base_block_number(&mut svm, 300 + MARGIN_BLOCKS);
+        let stale_root =
+            write_output_root(&mut svm, 300, NOW - OUTPUT_ROOT_CLOSE_DELAY_SECONDS as i64);
+        let receiver = Pubkey::new_unique();
+
+        let result = send_close_output_root(&mut svm, &guardian, None, receiver, bridge_pda, 300);
+        assert!(
+            result.is_err(),
+            "Expected closing a root without the co-guardian to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("CoGuardianSignatureRequired"),
+            "Unexpected error: {}",
+            err
+        );
+
+        send_close_output_root(
+            &mut svm,
+            &guardian,
+            Some(&co_guardian),
+            receiver,
+            bridge_pda,
+            300,
+        )
+        .expect("closing a stale root signed by both guardians should succeed");
+        assert!(svm
+            .get_account(&stale_root)
+            .is_none_or(|account| account.lamports == 0));
+    }
+}
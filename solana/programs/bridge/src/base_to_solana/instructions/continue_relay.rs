@@ -0,0 +1,360 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    constants::RELAY_CURSOR_SEED,
+    invoke_relayed_ixs,
+    state::{IncomingMessage, RelayCursor},
+    Message,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, PAUSE_RELAY};
+use crate::BridgeError;
+
+/// Accounts struct for the `continue_relay` instruction that executes the next slice of a
+/// message's instructions after `begin_relay`.
+#[derive(Accounts)]
+pub struct ContinueRelay<'info> {
+    /// The incoming message being relayed.
+    pub message: Account<'info, IncomingMessage>,
+
+    /// The main bridge state account used to check pause status
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The execution cursor created by `begin_relay` for this message.
+    /// - Uses PDA with RELAY_CURSOR_SEED and the message address
+    /// - Mutable to advance past the executed slice
+    /// - Closed to `payer` once the last slice executes
+    #[account(
+        mut,
+        seeds = [RELAY_CURSOR_SEED, message.key().as_ref()],
+        bump,
+        has_one = message,
+        has_one = payer
+    )]
+    pub relay_cursor: Account<'info, RelayCursor>,
+
+    /// The account that paid for the relay cursor, refunded its rent after the last slice.
+    /// CHECK: Must match `relay_cursor.payer`.
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn continue_relay_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, ContinueRelay<'info>>,
+    ix_count: u32,
+) -> Result<()> {
+    // Check if bridge is paused
+    ctx.accounts.bridge.require_not_paused(PAUSE_RELAY)?;
+
+    let start = ctx.accounts.relay_cursor.next_ix;
+    let end = start
+        .checked_add(ix_count)
+        .ok_or(BridgeError::InvalidRelaySlice)?;
+    require!(
+        ix_count > 0 && end <= ctx.accounts.relay_cursor.total_ixs,
+        BridgeError::InvalidRelaySlice
+    );
+
+    let ixs = match &ctx.accounts.message.message {
+        Message::Call(ixs) => ixs,
+        Message::Transfer { ixs, .. } => ixs,
+    };
+    let slice = ixs[start as usize..end as usize].to_vec();
+
+    // Advance before executing so the slice can never run twice
+    ctx.accounts.relay_cursor.next_ix = end;
+
+    invoke_relayed_ixs(
+        ctx.program_id,
+        &ctx.accounts.message.sender,
+        slice,
+        ctx.remaining_accounts,
+    )?;
+
+    if ctx.accounts.relay_cursor.is_complete() {
+        ctx.accounts
+            .relay_cursor
+            .close(ctx.accounts.payer.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SolanaMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::{BRIDGE_CPI_AUTHORITY_SEED, INCOMING_MESSAGE_VERSION},
+            Ix, IxAccount,
+        },
+        instruction::{BeginRelay as BeginRelayIx, ContinueRelay as ContinueRelayIx},
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    /// A system transfer of `lamports` from the bridge CPI authority of `SENDER` to `to`.
+    fn cpi_authority_transfer_ix(cpi_authority: Pubkey, to: Pubkey, lamports: u64) -> Ix {
+        // SystemInstruction::Transfer: u32 variant index followed by the u64 amount
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+
+        Ix {
+            program_id: system_program::ID,
+            accounts: vec![
+                IxAccount {
+                    pubkey: cpi_authority,
+                    is_writable: true,
+                    is_signer: true,
+                },
+                IxAccount {
+                    pubkey: to,
+                    is_writable: true,
+                    is_signer: false,
+                },
+            ],
+            data,
+        }
+    }
+
+    fn write_incoming_message(svm: &mut litesvm::LiteSVM, ixs: Vec<Ix>) -> Pubkey {
+        let incoming_message = IncomingMessage {
+            version: INCOMING_MESSAGE_VERSION,
+            nonce: 0,
+            sender: SENDER,
+            message: Message::Call(ixs),
+            executed: false,
+            proven_at: 0,
+        };
+        let mut data = Vec::new();
+        incoming_message.try_serialize(&mut data).unwrap();
+
+        let message = Pubkey::new_unique();
+        svm.set_account(
+            message,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        message
+    }
+
+    fn send_ix(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        ix: Instruction,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let tx = Transaction::new(
+            &[payer],
+            SolanaMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    fn continue_relay_ix(
+        message: Pubkey,
+        bridge: Pubkey,
+        relay_cursor: Pubkey,
+        payer: Pubkey,
+        remaining: &[AccountMeta],
+        ix_count: u32,
+    ) -> Instruction {
+        let mut accounts = accounts::ContinueRelay {
+            message,
+            bridge,
+            relay_cursor,
+            payer,
+        }
+        .to_account_metas(None);
+        accounts.extend_from_slice(remaining);
+
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: ContinueRelayIx { ix_count }.data(),
+        }
+    }
+
+    fn read_cursor(svm: &litesvm::LiteSVM, relay_cursor: Pubkey) -> RelayCursor {
+        let account = svm.get_account(&relay_cursor).unwrap();
+        RelayCursor::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    fn begin_relay_ix(
+        payer: Pubkey,
+        message: Pubkey,
+        bridge: Pubkey,
+        relay_cursor: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::BeginRelay {
+                payer,
+                message,
+                bridge,
+                guardian: None,
+                relay_cursor,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: BeginRelayIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_relay_message_in_two_slices() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, SENDER.as_ref()], &ID).0;
+        svm.airdrop(&cpi_authority, LAMPORTS_PER_SOL).unwrap();
+
+        let recipients = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let ixs = recipients
+            .iter()
+            .map(|to| cpi_authority_transfer_ix(cpi_authority, *to, LAMPORTS_PER_SOL / 10))
+            .collect();
+        let message = write_incoming_message(&mut svm, ixs);
+        let relay_cursor =
+            Pubkey::find_program_address(&[RELAY_CURSOR_SEED, message.as_ref()], &ID).0;
+
+        let begin_ix = begin_relay_ix(payer.pubkey(), message, bridge_pda, relay_cursor);
+        send_ix(&mut svm, &payer, begin_ix).expect("begin_relay should succeed");
+
+        let cursor = read_cursor(&svm, relay_cursor);
+        assert_eq!(cursor.next_ix, 0);
+        assert_eq!(cursor.total_ixs, 3);
+        assert_eq!(cursor.payer, payer.pubkey());
+        let cursor_rent = svm.get_account(&relay_cursor).unwrap().lamports;
+
+        let mut remaining = vec![
+            AccountMeta::new(cpi_authority, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+        remaining.extend(recipients.iter().map(|to| AccountMeta::new(*to, false)));
+
+        // First slice: two instructions
+        let ix = continue_relay_ix(
+            message,
+            bridge_pda,
+            relay_cursor,
+            payer.pubkey(),
+            &remaining,
+            2,
+        );
+        send_ix(&mut svm, &payer, ix).expect("first slice should succeed");
+        assert!(!read_cursor(&svm, relay_cursor).is_complete());
+        assert!(svm.get_account(&recipients[2]).is_none());
+
+        // Second slice: the remaining instruction
+        let ix = continue_relay_ix(
+            message,
+            bridge_pda,
+            relay_cursor,
+            payer.pubkey(),
+            &remaining,
+            1,
+        );
+        let payer_before = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        send_ix(&mut svm, &payer, ix).expect("second slice should succeed");
+
+        // The final slice closes the cursor and refunds its rent to the payer, who also paid the
+        // 5_000 lamport transaction fee
+        assert!(svm.get_account(&relay_cursor).is_none());
+        let payer_after = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        assert_eq!(payer_after + 5_000 - payer_before, cursor_rent);
+        for to in recipients {
+            assert_eq!(
+                svm.get_account(&to).unwrap().lamports,
+                LAMPORTS_PER_SOL / 10
+            );
+        }
+
+        let message_account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &message_account.data[..]).unwrap();
+        assert!(incoming.executed);
+
+        // Nothing is left to execute
+        let ix = continue_relay_ix(
+            message,
+            bridge_pda,
+            relay_cursor,
+            payer.pubkey(),
+            &remaining,
+            1,
+        );
+        let result = send_ix(&mut svm, &payer, ix);
+        assert!(result.is_err(), "Expected relay past the end to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("AccountNotInitialized"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_begin_relay_without_instructions_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = write_incoming_message(&mut svm, vec![]);
+        let relay_cursor =
+            Pubkey::find_program_address(&[RELAY_CURSOR_SEED, message.as_ref()], &ID).0;
+
+        let begin_ix = begin_relay_ix(payer.pubkey(), message, bridge_pda, relay_cursor);
+        let result = send_ix(&mut svm, &payer, begin_ix);
+        assert!(
+            result.is_err(),
+            "Expected begin_relay without instructions to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("NoInstructionsToRelay"),
+            "Unexpected error: {}",
+            err
+        );
+
+        // No cursor rent is left behind and the message can still be relayed in one call
+        assert!(svm.get_account(&relay_cursor).is_none());
+        let message_account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &message_account.data[..]).unwrap();
+        assert!(!incoming.executed);
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+
+/// Accounts struct for the `get_highest_relayed_nonce` view instruction that reports the highest
+/// Base nonce relayed on Solana.
+#[derive(Accounts)]
+pub struct GetHighestRelayedNonce<'info> {
+    /// The main bridge state account tracking relayed nonces.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+pub fn get_highest_relayed_nonce_handler(
+    ctx: Context<GetHighestRelayedNonce>,
+) -> Result<Option<u64>> {
+    Ok(ctx.accounts.bridge.highest_relayed_nonce)
+}
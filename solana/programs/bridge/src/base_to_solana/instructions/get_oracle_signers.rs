@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+
+/// Accounts struct for the `get_oracle_signers` view instruction that reports the base oracle
+/// committee allowed to attest output roots.
+#[derive(Accounts)]
+pub struct GetOracleSigners<'info> {
+    /// The main bridge state account holding the base oracle configuration.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+/// Base oracle committee, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleSigners {
+    /// Required approval weight of valid unique signatures.
+    pub threshold: u8,
+    /// The 20-byte EVM addresses of the active signers.
+    pub signers: Vec<[u8; 20]>,
+    /// Approval weight of each signer in `signers` (a weight of 0 counts as 1).
+    pub weights: Vec<u8>,
+}
+
+pub fn get_oracle_signers_handler(ctx: Context<GetOracleSigners>) -> Result<OracleSigners> {
+    let config = &ctx.accounts.bridge.base_oracle_config;
+    let signers = config.active_signers().to_vec();
+    let weights = config.weights[..signers.len()].to_vec();
+
+    Ok(OracleSigners {
+        threshold: config.threshold,
+        signers,
+        weights,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{BaseOracleConfig, MAX_SIGNER_COUNT},
+        instruction::{GetOracleSigners as GetOracleSignersIx, SetOracleSigners},
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn get_oracle_signers(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+    ) -> OracleSigners {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetOracleSigners { bridge: bridge_pda }.to_account_metas(None),
+            data: GetOracleSignersIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_oracle_signers should succeed");
+        svm.expire_blockhash();
+
+        assert_eq!(meta.return_data.program_id, ID);
+        OracleSigners::try_from_slice(&meta.return_data.data).unwrap()
+    }
+
+    #[test]
+    fn test_get_oracle_signers_matches_config_after_update() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let initial = get_oracle_signers(&mut svm, &payer, bridge_pda);
+        assert_eq!(
+            initial,
+            OracleSigners {
+                threshold: 1,
+                signers: vec![[1u8; 20]],
+                weights: vec![1],
+            }
+        );
+
+        // Replace the committee: drop the initial signer and add three new ones
+        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        signers[0] = [2u8; 20];
+        signers[1] = [3u8; 20];
+        signers[2] = [4u8; 20];
+        let mut weights = [0u8; MAX_SIGNER_COUNT as usize];
+        weights[..3].copy_from_slice(&[2, 1, 1]);
+        let cfg = BaseOracleConfig {
+            threshold: 3,
+            signer_count: 3,
+            signers,
+            weights,
+        };
+
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: payer.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: SetOracleSigners { cfg }.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("set_oracle_signers should succeed");
+
+        let updated = get_oracle_signers(&mut svm, &payer, bridge_pda);
+        assert_eq!(
+            updated,
+            OracleSigners {
+                threshold: 3,
+                signers: vec![[2u8; 20], [3u8; 20], [4u8; 20]],
+                weights: vec![2, 1, 1],
+            }
+        );
+    }
+}
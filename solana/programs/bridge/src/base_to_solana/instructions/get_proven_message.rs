@@ -0,0 +1,159 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{keccak, program::MAX_RETURN_DATA},
+};
+
+use crate::base_to_solana::{constants::INCOMING_MESSAGE_SEED, state::IncomingMessage};
+
+/// Serialized size of a `ProvenMessage` excluding the inline message bytes.
+const PROVEN_MESSAGE_FIXED_LEN: usize = 8 + 20 + 1 + 32 + 1 + 4;
+
+/// Accounts struct for the `get_proven_message` view instruction that reports the stored
+/// contents and execution status of a proven Base → Solana message.
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct GetProvenMessage<'info> {
+    /// The incoming message account created by `prove_message`.
+    /// - Uses PDA with INCOMING_MESSAGE_SEED and the message hash for deterministic address
+    #[account(seeds = [INCOMING_MESSAGE_SEED, &message_hash], bump)]
+    pub message: Account<'info, IncomingMessage>,
+}
+
+/// Status of a proven message, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ProvenMessage {
+    /// The nonce assigned to the message on Base.
+    pub nonce: u64,
+    /// The 20-byte EVM address of the sender on Base.
+    pub sender: [u8; 20],
+    /// Whether the message has been relayed on Solana.
+    pub executed: bool,
+    /// keccak256 of the Borsh-serialized message payload.
+    pub data_hash: [u8; 32],
+    /// The Borsh-serialized message payload, omitted when it does not fit in return data.
+    pub data: Option<Vec<u8>>,
+}
+
+pub fn get_proven_message_handler(
+    ctx: Context<GetProvenMessage>,
+    _message_hash: [u8; 32],
+) -> Result<ProvenMessage> {
+    let message = &ctx.accounts.message;
+    let data = message.message.try_to_vec()?;
+    let data_hash = keccak::hash(&data).0;
+
+    let data = if PROVEN_MESSAGE_FIXED_LEN + data.len() <= MAX_RETURN_DATA {
+        Some(data)
+    } else {
+        None
+    };
+
+    Ok(ProvenMessage {
+        nonce: message.nonce,
+        sender: message.sender,
+        executed: message.executed,
+        data_hash,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use solana_message::Message as SolMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::Message as BridgeMessage,
+        instruction::{GetProvenMessage as GetProvenMessageIx, ProveMessage as ProveMessageIx},
+        test_utils::{create_output_root_account, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn compute_message_hash(nonce: u64, sender: [u8; 20], data: &[u8]) -> [u8; 32] {
+        let mut v = Vec::new();
+        v.extend_from_slice(&nonce.to_be_bytes());
+        v.extend_from_slice(&sender);
+        v.extend_from_slice(data);
+        keccak::hash(&v).0
+    }
+
+    #[test]
+    fn test_get_proven_message_returns_status() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let nonce = 3u64;
+        let sender = [7u8; 20];
+        let data = BridgeMessage::Call(vec![]).try_to_vec().unwrap();
+        let message_hash = compute_message_hash(nonce, sender, &data);
+
+        // Single-leaf MMR: the root is the message hash itself
+        let output_root = create_output_root_account(&mut svm, message_hash, 1);
+        let message = Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+
+        let prove_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessage {
+                payer: payer.pubkey(),
+                output_root,
+                message,
+                bridge: bridge_pda,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageIx {
+                nonce,
+                sender,
+                data: data.clone(),
+                proof: vec![],
+                message_hash,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolMessage::new(&[prove_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("prove_message should succeed");
+
+        let view_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetProvenMessage { message }.to_account_metas(None),
+            data: GetProvenMessageIx { message_hash }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolMessage::new(&[view_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_proven_message should succeed");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let status = ProvenMessage::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            status,
+            ProvenMessage {
+                nonce,
+                sender,
+                executed: false,
+                data_hash: keccak::hash(&data).0,
+                data: Some(data),
+            }
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        constants::{INCOMING_MESSAGE_SEED, INCOMING_MESSAGE_VERSION},
+        prove_message::hash_message,
+        state::IncomingMessage,
+        Message,
+    },
+    common::{internal::resize::resize_account, DISCRIMINATOR_LEN},
+    BridgeError,
+};
+
+/// Layout of the `IncomingMessage` account written by the program before the version, nonce and
+/// proof time were recorded.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct LegacyIncomingMessage {
+    sender: [u8; 20],
+    message: Message,
+    executed: bool,
+}
+
+/// Accounts for the `migrate_incoming_message` instruction that rewrites an `IncomingMessage`
+/// account proven by a previous version of the program into the current layout.
+/// Anyone can migrate a message: the supplied nonce is checked against the message hash the
+/// account is derived from.
+#[derive(Accounts)]
+#[instruction(nonce: u64, message_hash: [u8; 32])]
+pub struct MigrateIncomingMessage<'info> {
+    /// The account that pays for the rent of the grown message account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The incoming message account still in the legacy layout, at the PDA with
+    /// INCOMING_MESSAGE_SEED and message_hash.
+    /// CHECK: Deserialized manually from the legacy layout in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [INCOMING_MESSAGE_SEED, &message_hash],
+        bump
+    )]
+    pub message: UncheckedAccount<'info>,
+
+    /// System program required for funding the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy `IncomingMessage` account to the current layout.
+///
+/// The sender, payload and execution flag are carried over. `nonce` must hash together with them
+/// to `message_hash`, as when the message was proven. Legacy messages did not record when they
+/// were proven, so `proven_at` is set to the migration time and the relay delay counts from there.
+pub fn migrate_incoming_message_handler(
+    ctx: Context<MigrateIncomingMessage>,
+    nonce: u64,
+    message_hash: [u8; 32],
+) -> Result<()> {
+    let message_info = ctx.accounts.message.to_account_info();
+    let legacy = {
+        let data = message_info.try_borrow_data()?;
+        require!(
+            data.starts_with(IncomingMessage::DISCRIMINATOR),
+            BridgeError::AlreadyMigrated
+        );
+        LegacyIncomingMessage::try_from_slice(&data[DISCRIMINATOR_LEN..])
+            .map_err(|_| BridgeError::AlreadyMigrated)?
+    };
+
+    let data = legacy.message.try_to_vec()?;
+    require!(
+        hash_message(&nonce.to_be_bytes(), &legacy.sender, &data) == message_hash,
+        BridgeError::InvalidMessageHash
+    );
+
+    let message = IncomingMessage {
+        version: INCOMING_MESSAGE_VERSION,
+        nonce,
+        sender: legacy.sender,
+        message: legacy.message,
+        executed: legacy.executed,
+        proven_at: Clock::get()?.unix_timestamp,
+    };
+
+    resize_account(
+        &message_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        DISCRIMINATOR_LEN + IncomingMessage::space(data.len()),
+    )?;
+    message.try_serialize(&mut &mut message_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SolanaMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::Ix,
+        instruction::MigrateIncomingMessage as MigrateIncomingMessageIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const NONCE: u64 = 5;
+    const SENDER: [u8; 20] = [3u8; 20];
+
+    /// Writes a legacy-layout message at the PDA of its hash and returns that hash and PDA.
+    fn write_legacy_incoming_message(svm: &mut litesvm::LiteSVM) -> ([u8; 32], Pubkey) {
+        let legacy = LegacyIncomingMessage {
+            sender: SENDER,
+            message: Message::Call(vec![Ix {
+                program_id: Pubkey::new_unique(),
+                accounts: vec![],
+                data: vec![1, 2, 3],
+            }]),
+            executed: false,
+        };
+        let message_hash = hash_message(
+            &NONCE.to_be_bytes(),
+            &SENDER,
+            &legacy.message.try_to_vec().unwrap(),
+        );
+        let message_pda =
+            Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+
+        let mut data = IncomingMessage::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            message_pda,
+            Account {
+                lamports,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        (message_hash, message_pda)
+    }
+
+    fn migrate_incoming_message(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        message: Pubkey,
+        nonce: u64,
+        message_hash: [u8; 32],
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateIncomingMessage {
+                payer: payer.pubkey(),
+                message,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateIncomingMessageIx {
+                nonce,
+                message_hash,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SolanaMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_incoming_message_carries_message() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let (message_hash, message_pda) = write_legacy_incoming_message(&mut svm);
+        migrate_incoming_message(&mut svm, &payer, message_pda, NONCE, message_hash)
+            .expect("migration should succeed");
+
+        let account = svm.get_account(&message_pda).unwrap();
+        let message = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(message.version, INCOMING_MESSAGE_VERSION);
+        assert_eq!(message.nonce, NONCE);
+        assert_eq!(message.sender, SENDER);
+        assert!(!message.executed);
+        assert_eq!(message.proven_at, 1747440000);
+        assert!(matches!(message.message, Message::Call(ixs) if ixs.len() == 1));
+
+        let result = migrate_incoming_message(&mut svm, &payer, message_pda, NONCE, message_hash);
+        assert!(result.is_err(), "Expected a second migration to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AlreadyMigrated"),
+            "Expected AlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_incoming_message_with_wrong_nonce_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let (message_hash, message_pda) = write_legacy_incoming_message(&mut svm);
+
+        let result =
+            migrate_incoming_message(&mut svm, &payer, message_pda, NONCE + 1, message_hash);
+        assert!(result.is_err(), "Expected a mismatched nonce to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidMessageHash"),
+            "Expected InvalidMessageHash error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::OUTPUT_ROOT_SEED, state::OutputRoot},
+    common::{internal::resize::resize_account, DISCRIMINATOR_LEN},
+    program::Bridge as BridgeProgram,
+    BridgeError,
+};
+
+/// Layout of the `OutputRoot` account written by the program before the registration time,
+/// block number and revalidation flag were recorded.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyOutputRoot {
+    root: [u8; 32],
+    total_leaf_count: u64,
+}
+
+/// Accounts for the `migrate_output_root` instruction that rewrites an `OutputRoot` account
+/// created by a previous version of the program into the current layout.
+/// Only the upgrade authority can migrate output roots.
+#[derive(Accounts)]
+#[instruction(base_block_number: u64)]
+pub struct MigrateOutputRoot<'info> {
+    /// The upgrade authority that is authorized to migrate output roots.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for the rent of the grown output root account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The output root account still in the legacy layout, at the PDA with OUTPUT_ROOT_SEED and
+    /// base_block_number.
+    /// CHECK: Deserialized manually from the legacy layout in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+        bump
+    )]
+    pub output_root: UncheckedAccount<'info>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ BridgeError::UnauthorizedConfigUpdate
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The bridge program itself.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ BridgeError::IncorrectBridgeProgram
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for funding the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy `OutputRoot` account to the current layout.
+///
+/// The root and leaf count are carried over and the block number is taken from the account's
+/// seeds. Legacy roots did not record when they were registered, so `registered_at` is set to the
+/// migration time and `max_root_age_seconds` counts from there. The root starts unrevalidated.
+pub fn migrate_output_root_handler(
+    ctx: Context<MigrateOutputRoot>,
+    base_block_number: u64,
+) -> Result<()> {
+    let output_root_info = ctx.accounts.output_root.to_account_info();
+    let legacy = {
+        let data = output_root_info.try_borrow_data()?;
+        require!(
+            data.len() == DISCRIMINATOR_LEN + LegacyOutputRoot::INIT_SPACE
+                && data.starts_with(OutputRoot::DISCRIMINATOR),
+            BridgeError::AlreadyMigrated
+        );
+        LegacyOutputRoot::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+
+    let output_root = OutputRoot {
+        root: legacy.root,
+        total_leaf_count: legacy.total_leaf_count,
+        registered_at: Clock::get()?.unix_timestamp,
+        base_block_number,
+        revalidated_epoch: OutputRoot::NOT_REVALIDATED,
+    };
+
+    resize_account(
+        &output_root_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE,
+    )?;
+    output_root.try_serialize(&mut &mut output_root_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::MigrateOutputRoot as MigrateOutputRootIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn write_legacy_output_root(svm: &mut litesvm::LiteSVM, base_block_number: u64) -> Pubkey {
+        let output_root_pda = Pubkey::find_program_address(
+            &[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+            &ID,
+        )
+        .0;
+
+        let mut data = OutputRoot::DISCRIMINATOR.to_vec();
+        LegacyOutputRoot {
+            root: [9u8; 32],
+            total_leaf_count: 11,
+        }
+        .serialize(&mut data)
+        .unwrap();
+
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            output_root_pda,
+            Account {
+                lamports,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        output_root_pda
+    }
+
+    fn migrate_output_root(
+        svm: &mut litesvm::LiteSVM,
+        upgrade_authority: &Keypair,
+        output_root: Pubkey,
+        base_block_number: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (program_data, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateOutputRoot {
+                upgrade_authority: upgrade_authority.pubkey(),
+                payer: upgrade_authority.pubkey(),
+                output_root,
+                program_data,
+                program: ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateOutputRootIx { base_block_number }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[upgrade_authority],
+            Message::new(&[ix], Some(&upgrade_authority.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_output_root_carries_root_and_leaf_count() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let output_root_pda = write_legacy_output_root(&mut svm, 300);
+        migrate_output_root(&mut svm, &payer, output_root_pda, 300)
+            .expect("migration should succeed");
+
+        let account = svm.get_account(&output_root_pda).unwrap();
+        assert_eq!(
+            account.data.len(),
+            DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE
+        );
+
+        let output_root = OutputRoot::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(output_root.root, [9u8; 32]);
+        assert_eq!(output_root.total_leaf_count, 11);
+        assert_eq!(output_root.base_block_number, 300);
+        assert_eq!(output_root.registered_at, 1747440000);
+        assert_eq!(output_root.revalidated_epoch, OutputRoot::NOT_REVALIDATED);
+
+        let result = migrate_output_root(&mut svm, &payer, output_root_pda, 300);
+        assert!(result.is_err(), "Expected a second migration to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AlreadyMigrated"),
+            "Expected AlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_output_root_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm, guardian, ..
+        } = setup_bridge();
+
+        let output_root_pda = write_legacy_output_root(&mut svm, 300);
+
+        let result = migrate_output_root(&mut svm, &guardian, output_root_pda, 300);
+        assert!(result.is_err(), "Expected the guardian to be rejected");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
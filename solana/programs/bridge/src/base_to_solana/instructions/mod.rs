@@ -1,10 +1,30 @@
+pub mod begin_relay;
 pub mod buffered;
+pub mod close_output_root;
+pub mod continue_relay;
+pub mod get_highest_relayed_nonce;
+pub mod get_oracle_signers;
+pub mod get_proven_message;
+pub mod migrate_incoming_message;
+pub mod migrate_output_root;
 pub mod prove_message;
 pub mod register_output_root;
+pub mod register_output_roots_batch;
 pub mod relay_message;
+pub mod revalidate_output_root;
 pub mod token;
 
+pub use begin_relay::*;
 pub use buffered::*;
+pub use close_output_root::*;
+pub use continue_relay::*;
+pub use get_highest_relayed_nonce::*;
+pub use get_oracle_signers::*;
+pub use get_proven_message::*;
+pub use migrate_incoming_message::*;
+pub use migrate_output_root::*;
 pub use prove_message::*;
 pub use register_output_root::*;
+pub use register_output_roots_batch::*;
 pub use relay_message::*;
+pub use revalidate_output_root::*;
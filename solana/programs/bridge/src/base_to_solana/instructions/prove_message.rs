@@ -1,10 +1,10 @@
 use anchor_lang::{prelude::*, solana_program::keccak};
 
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, PAUSE_PROVE};
 use crate::BridgeError;
 use crate::{
     base_to_solana::{
-        constants::INCOMING_MESSAGE_SEED,
+        constants::{INCOMING_MESSAGE_SEED, INCOMING_MESSAGE_VERSION},
         internal::mmr::{self},
         state::{IncomingMessage, OutputRoot},
         Message,
@@ -61,7 +61,7 @@ pub fn prove_message_handler(
     message_hash: [u8; 32],
 ) -> Result<()> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_PROVE)?;
 
     // Verify that the provided message hash matches the computed hash
     let computed_hash = hash_message(&nonce.to_be_bytes(), &sender, &data);
@@ -70,6 +70,28 @@ pub fn prove_message_handler(
         BridgeError::InvalidMessageHash
     );
 
+    // Reject roots outside the registered block range
+    ctx.accounts
+        .output_root
+        .validate_registered(ctx.accounts.bridge.base_block_number)?;
+
+    // Roots far behind the latest block must have been co-signed again by the current oracles
+    ctx.accounts.output_root.validate_revalidated(
+        ctx.accounts.bridge.base_block_number,
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .revalidate_old_roots_after_blocks,
+        ctx.accounts.bridge.base_oracle_epoch,
+    )?;
+
+    // Reject roots that were registered too long ago
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.output_root.validate_age(
+        ctx.accounts.bridge.protocol_config.max_root_age_seconds,
+        current_timestamp,
+    )?;
+
     // Verify the MMR proof to ensure the message was included on the source chain
     mmr::verify_proof(
         &ctx.accounts.output_root.root,
@@ -80,9 +102,12 @@ pub fn prove_message_handler(
     )?;
 
     *ctx.accounts.message = IncomingMessage {
+        version: INCOMING_MESSAGE_VERSION,
+        nonce,
         executed: false,
         sender,
         message: Message::try_from_slice(&data)?,
+        proven_at: current_timestamp,
     };
 
     Ok(())
@@ -93,7 +118,7 @@ pub fn prove_message_handler(
 /// - `nonce` is encoded as big-endian bytes.
 /// - `sender` is a 20-byte Base/EVM address.
 /// - `data` is the Borsh-serialized `Message` payload.
-fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
+pub fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
     let mut data_to_hash = Vec::new();
     data_to_hash.extend_from_slice(nonce);
     data_to_hash.extend_from_slice(sender);
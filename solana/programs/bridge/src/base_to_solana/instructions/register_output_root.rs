@@ -6,7 +6,10 @@ use crate::base_to_solana::{compute_output_root_message_hash, recover_unique_evm
 use crate::BridgeError;
 use crate::{
     base_to_solana::{constants::OUTPUT_ROOT_SEED, state::OutputRoot},
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{
+        bridge::Bridge, DuplicateRootRegistered, OutputRootRegistered, OverlappingSignersApproved,
+        ProtocolConfig, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_REGISTER_ROOT,
+    },
 };
 
 /// Accounts struct for the `register_output_root` instruction that stores Base MMR roots
@@ -43,7 +46,8 @@ pub struct RegisterOutputRoot<'info> {
     pub bridge: Account<'info, Bridge>,
 
     /// Partner `Config` account (PDA with seed "config") owned by partner program.
-    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in
+    /// the handler when partner approvals are required.
     /// CHECK: This is validated in the handler.
     pub partner_config: AccountInfo<'info>,
 
@@ -60,63 +64,219 @@ pub fn register_output_root_handler(
     signatures: Vec<[u8; 65]>,
 ) -> Result<()> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts
+        .bridge
+        .require_not_paused(PAUSE_REGISTER_ROOT)?;
 
     // Build message hash for signatures
     let message_hash =
         compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
 
+    let approvals = verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+        base_block_number,
+    )?;
+
+    let previous_block_number = ctx.accounts.bridge.base_block_number;
+    validate_next_block_number(
+        &ctx.accounts.bridge.protocol_config,
+        previous_block_number,
+        base_block_number,
+    )?;
+
+    let current_timestamp = consume_registration(&mut ctx.accounts.bridge, 1)?;
+    record_output_root(&mut ctx.accounts.bridge, output_root, base_block_number)?;
+
+    ctx.accounts.root.root = output_root;
+    ctx.accounts.root.total_leaf_count = total_leaf_count;
+    ctx.accounts.root.registered_at = current_timestamp;
+    ctx.accounts.root.base_block_number = base_block_number;
+    ctx.accounts.root.revalidated_epoch = OutputRoot::NOT_REVALIDATED;
+
+    emit!(OutputRootRegistered {
+        output_root,
+        base_block_number,
+        total_leaf_count,
+        base_signature_count: approvals.base_signature_count,
+        partner_signature_count: approvals.partner_signature_count,
+        payer: ctx.accounts.payer.key(),
+    });
+
+    Ok(())
+}
+
+/// Number of Base and partner oracle signers that approved a registration.
+pub(crate) struct OracleApprovals {
+    pub base_signature_count: u32,
+    pub partner_signature_count: u32,
+}
+
+/// Recovers the signers of `message_hash` and checks that they meet the Base oracle threshold
+/// and, when required, the partner threshold. Signers present in both sets are flagged with an
+/// event, or rejected when `disjoint_signer_sets` is enabled.
+pub(crate) fn verify_oracle_approvals(
+    bridge: &Bridge,
+    partner_config_info: &AccountInfo,
+    signatures: &[[u8; 65]],
+    message_hash: &[u8; 32],
+    base_block_number: u64,
+) -> Result<OracleApprovals> {
+    // Skip signature recovery entirely when no signatures were provided but some are required
+    require!(
+        !signatures.is_empty() || bridge.base_oracle_config.threshold == 0,
+        BridgeError::InsufficientBaseSignatures
+    );
+
     // Recover unique EVM signers from provided signatures
-    let unique_signers = recover_unique_evm_addresses(&signatures, &message_hash)?;
+    let unique_signers = recover_unique_evm_addresses(signatures, message_hash)?;
 
     // Verify Base oracle approvals
-    let base_approved_count = ctx
-        .accounts
-        .bridge
-        .base_oracle_config
-        .count_approvals(&unique_signers);
-
+    let base_oracle_config = &bridge.base_oracle_config;
     require!(
-        base_approved_count as u8 >= ctx.accounts.bridge.base_oracle_config.threshold,
+        base_oracle_config.count_approvals(&unique_signers) >= base_oracle_config.threshold as u32,
         BridgeError::InsufficientBaseSignatures
     );
 
-    if ctx.accounts.bridge.partner_oracle_config.required_threshold > 0 {
+    // Approval weight can exceed the number of signers, so count approving signers separately
+    let base_signature_count = unique_signers
+        .iter()
+        .filter(|signer| base_oracle_config.contains(signer))
+        .count() as u32;
+
+    let mut partner_signature_count = 0;
+    if bridge.partner_oracle_config.required_threshold > 0 {
         // Validate partner_config PDA using seed with the partner program id
         let expected_partner_cfg =
             Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0;
         require_keys_eq!(
-            ctx.accounts.partner_config.key(),
+            partner_config_info.key(),
             expected_partner_cfg,
             anchor_lang::error::ErrorCode::ConstraintSeeds
         );
+        require_keys_eq!(
+            *partner_config_info.owner,
+            PARTNER_PROGRAM_ID,
+            BridgeError::InvalidPartnerConfigOwner
+        );
 
         // Verify partner approvals using partner's signers (deserialize manually)
-        let partner_oracle_config = &ctx.accounts.bridge.partner_oracle_config;
-        let partner_config =
-            Signers::try_deserialize(&mut &ctx.accounts.partner_config.data.borrow()[..])?;
-        let partner_approved_count = partner_config.count_approvals(&unique_signers);
+        let partner_config = Signers::try_deserialize(&mut &partner_config_info.data.borrow()[..])?;
+        partner_signature_count = partner_config.count_approvals(&unique_signers);
         require!(
-            partner_approved_count as u8 >= partner_oracle_config.required_threshold,
+            partner_signature_count as u8 >= bridge.partner_oracle_config.required_threshold,
             BridgeError::InsufficientPartnerSignatures
         );
+
+        // Signers in both sets count toward both thresholds from a single signature
+        let overlapping_signers: Vec<[u8; 20]> = unique_signers
+            .iter()
+            .filter(|signer| base_oracle_config.contains(signer) && partner_config.contains(signer))
+            .copied()
+            .collect();
+        if !overlapping_signers.is_empty() {
+            require!(
+                !bridge.protocol_config.disjoint_signer_sets,
+                BridgeError::OverlappingSignerSets
+            );
+
+            emit!(OverlappingSignersApproved {
+                base_block_number,
+                signers: overlapping_signers,
+            });
+        }
     }
 
+    Ok(OracleApprovals {
+        base_signature_count,
+        partner_signature_count,
+    })
+}
+
+/// Checks that `base_block_number` may be registered right after `previous_block_number`: it must
+/// be strictly greater, aligned to the block interval and within `max_block_advance`.
+pub(crate) fn validate_next_block_number(
+    protocol_config: &ProtocolConfig,
+    previous_block_number: u64,
+    base_block_number: u64,
+) -> Result<()> {
     require!(
-        base_block_number > ctx.accounts.bridge.base_block_number
-            && base_block_number
-                % ctx
-                    .accounts
-                    .bridge
-                    .protocol_config
-                    .block_interval_requirement
-                == 0,
+        base_block_number > previous_block_number
+            && base_block_number.is_multiple_of(protocol_config.block_interval_requirement),
         BridgeError::IncorrectBlockNumber
     );
 
-    ctx.accounts.root.root = output_root;
-    ctx.accounts.root.total_leaf_count = total_leaf_count;
-    ctx.accounts.bridge.base_block_number = base_block_number;
+    // Bound how far a single registration can move the latest block forward. Skipped until the
+    // first root is registered, since the bridge starts at block 0.
+    let max_block_advance = protocol_config.max_block_advance;
+    require!(
+        max_block_advance == 0
+            || previous_block_number == 0
+            || base_block_number - previous_block_number <= max_block_advance,
+        BridgeError::BlockNumberTooFarAhead
+    );
+
+    Ok(())
+}
+
+/// Enforces the wall-clock cooldown and takes `count` registrations from the leaky bucket,
+/// returning the current timestamp.
+pub(crate) fn consume_registration(bridge: &mut Bridge, count: u64) -> Result<i64> {
+    // Enforce the wall-clock cooldown between registrations
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let elapsed = current_timestamp
+        .saturating_sub(bridge.last_registration_time)
+        .max(0) as u64;
+    require!(
+        elapsed >= bridge.protocol_config.min_registration_interval_seconds,
+        BridgeError::RegistrationTooSoon
+    );
+
+    // Take the registrations from the leaky bucket
+    let protocol_config = &bridge.protocol_config;
+    let (capacity, refill_interval_seconds) = (
+        protocol_config.registration_bucket_capacity,
+        protocol_config.registration_refill_interval_seconds,
+    );
+    require!(
+        bridge.registration_bucket.try_consume(
+            count,
+            capacity,
+            refill_interval_seconds,
+            current_timestamp
+        ),
+        BridgeError::RegistrationRateLimited
+    );
+
+    bridge.last_registration_time = current_timestamp;
+
+    Ok(current_timestamp)
+}
+
+/// Records `output_root` as the latest registered root, flagging (or, in strict mode,
+/// rejecting) a root identical to the previous one.
+pub(crate) fn record_output_root(
+    bridge: &mut Bridge,
+    output_root: [u8; 32],
+    base_block_number: u64,
+) -> Result<()> {
+    if output_root == bridge.last_output_root {
+        require!(
+            !bridge.protocol_config.reject_duplicate_roots,
+            BridgeError::DuplicateOutputRoot
+        );
+
+        emit!(DuplicateRootRegistered {
+            output_root,
+            previous_base_block_number: bridge.base_block_number,
+            base_block_number,
+        });
+    }
+
+    bridge.base_block_number = base_block_number;
+    bridge.last_output_root = output_root;
 
     Ok(())
 }
@@ -143,9 +303,12 @@ mod tests {
             constants::{OUTPUT_ROOT_SEED, PARTNER_SIGNERS_ACCOUNT_SEED},
             internal::compute_output_root_message_hash,
         },
-        common::{bridge::Bridge, MAX_SIGNER_COUNT},
+        common::{
+            bridge::{Bridge, RegistrationBucket},
+            MAX_SIGNER_COUNT,
+        },
         instruction::RegisterOutputRoot as RegisterOutputRootIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{emitted_events, mock_clock, setup_bridge, update_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -195,7 +358,10 @@ mod tests {
         base_block_number: u64,
         total_leaf_count: u64,
         signatures: Vec<[u8; 65]>,
-    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
         let root_pda = output_root_pda(base_block_number);
         let accounts = accounts::RegisterOutputRoot {
             payer: payer.pubkey(),
@@ -224,8 +390,7 @@ mod tests {
             svm.latest_blockhash(),
         );
 
-        svm.send_transaction(tx).map_err(Box::new)?;
-        Ok(())
+        svm.send_transaction(tx).map_err(Box::new)
     }
 
     fn make_eth_sig_and_addr(
@@ -261,27 +426,18 @@ mod tests {
         (sig65, addr)
     }
 
-    fn set_base_oracle_signers_threshold_one(
-        svm: &mut LiteSVM,
-        bridge_pda: Pubkey,
-        addr: [u8; 20],
-    ) {
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr;
-        bridge.base_oracle_config.signers = fixed_signers;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    fn set_base_oracle_signers_threshold_one(svm: &mut LiteSVM, addr: [u8; 20]) {
+        update_bridge(svm, |bridge| {
+            bridge.base_oracle_config.threshold = 1;
+            bridge.base_oracle_config.signer_count = 1;
+            let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            fixed_signers[0] = addr;
+            bridge.base_oracle_config.signers = fixed_signers;
+        });
     }
 
     fn prepare_base_sig_and_set_oracle(
         svm: &mut LiteSVM,
-        bridge_pda: Pubkey,
         sk_bytes: [u8; 32],
         output_root: [u8; 32],
         base_block_number: u64,
@@ -289,7 +445,7 @@ mod tests {
     ) -> [u8; 65] {
         let (sig, addr) =
             make_eth_sig_and_addr(sk_bytes, output_root, base_block_number, total_leaf_count);
-        set_base_oracle_signers_threshold_one(svm, bridge_pda, addr);
+        set_base_oracle_signers_threshold_one(svm, addr);
         sig
     }
 
@@ -310,7 +466,6 @@ mod tests {
         // Configure base oracle with a signer matching our generated signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [42u8; 32],
             output_root,
             base_block_number,
@@ -334,6 +489,53 @@ mod tests {
             .unwrap();
         let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
         assert_eq!(root.root, output_root);
+        assert_eq!(root.registered_at, svm.get_sysvar::<Clock>().unix_timestamp);
+        assert_eq!(root.base_block_number, base_block_number);
+    }
+
+    #[test]
+    fn test_register_output_root_emits_output_root_registered() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [3u8; 32];
+        let base_block_number = 300;
+        let total_leaf_count = 7;
+
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            [48u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+
+        let meta = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed");
+
+        let events = emitted_events::<OutputRootRegistered>(&meta);
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.output_root, output_root);
+        assert_eq!(event.base_block_number, base_block_number);
+        assert_eq!(event.total_leaf_count, total_leaf_count);
+        assert_eq!(event.base_signature_count, 1);
+        assert_eq!(event.partner_signature_count, 0);
+        assert_eq!(event.payer, payer.pubkey());
     }
 
     #[test]
@@ -353,7 +555,6 @@ mod tests {
         // Configure base oracle and provide a valid signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [43u8; 32],
             output_root,
             base_block_number,
@@ -396,7 +597,6 @@ mod tests {
         // Configure base oracle and provide a valid signature
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [44u8; 32],
             output_root,
             base_block_number,
@@ -431,13 +631,7 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
         // Pause the bridge
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         let result = send_register(
             &mut svm,
@@ -472,7 +666,6 @@ mod tests {
         // Configure base oracle and provide a valid signature so we hit the block interval check
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [45u8; 32],
             output_root,
             base_block_number,
@@ -508,13 +701,7 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
         // Set current base_block_number high
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_block_number = 600;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.base_block_number = 600);
 
         // Attempt to register an equal block number (aligned but not strictly greater)
         let output_root = [5u8; 32];
@@ -524,7 +711,6 @@ mod tests {
         // Configure base oracle and provide a valid signature so we hit the monotonicity check
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [46u8; 32],
             output_root,
             base_block_number,
@@ -549,6 +735,63 @@ mod tests {
         assert!(err_str.contains("IncorrectBlockNumber"));
     }
 
+    /// Registers a root at `base_block_number` with the latest block at 600 and
+    /// `max_block_advance` set to 900.
+    fn send_register_with_max_block_advance(
+        base_block_number: u64,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        update_bridge(&mut svm, |bridge| {
+            bridge.base_block_number = 600;
+            bridge.protocol_config.max_block_advance = 900;
+        });
+
+        let output_root = [6u8; 32];
+        let total_leaf_count = 10;
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            [47u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+    }
+
+    #[test]
+    fn test_register_output_root_within_max_block_advance() {
+        send_register_with_max_block_advance(1500)
+            .expect("advance equal to max_block_advance should succeed");
+    }
+
+    #[test]
+    fn test_register_output_root_fails_when_too_far_ahead() {
+        let result = send_register_with_max_block_advance(1800);
+        assert!(result.is_err(), "expected failure for excessive advance");
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(err_str.contains("BlockNumberTooFarAhead"));
+    }
+
     #[test]
     fn test_register_output_root_fails_with_insufficient_base_signatures() {
         let SetupBridgeResult {
@@ -560,17 +803,13 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
         // Raise base oracle threshold to 1 and set a dummy signer on the bridge config
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = [7u8; 20];
-        bridge.base_oracle_config.signers = fixed_signers;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.base_oracle_config.threshold = 1;
+            bridge.base_oracle_config.signer_count = 1;
+            let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            fixed_signers[0] = [7u8; 20];
+            bridge.base_oracle_config.signers = fixed_signers;
+        });
 
         // No signatures provided -> not enough unique approvals
         let result = send_register(
@@ -603,13 +842,9 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[[9u8; 20]]);
 
         // Set partner required_threshold to 1
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.partner_oracle_config.required_threshold = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.partner_oracle_config.required_threshold = 1;
+        });
 
         // Also satisfy base oracle threshold with a valid signature that partner does NOT accept
         let output_root = [8u8; 32];
@@ -617,7 +852,6 @@ mod tests {
         let total_leaf_count = 10;
         let sig = prepare_base_sig_and_set_oracle(
             &mut svm,
-            bridge_pda,
             [47u8; 32],
             output_root,
             base_block_number,
@@ -642,6 +876,54 @@ mod tests {
         assert!(err_str.contains("InsufficientPartnerSignatures"));
     }
 
+    #[test]
+    fn test_register_output_root_fails_with_wrong_partner_config_owner() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let output_root = [12u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 10;
+        let (sig, addr) =
+            make_eth_sig_and_addr([48u8; 32], output_root, base_block_number, total_leaf_count);
+        set_base_oracle_signers_threshold_one(&mut svm, addr);
+
+        // Partner data that would approve the signature, but owned by another program
+        let partner_cfg = write_partner_config_account(&mut svm, &[addr]);
+        let mut partner_acc = svm.get_account(&partner_cfg).unwrap();
+        partner_acc.owner = Pubkey::new_unique();
+        svm.set_account(partner_cfg, partner_acc).unwrap();
+
+        update_bridge(&mut svm, |bridge| {
+            bridge.partner_oracle_config.required_threshold = 1;
+        });
+
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        );
+        assert!(
+            result.is_err(),
+            "expected failure for a partner config with the wrong owner"
+        );
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(
+            err_str.contains("InvalidPartnerConfigOwner"),
+            "Unexpected error: {}",
+            err_str
+        );
+    }
+
     #[test]
     fn test_signature_verification_success_with_thresholds() {
         let SetupBridgeResult {
@@ -652,9 +934,6 @@ mod tests {
         } = setup_bridge();
 
         // Configure base oracle signers threshold = 2 with 2 authorized addrs on the bridge config
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-
         // Generate two ECDSA keypairs and signatures
         let sk1 = [1u8; 32];
         let sk2 = [2u8; 32];
@@ -666,26 +945,20 @@ mod tests {
         let (sig2, addr2) =
             make_eth_sig_and_addr(sk2, output_root, base_block_number, total_leaf_count);
 
-        bridge.base_oracle_config.threshold = 2;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr1;
-        fixed_signers[1] = addr2;
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 2;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.base_oracle_config.threshold = 2;
+            let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            fixed_signers[0] = addr1;
+            fixed_signers[1] = addr2;
+            bridge.base_oracle_config.signers = fixed_signers;
+            bridge.base_oracle_config.signer_count = 2;
+        });
 
         // Partner requires 1 signature and authorizes signer addr1
         let partner_cfg = write_partner_config_account(&mut svm, &[addr1]);
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.partner_oracle_config.required_threshold = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.partner_oracle_config.required_threshold = 1;
+        });
 
         // Submit both signatures
         send_register(
@@ -701,6 +974,74 @@ mod tests {
         .expect("register_output_root should succeed with valid signatures");
     }
 
+    /// Registers a root approved by a single signer that is in both the Base and partner sets.
+    fn send_register_with_overlapping_signer(
+        disjoint_signer_sets: bool,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let output_root = [12u8; 32];
+        let base_block_number = 300;
+        let total_leaf_count = 5;
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            [3u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+        let (_, addr) =
+            make_eth_sig_and_addr([3u8; 32], output_root, base_block_number, total_leaf_count);
+
+        let partner_cfg = write_partner_config_account(&mut svm, &[addr]);
+        update_bridge(&mut svm, |bridge| {
+            bridge.partner_oracle_config.required_threshold = 1;
+            bridge.protocol_config.disjoint_signer_sets = disjoint_signer_sets;
+        });
+
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+    }
+
+    #[test]
+    fn test_register_output_root_overlapping_signer_allowed_by_default() {
+        let meta = send_register_with_overlapping_signer(false)
+            .expect("overlapping signer should be accepted by default");
+
+        let events = emitted_events::<OverlappingSignersApproved>(&meta);
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.base_block_number, 300);
+        assert_eq!(event.signers.len(), 1);
+    }
+
+    #[test]
+    fn test_register_output_root_overlapping_signer_rejected_when_disjoint() {
+        let result = send_register_with_overlapping_signer(true);
+        assert!(
+            result.is_err(),
+            "expected overlapping signer to be rejected"
+        );
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(err_str.contains("OverlappingSignerSets"));
+    }
+
     #[test]
     fn test_signature_verification_deduplicates_signers() {
         let SetupBridgeResult {
@@ -711,9 +1052,6 @@ mod tests {
         } = setup_bridge();
 
         // Base oracle requires 2 unique approvals, but we will submit the same signer twice
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-
         let sk = [3u8; 32];
         let output_root = [12u8; 32];
         let base_block_number = 900;
@@ -721,15 +1059,13 @@ mod tests {
         let (sig, addr) =
             make_eth_sig_and_addr(sk, output_root, base_block_number, total_leaf_count);
 
-        bridge.base_oracle_config.threshold = 2;
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = addr;
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.base_oracle_config.threshold = 2;
+            let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            fixed_signers[0] = addr;
+            bridge.base_oracle_config.signers = fixed_signers;
+            bridge.base_oracle_config.signer_count = 1;
+        });
 
         // Partner threshold 0; focus on base signer dedup
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
@@ -763,18 +1099,14 @@ mod tests {
         let partner_cfg = write_partner_config_account(&mut svm, &[]);
 
         // Base oracle requires 1 signer but we'll submit an invalid signature (bad v)
-        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
-        bridge.base_oracle_config.threshold = 1;
-        // authorize some dummy address so that threshold logic would pass if signature were valid
-        let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
-        fixed_signers[0] = [0xAA; 20];
-        bridge.base_oracle_config.signers = fixed_signers;
-        bridge.base_oracle_config.signer_count = 1;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_acc.data = new_data;
-        svm.set_account(bridge_pda, bridge_acc).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.base_oracle_config.threshold = 1;
+            // authorize some dummy address so that threshold logic would pass if signature were valid
+            let mut fixed_signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            fixed_signers[0] = [0xAA; 20];
+            bridge.base_oracle_config.signers = fixed_signers;
+            bridge.base_oracle_config.signer_count = 1;
+        });
 
         let output_root = [13u8; 32];
         let base_block_number = 1200;
@@ -806,4 +1138,287 @@ mod tests {
                 || err_str.contains("custom program error")
         );
     }
+
+    #[test]
+    fn test_register_output_root_fails_when_too_soon() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let now = 1747440000;
+        mock_clock(&mut svm, now);
+
+        // Require 60 seconds between registrations
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.min_registration_interval_seconds = 60;
+        });
+
+        let sk = [42u8; 32];
+        let output_root = [1u8; 32];
+        let total_leaf_count = 1;
+
+        // First registration succeeds
+        let sig = prepare_base_sig_and_set_oracle(&mut svm, sk, output_root, 300, total_leaf_count);
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            300,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("first register_output_root should succeed");
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.last_registration_time, now);
+
+        // Second registration within the cooldown fails
+        mock_clock(&mut svm, now + 59);
+        let (sig, _) = make_eth_sig_and_addr(sk, output_root, 600, total_leaf_count);
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            600,
+            total_leaf_count,
+            vec![sig],
+        );
+        assert!(result.is_err(), "expected failure due to cooldown");
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(
+            err_str.contains("RegistrationTooSoon"),
+            "Expected RegistrationTooSoon error, got: {}",
+            err_str
+        );
+
+        // Once the cooldown has elapsed the registration succeeds
+        mock_clock(&mut svm, now + 60);
+        svm.expire_blockhash();
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            600,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed after the cooldown");
+    }
+
+    #[test]
+    fn test_register_output_root_duplicate_root_emits_event_or_fails_when_strict() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let sk = [42u8; 32];
+        let output_root = [1u8; 32];
+        let total_leaf_count = 1;
+
+        let sig = prepare_base_sig_and_set_oracle(&mut svm, sk, output_root, 300, total_leaf_count);
+        let meta = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            300,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("first register_output_root should succeed");
+        assert!(emitted_events::<DuplicateRootRegistered>(&meta).is_empty());
+
+        // Registering the same root again succeeds but is flagged with an event
+        let (sig, _) = make_eth_sig_and_addr(sk, output_root, 600, total_leaf_count);
+        let meta = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            600,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("duplicate root should be accepted when not strict");
+        assert!(!emitted_events::<DuplicateRootRegistered>(&meta).is_empty());
+
+        // In strict mode the duplicate is rejected
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.reject_duplicate_roots = true;
+        });
+
+        let (sig, _) = make_eth_sig_and_addr(sk, output_root, 900, total_leaf_count);
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            900,
+            total_leaf_count,
+            vec![sig],
+        );
+        assert!(result.is_err(), "expected duplicate root to be rejected");
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(
+            err_str.contains("DuplicateOutputRoot"),
+            "Expected DuplicateOutputRoot error, got: {}",
+            err_str
+        );
+
+        // A new root is still accepted
+        let new_output_root = [2u8; 32];
+        let (sig, _) = make_eth_sig_and_addr(sk, new_output_root, 900, total_leaf_count);
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            new_output_root,
+            900,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root with a new root should succeed");
+    }
+    #[test]
+    fn test_register_output_root_fails_when_rate_limited() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let now = 1747440000;
+        mock_clock(&mut svm, now);
+
+        // Allow a burst of 2 registrations, refilling one every 60 seconds
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.registration_bucket_capacity = 2;
+            bridge.protocol_config.registration_refill_interval_seconds = 60;
+            bridge.registration_bucket = RegistrationBucket::new(2, now);
+        });
+
+        let sk = [42u8; 32];
+        let output_root = [1u8; 32];
+        let total_leaf_count = 1;
+
+        // Drain the bucket
+        let sig = prepare_base_sig_and_set_oracle(&mut svm, sk, output_root, 300, total_leaf_count);
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            300,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("first register_output_root should succeed");
+
+        let (sig, _) = make_eth_sig_and_addr(sk, output_root, 600, total_leaf_count);
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            600,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("second register_output_root should succeed");
+
+        // The bucket is empty
+        let (sig, _) = make_eth_sig_and_addr(sk, output_root, 900, total_leaf_count);
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            900,
+            total_leaf_count,
+            vec![sig],
+        );
+        assert!(result.is_err(), "expected failure due to rate limit");
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(
+            err_str.contains("RegistrationRateLimited"),
+            "Expected RegistrationRateLimited error, got: {}",
+            err_str
+        );
+
+        // One registration is refilled after the interval
+        mock_clock(&mut svm, now + 60);
+        svm.expire_blockhash();
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            900,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed after refill");
+    }
+
+    #[test]
+    fn test_register_output_root_fails_with_empty_signatures() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        // Default test config requires a single base oracle signature
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.base_oracle_config.threshold, 1);
+
+        let result = send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            [1u8; 32],
+            300,
+            1,
+            vec![],
+        );
+        assert!(
+            result.is_err(),
+            "expected failure due to missing signatures"
+        );
+        let err_str = format!("{:?}", result.unwrap_err());
+        assert!(
+            err_str.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            err_str
+        );
+    }
 }
@@ -0,0 +1,479 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount},
+};
+
+use crate::{
+    base_to_solana::{
+        compute_output_root_batch_message_hash,
+        constants::{MAX_OUTPUT_ROOT_BATCH_SIZE, OUTPUT_ROOT_SEED},
+        consume_registration, record_output_root,
+        state::OutputRoot,
+        validate_next_block_number, verify_oracle_approvals,
+    },
+    common::{
+        bridge::Bridge, OutputRootRegistered, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_REGISTER_ROOT,
+    },
+    BridgeError,
+};
+
+/// A single output root within a `register_output_roots_batch` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutputRootEntry {
+    /// The 32-byte MMR root of Base messages for `base_block_number`
+    pub output_root: [u8; 32],
+    /// The Base block number this output root corresponds to
+    pub base_block_number: u64,
+    /// The total number of leaves in the MMR with this root
+    pub total_leaf_count: u64,
+}
+
+/// Accounts struct for the `register_output_roots_batch` instruction that registers several
+/// consecutive Base output roots under a single set of oracle signatures.
+///
+/// The output root PDAs (`[OUTPUT_ROOT_SEED, base_block_number.to_le_bytes()]`) are passed as
+/// writable `remaining_accounts`, one per entry and in the same order as the entries.
+#[derive(Accounts)]
+pub struct RegisterOutputRootsBatch<'info> {
+    /// Payer funds the output root accounts. Authorization is enforced via oracle EVM signatures.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account that tracks the latest registered Base block number.
+    /// - Uses PDA with BRIDGE_SEED
+    /// - Must be mutable to update the base_block_number field
+    /// - Enforces registrations are monotonic and aligned to the configured interval
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in
+    /// the handler when partner approvals are required.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the output root accounts.
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers `entries` in order, as if each had been registered with `register_output_root`.
+///
+/// The oracles sign a single digest over the whole batch (see
+/// `compute_output_root_batch_message_hash`). Every entry must be strictly after and aligned
+/// with the previous one, and the batch as a whole may advance the latest block by at most
+/// `max_block_advance`. The batch counts as one registration against the cooldown, but each entry
+/// takes its own token from the registration rate limit.
+pub fn register_output_roots_batch_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RegisterOutputRootsBatch<'info>>,
+    entries: Vec<OutputRootEntry>,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // Check if bridge is paused
+    ctx.accounts
+        .bridge
+        .require_not_paused(PAUSE_REGISTER_ROOT)?;
+
+    require!(
+        !entries.is_empty()
+            && entries.len() <= MAX_OUTPUT_ROOT_BATCH_SIZE as usize
+            && entries.len() == ctx.remaining_accounts.len(),
+        BridgeError::InvalidOutputRootBatch
+    );
+
+    // Build message hash for signatures over the whole batch
+    let message_hash = compute_output_root_batch_message_hash(&entries);
+
+    let last_block_number = entries[entries.len() - 1].base_block_number;
+    let approvals = verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+        last_block_number,
+    )?;
+
+    let mut previous_block_number = ctx.accounts.bridge.base_block_number;
+    for entry in &entries {
+        validate_next_block_number(
+            &ctx.accounts.bridge.protocol_config,
+            previous_block_number,
+            entry.base_block_number,
+        )?;
+        previous_block_number = entry.base_block_number;
+    }
+
+    // Bound the advance of the batch as a whole, not only of each entry
+    validate_next_block_number(
+        &ctx.accounts.bridge.protocol_config,
+        ctx.accounts.bridge.base_block_number,
+        last_block_number,
+    )?;
+
+    let current_timestamp = consume_registration(&mut ctx.accounts.bridge, entries.len() as u64)?;
+
+    for (entry, root_info) in entries.iter().zip(ctx.remaining_accounts) {
+        create_output_root_account(
+            ctx.program_id,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            root_info,
+            entry.base_block_number,
+        )?;
+
+        let root = OutputRoot {
+            root: entry.output_root,
+            total_leaf_count: entry.total_leaf_count,
+            registered_at: current_timestamp,
+            base_block_number: entry.base_block_number,
+            revalidated_epoch: OutputRoot::NOT_REVALIDATED,
+        };
+        root.try_serialize(&mut &mut root_info.try_borrow_mut_data()?[..])?;
+
+        record_output_root(
+            &mut ctx.accounts.bridge,
+            entry.output_root,
+            entry.base_block_number,
+        )?;
+
+        emit!(OutputRootRegistered {
+            output_root: entry.output_root,
+            base_block_number: entry.base_block_number,
+            total_leaf_count: entry.total_leaf_count,
+            base_signature_count: approvals.base_signature_count,
+            partner_signature_count: approvals.partner_signature_count,
+            payer: ctx.accounts.payer.key(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates the output root PDA for `base_block_number` at `root_info`, funded by `payer`.
+/// Mirrors Anchor's `init`, including for accounts that were pre-funded with lamports.
+fn create_output_root_account<'info>(
+    program_id: &Pubkey,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    root_info: &AccountInfo<'info>,
+    base_block_number: u64,
+) -> Result<()> {
+    let block_number_bytes = base_block_number.to_le_bytes();
+    let (expected_root, bump) =
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &block_number_bytes], program_id);
+    require_keys_eq!(
+        root_info.key(),
+        expected_root,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    let signer_seeds: &[&[u8]] = &[OUTPUT_ROOT_SEED, &block_number_bytes, &[bump]];
+    let space = DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE;
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = root_info.lamports();
+
+    if current_lamports == 0 {
+        create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: root_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent_exempt_lamports,
+            space as u64,
+            program_id,
+        )?;
+    } else {
+        let shortfall = rent_exempt_lamports.saturating_sub(current_lamports);
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: root_info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        allocate(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Allocate {
+                    account_to_allocate: root_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        assign(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                Assign {
+                    account_to_assign: root_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            program_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            keccak::hash as keccak_hash,
+        },
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+        common::MAX_SIGNER_COUNT,
+        instruction::RegisterOutputRootsBatch as RegisterOutputRootsBatchIx,
+        test_utils::{setup_bridge, update_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn output_root_pda(base_block_number: u64) -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+    }
+
+    fn entry(base_block_number: u64) -> OutputRootEntry {
+        OutputRootEntry {
+            output_root: [base_block_number as u8; 32],
+            base_block_number,
+            total_leaf_count: base_block_number / 100,
+        }
+    }
+
+    /// Signs the batch digest with a fresh key and makes that key the only Base oracle signer.
+    fn sign_batch_and_set_oracle(
+        svm: &mut LiteSVM,
+        entries: &[OutputRootEntry],
+        update: impl FnOnce(&mut Bridge),
+    ) -> [u8; 65] {
+        let msg_hash = compute_output_root_batch_message_hash(entries);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&[7u8; 32]).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&keccak_hash(&pk.serialize_uncompressed()[1..]).to_bytes()[12..]);
+
+        update_bridge(svm, |bridge| {
+            bridge.base_oracle_config.threshold = 1;
+            bridge.base_oracle_config.signer_count = 1;
+            bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            bridge.base_oracle_config.signers[0] = addr;
+            update(bridge);
+        });
+
+        sig65
+    }
+
+    fn send_register_batch(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        entries: Vec<OutputRootEntry>,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let mut accounts = accounts::RegisterOutputRootsBatch {
+            payer: payer.pubkey(),
+            bridge: bridge_pda,
+            partner_config: Pubkey::find_program_address(
+                &[PARTNER_SIGNERS_ACCOUNT_SEED],
+                &PARTNER_PROGRAM_ID,
+            )
+            .0,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            entries
+                .iter()
+                .map(|entry| AccountMeta::new(output_root_pda(entry.base_block_number), false)),
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RegisterOutputRootsBatchIx {
+                entries,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_registers_every_root() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let entries = vec![entry(300), entry(600), entry(900)];
+        let sig = sign_batch_and_set_oracle(&mut svm, &entries, |_| {});
+
+        send_register_batch(&mut svm, &payer, bridge_pda, entries.clone(), vec![sig])
+            .expect("register_output_roots_batch should succeed");
+
+        let now = svm.get_sysvar::<Clock>().unix_timestamp;
+        for entry in &entries {
+            let account = svm
+                .get_account(&output_root_pda(entry.base_block_number))
+                .unwrap();
+            assert_eq!(account.owner, ID);
+            let root = OutputRoot::try_deserialize(&mut &account.data[..]).unwrap();
+            assert_eq!(root.root, entry.output_root);
+            assert_eq!(root.base_block_number, entry.base_block_number);
+            assert_eq!(root.total_leaf_count, entry.total_leaf_count);
+            assert_eq!(root.registered_at, now);
+        }
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.base_block_number, 900);
+        assert_eq!(bridge.last_output_root, entries[2].output_root);
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_rejects_gap() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // 900 is aligned but skips 600, which is more than one interval past 300
+        let entries = vec![entry(300), entry(900)];
+        let sig = sign_batch_and_set_oracle(&mut svm, &entries, |bridge| {
+            bridge.protocol_config.max_block_advance = 300;
+        });
+
+        let err = send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig])
+            .expect_err("a batch with a gap should fail");
+        assert!(
+            format!("{:?}", err).contains("BlockNumberTooFarAhead"),
+            "Unexpected error: {:?}",
+            err
+        );
+
+        // Nothing from the batch was registered
+        assert!(svm.get_account(&output_root_pda(300)).is_none());
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.base_block_number, 0);
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_bounds_total_advance() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Each entry is one interval after the previous, but together they advance 600 blocks
+        let entries = vec![entry(600), entry(900), entry(1200)];
+        let sig = sign_batch_and_set_oracle(&mut svm, &entries, |bridge| {
+            bridge.base_block_number = 300;
+            bridge.protocol_config.max_block_advance = 300;
+        });
+
+        let err = send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig])
+            .expect_err("a batch advancing past max_block_advance should fail");
+        assert!(
+            format!("{:?}", err).contains("BlockNumberTooFarAhead"),
+            "Unexpected error: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_takes_a_token_per_entry() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let entries = vec![entry(300), entry(600), entry(900)];
+        let sig = sign_batch_and_set_oracle(&mut svm, &entries, |bridge| {
+            bridge.protocol_config.registration_bucket_capacity = 2;
+            bridge.protocol_config.registration_refill_interval_seconds = 60;
+            bridge.registration_bucket.tokens = 2;
+        });
+
+        let err = send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig])
+            .expect_err("a batch larger than the available tokens should fail");
+        assert!(
+            format!("{:?}", err).contains("RegistrationRateLimited"),
+            "Unexpected error: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_rejects_oversized_batch() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let entries: Vec<_> = (1..=MAX_OUTPUT_ROOT_BATCH_SIZE as u64 + 1)
+            .map(|i| entry(i * 300))
+            .collect();
+        let sig = sign_batch_and_set_oracle(&mut svm, &entries, |_| {});
+
+        let err = send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig])
+            .expect_err("a batch above MAX_OUTPUT_ROOT_BATCH_SIZE should fail");
+        assert!(
+            format!("{:?}", err).contains("InvalidOutputRootBatch"),
+            "Unexpected error: {:?}",
+            err
+        );
+    }
+}
@@ -4,14 +4,20 @@ use anchor_lang::{
 };
 
 use crate::base_to_solana::{
-    constants::BRIDGE_CPI_AUTHORITY_SEED, state::IncomingMessage, Message, Transfer,
+    constants::{BRIDGE_CPI_AUTHORITY_SEED, INCOMING_MESSAGE_VERSION},
+    state::IncomingMessage,
+    Ix, Message, Transfer,
 };
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, PAUSE_RELAY};
 use crate::BridgeError;
 
 /// Accounts struct for the relay message instruction that executes cross-chain messages from Base to Solana.
 /// This instruction processes incoming messages that contain either pure instruction calls or token transfers
 /// with additional instructions. The message execution is performed through CPI calls using a bridge authority.
+///
+/// The `guardian` slot is always part of the account list: when no co-signature is provided, pass the
+/// bridge program ID in its place. The transfer and instruction accounts are passed as `remaining_accounts`
+/// and start right after the guardian slot.
 #[derive(Accounts)]
 pub struct RelayMessage<'info> {
     /// The incoming message account containing the cross-chain message to be executed.
@@ -23,59 +29,815 @@ pub struct RelayMessage<'info> {
 
     /// The main bridge state account used to check pause status
     /// - Uses PDA with BRIDGE_SEED for deterministic address
-    #[account(seeds = [BRIDGE_SEED], bump)]
+    /// - Mutable to track the SOL released from the vault
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
+
+    /// Optional guardian co-signer, required for SOL finalizations above
+    /// `bridge.protocol_config.large_finalize_threshold`.
+    /// - Pass the bridge program ID when the guardian does not co-sign
+    #[account(address = bridge.guardian @ BridgeError::GuardianApprovalRequired)]
+    pub guardian: Option<Signer<'info>>,
 }
 
 pub fn relay_message_handler<'a, 'info>(
     ctx: Context<'a, '_, 'info, 'info, RelayMessage<'info>>,
 ) -> Result<()> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_RELAY)?;
+
+    require!(
+        ctx.accounts.message.version == INCOMING_MESSAGE_VERSION,
+        BridgeError::UnsupportedMessageVersion
+    );
 
     require!(!ctx.accounts.message.executed, BridgeError::AlreadyExecuted);
 
+    let nonce = ctx.accounts.message.nonce;
+    ctx.accounts.bridge.record_relayed_nonce(nonce)?;
+
+    // Give proven messages time to settle before anyone can relay them
+    ctx.accounts.message.validate_relay_delay(
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .min_prove_to_relay_delay_seconds,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     let message = ctx.accounts.message.message.clone();
     let (transfer, ixs) = match message {
         Message::Call(ixs) => (None, ixs),
         Message::Transfer { transfer, ixs } => (Some(transfer), ixs),
     };
 
+    if ctx.accounts.bridge.protocol_config.reject_duplicate_ixs {
+        ensure_no_duplicate_ixs(&ixs)?;
+    }
+
     // Process the transfer if it exists
     if let Some(transfer) = transfer {
-        match transfer {
-            Transfer::Sol(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::Spl(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::WrappedToken(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-        };
+        finalize_transfer(
+            &mut ctx.accounts.bridge,
+            ctx.accounts.guardian.is_some(),
+            transfer,
+            ctx.remaining_accounts,
+        )?;
     }
 
     ctx.accounts.message.executed = true;
 
-    // Derive the bridge CPI authority PDA tied to the message sender; used to sign all downstream CPIs.
-    let (_, bump) = Pubkey::find_program_address(
-        &[
-            BRIDGE_CPI_AUTHORITY_SEED,
-            ctx.accounts.message.sender.as_ref(),
-        ],
+    // Relayed instructions must not be able to close or realloc the bridge's own accounts
+    let protected_accounts = [ctx.accounts.bridge.key(), ctx.accounts.message.key()];
+    for ix in &ixs {
+        ensure_no_protected_mutation(ix, ctx.program_id, &protected_accounts)?;
+    }
+
+    invoke_relayed_ixs(
         ctx.program_id,
-    );
+        &ctx.accounts.message.sender,
+        ixs,
+        ctx.remaining_accounts,
+    )
+}
+
+/// Validates and finalizes the token transfer carried by an incoming message.
+///
+/// Large SOL finalizations act as a circuit breaker and need the guardian's co-signature
+/// (`guardian_signed`).
+pub(crate) fn finalize_transfer<'info>(
+    bridge: &mut Bridge,
+    guardian_signed: bool,
+    transfer: Transfer,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    transfer.validate()?;
+
+    let threshold = bridge.protocol_config.large_finalize_threshold;
+    if let Transfer::Sol(sol_transfer) = &transfer {
+        require!(
+            threshold == 0 || sol_transfer.amount <= threshold || guardian_signed,
+            BridgeError::GuardianApprovalRequired
+        );
+    }
 
-    let bridge_cpi_authority_seeds: &[&[u8]] = &[
-        BRIDGE_CPI_AUTHORITY_SEED,
-        ctx.accounts.message.sender.as_ref(),
-        &[bump],
-    ];
+    match transfer {
+        Transfer::Sol(transfer) => transfer.finalize(bridge, remaining_accounts),
+        Transfer::Spl(transfer) => transfer.finalize(remaining_accounts),
+        Transfer::WrappedToken(transfer) => transfer.finalize(remaining_accounts),
+    }
+}
+
+/// Executes relayed instructions via CPI, signed by the bridge CPI authority PDA tied to the
+/// message `sender`.
+pub(crate) fn invoke_relayed_ixs<'info>(
+    program_id: &Pubkey,
+    sender: &[u8; 20],
+    ixs: Vec<Ix>,
+    account_infos: &[AccountInfo<'info>],
+) -> Result<()> {
+    let (_, bump) =
+        Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], program_id);
+
+    let bridge_cpi_authority_seeds: &[&[u8]] =
+        &[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref(), &[bump]];
 
-    // Execute the provided downstream instructions via signed CPI
     for ix in ixs {
         // NOTE: We always do a signed CPI even if the actual program CPIed into might not require the bridge authority signer.
         solana_program::program::invoke_signed(
             &ix.into(),
-            ctx.remaining_accounts,
+            account_infos,
             &[bridge_cpi_authority_seeds],
         )?;
     }
 
     Ok(())
 }
+
+/// Rejects messages containing two identical instructions, which usually point to an encoding bug
+/// (e.g. the same mint or transfer repeated).
+pub(crate) fn ensure_no_duplicate_ixs(ixs: &[Ix]) -> Result<()> {
+    for (i, ix) in ixs.iter().enumerate() {
+        require!(
+            !ixs[i + 1..].contains(ix),
+            BridgeError::DuplicateRelayedInstruction
+        );
+    }
+
+    Ok(())
+}
+
+/// Rejects relayed instructions that could mutate accounts owned by the bridge.
+///
+/// Only the bridge program can close or realloc its own PDAs (state, output roots, incoming
+/// messages), so re-entering the bridge is refused outright. Protected accounts must also not be
+/// passed as writable to any other program.
+pub(crate) fn ensure_no_protected_mutation(
+    ix: &Ix,
+    bridge_program_id: &Pubkey,
+    protected_accounts: &[Pubkey],
+) -> Result<()> {
+    require_keys_neq!(
+        ix.program_id,
+        *bridge_program_id,
+        BridgeError::ProtectedAccountMutation
+    );
+
+    require!(
+        !ix.accounts
+            .iter()
+            .any(|account| account.is_writable && protected_accounts.contains(&account.pubkey)),
+        BridgeError::ProtectedAccountMutation
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            native_token::LAMPORTS_PER_SOL,
+        },
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_message::Message as SolanaMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{token::FinalizeBridgeSol, IxAccount},
+        common::SOL_VAULT_SEED,
+        instruction::{
+            GetHighestRelayedNonce as GetHighestRelayedNonceIx, RelayMessage as RelayMessageIx,
+        },
+        test_utils::{mock_clock, setup_bridge, update_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn write_incoming_message(
+        svm: &mut litesvm::LiteSVM,
+        incoming_message: &IncomingMessage,
+    ) -> Pubkey {
+        let message_pda = Pubkey::new_unique();
+
+        let mut data = Vec::new();
+        incoming_message.try_serialize(&mut data).unwrap();
+
+        svm.set_account(
+            message_pda,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        message_pda
+    }
+
+    fn relay_message_ix(message: Pubkey, bridge: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                bridge,
+                guardian: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_relay_message_current_version_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = write_incoming_message(
+            &mut svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION,
+                nonce: 0,
+                sender: [7u8; 20],
+                message: Message::Call(vec![]),
+                executed: false,
+                proven_at: 0,
+            },
+        );
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(
+                &[relay_message_ix(message, bridge_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to relay message");
+
+        let message_account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &message_account.data[..]).unwrap();
+        assert_eq!(incoming.version, INCOMING_MESSAGE_VERSION);
+        assert!(incoming.executed);
+    }
+
+    #[test]
+    fn test_relay_message_unknown_version_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = write_incoming_message(
+            &mut svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION + 1,
+                nonce: 0,
+                sender: [7u8; 20],
+                message: Message::Call(vec![]),
+                executed: false,
+                proven_at: 0,
+            },
+        );
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(
+                &[relay_message_ix(message, bridge_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(
+            result.is_err(),
+            "Expected transaction to fail with an unknown message version"
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnsupportedMessageVersion"),
+            "Expected UnsupportedMessageVersion error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_relay_message_rejects_ix_targeting_bridge_program() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // A relayed instruction that re-enters the bridge with its state account writable
+        let message = write_incoming_message(
+            &mut svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION,
+                nonce: 0,
+                sender: [7u8; 20],
+                message: Message::Call(vec![Ix {
+                    program_id: ID,
+                    accounts: vec![IxAccount {
+                        pubkey: bridge_pda,
+                        is_writable: true,
+                        is_signer: false,
+                    }],
+                    data: vec![],
+                }]),
+                executed: false,
+                proven_at: 0,
+            },
+        );
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(
+                &[relay_message_ix(message, bridge_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected relay to fail");
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("ProtectedAccountMutation"),
+            "Expected ProtectedAccountMutation error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_ensure_no_protected_mutation() {
+        let protected = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+        let ix = |program_id: Pubkey, is_writable: bool| Ix {
+            program_id,
+            accounts: vec![IxAccount {
+                pubkey: protected,
+                is_writable,
+                is_signer: false,
+            }],
+            data: vec![],
+        };
+
+        // Read-only references from other programs are allowed
+        assert!(ensure_no_protected_mutation(&ix(other_program, false), &ID, &[protected]).is_ok());
+        // Writable references and re-entering the bridge are not
+        assert!(ensure_no_protected_mutation(&ix(other_program, true), &ID, &[protected]).is_err());
+        assert!(ensure_no_protected_mutation(&ix(ID, false), &ID, &[protected]).is_err());
+    }
+
+    fn set_locked_sol(svm: &mut litesvm::LiteSVM, locked_sol: u64) {
+        update_bridge(svm, |bridge| bridge.locked_sol = locked_sol);
+    }
+
+    fn relay_sol_transfer(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        to: Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        relay_sol_transfer_with_guardian(svm, payer, bridge_pda, to, amount, None)
+    }
+
+    fn relay_sol_transfer_with_guardian(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        to: Pubkey,
+        amount: u64,
+        guardian: Option<&solana_keypair::Keypair>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let message = write_incoming_message(
+            svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION,
+                nonce: 0,
+                sender: [7u8; 20],
+                message: Message::Transfer {
+                    transfer: Transfer::Sol(FinalizeBridgeSol { to, amount }),
+                    ixs: vec![],
+                },
+                executed: false,
+                proven_at: 0,
+            },
+        );
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let mut ix = relay_message_ix(message, bridge_pda);
+        if let Some(guardian) = guardian {
+            ix.accounts = accounts::RelayMessage {
+                message,
+                bridge: bridge_pda,
+                guardian: Some(guardian.pubkey()),
+            }
+            .to_account_metas(None);
+        }
+        ix.accounts.extend([
+            AccountMeta::new(sol_vault, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        ]);
+
+        let mut signers = vec![payer];
+        signers.extend(guardian);
+        let tx = Transaction::new(
+            &signers,
+            SolanaMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_relay_sol_transfer_decrements_locked_sol() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 10 * LAMPORTS_PER_SOL).unwrap();
+        set_locked_sol(&mut svm, 5 * LAMPORTS_PER_SOL);
+
+        let to = Pubkey::new_unique();
+        relay_sol_transfer(&mut svm, &payer, bridge_pda, to, 2 * LAMPORTS_PER_SOL)
+            .expect("Failed to relay SOL transfer");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.locked_sol, 3 * LAMPORTS_PER_SOL);
+        assert_eq!(svm.get_account(&to).unwrap().lamports, 2 * LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn test_relay_sol_transfer_fails_when_exceeding_locked_sol() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The vault holds more than was ever locked through bridge_sol
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 10 * LAMPORTS_PER_SOL).unwrap();
+        set_locked_sol(&mut svm, LAMPORTS_PER_SOL);
+
+        let to = Pubkey::new_unique();
+        let result = relay_sol_transfer(&mut svm, &payer, bridge_pda, to, 2 * LAMPORTS_PER_SOL);
+        assert!(
+            result.is_err(),
+            "Expected relay to fail when releasing more than locked"
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("SolReleaseExceedsLocked"),
+            "Expected SolReleaseExceedsLocked error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_relay_sol_transfer_rejects_zero_amount() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, LAMPORTS_PER_SOL).unwrap();
+        set_locked_sol(&mut svm, LAMPORTS_PER_SOL);
+
+        let to = Pubkey::new_unique();
+        let result = relay_sol_transfer(&mut svm, &payer, bridge_pda, to, 0);
+        assert!(result.is_err(), "Expected relay of a zero amount to fail");
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidBridgePayload"),
+            "Expected InvalidBridgePayload error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_relay_large_sol_transfer_requires_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 10 * LAMPORTS_PER_SOL).unwrap();
+        set_locked_sol(&mut svm, 10 * LAMPORTS_PER_SOL);
+
+        // Finalizations above 1 SOL need the guardian
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.large_finalize_threshold = LAMPORTS_PER_SOL;
+        });
+
+        let to = Pubkey::new_unique();
+
+        // At the threshold no guardian is needed
+        relay_sol_transfer(&mut svm, &payer, bridge_pda, to, LAMPORTS_PER_SOL)
+            .expect("relay below the threshold should succeed without the guardian");
+
+        // Above the threshold the guardian must co-sign
+        let result = relay_sol_transfer(&mut svm, &payer, bridge_pda, to, 2 * LAMPORTS_PER_SOL);
+        assert!(result.is_err(), "Expected relay without guardian to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("GuardianApprovalRequired"),
+            "Expected GuardianApprovalRequired error, got: {}",
+            error_string
+        );
+
+        relay_sol_transfer_with_guardian(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            to,
+            2 * LAMPORTS_PER_SOL,
+            Some(&guardian),
+        )
+        .expect("relay above the threshold should succeed with the guardian");
+
+        assert_eq!(svm.get_account(&to).unwrap().lamports, 3 * LAMPORTS_PER_SOL);
+    }
+
+    fn relay_with_prove_to_relay_delay(
+        elapsed_seconds: i64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.min_prove_to_relay_delay_seconds = 60;
+        });
+
+        let proven_at = svm.get_sysvar::<Clock>().unix_timestamp;
+        let message = write_incoming_message(
+            &mut svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION,
+                nonce: 0,
+                sender: [7u8; 20],
+                message: Message::Call(vec![]),
+                executed: false,
+                proven_at,
+            },
+        );
+        mock_clock(&mut svm, proven_at + elapsed_seconds);
+
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(
+                &[relay_message_ix(message, bridge_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_relay_before_prove_to_relay_delay_fails() {
+        let err = relay_with_prove_to_relay_delay(59).unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("RelayTooSoon"),
+            "Unexpected error: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_relay_after_prove_to_relay_delay_succeeds() {
+        relay_with_prove_to_relay_delay(60).expect("relay after the delay should succeed");
+    }
+
+    fn relay_duplicate_ixs(
+        reject_duplicate_ixs: bool,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.reject_duplicate_ixs = reject_duplicate_ixs;
+        });
+
+        // The same zero-lamport system transfer from the sender's CPI authority, twice
+        let sender = [7u8; 20];
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], &ID).0;
+        let to = Pubkey::new_unique();
+        let mut transfer_data = 2u32.to_le_bytes().to_vec();
+        transfer_data.extend_from_slice(&0u64.to_le_bytes());
+        let transfer_ix = Ix {
+            program_id: anchor_lang::system_program::ID,
+            accounts: vec![
+                IxAccount {
+                    pubkey: cpi_authority,
+                    is_writable: true,
+                    is_signer: true,
+                },
+                IxAccount {
+                    pubkey: to,
+                    is_writable: true,
+                    is_signer: false,
+                },
+            ],
+            data: transfer_data,
+        };
+
+        let message = write_incoming_message(
+            &mut svm,
+            &IncomingMessage {
+                version: INCOMING_MESSAGE_VERSION,
+                nonce: 0,
+                sender,
+                message: Message::Call(vec![transfer_ix.clone(), transfer_ix]),
+                executed: false,
+                proven_at: 0,
+            },
+        );
+
+        let mut ix = relay_message_ix(message, bridge_pda);
+        ix.accounts.extend([
+            AccountMeta::new(cpi_authority, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        ]);
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_relay_duplicate_ixs_allowed_by_default() {
+        relay_duplicate_ixs(false).expect("duplicate instructions should relay by default");
+    }
+
+    #[test]
+    fn test_relay_duplicate_ixs_rejected_when_enabled() {
+        let err = relay_duplicate_ixs(true).unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("DuplicateRelayedInstruction"),
+            "Unexpected error: {:?}",
+            err
+        );
+    }
+
+    /// Relays one `Call` message per entry of `nonces`, in order, returning each relay's outcome
+    /// and the highest relayed nonce reported afterwards.
+    fn relay_nonces(
+        nonces: &[u64],
+        enforce_relay_nonce_order: bool,
+    ) -> (
+        Vec<std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>>>,
+        Option<u64>,
+    ) {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.enforce_relay_nonce_order = enforce_relay_nonce_order;
+        });
+
+        let results = nonces
+            .iter()
+            .map(|&nonce| {
+                let message = write_incoming_message(
+                    &mut svm,
+                    &IncomingMessage {
+                        version: INCOMING_MESSAGE_VERSION,
+                        nonce,
+                        sender: [7u8; 20],
+                        message: Message::Call(vec![]),
+                        executed: false,
+                        proven_at: 0,
+                    },
+                );
+                let tx = Transaction::new(
+                    &[&payer],
+                    SolanaMessage::new(
+                        &[relay_message_ix(message, bridge_pda)],
+                        Some(&payer.pubkey()),
+                    ),
+                    svm.latest_blockhash(),
+                );
+                let result = svm.send_transaction(tx).map(|_| ()).map_err(Box::new);
+                svm.expire_blockhash();
+                result
+            })
+            .collect();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetHighestRelayedNonce { bridge: bridge_pda }
+                .to_account_metas(None),
+            data: GetHighestRelayedNonceIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            SolanaMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_highest_relayed_nonce should succeed");
+        let highest_relayed_nonce = Option::<u64>::try_from_slice(&meta.return_data.data).unwrap();
+
+        (results, highest_relayed_nonce)
+    }
+
+    #[test]
+    fn test_relay_in_nonce_order_tracks_highest_nonce() {
+        let (results, highest_relayed_nonce) = relay_nonces(&[0, 1, 3], true);
+        for result in results {
+            result.expect("increasing nonces should relay");
+        }
+        // The skipped nonce 2 is visible to monitoring as a gap below the highest relayed nonce
+        assert_eq!(highest_relayed_nonce, Some(3));
+    }
+
+    #[test]
+    fn test_relay_duplicate_nonce_rejected_when_enforced() {
+        let (results, highest_relayed_nonce) = relay_nonces(&[4, 4], true);
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("RelayNonceOutOfOrder"),
+            "Unexpected error: {:?}",
+            err
+        );
+        assert_eq!(highest_relayed_nonce, Some(4));
+    }
+
+    #[test]
+    fn test_relay_out_of_order_nonce() {
+        let (results, highest_relayed_nonce) = relay_nonces(&[2, 1], true);
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err();
+        assert!(
+            format!("{:?}", err).contains("RelayNonceOutOfOrder"),
+            "Unexpected error: {:?}",
+            err
+        );
+        assert_eq!(highest_relayed_nonce, Some(2));
+
+        // Without enforcement the late message relays but does not lower the highest nonce
+        let (results, highest_relayed_nonce) = relay_nonces(&[2, 1], false);
+        for result in results {
+            result.expect("out-of-order nonces should relay when not enforced");
+        }
+        assert_eq!(highest_relayed_nonce, Some(2));
+    }
+}
@@ -0,0 +1,295 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::{
+    compute_output_root_revalidation_message_hash, constants::OUTPUT_ROOT_SEED, state::OutputRoot,
+    verify_oracle_approvals,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+
+/// Accounts struct for the `revalidate_output_root` instruction that records a fresh co-sign of
+/// an already registered output root by the current Base oracle set.
+#[derive(Accounts)]
+#[instruction(base_block_number: u64)]
+pub struct RevalidateOutputRoot<'info> {
+    /// The main bridge state account holding the current Base oracle set.
+    /// - Uses PDA with BRIDGE_SEED
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The output root account being re-validated.
+    /// - Uses PDA with OUTPUT_ROOT_SEED and base_block_number
+    #[account(
+        mut,
+        seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+        bump
+    )]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address and owner are validated in
+    /// the handler when partner approvals are required.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+}
+
+/// Marks an output root as re-validated in the current `base_oracle_epoch` once the current
+/// oracle sets sign it again.
+///
+/// Roots are signed by the oracle set active at registration. When
+/// `revalidate_old_roots_after_blocks` is set, roots far behind the latest registered block can
+/// only be proven against after the signers active now have approved the root, meeting the same
+/// thresholds as a registration. They sign a revalidation digest bound to the current
+/// `base_oracle_epoch` (see `compute_output_root_revalidation_message_hash`), so neither the
+/// registration signatures nor approvals given to an earlier signer set can be replayed.
+pub fn revalidate_output_root_handler(
+    ctx: Context<RevalidateOutputRoot>,
+    base_block_number: u64,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    let output_root = &ctx.accounts.output_root;
+    let message_hash = compute_output_root_revalidation_message_hash(
+        &output_root.root,
+        base_block_number,
+        output_root.total_leaf_count,
+        ctx.accounts.bridge.base_oracle_epoch,
+    );
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+        base_block_number,
+    )?;
+
+    ctx.accounts.output_root.revalidated_epoch = ctx.accounts.bridge.base_oracle_epoch;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::Instruction, keccak::hash as keccak_hash, native_token::LAMPORTS_PER_SOL,
+        },
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            compute_output_root_message_hash,
+            constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+        },
+        common::MAX_SIGNER_COUNT,
+        instruction::RevalidateOutputRoot as RevalidateOutputRootIx,
+        test_utils::{setup_bridge, update_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const BASE_BLOCK_NUMBER: u64 = 600;
+    const ROOT: [u8; 32] = [5u8; 32];
+    const TOTAL_LEAF_COUNT: u64 = 3;
+
+    fn output_root_pda() -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &BASE_BLOCK_NUMBER.to_le_bytes()], &ID).0
+    }
+
+    fn write_output_root(svm: &mut LiteSVM) {
+        let output_root = OutputRoot {
+            root: ROOT,
+            total_leaf_count: TOTAL_LEAF_COUNT,
+            registered_at: 0,
+            base_block_number: BASE_BLOCK_NUMBER,
+            revalidated_epoch: OutputRoot::NOT_REVALIDATED,
+        };
+        let mut data = Vec::new();
+        output_root.try_serialize(&mut data).unwrap();
+        svm.set_account(
+            output_root_pda(),
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    /// Signs the root's revalidation message for signer epoch 0 with `sk_bytes` and returns the
+    /// 65-byte signature along with the signer's EVM address.
+    fn sign_root(sk_bytes: [u8; 32]) -> ([u8; 65], [u8; 20]) {
+        sign_digest(
+            sk_bytes,
+            compute_output_root_revalidation_message_hash(
+                &ROOT,
+                BASE_BLOCK_NUMBER,
+                TOTAL_LEAF_COUNT,
+                0,
+            ),
+        )
+    }
+
+    fn sign_digest(sk_bytes: [u8; 32], msg_hash: [u8; 32]) -> ([u8; 65], [u8; 20]) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        (sig65, addr)
+    }
+
+    fn set_single_oracle(svm: &mut LiteSVM, addr: [u8; 20]) {
+        update_bridge(svm, |bridge| {
+            let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+            signers[0] = addr;
+            bridge.base_oracle_config.signers = signers;
+            bridge.base_oracle_config.signer_count = 1;
+            bridge.base_oracle_config.threshold = 1;
+        });
+    }
+
+    fn send_revalidate(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RevalidateOutputRoot {
+                bridge: bridge_pda,
+                output_root: output_root_pda(),
+                partner_config: Pubkey::find_program_address(
+                    &[PARTNER_SIGNERS_ACCOUNT_SEED],
+                    &PARTNER_PROGRAM_ID,
+                )
+                .0,
+            }
+            .to_account_metas(None),
+            data: RevalidateOutputRootIx {
+                base_block_number: BASE_BLOCK_NUMBER,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    fn is_revalidated(svm: &LiteSVM) -> bool {
+        let account = svm.get_account(&output_root_pda()).unwrap();
+        let revalidated_epoch = OutputRoot::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .revalidated_epoch;
+        let bridge_pda = Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0;
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        revalidated_epoch
+            == Bridge::try_deserialize(&mut &bridge_account.data[..])
+                .unwrap()
+                .base_oracle_epoch
+    }
+
+    #[test]
+    fn test_revalidate_output_root_with_current_oracle_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        write_output_root(&mut svm);
+
+        let (sig, addr) = sign_root([21u8; 32]);
+        set_single_oracle(&mut svm, addr);
+
+        send_revalidate(&mut svm, &payer, bridge_pda, vec![sig])
+            .expect("revalidate_output_root should succeed");
+        assert!(is_revalidated(&svm));
+    }
+
+    #[test]
+    fn test_revalidate_output_root_rejects_rotated_out_signer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        write_output_root(&mut svm);
+
+        let (old_sig, _) = sign_root([21u8; 32]);
+        let (_, new_addr) = sign_root([22u8; 32]);
+        set_single_oracle(&mut svm, new_addr);
+
+        let result = send_revalidate(&mut svm, &payer, bridge_pda, vec![old_sig]);
+        assert!(
+            result.is_err(),
+            "Expected rotated-out signer to be rejected"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("InsufficientBaseSignatures"),
+            "Unexpected error: {}",
+            err
+        );
+        assert!(!is_revalidated(&svm));
+    }
+
+    #[test]
+    fn test_revalidate_output_root_rejects_registration_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        write_output_root(&mut svm);
+
+        let (_, addr) = sign_root([21u8; 32]);
+        let (registration_sig, _) = sign_digest(
+            [21u8; 32],
+            compute_output_root_message_hash(&ROOT, BASE_BLOCK_NUMBER, TOTAL_LEAF_COUNT),
+        );
+        set_single_oracle(&mut svm, addr);
+
+        let result = send_revalidate(&mut svm, &payer, bridge_pda, vec![registration_sig]);
+        assert!(
+            result.is_err(),
+            "Expected a replayed registration signature to be rejected"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("InsufficientBaseSignatures"),
+            "Unexpected error: {}",
+            err
+        );
+        assert!(!is_revalidated(&svm));
+    }
+}
@@ -4,7 +4,10 @@ use anchor_lang::{
 };
 
 use crate::BridgeError;
-use crate::{common::SOL_VAULT_SEED, ID};
+use crate::{
+    common::{bridge::Bridge, SOL_VAULT_SEED},
+    ID,
+};
 
 /// Instruction data for finalizing a native SOL transfer from Base to Solana.
 ///
@@ -23,7 +26,11 @@ pub struct FinalizeBridgeSol {
 }
 
 impl FinalizeBridgeSol {
-    pub fn finalize<'info>(&self, account_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+    pub fn finalize<'info>(
+        &self,
+        bridge: &mut Bridge,
+        account_infos: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
         // Read the accounts in the expected order
         let mut iter = account_infos.iter();
         let sol_vault_info = next_account_info(&mut iter)?;
@@ -43,6 +50,17 @@ impl FinalizeBridgeSol {
             BridgeError::IncorrectSolVault
         );
 
+        // Never release more SOL than was locked through `bridge_sol`
+        bridge.locked_sol = bridge
+            .locked_sol
+            .checked_sub(self.amount)
+            .ok_or(BridgeError::SolReleaseExceedsLocked)?;
+
+        let daily_limit = bridge.protocol_config.daily_inbound_sol_limit;
+        bridge
+            .inbound_sol_volume
+            .add(self.amount, daily_limit, Clock::get()?.unix_timestamp)?;
+
         // Transfer SOL from the SOL vault to the recipient
         let seeds: &[&[&[u8]]] = &[&[SOL_VAULT_SEED, &[sol_vault_bump]]];
         let cpi_ctx = CpiContext::new_with_signer(
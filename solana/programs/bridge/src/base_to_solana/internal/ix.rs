@@ -2,7 +2,7 @@ use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
 
 /// Instruction to be executed by the bridge program via signed CPI during message relay.
 /// Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging.
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
 pub struct Ix {
     /// Program that will process this instruction.
     pub program_id: Pubkey,
@@ -14,7 +14,7 @@ pub struct Ix {
 
 /// Account used in an instruction.
 /// Similar to Solana's `AccountMeta`, but serializable with Anchor and supports PDAs via `PubkeyOrPda`.
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
 pub struct IxAccount {
     /// Public key of the account.
     pub pubkey: Pubkey,
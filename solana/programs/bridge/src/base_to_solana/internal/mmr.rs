@@ -34,6 +34,12 @@ pub fn verify_proof(
 
     require!(*leaf_index < total_leaf_count, BridgeError::InvalidProof);
 
+    // Reject over-long proofs before spending compute on hashing
+    require!(
+        proof.len() <= max_proof_len(total_leaf_count),
+        BridgeError::ProofTooLong
+    );
+
     let calculated_root =
         calculate_root_from_proof(proof, leaf_hash, *leaf_index, total_leaf_count)?;
 
@@ -42,6 +48,22 @@ pub fn verify_proof(
     Ok(())
 }
 
+/// Returns the maximum number of proof elements for any leaf of an MMR with `total_leaf_count`
+/// leaves.
+///
+/// A proof holds the intra-mountain path (at most the height of the tallest mountain,
+/// `floor(log2(total_leaf_count))`) followed by the peaks of every other mountain
+/// (`popcount(total_leaf_count) - 1`).
+pub fn max_proof_len(total_leaf_count: u64) -> usize {
+    if total_leaf_count == 0 {
+        return 0;
+    }
+
+    let max_height = total_leaf_count.ilog2();
+    let other_peaks = total_leaf_count.count_ones() - 1;
+    (max_height + other_peaks) as usize
+}
+
 /// Calculates the MMR root given a leaf, its proof, and the MMR structure.
 ///
 /// This function reconstructs the peaks of the MMR based on the provided leaf and its proof,
@@ -177,3 +199,42 @@ fn efficient_keccak256(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
     data_to_hash.extend_from_slice(b);
     keccak::hash(&data_to_hash).to_bytes()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_proof_len() {
+        assert_eq!(max_proof_len(0), 0);
+        assert_eq!(max_proof_len(1), 0);
+        assert_eq!(max_proof_len(2), 1);
+        assert_eq!(max_proof_len(3), 2);
+        assert_eq!(max_proof_len(8), 3);
+        assert_eq!(max_proof_len(15), 6);
+        assert_eq!(max_proof_len(u64::MAX), 126);
+    }
+
+    #[test]
+    fn test_verify_proof_accepts_max_length_proof() {
+        // Three leaves: one mountain of height 1 (leaves 0, 1) and one of height 0 (leaf 2)
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let root = ordered_keccak256(commutative_keccak256(leaves[0], leaves[1]), leaves[2]);
+
+        let proof = [leaves[1], leaves[2]];
+        assert_eq!(proof.len(), max_proof_len(3));
+        assert!(verify_proof(&root, &leaves[0], &0, &proof, 3).is_ok());
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_over_long_proof() {
+        let leaves = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let root = ordered_keccak256(commutative_keccak256(leaves[0], leaves[1]), leaves[2]);
+
+        let proof = [leaves[1], leaves[2], [0u8; 32]];
+        assert_eq!(
+            verify_proof(&root, &leaves[0], &0, &proof, 3).unwrap_err(),
+            BridgeError::ProofTooLong.into()
+        );
+    }
+}
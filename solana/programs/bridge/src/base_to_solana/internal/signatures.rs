@@ -1,21 +1,95 @@
-use crate::BridgeError;
+use crate::{base_to_solana::OutputRootEntry, BridgeError};
 use anchor_lang::{
     prelude::*,
     solana_program::{keccak, secp256k1_recover::secp256k1_recover},
 };
 
+/// Computes the digest that Base oracles sign to attest to an output root.
+///
 /// message = keccak256("\x19Ethereum Signed Message:\n" || len || (output_root || base_block_number_be || total_leaf_count_be))
+///
+/// The preimage is fixed: the 32-byte root followed by the block number and leaf count as
+/// big-endian `u64`s, wrapped in the EIP-191 personal message prefix with `len` = "48".
+/// Off-chain signers must produce exactly this digest; it is re-exported at the crate root.
 pub fn compute_output_root_message_hash(
     output_root: &[u8; 32],
     base_block_number: u64,
     total_leaf_count: u64,
 ) -> [u8; 32] {
-    // Construct the original message bytes
-    let mut message_bytes = Vec::with_capacity(32 + 8 + 8);
+    let mut message_bytes = Vec::with_capacity(OUTPUT_ROOT_PREIMAGE_LEN);
+    extend_output_root_preimage(
+        &mut message_bytes,
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    );
+
+    hash_eth_signed_message(&message_bytes)
+}
+
+/// Computes the digest that Base oracles sign to attest to a batch of output roots.
+///
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || (preimage_0 || preimage_1 || ...))
+///
+/// Each `preimage_i` is the 48-byte `output_root || base_block_number_be || total_leaf_count_be`
+/// used by [`compute_output_root_message_hash`], in batch order, so `len` is `48 * entries.len()`.
+pub fn compute_output_root_batch_message_hash(entries: &[OutputRootEntry]) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(OUTPUT_ROOT_PREIMAGE_LEN * entries.len());
+    for entry in entries {
+        extend_output_root_preimage(
+            &mut message_bytes,
+            &entry.output_root,
+            entry.base_block_number,
+            entry.total_leaf_count,
+        );
+    }
+
+    hash_eth_signed_message(&message_bytes)
+}
+
+/// Computes the digest that Base oracles sign to re-validate an already registered output root.
+///
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("revalidate" || output_root || base_block_number_be || total_leaf_count_be || epoch_be))
+///
+/// The `"revalidate"` tag keeps the public registration signatures from being replayed as
+/// re-validations, and the signer-set `epoch` (`Bridge::base_oracle_epoch`) keeps a
+/// re-validation from being replayed under a later signer set. `len` is "66".
+pub fn compute_output_root_revalidation_message_hash(
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+    epoch: u64,
+) -> [u8; 32] {
+    let mut message_bytes =
+        Vec::with_capacity(REVALIDATION_TAG.len() + OUTPUT_ROOT_PREIMAGE_LEN + 8);
+    message_bytes.extend_from_slice(REVALIDATION_TAG);
+    extend_output_root_preimage(
+        &mut message_bytes,
+        output_root,
+        base_block_number,
+        total_leaf_count,
+    );
+    message_bytes.extend_from_slice(&epoch.to_be_bytes());
+
+    hash_eth_signed_message(&message_bytes)
+}
+
+const OUTPUT_ROOT_PREIMAGE_LEN: usize = 32 + 8 + 8;
+
+const REVALIDATION_TAG: &[u8] = b"revalidate";
+
+fn extend_output_root_preimage(
+    message_bytes: &mut Vec<u8>,
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+) {
     message_bytes.extend_from_slice(output_root);
     message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
     message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
+}
 
+fn hash_eth_signed_message(message_bytes: &[u8]) -> [u8; 32] {
     // Apply the Ethereum signed message prefix per EIP-191
     // "\x19Ethereum Signed Message:\n" + len(message) + message
     let prefix: &[u8] = b"\x19Ethereum Signed Message:\n";
@@ -25,7 +99,7 @@ pub fn compute_output_root_message_hash(
         Vec::with_capacity(prefix.len() + len_dec_string.len() + message_bytes.len());
     prefixed.extend_from_slice(prefix);
     prefixed.extend_from_slice(len_dec_string.as_bytes());
-    prefixed.extend_from_slice(&message_bytes);
+    prefixed.extend_from_slice(message_bytes);
 
     keccak::hash(&prefixed).0
 }
@@ -67,3 +141,87 @@ pub fn recover_eth_address(signature: &[u8; 65], message_hash: &[u8; 32]) -> Res
     eth_pubkey_bytes.copy_from_slice(&h[12..]);
     Ok(eth_pubkey_bytes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn test_compute_output_root_message_hash_layout_is_stable() {
+        let output_root = [0x11u8; 32];
+        let base_block_number = 600u64;
+        let total_leaf_count = 42u64;
+
+        let mut preimage = b"\x19Ethereum Signed Message:\n48".to_vec();
+        preimage.extend_from_slice(&output_root);
+        preimage.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0x02, 0x58]);
+        preimage.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0x2a]);
+
+        let hash =
+            compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
+
+        assert_eq!(hash, keccak::hash(&preimage).0);
+        assert_eq!(
+            hash,
+            hex!("84dc0a24bf3045072f7b4a24f6402fd41acf574b818c4b3ac6001a0ceaa8735f")
+        );
+    }
+
+    #[test]
+    fn test_compute_output_root_revalidation_message_hash_is_domain_separated() {
+        let output_root = [0x11u8; 32];
+
+        let mut preimage = b"\x19Ethereum Signed Message:\n66revalidate".to_vec();
+        preimage.extend_from_slice(&output_root);
+        preimage.extend_from_slice(&600u64.to_be_bytes());
+        preimage.extend_from_slice(&42u64.to_be_bytes());
+        preimage.extend_from_slice(&7u64.to_be_bytes());
+
+        let hash = compute_output_root_revalidation_message_hash(&output_root, 600, 42, 7);
+        assert_eq!(hash, keccak::hash(&preimage).0);
+        assert_ne!(
+            hash,
+            compute_output_root_message_hash(&output_root, 600, 42)
+        );
+        assert_ne!(
+            hash,
+            compute_output_root_revalidation_message_hash(&output_root, 600, 42, 8)
+        );
+    }
+
+    #[test]
+    fn test_compute_output_root_batch_message_hash_concatenates_entries() {
+        let entries = [
+            OutputRootEntry {
+                output_root: [0x11u8; 32],
+                base_block_number: 600,
+                total_leaf_count: 42,
+            },
+            OutputRootEntry {
+                output_root: [0x22u8; 32],
+                base_block_number: 900,
+                total_leaf_count: 43,
+            },
+        ];
+
+        // A single-entry batch signs the same digest as a single registration
+        assert_eq!(
+            compute_output_root_batch_message_hash(&entries[..1]),
+            compute_output_root_message_hash(&[0x11u8; 32], 600, 42)
+        );
+
+        let mut preimage = b"\x19Ethereum Signed Message:\n96".to_vec();
+        preimage.extend_from_slice(&[0x11u8; 32]);
+        preimage.extend_from_slice(&600u64.to_be_bytes());
+        preimage.extend_from_slice(&42u64.to_be_bytes());
+        preimage.extend_from_slice(&[0x22u8; 32]);
+        preimage.extend_from_slice(&900u64.to_be_bytes());
+        preimage.extend_from_slice(&43u64.to_be_bytes());
+
+        assert_eq!(
+            compute_output_root_batch_message_hash(&entries),
+            keccak::hash(&preimage).0
+        );
+    }
+}
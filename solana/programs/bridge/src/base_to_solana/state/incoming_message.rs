@@ -4,6 +4,7 @@ use crate::base_to_solana::{
     token::{FinalizeBridgeSol, FinalizeBridgeSpl, FinalizeBridgeWrappedToken},
     Ix,
 };
+use crate::BridgeError;
 
 /// Represents a cross-chain message sent from Base to Solana
 /// that is waiting to be processed or has already been executed.
@@ -16,6 +17,13 @@ use crate::base_to_solana::{
 #[account]
 #[derive(Debug)]
 pub struct IncomingMessage {
+    /// Layout version of this account. Checked on relay so that accounts written with a
+    /// different layout are rejected instead of being silently mis-parsed.
+    pub version: u8,
+
+    /// The nonce assigned to this message on Base.
+    pub nonce: u64,
+
     /// The 20-byte EVM address of the sender on Base who initiated this bridge operation.
     /// Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.
     /// This field does not restrict who can call the relay instruction.
@@ -28,17 +36,41 @@ pub struct IncomingMessage {
     /// Flag indicating whether this message has been successfully executed on Solana.
     /// Once set to true, the message cannot be executed again, preventing replay attacks.
     pub executed: bool,
+
+    /// Unix timestamp at which the message was proven. Relays are rejected until the configured
+    /// `min_prove_to_relay_delay_seconds` has elapsed since then.
+    pub proven_at: i64,
 }
 
 impl IncomingMessage {
     /// Returns the byte size for account allocation excluding the DISCRIMINATOR_LEN-byte Anchor discriminator.
     ///
     /// Layout:
+    /// - `version`: 1 byte
+    /// - `nonce`: 8 bytes
     /// - `sender`: 20 bytes
     /// - `message`: 4-byte length prefix + `data_len` bytes (Anchor-serialized `Message`)
     /// - `executed`: 1 byte
+    /// - `proven_at`: 8 bytes
     pub fn space(data_len: usize) -> usize {
-        20 + (4 + data_len) + 1
+        1 + 8 + 20 + (4 + data_len) + 1 + 8
+    }
+
+    /// Ensures at least `min_delay_seconds` have passed since the message was proven.
+    /// A `min_delay_seconds` of 0 disables the check.
+    pub fn validate_relay_delay(
+        &self,
+        min_delay_seconds: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        if min_delay_seconds == 0 {
+            return Ok(());
+        }
+
+        let elapsed = current_timestamp.saturating_sub(self.proven_at).max(0) as u64;
+        require!(elapsed >= min_delay_seconds, BridgeError::RelayTooSoon);
+
+        Ok(())
     }
 }
 
@@ -81,3 +113,100 @@ pub enum Transfer {
     /// Mints wrapped tokens on Solana to represent the Base asset.
     WrappedToken(FinalizeBridgeWrappedToken),
 }
+
+impl Transfer {
+    /// Rejects transfer payloads whose fields can never describe a valid transfer, so that a
+    /// malformed message fails with `InvalidBridgePayload` instead of an arbitrary error
+    /// further down the finalization path.
+    ///
+    /// - `amount` must be non-zero
+    /// - `to` and `local_token` must not be the default (all-zero) pubkey
+    /// - `remote_token` must not be the zero address
+    pub fn validate(&self) -> Result<()> {
+        let valid = match self {
+            Transfer::Sol(transfer) => transfer.amount > 0 && transfer.to != Pubkey::default(),
+            Transfer::Spl(transfer) => {
+                transfer.amount > 0
+                    && transfer.to != Pubkey::default()
+                    && transfer.local_token != Pubkey::default()
+                    && transfer.remote_token != [0u8; 20]
+            }
+            Transfer::WrappedToken(transfer) => {
+                transfer.amount > 0
+                    && transfer.to != Pubkey::default()
+                    && transfer.local_token != Pubkey::default()
+            }
+        };
+        require!(valid, BridgeError::InvalidBridgePayload);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spl_transfer() -> FinalizeBridgeSpl {
+        FinalizeBridgeSpl {
+            remote_token: [1u8; 20],
+            local_token: Pubkey::new_unique(),
+            to: Pubkey::new_unique(),
+            amount: 100,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_well_formed_transfers() {
+        assert!(Transfer::Sol(FinalizeBridgeSol {
+            to: Pubkey::new_unique(),
+            amount: 1,
+        })
+        .validate()
+        .is_ok());
+        assert!(Transfer::Spl(spl_transfer()).validate().is_ok());
+        assert!(Transfer::WrappedToken(FinalizeBridgeWrappedToken {
+            local_token: Pubkey::new_unique(),
+            to: Pubkey::new_unique(),
+            amount: 1,
+        })
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_transfers() {
+        let malformed = [
+            Transfer::Sol(FinalizeBridgeSol {
+                to: Pubkey::new_unique(),
+                amount: 0,
+            }),
+            Transfer::Sol(FinalizeBridgeSol {
+                to: Pubkey::default(),
+                amount: 1,
+            }),
+            Transfer::Spl(FinalizeBridgeSpl {
+                remote_token: [0u8; 20],
+                ..spl_transfer()
+            }),
+            Transfer::Spl(FinalizeBridgeSpl {
+                local_token: Pubkey::default(),
+                ..spl_transfer()
+            }),
+            Transfer::WrappedToken(FinalizeBridgeWrappedToken {
+                local_token: Pubkey::new_unique(),
+                to: Pubkey::default(),
+                amount: 1,
+            }),
+        ];
+
+        for transfer in malformed {
+            assert_eq!(
+                transfer.validate().unwrap_err(),
+                BridgeError::InvalidBridgePayload.into(),
+                "expected {:?} to be rejected",
+                transfer
+            );
+        }
+    }
+}
@@ -1,9 +1,11 @@
 pub mod incoming_message;
 pub mod output_root;
 pub mod prove_buffer;
+pub mod relay_cursor;
 pub mod signers;
 
 pub use incoming_message::*;
 pub use output_root::*;
 pub use prove_buffer::*;
+pub use relay_cursor::*;
 pub use signers::*;
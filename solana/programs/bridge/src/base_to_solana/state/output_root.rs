@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::BridgeError;
+
 /// Represents a cryptographic commitment to the set of Base L2 bridge messages
 /// at a specific Base block number.
 ///
@@ -23,4 +25,66 @@ pub struct OutputRoot {
     /// was generated. This is crucial for determining the MMR structure and
     /// mountain configuration at the time of proof validation.
     pub total_leaf_count: u64,
+
+    /// Solana unix timestamp at which this root was registered.
+    pub registered_at: i64,
+
+    /// The Base block number this root was registered for.
+    pub base_block_number: u64,
+
+    /// The `base_oracle_epoch` in which the oracle set co-signed this root again through
+    /// `revalidate_output_root`, or `NOT_REVALIDATED`. Rotating the oracle set starts a new epoch,
+    /// so the root must then be re-validated by the new set.
+    pub revalidated_epoch: u64,
+}
+
+impl OutputRoot {
+    /// `revalidated_epoch` of a root that was never re-validated.
+    pub const NOT_REVALIDATED: u64 = u64::MAX;
+
+    /// Ensures the root was registered no more than `max_age_seconds` before `current_timestamp`.
+    /// A `max_age_seconds` of 0 disables the check.
+    pub fn validate_age(&self, max_age_seconds: u64, current_timestamp: i64) -> Result<()> {
+        if max_age_seconds == 0 {
+            return Ok(());
+        }
+
+        let age = current_timestamp.saturating_sub(self.registered_at).max(0) as u64;
+        require!(age <= max_age_seconds, BridgeError::OutputRootTooOld);
+
+        Ok(())
+    }
+
+    /// Ensures the root belongs to a registered Base block, i.e. a non-zero block that does not
+    /// exceed the bridge's latest registered `base_block_number`.
+    pub fn validate_registered(&self, latest_base_block_number: u64) -> Result<()> {
+        require!(
+            self.base_block_number > 0 && self.base_block_number <= latest_base_block_number,
+            BridgeError::RootNotRegistered
+        );
+
+        Ok(())
+    }
+
+    /// Ensures a root more than `revalidate_after_blocks` Base blocks behind
+    /// `latest_base_block_number` has been re-validated in the current `base_oracle_epoch`. A
+    /// `revalidate_after_blocks` of 0 disables the check.
+    pub fn validate_revalidated(
+        &self,
+        latest_base_block_number: u64,
+        revalidate_after_blocks: u64,
+        base_oracle_epoch: u64,
+    ) -> Result<()> {
+        if revalidate_after_blocks == 0 || self.revalidated_epoch == base_oracle_epoch {
+            return Ok(());
+        }
+
+        let behind = latest_base_block_number.saturating_sub(self.base_block_number);
+        require!(
+            behind <= revalidate_after_blocks,
+            BridgeError::OutputRootRequiresRevalidation
+        );
+
+        Ok(())
+    }
 }
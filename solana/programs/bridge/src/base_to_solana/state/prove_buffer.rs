@@ -8,6 +8,12 @@ pub struct ProveBuffer {
     /// The owner who can modify and eventually consume this buffer
     pub owner: Pubkey,
 
+    /// Maximum number of `data` bytes this buffer was allocated for
+    pub max_data_len: u64,
+
+    /// Maximum number of `proof` nodes this buffer was allocated for
+    pub max_proof_len: u64,
+
     /// Serialized `Message` data (Anchor-serialized)
     pub data: Vec<u8>,
 
@@ -19,6 +25,8 @@ impl ProveBuffer {
     /// Calculate serialized space needed for a `ProveBuffer` (not including the 8-byte discriminator)
     pub fn space(max_data_len: usize, max_proof_len: usize) -> usize {
         32 + // owner
+        8 + // max_data_len
+        8 + // max_proof_len
         4 + max_data_len + // data vec
         4 + (max_proof_len * 32) // proof vec
     }
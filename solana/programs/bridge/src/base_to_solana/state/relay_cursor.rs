@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+/// Execution cursor for an incoming message relayed over several transactions.
+///
+/// Created by `begin_relay` and advanced by each `continue_relay` call. Every slice of the
+/// message's instruction list starts at `next_ix`, so a slice can only ever execute once.
+/// The final slice closes the cursor and refunds its rent to `payer`.
+#[account]
+#[derive(InitSpace)]
+pub struct RelayCursor {
+    /// The incoming message being relayed.
+    pub message: Pubkey,
+
+    /// The account that paid for the cursor and receives its rent back once the relay completes.
+    pub payer: Pubkey,
+
+    /// Index of the next instruction of the message to execute.
+    pub next_ix: u32,
+
+    /// Total number of instructions in the message.
+    pub total_ixs: u32,
+}
+
+impl RelayCursor {
+    /// Whether every instruction of the message has been executed.
+    pub fn is_complete(&self) -> bool {
+        self.next_ix == self.total_ixs
+    }
+}
@@ -84,6 +84,14 @@ impl Signers {
 
         count
     }
+
+    /// Whether `evm_address` is the active or candidate address of a configured partner signer.
+    pub fn contains(&self, evm_address: &[u8; 20]) -> bool {
+        self.signers.iter().any(|configured| {
+            configured.evm_address == *evm_address
+                || configured.new_evm_address.as_ref() == Some(evm_address)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -102,6 +110,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn contains_matches_old_and_new_addresses() {
+        let cfg = Signers {
+            signers: vec![signer(1, Some(2))],
+        };
+        assert!(cfg.contains(&addr(1)));
+        assert!(cfg.contains(&addr(2)));
+        assert!(!cfg.contains(&addr(3)));
+    }
+
     #[test]
     fn returns_zero_when_no_configured_signers() {
         let cfg = Signers { signers: vec![] };
@@ -11,6 +11,39 @@ pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault";
 #[constant]
 pub const WRAPPED_TOKEN_SEED: &[u8] = b"wrapped_token";
 #[constant]
+pub const WRAPPED_TOKEN_INFO_SEED: &[u8] = b"wrapped_token_info";
+#[constant]
+pub const DENIED_REMOTE_TOKEN_SEED: &[u8] = b"denied_remote_token";
+#[constant]
+pub const RATE_LIMIT_SEED: &[u8] = b"rate_limit";
+#[constant]
 pub const MAX_PARTNER_VALIDATOR_THRESHOLD: u8 = 5;
 #[constant]
 pub const MAX_SIGNER_COUNT: u8 = 16;
+#[constant]
+pub const MAX_REMOTE_TOKEN_DECIMALS: u8 = 18;
+#[constant]
+pub const MAX_TOKEN_NAME_LEN: u8 = 32;
+#[constant]
+pub const MAX_TOKEN_SYMBOL_LEN: u8 = 10;
+#[constant]
+pub const GAS_MULTIPLIER_BPS_DENOMINATOR: u64 = 10_000;
+#[constant]
+pub const MAX_GAS_MULTIPLIER_BPS: u32 = 100_000;
+#[constant]
+pub const MAX_BASE_FEE: u64 = 1_000_000_000_000u64;
+#[constant]
+pub const DAILY_VOLUME_WINDOW_SECONDS: u64 = 86_400;
+#[constant]
+pub const PAUSE_PROVE: u16 = 1;
+#[constant]
+pub const PAUSE_RELAY: u16 = 2;
+#[constant]
+pub const PAUSE_BRIDGE_TOKEN: u16 = 4;
+#[constant]
+pub const PAUSE_BRIDGE_CALL: u16 = 8;
+#[constant]
+pub const PAUSE_REGISTER_ROOT: u16 = 16;
+#[constant]
+pub const PAUSE_ALL_OPS: u16 =
+    PAUSE_PROVE | PAUSE_RELAY | PAUSE_BRIDGE_TOKEN | PAUSE_BRIDGE_CALL | PAUSE_REGISTER_ROOT;
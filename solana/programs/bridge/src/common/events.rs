@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+/// Emitted when the guardian changes the output root block interval requirement.
+#[event]
+pub struct BlockIntervalRequirementUpdated {
+    /// The previous block interval requirement
+    pub old_interval: u64,
+    /// The new block interval requirement
+    pub new_interval: u64,
+    /// The latest registered Base block number at the time of the change
+    pub base_block_number: u64,
+}
+
+/// Emitted when an output root is registered with the same root as the previous registration,
+/// which may indicate a stalled oracle feed.
+#[event]
+pub struct DuplicateRootRegistered {
+    /// The repeated output root
+    pub output_root: [u8; 32],
+    /// The Base block number of the previous registration
+    pub previous_base_block_number: u64,
+    /// The Base block number of the new registration
+    pub base_block_number: u64,
+}
+
+/// Emitted when a new output root is registered, after all approval and rate limit checks pass.
+#[event]
+pub struct OutputRootRegistered {
+    /// The registered Base MMR root
+    pub output_root: [u8; 32],
+    /// The Base block number the root was registered for
+    pub base_block_number: u64,
+    /// The MMR's total leaf count at this checkpoint
+    pub total_leaf_count: u64,
+    /// Number of Base oracle signers that approved the root
+    pub base_signature_count: u32,
+    /// Number of partner oracle signers that approved the root (0 when partner approvals are
+    /// not required)
+    pub partner_signature_count: u32,
+    /// The account that paid for the registration
+    pub payer: Pubkey,
+}
+
+/// Emitted when signers approving an output root are part of both the Base and partner oracle
+/// sets, so each of their signatures counted toward both thresholds.
+#[event]
+pub struct OverlappingSignersApproved {
+    /// The Base block number of the registration
+    pub base_block_number: u64,
+    /// EVM addresses present in both signer sets
+    pub signers: Vec<[u8; 20]>,
+}
+
+/// Emitted when the guardian overrides the EIP-1559 base fee in an emergency.
+#[event]
+pub struct BaseFeeReset {
+    /// The base fee before the reset
+    pub old_base_fee: u64,
+    /// The base fee after the reset
+    pub new_base_fee: u64,
+}
+
+/// EIP-1559 configuration parameter changed by the guardian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Eip1559ConfigField {
+    Target,
+    Denominator,
+    WindowDurationSeconds,
+    MinimumBaseFee,
+    DecayGraceWindows,
+}
+
+/// Emitted when the guardian changes an EIP-1559 configuration parameter, so that past fees can
+/// be traced back to the configuration they were charged under.
+#[event]
+pub struct Eip1559ConfigChanged {
+    /// The parameter that changed
+    pub field: Eip1559ConfigField,
+    /// The value before the change
+    pub old_value: u64,
+    /// The value after the change
+    pub new_value: u64,
+    /// Unix timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Emitted when `wrap_token` creates a new wrapped mint for a Base token.
+#[event]
+pub struct WrappedTokenCreated {
+    /// The wrapped mint created on Solana
+    pub mint: Pubkey,
+    /// The 20-byte address of the token on Base
+    pub remote_token: [u8; 20],
+    /// Decimals of the wrapped mint
+    pub decimals: u8,
+    /// Token name
+    pub name: String,
+    /// Token symbol
+    pub symbol: String,
+    /// Decimal scaling exponent between the Base token and the wrapped mint
+    pub scaler_exponent: u8,
+}
+
+/// Emitted when the guardian freezes or thaws a wrapped token account.
+#[event]
+pub struct WrappedAccountFreezeUpdated {
+    /// The wrapped mint
+    pub mint: Pubkey,
+    /// The token account that was frozen or thawed
+    pub token_account: Pubkey,
+    /// Whether the account is now frozen
+    pub frozen: bool,
+}
+
+/// Emitted when the guardian proposes a new guardian.
+#[event]
+pub struct GuardianTransferProposed {
+    /// The current guardian
+    pub guardian: Pubkey,
+    /// The proposed guardian, pending acceptance
+    pub pending_guardian: Pubkey,
+}
+
+/// Emitted when the pending guardian accepts the guardian role.
+#[event]
+pub struct GuardianTransferAccepted {
+    /// The guardian being replaced
+    pub previous_guardian: Pubkey,
+    /// The new guardian
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when the guardian cancels a pending guardian proposal.
+#[event]
+pub struct GuardianTransferCancelled {
+    /// The current guardian
+    pub guardian: Pubkey,
+    /// The proposal that was cancelled, if any
+    pub pending_guardian: Option<Pubkey>,
+}
+
+/// Emitted when the guardians change the co-guardian.
+#[event]
+pub struct CoGuardianUpdated {
+    /// The co-guardian being replaced (the default pubkey if none was set)
+    pub previous_co_guardian: Pubkey,
+    /// The new co-guardian (the default pubkey if it was removed)
+    pub new_co_guardian: Pubkey,
+}
+
+/// Emitted whenever the Base oracle signer set or its threshold changes.
+#[event]
+pub struct OracleSignersUpdated {
+    /// Number of signers after the update
+    pub signer_count: u8,
+    /// Required approval weight after the update
+    pub threshold: u8,
+    /// Oracle signer epoch started by the update
+    pub epoch: u64,
+}
@@ -1,21 +1,80 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{BaseOracleConfig, SetBridgeConfigFromUpgradeAuthority};
+use crate::common::{
+    bridge::Bridge, BaseOracleConfig, OracleSignersUpdated, SetBridgeConfigFromUpgradeAuthority,
+};
 
 /// Set or update the oracle signer configuration.
 ///
 /// Updates the `oracle_signers` account with a new approval `threshold` and a
 /// new list of unique EVM signer addresses. This instruction is used to rotate
 /// oracle keys or adjust the required threshold for output root attestations.
+/// Also requires the co-guardian when one is configured.
 pub fn set_oracle_signers_handler(
     ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
     cfg: BaseOracleConfig,
 ) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
     cfg.validate()?;
     ctx.accounts.bridge.base_oracle_config = cfg;
+    record_oracle_signers_update(&mut ctx.accounts.bridge);
     Ok(())
 }
 
+/// Add a single signer (with unit weight) to the oracle signer set.
+/// Also requires the co-guardian when one is configured.
+pub fn add_oracle_signer_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    new_signer: [u8; 20],
+) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
+    ctx.accounts
+        .bridge
+        .base_oracle_config
+        .add_signer(new_signer)?;
+    record_oracle_signers_update(&mut ctx.accounts.bridge);
+    Ok(())
+}
+
+/// Remove a single signer from the oracle signer set. Fails if the remaining signers could no
+/// longer meet the threshold. Also requires the co-guardian when one is configured.
+pub fn remove_oracle_signer_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    signer: [u8; 20],
+) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
+    ctx.accounts
+        .bridge
+        .base_oracle_config
+        .remove_signer(&signer)?;
+    record_oracle_signers_update(&mut ctx.accounts.bridge);
+    Ok(())
+}
+
+/// Set the approval threshold of the oracle signer set.
+/// Also requires the co-guardian when one is configured.
+pub fn set_oracle_threshold_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    threshold: u8,
+) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
+    let base_oracle_config = &mut ctx.accounts.bridge.base_oracle_config;
+    base_oracle_config.threshold = threshold;
+    base_oracle_config.validate()?;
+    record_oracle_signers_update(&mut ctx.accounts.bridge);
+    Ok(())
+}
+
+/// Starts a new oracle signer epoch and emits the updated signer set.
+fn record_oracle_signers_update(bridge: &mut Bridge) {
+    bridge.base_oracle_epoch += 1;
+    emit!(OracleSignersUpdated {
+        signer_count: bridge.base_oracle_config.signer_count,
+        threshold: bridge.base_oracle_config.threshold,
+        epoch: bridge.base_oracle_epoch,
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -28,8 +87,10 @@ mod tests {
     use solana_transaction::Transaction;
 
     use crate::{
-        accounts, common::bridge::Bridge, instruction::SetOracleSigners, test_utils::*, ID,
-        MAX_SIGNER_COUNT,
+        accounts,
+        instruction::{AddOracleSigner, RemoveOracleSigner, SetOracleSigners, SetOracleThreshold},
+        test_utils::*,
+        ID, MAX_SIGNER_COUNT,
     };
 
     /// Helper to create a BaseOracleConfig for testing
@@ -42,6 +103,7 @@ mod tests {
             threshold,
             signer_count,
             signers,
+            weights: [1u8; MAX_SIGNER_COUNT as usize],
         }
     }
 
@@ -64,6 +126,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -91,6 +154,7 @@ mod tests {
         let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
         assert_eq!(bridge.base_oracle_config.threshold, 2);
         assert_eq!(bridge.base_oracle_config.signer_count, 2);
+        assert_eq!(bridge.base_oracle_epoch, 1);
     }
 
     #[test]
@@ -111,6 +175,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -161,6 +226,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -212,6 +278,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -224,6 +291,7 @@ mod tests {
             threshold: 2,
             signer_count: 2,
             signers,
+            weights: [1u8; MAX_SIGNER_COUNT as usize],
         };
 
         let ix = Instruction {
@@ -253,4 +321,228 @@ mod tests {
             error_string
         );
     }
+
+    fn send_from_upgrade_authority(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        data: Vec<u8>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        send_from_upgrade_authority_and_co_guardian(svm, payer, None, bridge_pda, data)
+    }
+
+    fn send_from_upgrade_authority_and_co_guardian(
+        svm: &mut litesvm::LiteSVM,
+        payer: &solana_keypair::Keypair,
+        co_guardian: Option<&solana_keypair::Keypair>,
+        bridge_pda: Pubkey,
+        data: Vec<u8>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: payer.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                co_guardian: co_guardian.map(|co_guardian| co_guardian.pubkey()),
+            }
+            .to_account_metas(None),
+            data,
+        };
+
+        let mut signers = vec![payer];
+        signers.extend(co_guardian);
+        let tx = Transaction::new(
+            &signers,
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    fn oracle_config(svm: &litesvm::LiteSVM, bridge_pda: Pubkey) -> BaseOracleConfig {
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .base_oracle_config
+    }
+
+    #[test]
+    fn test_rotate_oracle_signers_one_at_a_time() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_signer = [9u8; 20];
+        send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            AddOracleSigner { new_signer }.data(),
+        )
+        .expect("add_oracle_signer should succeed");
+        send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            SetOracleThreshold { threshold: 2 }.data(),
+        )
+        .expect("set_oracle_threshold should succeed");
+
+        let config = oracle_config(&svm, bridge_pda);
+        assert_eq!(config.signer_count, 2);
+        assert_eq!(config.threshold, 2);
+        assert!(config.contains(&new_signer));
+
+        // Dropping to one signer while two approvals are required is rejected
+        let result = send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            RemoveOracleSigner { signer: [1u8; 20] }.data(),
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("InvalidThreshold"),
+            "Unexpected error: {}",
+            err
+        );
+
+        send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            SetOracleThreshold { threshold: 1 }.data(),
+        )
+        .expect("set_oracle_threshold should succeed");
+        send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            RemoveOracleSigner { signer: [1u8; 20] }.data(),
+        )
+        .expect("remove_oracle_signer should succeed");
+
+        let config = oracle_config(&svm, bridge_pda);
+        assert_eq!(config.active_signers(), &[new_signer]);
+        assert_eq!(config.threshold, 1);
+    }
+
+    #[test]
+    fn test_add_oracle_signer_duplicate_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_from_upgrade_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            AddOracleSigner {
+                new_signer: [1u8; 20],
+            }
+            .data(),
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("DuplicateSigner"), "Unexpected error: {}", err);
+    }
+
+    /// Configures a co-guardian and a two-signer oracle set with a threshold of one, then checks
+    /// that `data` fails when signed by the upgrade authority alone and succeeds once the
+    /// co-guardian also signs.
+    fn assert_requires_co_guardian(data: impl Fn() -> Vec<u8>) -> (litesvm::LiteSVM, Pubkey) {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = solana_keypair::Keypair::new();
+        svm.airdrop(&co_guardian.pubkey(), 1_000_000_000).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.co_guardian = co_guardian.pubkey();
+            bridge.base_oracle_config = base_oracle_config(1, 2);
+        });
+
+        let result = send_from_upgrade_authority(&mut svm, &payer, bridge_pda, data());
+        assert!(
+            result.is_err(),
+            "Expected update without co-guardian to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("CoGuardianSignatureRequired"),
+            "Expected CoGuardianSignatureRequired error, got: {}",
+            err
+        );
+        assert_eq!(
+            Bridge::try_deserialize(&mut &svm.get_account(&bridge_pda).unwrap().data[..])
+                .unwrap()
+                .base_oracle_epoch,
+            0
+        );
+
+        send_from_upgrade_authority_and_co_guardian(
+            &mut svm,
+            &payer,
+            Some(&co_guardian),
+            bridge_pda,
+            data(),
+        )
+        .expect("Update signed by the upgrade authority and co-guardian should succeed");
+
+        (svm, bridge_pda)
+    }
+
+    #[test]
+    fn test_set_oracle_signers_requires_co_guardian_signature() {
+        let (svm, bridge_pda) = assert_requires_co_guardian(|| {
+            SetOracleSigners {
+                cfg: base_oracle_config(2, 2),
+            }
+            .data()
+        });
+
+        let config = oracle_config(&svm, bridge_pda);
+        assert_eq!(config.signer_count, 2);
+        assert_eq!(config.threshold, 2);
+    }
+
+    #[test]
+    fn test_add_oracle_signer_requires_co_guardian_signature() {
+        let new_signer = [9u8; 20];
+        let (svm, bridge_pda) =
+            assert_requires_co_guardian(|| AddOracleSigner { new_signer }.data());
+
+        assert!(oracle_config(&svm, bridge_pda).contains(&new_signer));
+    }
+
+    #[test]
+    fn test_remove_oracle_signer_requires_co_guardian_signature() {
+        let (svm, bridge_pda) =
+            assert_requires_co_guardian(|| RemoveOracleSigner { signer: [1u8; 20] }.data());
+
+        let config = oracle_config(&svm, bridge_pda);
+        assert_eq!(config.active_signers(), &[[2u8; 20]]);
+    }
+
+    #[test]
+    fn test_set_oracle_threshold_requires_co_guardian_signature() {
+        let (svm, bridge_pda) =
+            assert_requires_co_guardian(|| SetOracleThreshold { threshold: 2 }.data());
+
+        assert_eq!(oracle_config(&svm, bridge_pda).threshold, 2);
+    }
 }
@@ -12,6 +12,26 @@ pub fn set_max_call_buffer_size_handler(
     Ok(())
 }
 
+/// Set the maximum number of proof nodes a prove buffer can be allocated for
+pub fn set_max_prove_buffer_proof_len_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_len: u64,
+) -> Result<()> {
+    ctx.accounts.bridge.buffer_config.max_prove_buffer_proof_len = new_len;
+
+    Ok(())
+}
+
+/// Set the maximum number of appends to a single call buffer
+pub fn set_max_call_buffer_appends_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_max: u64,
+) -> Result<()> {
+    ctx.accounts.bridge.buffer_config.max_call_buffer_appends = new_max;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,7 +44,10 @@ mod tests {
     use crate::{
         accounts,
         common::bridge::Bridge,
-        instruction::SetMaxCallBufferSize as SetMaxCallBufferSizeIx,
+        instruction::{
+            SetMaxCallBufferSize as SetMaxCallBufferSizeIx,
+            SetMaxProveBufferProofLen as SetMaxProveBufferProofLenIx,
+        },
         test_utils::{setup_bridge, SetupBridgeResult},
         ID,
     };
@@ -127,4 +150,45 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_set_max_prove_buffer_proof_len_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_len = 32u64;
+
+        let accounts = accounts::SetBridgeConfigFromGuardian {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetMaxProveBufferProofLenIx { new_len }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+            .expect("Failed to send set_max_prove_buffer_proof_len transaction");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+
+        assert_eq!(
+            bridge_data.buffer_config.max_prove_buffer_proof_len,
+            new_len
+        );
+    }
 }
@@ -1,14 +1,21 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{
+        bridge::Eip1559Config, BaseFeeReset, Eip1559ConfigChanged, Eip1559ConfigField,
+        SetBridgeConfigFromGuardian, MAX_BASE_FEE,
+    },
+    BridgeError,
+};
 
 /// Set the minimum base fee parameter
 pub fn set_minimum_base_fee_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_fee: u64,
 ) -> Result<()> {
-    ctx.accounts.bridge.eip1559.config.minimum_base_fee = new_fee;
-    Ok(())
+    let config = &mut ctx.accounts.bridge.eip1559.config;
+    let old_fee = std::mem::replace(&mut config.minimum_base_fee, new_fee);
+    emit_config_changed(Eip1559ConfigField::MinimumBaseFee, old_fee, new_fee)
 }
 
 /// Set the window duration parameter
@@ -16,9 +23,14 @@ pub fn set_window_duration_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_duration: u64,
 ) -> Result<()> {
-    ctx.accounts.bridge.eip1559.config.window_duration_seconds = new_duration;
-    ctx.accounts.bridge.eip1559.config.validate()?;
-    Ok(())
+    let config = &mut ctx.accounts.bridge.eip1559.config;
+    let old_duration = std::mem::replace(&mut config.window_duration_seconds, new_duration);
+    config.validate()?;
+    emit_config_changed(
+        Eip1559ConfigField::WindowDurationSeconds,
+        old_duration,
+        new_duration,
+    )
 }
 
 /// Set the gas target parameter
@@ -26,8 +38,9 @@ pub fn set_gas_target_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_target: u64,
 ) -> Result<()> {
-    ctx.accounts.bridge.eip1559.config.target = new_target;
-    Ok(())
+    let config = &mut ctx.accounts.bridge.eip1559.config;
+    let old_target = std::mem::replace(&mut config.target, new_target);
+    emit_config_changed(Eip1559ConfigField::Target, old_target, new_target)
 }
 
 /// Set the adjustment denominator parameter
@@ -35,7 +48,355 @@ pub fn set_adjustment_denominator_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_denominator: u64,
 ) -> Result<()> {
-    ctx.accounts.bridge.eip1559.config.denominator = new_denominator;
-    ctx.accounts.bridge.eip1559.config.validate()?;
+    let config = &mut ctx.accounts.bridge.eip1559.config;
+    let old_denominator = std::mem::replace(&mut config.denominator, new_denominator);
+    config.validate()?;
+    emit_config_changed(
+        Eip1559ConfigField::Denominator,
+        old_denominator,
+        new_denominator,
+    )
+}
+
+/// Set the number of consecutive empty windows before the base fee starts to decay
+pub fn set_decay_grace_windows_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_grace_windows: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.bridge.eip1559.config;
+    let old_grace_windows = std::mem::replace(&mut config.decay_grace_windows, new_grace_windows);
+    emit_config_changed(
+        Eip1559ConfigField::DecayGraceWindows,
+        old_grace_windows,
+        new_grace_windows,
+    )
+}
+
+/// Replace the whole EIP-1559 configuration at once.
+///
+/// The fee state is settled under the old configuration first (see `Eip1559::settle`), so gas
+/// recorded against the old target or window length is never priced with the new ones. An
+/// `Eip1559ConfigChanged` event is emitted for every parameter whose value changes.
+pub fn set_eip1559_params_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_config: Eip1559Config,
+) -> Result<()> {
+    new_config.validate()?;
+
+    let eip1559 = &mut ctx.accounts.bridge.eip1559;
+    eip1559.settle(Clock::get()?.unix_timestamp);
+    eip1559.current_base_fee = eip1559.current_base_fee.max(new_config.minimum_base_fee);
+    let old_config = std::mem::replace(&mut eip1559.config, new_config.clone());
+
+    let changes = [
+        (
+            Eip1559ConfigField::Target,
+            old_config.target,
+            new_config.target,
+        ),
+        (
+            Eip1559ConfigField::Denominator,
+            old_config.denominator,
+            new_config.denominator,
+        ),
+        (
+            Eip1559ConfigField::WindowDurationSeconds,
+            old_config.window_duration_seconds,
+            new_config.window_duration_seconds,
+        ),
+        (
+            Eip1559ConfigField::MinimumBaseFee,
+            old_config.minimum_base_fee,
+            new_config.minimum_base_fee,
+        ),
+        (
+            Eip1559ConfigField::DecayGraceWindows,
+            old_config.decay_grace_windows,
+            new_config.decay_grace_windows,
+        ),
+    ];
+    for (field, old_value, new_value) in changes {
+        if old_value != new_value {
+            emit_config_changed(field, old_value, new_value)?;
+        }
+    }
+
     Ok(())
 }
+
+fn emit_config_changed(field: Eip1559ConfigField, old_value: u64, new_value: u64) -> Result<()> {
+    emit!(Eip1559ConfigChanged {
+        field,
+        old_value,
+        new_value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Emergency override of the current base fee, e.g. when it is stuck at an extreme value.
+/// The new fee must lie in `[minimum_base_fee, MAX_BASE_FEE]` so that a reset cannot push
+/// every later `gas_cost` into `GasCostOverflow`. The current window is restarted so that gas
+/// recorded under the old fee does not immediately move the new one.
+pub fn reset_base_fee_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_fee: u64,
+) -> Result<()> {
+    let eip1559 = &mut ctx.accounts.bridge.eip1559;
+    require!(
+        (eip1559.config.minimum_base_fee..=MAX_BASE_FEE).contains(&new_fee),
+        BridgeError::InvalidBaseFee
+    );
+
+    let old_base_fee = eip1559.current_base_fee;
+    eip1559.current_base_fee = new_fee;
+    eip1559.current_window_gas_used = 0;
+    eip1559.window_start_time = Clock::get()?.unix_timestamp;
+    eip1559.empty_window_streak = 0;
+
+    emit!(BaseFeeReset {
+        old_base_fee,
+        new_base_fee: new_fee,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::{
+            ResetBaseFee as ResetBaseFeeIx, SetAdjustmentDenominator as SetAdjustmentDenominatorIx,
+            SetEip1559Params as SetEip1559ParamsIx, SetGasTarget as SetGasTargetIx,
+        },
+        test_utils::{emitted_events, mock_clock, setup_bridge, update_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn send_reset_base_fee(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        new_fee: u64,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+            }
+            .to_account_metas(None),
+            data: ResetBaseFeeIx { new_fee }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    fn set_current_base_fee(svm: &mut litesvm::LiteSVM, base_fee: u64) {
+        update_bridge(svm, |bridge| {
+            bridge.eip1559.current_base_fee = base_fee;
+            bridge.eip1559.current_window_gas_used = 1_000_000;
+        });
+    }
+
+    #[test]
+    fn test_reset_base_fee_from_extreme_value() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_current_base_fee(&mut svm, u64::MAX / 2);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        let new_fee = bridge.eip1559.config.minimum_base_fee;
+
+        let meta = send_reset_base_fee(&mut svm, &guardian, bridge_pda, new_fee)
+            .expect("Failed to send reset_base_fee transaction");
+
+        // The reset is recorded as an event
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.eip1559.current_base_fee, new_fee);
+        assert_eq!(bridge.eip1559.current_window_gas_used, 0);
+    }
+
+    #[test]
+    fn test_reset_base_fee_below_minimum_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        let minimum_base_fee = bridge.eip1559.config.minimum_base_fee;
+        assert!(minimum_base_fee > 0);
+
+        let result = send_reset_base_fee(&mut svm, &guardian, bridge_pda, minimum_base_fee - 1);
+        assert!(result.is_err());
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("InvalidBaseFee"), "Unexpected error: {}", err);
+    }
+
+    #[test]
+    fn test_reset_base_fee_above_maximum_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        send_reset_base_fee(&mut svm, &guardian, bridge_pda, MAX_BASE_FEE)
+            .expect("Failed to send reset_base_fee transaction");
+        svm.expire_blockhash();
+
+        for new_fee in [MAX_BASE_FEE + 1, u64::MAX] {
+            let result = send_reset_base_fee(&mut svm, &guardian, bridge_pda, new_fee);
+            assert!(result.is_err());
+            let err = format!("{:?}", result.unwrap_err());
+            assert!(err.contains("InvalidBaseFee"), "Unexpected error: {}", err);
+        }
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.eip1559.current_base_fee, MAX_BASE_FEE);
+    }
+
+    #[test]
+    fn test_set_eip1559_params_settles_window_under_old_params() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // A window ending now with twice the old target used
+        let bridge = update_bridge(&mut svm, |bridge| {
+            bridge.eip1559.current_window_gas_used = 2 * bridge.eip1559.config.target;
+        });
+
+        let now =
+            bridge.eip1559.window_start_time + bridge.eip1559.config.window_duration_seconds as i64;
+        mock_clock(&mut svm, now);
+        let expected_base_fee = bridge.eip1559.clone().refresh_base_fee(now);
+
+        let mut new_config = bridge.eip1559.config.clone();
+        new_config.target *= 2;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+            }
+            .to_account_metas(None),
+            data: SetEip1559ParamsIx {
+                new_config: new_config.clone(),
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("set_eip1559_params should succeed");
+
+        // Only the target changed
+        let events = emitted_events::<Eip1559ConfigChanged>(&meta);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].field, Eip1559ConfigField::Target);
+        assert_eq!(events[0].old_value, bridge.eip1559.config.target);
+        assert_eq!(events[0].new_value, new_config.target);
+        assert_eq!(events[0].timestamp, now);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.eip1559.config, new_config);
+        assert_eq!(bridge.eip1559.current_base_fee, expected_base_fee);
+        assert_eq!(bridge.eip1559.current_window_gas_used, 0);
+        assert_eq!(bridge.eip1559.window_start_time, now);
+    }
+
+    #[test]
+    fn test_eip1559_setters_emit_config_changed() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let old_config = Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .eip1559
+            .config;
+        let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+        let mut send = |data: Vec<u8>| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::SetBridgeConfigFromGuardian {
+                    bridge: bridge_pda,
+                    guardian: guardian.pubkey(),
+                }
+                .to_account_metas(None),
+                data,
+            };
+            let tx = Transaction::new(
+                &[&guardian],
+                Message::new(&[ix], Some(&guardian.pubkey())),
+                svm.latest_blockhash(),
+            );
+            let meta = svm.send_transaction(tx).expect("setter should succeed");
+            svm.expire_blockhash();
+            emitted_events::<Eip1559ConfigChanged>(&meta)
+        };
+
+        let new_target = old_config.target + 1;
+        let events = send(SetGasTargetIx { new_target }.data());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].field, Eip1559ConfigField::Target);
+        assert_eq!(events[0].old_value, old_config.target);
+        assert_eq!(events[0].new_value, new_target);
+        assert_eq!(events[0].timestamp, now);
+
+        let new_denominator = old_config.denominator + 1;
+        let events = send(SetAdjustmentDenominatorIx { new_denominator }.data());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].field, Eip1559ConfigField::Denominator);
+        assert_eq!(events[0].old_value, old_config.denominator);
+        assert_eq!(events[0].new_value, new_denominator);
+        assert_eq!(events[0].timestamp, now);
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{common::SetBridgeConfigFromGuardian, solana_to_base::CallType};
 
 /// Set the gas cost scaler
 pub fn set_gas_cost_scaler_handler(
@@ -38,3 +38,34 @@ pub fn set_gas_per_call_handler(
     ctx.accounts.bridge.gas_config.gas_per_call = new_val;
     Ok(())
 }
+
+/// Set the minimum fee charged per cross-chain message
+pub fn set_minimum_fee_lamports_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_minimum_fee: u64,
+) -> Result<()> {
+    ctx.accounts.bridge.gas_config.minimum_fee_lamports = new_minimum_fee;
+    ctx.accounts.bridge.gas_config.validate()?;
+    Ok(())
+}
+
+/// Set the maximum fee charged per cross-chain message (0 removes the ceiling)
+pub fn set_maximum_fee_lamports_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_maximum_fee: u64,
+) -> Result<()> {
+    ctx.accounts.bridge.gas_config.maximum_fee_lamports = new_maximum_fee;
+    ctx.accounts.bridge.gas_config.validate()?;
+    Ok(())
+}
+
+/// Set the fee multiplier (in basis points) applied to messages carrying a call of type `ty`
+pub fn set_call_type_gas_multiplier_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    ty: CallType,
+    multiplier_bps: u32,
+) -> Result<()> {
+    ctx.accounts.bridge.gas_config.call_type_gas_multipliers_bps[ty as usize] = multiplier_bps;
+    ctx.accounts.bridge.gas_config.validate()?;
+    Ok(())
+}
@@ -15,6 +15,9 @@ pub use gas::*;
 pub mod protocol;
 pub use protocol::*;
 
+pub mod remote_token;
+pub use remote_token::*;
+
 pub mod buffer;
 pub use buffer::*;
 
@@ -44,8 +47,40 @@ pub struct SetBridgeConfigFromGuardian<'info> {
     pub guardian: Signer<'info>,
 }
 
+/// Accounts struct for critical bridge configuration changes
+/// The guardian must sign, together with the co-guardian when one is configured
+#[derive(Accounts)]
+pub struct SetBridgeConfigFromGuardians<'info> {
+    /// The bridge account containing configuration
+    #[account(
+        mut,
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to update configuration
+    pub guardian: Signer<'info>,
+
+    /// The co-guardian, required when `bridge.co_guardian` is set
+    pub co_guardian: Option<Signer<'info>>,
+}
+
+impl SetBridgeConfigFromGuardians<'_> {
+    /// Checks that the configured co-guardian, if any, signed alongside the guardian.
+    pub fn require_co_guardian(&self) -> Result<()> {
+        self.bridge.require_co_guardian(
+            self.co_guardian
+                .as_ref()
+                .map(|co_guardian| co_guardian.key()),
+        )
+    }
+}
+
 /// Accounts struct for sensitive bridge configuration setter instructions
-/// Only the upgrade authority can update these parameters
+/// Only the upgrade authority can update these parameters, together with the co-guardian when one
+/// is configured
 #[derive(Accounts)]
 pub struct SetBridgeConfigFromUpgradeAuthority<'info> {
     /// The upgrade authority account
@@ -60,4 +95,18 @@ pub struct SetBridgeConfigFromUpgradeAuthority<'info> {
 
     #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ BridgeError::IncorrectBridgeProgram)]
     pub program: Program<'info, BridgeProgram>,
+
+    /// The co-guardian, required when `bridge.co_guardian` is set
+    pub co_guardian: Option<Signer<'info>>,
+}
+
+impl SetBridgeConfigFromUpgradeAuthority<'_> {
+    /// Checks that the configured co-guardian, if any, signed alongside the upgrade authority.
+    pub fn require_co_guardian(&self) -> Result<()> {
+        self.bridge.require_co_guardian(
+            self.co_guardian
+                .as_ref()
+                .map(|co_guardian| co_guardian.key()),
+        )
+    }
 }
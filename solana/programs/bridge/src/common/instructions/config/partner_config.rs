@@ -7,10 +7,12 @@ use crate::common::{PartnerOracleConfig, SetBridgeConfigFromUpgradeAuthority};
 /// Updates the `oracle_signers` account with a new approval `threshold` and a
 /// new list of unique EVM signer addresses. This instruction is used to rotate
 /// oracle keys or adjust the required threshold for output root attestations.
+/// Also requires the co-guardian when one is configured.
 pub fn set_partner_config_handler(
     ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
     partner_cfg: PartnerOracleConfig,
 ) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
     partner_cfg.validate()?;
     ctx.accounts.bridge.partner_oracle_config = partner_cfg;
     Ok(())
@@ -50,6 +52,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -98,6 +101,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -150,6 +154,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -1,17 +1,45 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{SetBridgeConfigFromGuardians, PAUSE_ALL_OPS},
+    BridgeError,
+};
 
 /// Set the pause status of the bridge
-/// Only the guardian can call this function
+/// Only the guardian can call this function. Unpausing also requires the co-guardian when one is
+/// configured, while pausing stays available to the guardian alone for emergencies.
 pub fn set_pause_status_handler(
-    ctx: Context<SetBridgeConfigFromGuardian>,
+    ctx: Context<SetBridgeConfigFromGuardians>,
     paused: bool,
 ) -> Result<()> {
+    if !paused {
+        ctx.accounts.require_co_guardian()?;
+    }
+
     ctx.accounts.bridge.paused = paused;
     Ok(())
 }
 
+/// Set which individual bridge operations are paused (`PAUSE_*` flags)
+/// Only the guardian can call this function. Like unpausing, clearing a flag also requires the
+/// co-guardian when one is configured.
+pub fn set_paused_ops_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    paused_ops: u16,
+) -> Result<()> {
+    require!(
+        paused_ops & !PAUSE_ALL_OPS == 0,
+        BridgeError::InvalidPausedOps
+    );
+
+    if ctx.accounts.bridge.paused_ops & !paused_ops != 0 {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.paused_ops = paused_ops;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,9 +51,9 @@ mod tests {
 
     use crate::{
         accounts,
-        common::bridge::Bridge,
-        instruction::SetPauseStatus as SetPauseStatusIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        common::{bridge::Bridge, PAUSE_BRIDGE_TOKEN, PAUSE_RELAY},
+        instruction::{SetPauseStatus as SetPauseStatusIx, SetPausedOps as SetPausedOpsIx},
+        test_utils::{setup_bridge, update_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -42,9 +70,10 @@ mod tests {
         let new_paused = true;
 
         // Build the instruction accounts
-        let accounts = accounts::SetBridgeConfigFromGuardian {
+        let accounts = accounts::SetBridgeConfigFromGuardians {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -92,9 +121,10 @@ mod tests {
         let new_paused = true;
 
         // Build the instruction accounts with fake guardian
-        let accounts = accounts::SetBridgeConfigFromGuardian {
+        let accounts = accounts::SetBridgeConfigFromGuardians {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -127,4 +157,118 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_unpause_requires_co_guardian_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Pause the bridge and configure a co-guardian
+        let co_guardian = solana_keypair::Keypair::new();
+        svm.airdrop(&co_guardian.pubkey(), 1_000_000_000).unwrap();
+        update_bridge(&mut svm, |bridge| {
+            bridge.paused = true;
+            bridge.co_guardian = co_guardian.pubkey();
+        });
+
+        // The guardian alone cannot unpause
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: SetPauseStatusIx { new_paused: false }.data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(
+            result.is_err(),
+            "Expected unpause without co-guardian to fail"
+        );
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("CoGuardianSignatureRequired"),
+            "Expected CoGuardianSignatureRequired error, got: {}",
+            error_string
+        );
+
+        // Both guardians together can
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: Some(co_guardian.pubkey()),
+            }
+            .to_account_metas(None),
+            data: SetPauseStatusIx { new_paused: false }.data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian, &co_guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Unpause signed by both guardians should succeed");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert!(!bridge_data.paused);
+    }
+
+    #[test]
+    fn test_set_paused_ops_updates_mask_and_rejects_unknown_flags() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let mut send = |paused_ops: u16| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::SetBridgeConfigFromGuardians {
+                    bridge: bridge_pda,
+                    guardian: guardian.pubkey(),
+                    co_guardian: None,
+                }
+                .to_account_metas(None),
+                data: SetPausedOpsIx { paused_ops }.data(),
+            };
+            let tx = Transaction::new(
+                &[&guardian],
+                Message::new(&[ix], Some(&guardian.pubkey())),
+                svm.latest_blockhash(),
+            );
+            let result = svm.send_transaction(tx).map_err(Box::new);
+            svm.expire_blockhash();
+            result
+        };
+
+        send(PAUSE_BRIDGE_TOKEN | PAUSE_RELAY).expect("set_paused_ops should succeed");
+
+        let error_string = format!("{:?}", send(PAUSE_ALL_OPS + 1).unwrap_err());
+        assert!(
+            error_string.contains("InvalidPausedOps"),
+            "Expected InvalidPausedOps error, got: {}",
+            error_string
+        );
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge_data.paused_ops, PAUSE_BRIDGE_TOKEN | PAUSE_RELAY);
+        assert!(!bridge_data.paused);
+    }
 }
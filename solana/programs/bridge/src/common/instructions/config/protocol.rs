@@ -1,18 +1,329 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{
+        bridge::RegistrationBucket, BlockIntervalRequirementUpdated, SetBridgeConfigFromGuardian,
+        SetBridgeConfigFromGuardians,
+    },
+    BridgeError,
+};
 
 /// Set the block interval requirement
+///
+/// Guardian-only: the interval constrains which Base blocks can be registered rather than how
+/// often, which the registration interval and rate limit already bound.
+///
+/// The latest registered `base_block_number` must be aligned to the new interval so that
+/// registrations in flight keep satisfying the monotonic and alignment checks.
 pub fn set_block_interval_requirement_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_interval: u64,
 ) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let old_interval = bridge.protocol_config.block_interval_requirement;
+
+    bridge.protocol_config.block_interval_requirement = new_interval;
+    bridge.protocol_config.validate()?;
+
+    require!(
+        bridge.base_block_number.is_multiple_of(new_interval),
+        BridgeError::IncompatibleBlockInterval
+    );
+
+    emit!(BlockIntervalRequirementUpdated {
+        old_interval,
+        new_interval,
+        base_block_number: bridge.base_block_number,
+    });
+
+    Ok(())
+}
+
+/// Set the minimum number of seconds between output root registrations
+/// Lowering or disabling the interval also requires the co-guardian when one is configured.
+pub fn set_min_registration_interval_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_interval_seconds: u64,
+) -> Result<()> {
+    let old_interval_seconds = ctx
+        .accounts
+        .bridge
+        .protocol_config
+        .min_registration_interval_seconds;
+    if new_interval_seconds < old_interval_seconds {
+        ctx.accounts.require_co_guardian()?;
+    }
+
     ctx.accounts
         .bridge
         .protocol_config
-        .block_interval_requirement = new_interval;
+        .min_registration_interval_seconds = new_interval_seconds;
+
+    Ok(())
+}
+
+/// Set the leaky bucket limiting output root registrations
+///
+/// The bucket is refilled to the new capacity so the new limit applies from now on.
+/// Raising or disabling the capacity, or shortening the refill interval, also requires the
+/// co-guardian when one is configured.
+pub fn set_registration_rate_limit_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    capacity: u64,
+    refill_interval_seconds: u64,
+) -> Result<()> {
+    let protocol_config = &ctx.accounts.bridge.protocol_config;
+    if loosens_limit(protocol_config.registration_bucket_capacity, capacity)
+        || refill_interval_seconds < protocol_config.registration_refill_interval_seconds
+    {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    let bridge = &mut ctx.accounts.bridge;
+    bridge.protocol_config.registration_bucket_capacity = capacity;
+    bridge.protocol_config.registration_refill_interval_seconds = refill_interval_seconds;
+    bridge.protocol_config.validate()?;
+
+    bridge.registration_bucket = RegistrationBucket::new(capacity, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
+
+/// Set whether registering an output root identical to the previous one is rejected
+/// Turning the check off also requires the co-guardian when one is configured.
+pub fn set_reject_duplicate_roots_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    reject_duplicate_roots: bool,
+) -> Result<()> {
+    if turns_off(
+        ctx.accounts.bridge.protocol_config.reject_duplicate_roots,
+        reject_duplicate_roots,
+    ) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.reject_duplicate_roots = reject_duplicate_roots;
+
+    Ok(())
+}
+
+/// Set whether relaying a message at or below the highest relayed nonce is rejected
+/// Turning the check off also requires the co-guardian when one is configured.
+pub fn set_enforce_relay_nonce_order_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    enforce_relay_nonce_order: bool,
+) -> Result<()> {
+    if turns_off(
+        ctx.accounts
+            .bridge
+            .protocol_config
+            .enforce_relay_nonce_order,
+        enforce_relay_nonce_order,
+    ) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .enforce_relay_nonce_order = enforce_relay_nonce_order;
+
+    Ok(())
+}
+
+/// Set whether `bridge_call` and `bridge_call_buffered` reject third-party sponsors
+/// Turning the check off also requires the co-guardian when one is configured.
+pub fn set_require_self_call_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    require_self_call: bool,
+) -> Result<()> {
+    if turns_off(
+        ctx.accounts.bridge.protocol_config.require_self_call,
+        require_self_call,
+    ) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.require_self_call = require_self_call;
+
+    Ok(())
+}
 
-    ctx.accounts.bridge.protocol_config.validate()?;
+/// Set whether relaying a message with identical instructions is rejected
+/// Turning the check off also requires the co-guardian when one is configured.
+pub fn set_reject_duplicate_ixs_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    reject_duplicate_ixs: bool,
+) -> Result<()> {
+    if turns_off(
+        ctx.accounts.bridge.protocol_config.reject_duplicate_ixs,
+        reject_duplicate_ixs,
+    ) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.reject_duplicate_ixs = reject_duplicate_ixs;
+
+    Ok(())
+}
+
+/// Set whether a signer may count toward both the Base and partner approval thresholds
+/// Turning the check off also requires the co-guardian when one is configured.
+pub fn set_disjoint_signer_sets_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    disjoint_signer_sets: bool,
+) -> Result<()> {
+    if turns_off(
+        ctx.accounts.bridge.protocol_config.disjoint_signer_sets,
+        disjoint_signer_sets,
+    ) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.disjoint_signer_sets = disjoint_signer_sets;
+
+    Ok(())
+}
+
+/// Set the maximum number of wrapped mints that `wrap_token` can create
+/// Raising or disabling the limit also requires the co-guardian when one is configured.
+pub fn set_max_wrapped_tokens_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_max: u64,
+) -> Result<()> {
+    let old_max = ctx.accounts.bridge.protocol_config.max_wrapped_tokens;
+    if loosens_limit(old_max, new_max) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.max_wrapped_tokens = new_max;
+
+    Ok(())
+}
+
+/// Whether moving a limit for which 0 disables the check from `old` to `new` loosens it.
+pub(super) fn loosens_limit(old: u64, new: u64) -> bool {
+    old != 0 && (new == 0 || new > old)
+}
+
+/// Whether moving a boolean guard from `old` to `new` turns it off.
+fn turns_off(old: bool, new: bool) -> bool {
+    old && !new
+}
+
+/// Set the lamport amount above which SOL finalizations require a guardian co-signature
+/// Raising or disabling the threshold also requires the co-guardian when one is configured.
+pub fn set_large_finalize_threshold_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_threshold: u64,
+) -> Result<()> {
+    let old_threshold = ctx.accounts.bridge.protocol_config.large_finalize_threshold;
+    if loosens_limit(old_threshold, new_threshold) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.large_finalize_threshold = new_threshold;
+
+    Ok(())
+}
+
+/// Set the daily limits on SOL bridged in each direction
+/// Raising or disabling either limit also requires the co-guardian when one is configured.
+pub fn set_daily_sol_volume_limits_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    outbound_limit: u64,
+    inbound_limit: u64,
+) -> Result<()> {
+    let protocol_config = &ctx.accounts.bridge.protocol_config;
+    if loosens_limit(protocol_config.daily_outbound_sol_limit, outbound_limit)
+        || loosens_limit(protocol_config.daily_inbound_sol_limit, inbound_limit)
+    {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    let protocol_config = &mut ctx.accounts.bridge.protocol_config;
+    protocol_config.daily_outbound_sol_limit = outbound_limit;
+    protocol_config.daily_inbound_sol_limit = inbound_limit;
+
+    Ok(())
+}
+
+/// Set the maximum age of an output root that messages can be proven against
+/// Raising or disabling the maximum age also requires the co-guardian when one is configured.
+pub fn set_max_root_age_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_max_age_seconds: u64,
+) -> Result<()> {
+    let old_max_age_seconds = ctx.accounts.bridge.protocol_config.max_root_age_seconds;
+    if loosens_limit(old_max_age_seconds, new_max_age_seconds) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.max_root_age_seconds = new_max_age_seconds;
+
+    Ok(())
+}
+
+/// Set how many Base blocks an output root may fall behind the latest one before it must be
+/// re-validated
+/// Raising the threshold or disabling re-validation also requires the co-guardian when one is
+/// configured.
+pub fn set_revalidate_old_roots_after_blocks_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_blocks: u64,
+) -> Result<()> {
+    let old_blocks = ctx
+        .accounts
+        .bridge
+        .protocol_config
+        .revalidate_old_roots_after_blocks;
+    if loosens_limit(old_blocks, new_blocks) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .revalidate_old_roots_after_blocks = new_blocks;
+
+    Ok(())
+}
+
+/// Set how many Base blocks a new output root may advance past the latest registered one
+/// Raising or disabling the bound also requires the co-guardian when one is configured.
+pub fn set_max_block_advance_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_max_block_advance: u64,
+) -> Result<()> {
+    let old_max_block_advance = ctx.accounts.bridge.protocol_config.max_block_advance;
+    if loosens_limit(old_max_block_advance, new_max_block_advance) {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts.bridge.protocol_config.max_block_advance = new_max_block_advance;
+
+    Ok(())
+}
+
+/// Set how long a proven message must wait before it can be relayed
+/// Shortening or disabling the delay also requires the co-guardian when one is configured.
+pub fn set_min_prove_to_relay_delay_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_delay_seconds: u64,
+) -> Result<()> {
+    let old_delay_seconds = ctx
+        .accounts
+        .bridge
+        .protocol_config
+        .min_prove_to_relay_delay_seconds;
+    if new_delay_seconds < old_delay_seconds {
+        ctx.accounts.require_co_guardian()?;
+    }
+
+    ctx.accounts
+        .bridge
+        .protocol_config
+        .min_prove_to_relay_delay_seconds = new_delay_seconds;
 
     Ok(())
 }
@@ -29,8 +340,21 @@ mod tests {
     use crate::{
         accounts,
         common::bridge::Bridge,
-        instruction::SetBlockIntervalRequirement as SetBlockIntervalRequirementIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        instruction::{
+            SetBlockIntervalRequirement as SetBlockIntervalRequirementIx,
+            SetDisjointSignerSets as SetDisjointSignerSetsIx,
+            SetEnforceRelayNonceOrder as SetEnforceRelayNonceOrderIx,
+            SetLargeFinalizeThreshold as SetLargeFinalizeThresholdIx,
+            SetMaxRootAge as SetMaxRootAgeIx, SetMaxWrappedTokens as SetMaxWrappedTokensIx,
+            SetMinProveToRelayDelay as SetMinProveToRelayDelayIx,
+            SetMinRegistrationInterval as SetMinRegistrationIntervalIx,
+            SetRegistrationRateLimit as SetRegistrationRateLimitIx,
+            SetRejectDuplicateIxs as SetRejectDuplicateIxsIx,
+            SetRejectDuplicateRoots as SetRejectDuplicateRootsIx,
+            SetRequireSelfCall as SetRequireSelfCallIx,
+            SetRevalidateOldRootsAfterBlocks as SetRevalidateOldRootsAfterBlocksIx,
+        },
+        test_utils::{send_guardian_config_ix, setup_bridge, update_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -132,4 +456,551 @@ mod tests {
             error_string
         );
     }
+
+    fn set_base_block_number(svm: &mut litesvm::LiteSVM, block: u64) {
+        update_bridge(svm, |bridge| bridge.base_block_number = block);
+    }
+
+    #[test]
+    fn test_set_block_interval_requirement_compatible_with_base_block_number() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Latest registered block 600 is aligned to both 300 and 200
+        set_base_block_number(&mut svm, 600);
+
+        let accounts = accounts::SetBridgeConfigFromGuardian {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetBlockIntervalRequirementIx { new_interval: 200 }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to send set_block_interval_requirement transaction");
+
+        // The change is recorded as an event
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge_data.protocol_config.block_interval_requirement, 200);
+    }
+
+    #[test]
+    fn test_set_block_interval_requirement_incompatible_with_base_block_number() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Latest registered block 600 is not aligned to 700
+        set_base_block_number(&mut svm, 600);
+
+        let accounts = accounts::SetBridgeConfigFromGuardian {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetBlockIntervalRequirementIx { new_interval: 700 }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(
+            result.is_err(),
+            "Expected transaction to fail with an incompatible interval"
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("IncompatibleBlockInterval"),
+            "Expected IncompatibleBlockInterval error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_set_min_registration_interval_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_interval_seconds = 120u64;
+
+        let accounts = accounts::SetBridgeConfigFromGuardians {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetMinRegistrationIntervalIx {
+                new_interval_seconds,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+            .expect("Failed to send set_min_registration_interval transaction");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+
+        assert_eq!(
+            bridge_data
+                .protocol_config
+                .min_registration_interval_seconds,
+            new_interval_seconds
+        );
+    }
+
+    #[test]
+    fn test_set_max_root_age_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_max_age_seconds = 3_600u64;
+
+        let accounts = accounts::SetBridgeConfigFromGuardians {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetMaxRootAgeIx {
+                new_max_age_seconds,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx)
+            .expect("Failed to send set_max_root_age transaction");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+
+        assert_eq!(
+            bridge_data.protocol_config.max_root_age_seconds,
+            new_max_age_seconds
+        );
+    }
+
+    fn send_set_registration_rate_limit(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        capacity: u64,
+        refill_interval_seconds: u64,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let accounts = accounts::SetBridgeConfigFromGuardians {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetRegistrationRateLimitIx {
+                capacity,
+                refill_interval_seconds,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_registration_rate_limit_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        send_set_registration_rate_limit(&mut svm, &guardian, bridge_pda, 5, 120)
+            .expect("Failed to send set_registration_rate_limit transaction");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+
+        assert_eq!(bridge_data.protocol_config.registration_bucket_capacity, 5);
+        assert_eq!(
+            bridge_data
+                .protocol_config
+                .registration_refill_interval_seconds,
+            120
+        );
+        assert_eq!(bridge_data.registration_bucket.tokens, 5);
+    }
+
+    #[test]
+    fn test_set_registration_rate_limit_rejects_zero_refill_interval() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = send_set_registration_rate_limit(&mut svm, &guardian, bridge_pda, 5, 0);
+        assert!(result.is_err());
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("InvalidRegistrationRateLimit"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_loosens_limit() {
+        assert!(!loosens_limit(0, 0));
+        assert!(!loosens_limit(0, 100));
+        assert!(!loosens_limit(100, 50));
+        assert!(!loosens_limit(100, 100));
+        assert!(loosens_limit(100, 101));
+        assert!(loosens_limit(100, 0));
+    }
+
+    #[test]
+    fn test_loosening_large_finalize_threshold_requires_co_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = solana_keypair::Keypair::new();
+        update_bridge(&mut svm, |bridge| bridge.co_guardian = co_guardian.pubkey());
+
+        let guardian_only = || accounts::SetBridgeConfigFromGuardians {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+        };
+
+        // Enabling and lowering the threshold only tightens the check
+        for new_threshold in [1_000, 500] {
+            send_guardian_config_ix(
+                &mut svm,
+                &[&guardian],
+                guardian_only(),
+                SetLargeFinalizeThresholdIx { new_threshold }.data(),
+            )
+            .expect("tightening the threshold should not need the co-guardian");
+        }
+
+        // Raising or disabling it needs the co-guardian
+        for new_threshold in [501, 0] {
+            let result = send_guardian_config_ix(
+                &mut svm,
+                &[&guardian],
+                guardian_only(),
+                SetLargeFinalizeThresholdIx { new_threshold }.data(),
+            );
+            assert!(
+                result.is_err(),
+                "Expected loosening the threshold to {} without the co-guardian to fail",
+                new_threshold
+            );
+            let err = format!("{:?}", result.unwrap_err());
+            assert!(
+                err.contains("CoGuardianSignatureRequired"),
+                "Unexpected error: {}",
+                err
+            );
+        }
+
+        send_guardian_config_ix(
+            &mut svm,
+            &[&guardian, &co_guardian],
+            accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: Some(co_guardian.pubkey()),
+            },
+            SetLargeFinalizeThresholdIx { new_threshold: 0 }.data(),
+        )
+        .expect("disabling the threshold signed by both guardians should succeed");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge_data.protocol_config.large_finalize_threshold, 0);
+    }
+
+    #[test]
+    fn test_turns_off() {
+        assert!(!turns_off(false, false));
+        assert!(!turns_off(false, true));
+        assert!(!turns_off(true, true));
+        assert!(turns_off(true, false));
+    }
+
+    /// Sends `tighten` signed by the guardian alone, then checks that `loosen` needs the
+    /// co-guardian.
+    fn assert_loosening_requires_co_guardian(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &solana_keypair::Keypair,
+        co_guardian: &solana_keypair::Keypair,
+        bridge_pda: Pubkey,
+        tighten: Vec<u8>,
+        loosen: Vec<u8>,
+    ) {
+        let guardian_only = || accounts::SetBridgeConfigFromGuardians {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+        };
+
+        send_guardian_config_ix(svm, &[guardian], guardian_only(), tighten)
+            .expect("tightening should not need the co-guardian");
+
+        let result = send_guardian_config_ix(svm, &[guardian], guardian_only(), loosen.clone());
+        assert!(
+            result.is_err(),
+            "Expected loosening without the co-guardian to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("CoGuardianSignatureRequired"),
+            "Unexpected error: {}",
+            err
+        );
+
+        send_guardian_config_ix(
+            svm,
+            &[guardian, co_guardian],
+            accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: Some(co_guardian.pubkey()),
+            },
+            loosen,
+        )
+        .expect("loosening signed by both guardians should succeed");
+    }
+
+    #[test]
+    fn test_loosening_protocol_limits_requires_co_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = solana_keypair::Keypair::new();
+        update_bridge(&mut svm, |bridge| {
+            bridge.co_guardian = co_guardian.pubkey();
+            let protocol_config = &mut bridge.protocol_config;
+            protocol_config.min_registration_interval_seconds = 60;
+            protocol_config.max_root_age_seconds = 3_600;
+            protocol_config.registration_bucket_capacity = 5;
+            protocol_config.registration_refill_interval_seconds = 60;
+            protocol_config.max_wrapped_tokens = 10;
+            protocol_config.revalidate_old_roots_after_blocks = 1_000;
+            protocol_config.min_prove_to_relay_delay_seconds = 60;
+        });
+
+        let cases = [
+            (
+                SetMinRegistrationIntervalIx {
+                    new_interval_seconds: 120,
+                }
+                .data(),
+                SetMinRegistrationIntervalIx {
+                    new_interval_seconds: 0,
+                }
+                .data(),
+            ),
+            (
+                SetMaxRootAgeIx {
+                    new_max_age_seconds: 1_800,
+                }
+                .data(),
+                SetMaxRootAgeIx {
+                    new_max_age_seconds: 0,
+                }
+                .data(),
+            ),
+            (
+                SetRegistrationRateLimitIx {
+                    capacity: 3,
+                    refill_interval_seconds: 120,
+                }
+                .data(),
+                SetRegistrationRateLimitIx {
+                    capacity: 3,
+                    refill_interval_seconds: 60,
+                }
+                .data(),
+            ),
+            (
+                SetMaxWrappedTokensIx { new_max: 5 }.data(),
+                SetMaxWrappedTokensIx { new_max: 6 }.data(),
+            ),
+            (
+                SetRevalidateOldRootsAfterBlocksIx { new_blocks: 500 }.data(),
+                SetRevalidateOldRootsAfterBlocksIx { new_blocks: 0 }.data(),
+            ),
+            (
+                SetMinProveToRelayDelayIx {
+                    new_delay_seconds: 120,
+                }
+                .data(),
+                SetMinProveToRelayDelayIx {
+                    new_delay_seconds: 30,
+                }
+                .data(),
+            ),
+        ];
+        for (tighten, loosen) in cases {
+            assert_loosening_requires_co_guardian(
+                &mut svm,
+                &guardian,
+                &co_guardian,
+                bridge_pda,
+                tighten,
+                loosen,
+            );
+        }
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let protocol_config = Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .protocol_config;
+        assert_eq!(protocol_config.min_registration_interval_seconds, 0);
+        assert_eq!(protocol_config.max_root_age_seconds, 0);
+        assert_eq!(protocol_config.registration_refill_interval_seconds, 60);
+        assert_eq!(protocol_config.max_wrapped_tokens, 6);
+        assert_eq!(protocol_config.revalidate_old_roots_after_blocks, 0);
+        assert_eq!(protocol_config.min_prove_to_relay_delay_seconds, 30);
+    }
+
+    #[test]
+    fn test_turning_off_protocol_guards_requires_co_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = solana_keypair::Keypair::new();
+        update_bridge(&mut svm, |bridge| bridge.co_guardian = co_guardian.pubkey());
+
+        let cases: [fn(bool) -> Vec<u8>; 5] = [
+            |reject_duplicate_roots| {
+                SetRejectDuplicateRootsIx {
+                    reject_duplicate_roots,
+                }
+                .data()
+            },
+            |enforce_relay_nonce_order| {
+                SetEnforceRelayNonceOrderIx {
+                    enforce_relay_nonce_order,
+                }
+                .data()
+            },
+            |reject_duplicate_ixs| {
+                SetRejectDuplicateIxsIx {
+                    reject_duplicate_ixs,
+                }
+                .data()
+            },
+            |disjoint_signer_sets| {
+                SetDisjointSignerSetsIx {
+                    disjoint_signer_sets,
+                }
+                .data()
+            },
+            |require_self_call| SetRequireSelfCallIx { require_self_call }.data(),
+        ];
+        for data in cases {
+            assert_loosening_requires_co_guardian(
+                &mut svm,
+                &guardian,
+                &co_guardian,
+                bridge_pda,
+                data(true),
+                data(false),
+            );
+        }
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let protocol_config = Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .protocol_config;
+        assert!(!protocol_config.reject_duplicate_roots);
+        assert!(!protocol_config.enforce_relay_nonce_order);
+        assert!(!protocol_config.reject_duplicate_ixs);
+        assert!(!protocol_config.disjoint_signer_sets);
+        assert!(!protocol_config.require_self_call);
+    }
 }
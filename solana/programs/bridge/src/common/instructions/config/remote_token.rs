@@ -0,0 +1,328 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit, BRIDGE_SEED,
+        DENIED_REMOTE_TOKEN_SEED, DISCRIMINATOR_LEN, RATE_LIMIT_SEED,
+    },
+    BridgeError,
+};
+
+use super::protocol::loosens_limit;
+
+/// Accounts struct for the `add_denied_remote_token` instruction
+/// Only the guardian can deny a remote token; it pays the rent of the denylist entry
+#[derive(Accounts)]
+#[instruction(remote_token: [u8; 20])]
+pub struct AddDeniedRemoteToken<'info> {
+    /// The bridge account used to authorize the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to update the denylist
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The denylist entry of `remote_token`, created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + DeniedRemoteToken::INIT_SPACE,
+        seeds = [DENIED_REMOTE_TOKEN_SEED, remote_token.as_ref()],
+        bump
+    )]
+    pub denied_remote_token: Account<'info, DeniedRemoteToken>,
+
+    /// System program required for creating the denylist entry
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the `remove_denied_remote_token` instruction
+/// The guardian must sign, together with the co-guardian when one is configured
+#[derive(Accounts)]
+#[instruction(remote_token: [u8; 20])]
+pub struct RemoveDeniedRemoteToken<'info> {
+    /// The bridge account used to authorize the guardians
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to update the denylist, refunded the entry's rent
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The co-guardian, required when `bridge.co_guardian` is set
+    pub co_guardian: Option<Signer<'info>>,
+
+    /// The denylist entry of `remote_token`, closed by this instruction
+    #[account(
+        mut,
+        close = guardian,
+        seeds = [DENIED_REMOTE_TOKEN_SEED, remote_token.as_ref()],
+        bump
+    )]
+    pub denied_remote_token: Account<'info, DeniedRemoteToken>,
+}
+
+/// Accounts struct for the `set_rate_limit` instruction
+/// The guardian must sign, together with the co-guardian when the change loosens the limit
+#[derive(Accounts)]
+#[instruction(remote_token: [u8; 20])]
+pub struct SetRateLimit<'info> {
+    /// The bridge account used to authorize the guardians
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to set rate limits; it pays the rent of a new limit and is
+    /// refunded when the limit is removed
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The co-guardian, required when `bridge.co_guardian` is set and the change loosens the limit
+    pub co_guardian: Option<Signer<'info>>,
+
+    /// The rate limit of `remote_token`, created if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        space = DISCRIMINATOR_LEN + RemoteTokenRateLimit::INIT_SPACE,
+        seeds = [RATE_LIMIT_SEED, remote_token.as_ref()],
+        bump
+    )]
+    pub rate_limit: Account<'info, RemoteTokenRateLimit>,
+
+    /// System program required for creating the rate limit
+    pub system_program: Program<'info, System>,
+}
+
+/// Add a Base token address to the denylist of tokens that can no longer be bridged or wrapped
+/// Denying an already denied token is a no-op.
+pub fn add_denied_remote_token_handler(
+    ctx: Context<AddDeniedRemoteToken>,
+    remote_token: [u8; 20],
+) -> Result<()> {
+    ctx.accounts.denied_remote_token.remote_token = remote_token;
+
+    Ok(())
+}
+
+/// Remove a Base token address from the denylist by closing its entry
+/// Also requires the co-guardian when one is configured.
+pub fn remove_denied_remote_token_handler(
+    ctx: Context<RemoveDeniedRemoteToken>,
+    _remote_token: [u8; 20],
+) -> Result<()> {
+    let co_guardian = ctx.accounts.co_guardian.as_ref().map(|signer| signer.key());
+    ctx.accounts.bridge.require_co_guardian(co_guardian)
+}
+
+/// Set (or, with a `max_per_window` of 0, remove) the outflow limit of a Base token address
+/// Removing a limit, raising its maximum or shortening its window also requires the co-guardian
+/// when one is configured.
+pub fn set_rate_limit_handler(
+    ctx: Context<SetRateLimit>,
+    remote_token: [u8; 20],
+    max_per_window: u64,
+    window_seconds: u64,
+) -> Result<()> {
+    // A limit created by this instruction is zeroed, so only an existing limit can be loosened
+    let rate_limit = &ctx.accounts.rate_limit;
+    if loosens_limit(rate_limit.max_per_window, max_per_window)
+        || window_seconds < rate_limit.window_seconds
+    {
+        let co_guardian = ctx.accounts.co_guardian.as_ref().map(|signer| signer.key());
+        ctx.accounts.bridge.require_co_guardian(co_guardian)?;
+    }
+
+    if max_per_window == 0 {
+        return ctx
+            .accounts
+            .rate_limit
+            .close(ctx.accounts.guardian.to_account_info());
+    }
+
+    ctx.accounts.rate_limit.set(
+        remote_token,
+        max_per_window,
+        window_seconds,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{system_program, InstructionData};
+    use solana_keypair::Keypair;
+    use solana_signer::Signer;
+
+    use crate::{
+        accounts,
+        instruction::{
+            AddDeniedRemoteToken as AddDeniedRemoteTokenIx,
+            RemoveDeniedRemoteToken as RemoveDeniedRemoteTokenIx, SetRateLimit as SetRateLimitIx,
+        },
+        test_utils::{send_guardian_config_ix, setup_bridge, update_bridge, SetupBridgeResult},
+    };
+
+    #[test]
+    fn test_add_and_remove_denied_remote_token() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let remote_token = [5u8; 20];
+        let denied_remote_token = DeniedRemoteToken::address(&remote_token);
+        let add_accounts = || accounts::AddDeniedRemoteToken {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            denied_remote_token,
+            system_program: system_program::ID,
+        };
+        send_guardian_config_ix(
+            &mut svm,
+            &[&guardian],
+            add_accounts(),
+            AddDeniedRemoteTokenIx { remote_token }.data(),
+        )
+        .expect("add_denied_remote_token should succeed");
+        send_guardian_config_ix(
+            &mut svm,
+            &[&guardian],
+            add_accounts(),
+            AddDeniedRemoteTokenIx { remote_token }.data(),
+        )
+        .expect("denying an already denied token should be a no-op");
+
+        let account = svm.get_account(&denied_remote_token).unwrap();
+        let entry = DeniedRemoteToken::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(entry.remote_token, remote_token);
+
+        let remove_accounts = || accounts::RemoveDeniedRemoteToken {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian: None,
+            denied_remote_token,
+        };
+        send_guardian_config_ix(
+            &mut svm,
+            &[&guardian],
+            remove_accounts(),
+            RemoveDeniedRemoteTokenIx { remote_token }.data(),
+        )
+        .expect("remove_denied_remote_token should succeed");
+        assert!(svm
+            .get_account(&denied_remote_token)
+            .is_none_or(|account| account.data.is_empty()));
+
+        let result = send_guardian_config_ix(
+            &mut svm,
+            &[&guardian],
+            remove_accounts(),
+            RemoveDeniedRemoteTokenIx { remote_token }.data(),
+        );
+        assert!(result.is_err());
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("AccountNotInitialized"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_set_rate_limit_loosening_requires_co_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let co_guardian = Keypair::new();
+        update_bridge(&mut svm, |bridge| bridge.co_guardian = co_guardian.pubkey());
+
+        let remote_token = [7u8; 20];
+        let rate_limit = RemoteTokenRateLimit::address(&remote_token);
+        let accounts = |co_guardian: Option<Pubkey>| accounts::SetRateLimit {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            co_guardian,
+            rate_limit,
+            system_program: system_program::ID,
+        };
+        let data = |max_per_window: u64, window_seconds: u64| {
+            SetRateLimitIx {
+                remote_token,
+                max_per_window,
+                window_seconds,
+            }
+            .data()
+        };
+
+        // Creating and tightening a limit only needs the guardian
+        for (max_per_window, window_seconds) in [(1_000, 60), (500, 120)] {
+            send_guardian_config_ix(
+                &mut svm,
+                &[&guardian],
+                accounts(None),
+                data(max_per_window, window_seconds),
+            )
+            .expect("tightening the limit should not need the co-guardian");
+        }
+
+        let account = svm.get_account(&rate_limit).unwrap();
+        let limit = RemoteTokenRateLimit::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(limit.remote_token, remote_token);
+        assert_eq!(limit.max_per_window, 500);
+        assert_eq!(limit.window_seconds, 120);
+
+        // Raising the maximum, shortening the window or removing the limit needs the co-guardian
+        for (max_per_window, window_seconds) in [(501, 120), (500, 60), (0, 0)] {
+            let result = send_guardian_config_ix(
+                &mut svm,
+                &[&guardian],
+                accounts(None),
+                data(max_per_window, window_seconds),
+            );
+            assert!(
+                result.is_err(),
+                "Expected loosening the limit to {}/{}s without the co-guardian to fail",
+                max_per_window,
+                window_seconds
+            );
+            let err = format!("{:?}", result.unwrap_err());
+            assert!(
+                err.contains("CoGuardianSignatureRequired"),
+                "Unexpected error: {}",
+                err
+            );
+        }
+
+        send_guardian_config_ix(
+            &mut svm,
+            &[&guardian, &co_guardian],
+            accounts(Some(co_guardian.pubkey())),
+            data(0, 0),
+        )
+        .expect("removing the limit signed by both guardians should succeed");
+        assert!(svm
+            .get_account(&rate_limit)
+            .is_none_or(|account| account.data.is_empty()));
+    }
+}
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{FreezeAccount, ThawAccount, Token2022},
+    token_interface::{self, Mint, TokenAccount},
+};
+
+use crate::{
+    common::{
+        bridge::Bridge, PartialTokenMetadata, WrappedAccountFreezeUpdated, BRIDGE_SEED,
+        WRAPPED_TOKEN_SEED,
+    },
+    BridgeError, ID,
+};
+
+/// Accounts struct for the guardian-only `freeze_wrapped_account` and `thaw_wrapped_account`
+/// instructions. The wrapped mint is its own freeze authority, so the bridge signs the Token-2022
+/// CPI with the mint's PDA seeds.
+#[derive(Accounts)]
+pub struct FreezeWrappedAccount<'info> {
+    /// The bridge account used to authorize the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to freeze and thaw wrapped token accounts
+    pub guardian: Signer<'info>,
+
+    /// The wrapped mint. Its address must be the wrapped token PDA derived from its metadata and
+    /// decimals, so only mints created by `wrap_token` can be used.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The token account of `mint` to freeze or thaw
+    #[account(mut, token::mint = mint)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// SPL Token-2022 program the wrapped mint belongs to
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Freezes a wrapped token account, blocking transfers and burns (including bridging back to Base)
+/// until it is thawed.
+pub fn freeze_wrapped_account_handler(ctx: Context<FreezeWrappedAccount>) -> Result<()> {
+    set_frozen(ctx, true)
+}
+
+/// Thaws a wrapped token account frozen by `freeze_wrapped_account`.
+pub fn thaw_wrapped_account_handler(ctx: Context<FreezeWrappedAccount>) -> Result<()> {
+    set_frozen(ctx, false)
+}
+
+fn set_frozen(ctx: Context<FreezeWrappedAccount>, frozen: bool) -> Result<()> {
+    let mint = &ctx.accounts.mint;
+
+    // Checks that the mint is a wrapped token PDA created by this program
+    let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
+
+    // Derive the seeds for the wrapped token mint
+    let decimals_bytes = mint.decimals.to_le_bytes();
+    let metadata_hash = partial_token_metadata.hash();
+    let (_, mint_bump) = Pubkey::find_program_address(
+        &[
+            WRAPPED_TOKEN_SEED,
+            decimals_bytes.as_ref(),
+            metadata_hash.as_ref(),
+        ],
+        &ID,
+    );
+
+    let seeds: &[&[&[u8]]] = &[&[
+        WRAPPED_TOKEN_SEED,
+        decimals_bytes.as_ref(),
+        metadata_hash.as_ref(),
+        &[mint_bump],
+    ]];
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let account = ctx.accounts.token_account.to_account_info();
+    let mint = mint.to_account_info();
+    if frozen {
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program,
+            FreezeAccount {
+                account,
+                mint: mint.clone(),
+                authority: mint,
+            },
+            seeds,
+        );
+        token_interface::freeze_account(cpi_ctx)?;
+    } else {
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program,
+            ThawAccount {
+                account,
+                mint: mint.clone(),
+                authority: mint,
+            },
+            seeds,
+        );
+        token_interface::thaw_account(cpi_ctx)?;
+    }
+
+    emit!(WrappedAccountFreezeUpdated {
+        mint: ctx.accounts.mint.key(),
+        token_account: ctx.accounts.token_account.key(),
+        frozen,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use anchor_spl::token_2022::spl_token_2022::state::AccountState;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{DeniedRemoteToken, RemoteTokenRateLimit},
+        instruction::{
+            BridgeWrappedToken as BridgeWrappedTokenIx,
+            FreezeWrappedAccount as FreezeWrappedAccountIx,
+            ThawWrappedAccount as ThawWrappedAccountIx,
+        },
+        test_utils::{
+            create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
+            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+    };
+
+    fn send_ix(
+        svm: &mut litesvm::LiteSVM,
+        signers: &[&Keypair],
+        ix: Instruction,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let tx = Transaction::new(
+            signers,
+            Message::new(&[ix], Some(&signers[0].pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    #[test]
+    fn test_frozen_wrapped_account_cannot_bridge_back() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 0,
+        };
+        let initial_amount = 1_000_000u64;
+        let mint = create_mock_wrapped_mint(&mut svm, initial_amount, 6, &partial_token_metadata);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let token_account = Keypair::new().pubkey();
+        create_mock_token_account(&mut svm, token_account, mint, from.pubkey(), initial_amount);
+
+        let freeze_accounts = accounts::FreezeWrappedAccount {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            mint,
+            token_account,
+            token_program: anchor_spl::token_2022::ID,
+        }
+        .to_account_metas(None);
+
+        let bridge_back = |svm: &mut litesvm::LiteSVM| {
+            let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::BridgeWrappedToken {
+                    payer: payer.pubkey(),
+                    from: from.pubkey(),
+                    gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                    mint,
+                    from_token_account: token_account,
+                    bridge: bridge_pda,
+                    denied_remote_token: DeniedRemoteToken::address(
+                        &partial_token_metadata.remote_token,
+                    ),
+                    rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
+                    outgoing_message,
+                    token_program: anchor_spl::token_2022::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: BridgeWrappedTokenIx {
+                    outgoing_message_salt,
+                    to: [1u8; 20],
+                    amount: 1_000,
+                    call: None,
+                }
+                .data(),
+            };
+            send_ix(svm, &[&payer, &from], ix)
+        };
+
+        // Only the guardian can freeze
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::FreezeWrappedAccount {
+                bridge: bridge_pda,
+                guardian: payer.pubkey(),
+                mint,
+                token_account,
+                token_program: anchor_spl::token_2022::ID,
+            }
+            .to_account_metas(None),
+            data: FreezeWrappedAccountIx {}.data(),
+        };
+        let err = format!("{:?}", send_ix(&mut svm, &[&payer], ix).unwrap_err());
+        assert!(
+            err.contains("UnauthorizedConfigUpdate"),
+            "Unexpected error: {}",
+            err
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: freeze_accounts.clone(),
+            data: FreezeWrappedAccountIx {}.data(),
+        };
+        send_ix(&mut svm, &[&guardian], ix).expect("freeze_wrapped_account should succeed");
+
+        let account = svm.get_account(&token_account).unwrap();
+        let state = TokenAccount::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(state.state, AccountState::Frozen);
+
+        // Burning from a frozen account fails inside Token-2022
+        assert!(bridge_back(&mut svm).is_err());
+        let account = svm.get_account(&token_account).unwrap();
+        let state = TokenAccount::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(state.amount, initial_amount);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: freeze_accounts,
+            data: ThawWrappedAccountIx {}.data(),
+        };
+        send_ix(&mut svm, &[&guardian], ix).expect("thaw_wrapped_account should succeed");
+
+        bridge_back(&mut svm).expect("bridging back should succeed once thawed");
+        let account = svm.get_account(&token_account).unwrap();
+        let state = TokenAccount::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(state.amount, initial_amount - 1_000);
+    }
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{bridge::Bridge, BRIDGE_SEED};
+
+/// Accounts struct for the `get_base_fee` view instruction that reports the live EIP-1559 base
+/// fee.
+#[derive(Accounts)]
+pub struct GetBaseFee<'info> {
+    /// The main bridge state account holding the EIP-1559 state.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Read-only: the refreshed state is never persisted
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+/// EIP-1559 state as of the current timestamp, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct BaseFee {
+    /// Base fee that would be charged by a bridge operation now
+    pub current_base_fee: u64,
+    /// Gas used so far in the current window
+    pub current_window_gas_used: u64,
+    /// Unix timestamp when the current window started
+    pub window_start_time: i64,
+}
+
+/// Refreshes a copy of the bridge's EIP-1559 state for the current timestamp, exactly as
+/// `pay_for_gas` does, and returns it without writing it back.
+pub fn get_base_fee_handler(ctx: Context<GetBaseFee>) -> Result<BaseFee> {
+    let mut eip1559 = ctx.accounts.bridge.eip1559.clone();
+    let current_base_fee = eip1559.refresh_base_fee(Clock::get()?.unix_timestamp);
+
+    Ok(BaseFee {
+        current_base_fee,
+        current_window_gas_used: eip1559.current_window_gas_used,
+        window_start_time: eip1559.window_start_time,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::GetBaseFee as GetBaseFeeIx,
+        test_utils::{mock_clock, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn get_base_fee(svm: &mut litesvm::LiteSVM, payer: &Keypair, bridge_pda: Pubkey) -> BaseFee {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetBaseFee { bridge: bridge_pda }.to_account_metas(None),
+            data: GetBaseFeeIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_base_fee should succeed");
+        svm.expire_blockhash();
+
+        assert_eq!(meta.return_data.program_id, ID);
+        BaseFee::try_from_slice(&meta.return_data.data).unwrap()
+    }
+
+    #[test]
+    fn test_get_base_fee_refreshes_without_persisting() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let bridge_before = svm.get_account(&bridge_pda).unwrap().data;
+        let bridge = Bridge::try_deserialize(&mut &bridge_before[..]).unwrap();
+
+        // Move past several empty windows so the base fee decays
+        let now = bridge.eip1559.window_start_time
+            + 3 * bridge.eip1559.config.window_duration_seconds as i64;
+        mock_clock(&mut svm, now);
+
+        let mut expected = bridge.eip1559.clone();
+        let expected_base_fee = expected.refresh_base_fee(now);
+
+        let base_fee = get_base_fee(&mut svm, &payer, bridge_pda);
+        assert_eq!(
+            base_fee,
+            BaseFee {
+                current_base_fee: expected_base_fee,
+                current_window_gas_used: expected.current_window_gas_used,
+                window_start_time: expected.window_start_time,
+            }
+        );
+        assert_eq!(svm.get_account(&bridge_pda).unwrap().data, bridge_before);
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED},
+    solana_to_base::CallType,
+};
+
+/// Accounts struct for the `get_fee_bounds` view instruction that reports the range of fees a
+/// standard call can be charged under the current gas config.
+#[derive(Accounts)]
+pub struct GetFeeBounds<'info> {
+    /// The main bridge state account holding the EIP-1559 state and gas config.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Read-only: the refreshed state is never persisted
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+/// Lamport fees for a `CallType::Call` message, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeBounds {
+    /// Fee charged at the minimum base fee, never below `minimum_fee_lamports`
+    pub minimum_fee: u64,
+    /// Fee that would be charged by a `bridge_call` now
+    pub current_fee: u64,
+    /// `maximum_fee_lamports`, or `None` when the gas config sets no ceiling
+    pub maximum_fee: Option<u64>,
+}
+
+/// Prices a standard call at the minimum base fee and at a copy of the EIP-1559 state refreshed
+/// for the current timestamp, exactly as `pay_for_gas` does, without writing the refresh back.
+pub fn get_fee_bounds_handler(ctx: Context<GetFeeBounds>) -> Result<FeeBounds> {
+    let bridge = &ctx.accounts.bridge;
+    let gas_config = &bridge.gas_config;
+
+    let mut eip1559 = bridge.eip1559.clone();
+    let current_base_fee = eip1559.refresh_base_fee(Clock::get()?.unix_timestamp);
+
+    Ok(FeeBounds {
+        minimum_fee: gas_config.gas_cost(eip1559.config.minimum_base_fee, Some(CallType::Call))?,
+        current_fee: gas_config.gas_cost(current_base_fee, Some(CallType::Call))?,
+        maximum_fee: match gas_config.maximum_fee_lamports {
+            0 => None,
+            maximum_fee_lamports => Some(maximum_fee_lamports),
+        },
+    })
+}
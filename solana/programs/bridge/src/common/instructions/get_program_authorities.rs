@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::constants::{
+        BRIDGE_CPI_AUTHORITY_SEED, PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED,
+    },
+    common::{BRIDGE_SEED, SOL_VAULT_SEED},
+};
+
+/// Accounts struct for the `get_program_authorities` view instruction. No accounts are needed:
+/// every address is derived from constant seeds and the program ID.
+#[derive(Accounts)]
+pub struct GetProgramAuthorities {}
+
+/// A program-derived address and the bump it was found with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ProgramAuthority {
+    pub address: Pubkey,
+    pub bump: u8,
+}
+
+impl ProgramAuthority {
+    fn find(seeds: &[&[u8]], program_id: &Pubkey) -> Self {
+        let (address, bump) = Pubkey::find_program_address(seeds, program_id);
+        Self { address, bump }
+    }
+}
+
+/// PDAs the bridge signs with or checks against, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct ProgramAuthorities {
+    /// Main bridge state account (`[BRIDGE_SEED]`)
+    pub bridge: ProgramAuthority,
+    /// Vault holding bridged SOL, which signs SOL finalizations (`[SOL_VAULT_SEED]`)
+    pub sol_vault: ProgramAuthority,
+    /// Signer of relayed instruction CPIs for `sender` (`[BRIDGE_CPI_AUTHORITY_SEED, sender]`)
+    pub bridge_cpi_authority: ProgramAuthority,
+    /// Partner signer set checked during output root registration
+    /// (`[PARTNER_SIGNERS_ACCOUNT_SEED]` under `PARTNER_PROGRAM_ID`)
+    pub partner_signers: ProgramAuthority,
+}
+
+/// Derives the bridge's PDAs, including the CPI authority relayed messages from `sender` are
+/// signed with.
+pub fn get_program_authorities_handler(
+    ctx: Context<GetProgramAuthorities>,
+    sender: [u8; 20],
+) -> Result<ProgramAuthorities> {
+    let program_id = ctx.program_id;
+
+    Ok(ProgramAuthorities {
+        bridge: ProgramAuthority::find(&[BRIDGE_SEED], program_id),
+        sol_vault: ProgramAuthority::find(&[SOL_VAULT_SEED], program_id),
+        bridge_cpi_authority: ProgramAuthority::find(
+            &[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()],
+            program_id,
+        ),
+        partner_signers: ProgramAuthority::find(
+            &[PARTNER_SIGNERS_ACCOUNT_SEED],
+            &PARTNER_PROGRAM_ID,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::GetProgramAuthorities as GetProgramAuthoritiesIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_get_program_authorities_match_cpi_seeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = [7u8; 20];
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetProgramAuthorities {}.to_account_metas(None),
+            data: GetProgramAuthoritiesIx { sender }.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_program_authorities should succeed");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let authorities = ProgramAuthorities::try_from_slice(&meta.return_data.data).unwrap();
+
+        assert_eq!(authorities.bridge.address, bridge_pda);
+
+        // The returned bumps must re-derive the same addresses the bridge signs CPIs with
+        let cpi_authority = authorities.bridge_cpi_authority;
+        assert_eq!(
+            Pubkey::create_program_address(
+                &[
+                    BRIDGE_CPI_AUTHORITY_SEED,
+                    sender.as_ref(),
+                    &[cpi_authority.bump]
+                ],
+                &ID,
+            )
+            .unwrap(),
+            cpi_authority.address
+        );
+        let sol_vault = authorities.sol_vault;
+        assert_eq!(
+            Pubkey::create_program_address(&[SOL_VAULT_SEED, &[sol_vault.bump]], &ID).unwrap(),
+            sol_vault.address
+        );
+        assert_eq!(
+            authorities.partner_signers.address,
+            Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{bridge::Bridge, BRIDGE_SEED, SOL_VAULT_SEED};
+
+/// Accounts struct for the `get_total_locked_sol` view instruction that reports how much SOL
+/// is locked in the bridge, for solvency and proof-of-reserves reporting.
+#[derive(Accounts)]
+pub struct GetTotalLockedSol<'info> {
+    /// The main bridge state account tracking the SOL locked by `bridge_sol`.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The SOL vault holding the lamports of all bridged SOL.
+    /// CHECK: This is the SOL vault account.
+    #[account(seeds = [SOL_VAULT_SEED], bump)]
+    pub sol_vault: AccountInfo<'info>,
+}
+
+/// Locked SOL totals, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct TotalLockedSol {
+    /// Lamports accounted as locked: bridged out and not yet finalized back to Solana.
+    pub locked_sol: u64,
+    /// Actual lamport balance of the SOL vault. May exceed `locked_sol` (e.g. direct deposits),
+    /// but never be lower for a solvent bridge.
+    pub vault_balance: u64,
+}
+
+pub fn get_total_locked_sol_handler(ctx: Context<GetTotalLockedSol>) -> Result<TotalLockedSol> {
+    Ok(TotalLockedSol {
+        locked_sol: ctx.accounts.bridge.locked_sol,
+        vault_balance: ctx.accounts.sol_vault.lamports(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::INCOMING_MESSAGE_VERSION, token::FinalizeBridgeSol, IncomingMessage,
+            Message as IncomingMessageBody, Transfer,
+        },
+        common::{DeniedRemoteToken, RemoteTokenRateLimit},
+        instruction::{
+            BridgeSol as BridgeSolIx, GetTotalLockedSol as GetTotalLockedSolIx,
+            RelayMessage as RelayMessageIx,
+        },
+        test_utils::{
+            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_REMOTE_SOL_ADDRESS,
+        },
+        ID,
+    };
+
+    fn get_total_locked_sol(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> TotalLockedSol {
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetTotalLockedSol {
+                bridge: bridge_pda,
+                sol_vault,
+            }
+            .to_account_metas(None),
+            data: GetTotalLockedSolIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_total_locked_sol should succeed");
+        svm.expire_blockhash();
+
+        assert_eq!(meta.return_data.program_id, ID);
+        TotalLockedSol::try_from_slice(&meta.return_data.data).unwrap()
+    }
+
+    fn bridge_sol(svm: &mut litesvm::LiteSVM, payer: &Keypair, bridge_pda: Pubkey, amount: u64) {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSol {
+                payer: payer.pubkey(),
+                from: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                sol_vault,
+                bridge: bridge_pda,
+                denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+                rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
+                outgoing_message,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: None,
+                reserve_lamports: None,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("bridge_sol should succeed");
+    }
+
+    fn finalize_sol(svm: &mut litesvm::LiteSVM, payer: &Keypair, bridge_pda: Pubkey, amount: u64) {
+        let incoming_message = IncomingMessage {
+            version: INCOMING_MESSAGE_VERSION,
+            nonce: 0,
+            sender: [7u8; 20],
+            message: IncomingMessageBody::Transfer {
+                transfer: Transfer::Sol(FinalizeBridgeSol {
+                    to: payer.pubkey(),
+                    amount,
+                }),
+                ixs: vec![],
+            },
+            executed: false,
+            proven_at: 0,
+        };
+        let mut data = Vec::new();
+        incoming_message.try_serialize(&mut data).unwrap();
+        let message = Pubkey::new_unique();
+        svm.set_account(
+            message,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let mut accounts = accounts::RelayMessage {
+            message,
+            bridge: bridge_pda,
+            guardian: None,
+        }
+        .to_account_metas(None);
+        accounts.extend([
+            AccountMeta::new(sol_vault, false),
+            AccountMeta::new(payer.pubkey(), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ]);
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("relay_message should succeed");
+    }
+
+    #[test]
+    fn test_get_total_locked_sol_tracks_bridges_and_finalizations() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let initial = get_total_locked_sol(&mut svm, &payer, bridge_pda);
+        assert_eq!(initial.locked_sol, 0);
+
+        bridge_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL);
+        bridge_sol(&mut svm, &payer, bridge_pda, 2 * LAMPORTS_PER_SOL);
+        bridge_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL / 2);
+
+        let after_bridges = get_total_locked_sol(&mut svm, &payer, bridge_pda);
+        assert_eq!(
+            after_bridges.locked_sol,
+            3 * LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 2
+        );
+        assert_eq!(
+            after_bridges.vault_balance,
+            initial.vault_balance + after_bridges.locked_sol
+        );
+
+        finalize_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL);
+
+        let after_finalize = get_total_locked_sol(&mut svm, &payer, bridge_pda);
+        assert_eq!(
+            after_finalize.locked_sol,
+            2 * LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 2
+        );
+        assert_eq!(
+            after_finalize.vault_balance,
+            initial.vault_balance + after_finalize.locked_sol
+        );
+    }
+}
@@ -1,16 +1,104 @@
 use anchor_lang::prelude::*;
 
-use crate::common::config::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{
+        bridge::Bridge,
+        config::{SetBridgeConfigFromGuardian, SetBridgeConfigFromGuardians},
+        CoGuardianUpdated, GuardianTransferAccepted, GuardianTransferCancelled,
+        GuardianTransferProposed, BRIDGE_SEED,
+    },
+    BridgeError,
+};
 
-/// Transfer guardian authority to a new pubkey.
-/// Only the current guardian can call this function.
-///
-/// Note: No additional validation is performed on `new_guardian` (it may be any pubkey).
+/// Accounts struct for accepting a pending guardian proposal
+/// Only the proposed guardian can accept
+#[derive(Accounts)]
+pub struct AcceptGuardian<'info> {
+    /// The bridge account holding the pending guardian
+    #[account(
+        mut,
+        seeds = [BRIDGE_SEED],
+        bump,
+        constraint = bridge.pending_guardian == Some(new_guardian.key())
+            @ BridgeError::UnauthorizedGuardianAcceptance
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The proposed guardian accepting the role
+    pub new_guardian: Signer<'info>,
+}
+
+/// Propose a new guardian. The role is only handed over once `new_guardian` accepts it via
+/// `accept_guardian`, so a mistyped key cannot lock the guardian out.
+/// Only the current guardian can call this function, together with the co-guardian when one is
+/// configured.
 pub fn transfer_guardian_handler(
-    ctx: Context<SetBridgeConfigFromGuardian>,
+    ctx: Context<SetBridgeConfigFromGuardians>,
     new_guardian: Pubkey,
 ) -> Result<()> {
-    ctx.accounts.bridge.guardian = new_guardian;
+    ctx.accounts.require_co_guardian()?;
+
+    ctx.accounts.bridge.pending_guardian = Some(new_guardian);
+
+    emit!(GuardianTransferProposed {
+        guardian: ctx.accounts.guardian.key(),
+        pending_guardian: new_guardian,
+    });
+
+    Ok(())
+}
+
+/// Accept a pending guardian proposal, making the signer the guardian.
+pub fn accept_guardian_handler(ctx: Context<AcceptGuardian>) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let previous_guardian = bridge.guardian;
+    bridge.guardian = ctx.accounts.new_guardian.key();
+    bridge.pending_guardian = None;
+
+    emit!(GuardianTransferAccepted {
+        previous_guardian,
+        new_guardian: bridge.guardian,
+    });
+
+    Ok(())
+}
+
+/// Set the co-guardian that must co-sign critical configuration changes. Passing the default
+/// pubkey removes it.
+/// Only the current guardian can call this function, together with the current co-guardian when
+/// one is configured.
+pub fn set_co_guardian_handler(
+    ctx: Context<SetBridgeConfigFromGuardians>,
+    new_co_guardian: Pubkey,
+) -> Result<()> {
+    ctx.accounts.require_co_guardian()?;
+    require_keys_neq!(
+        new_co_guardian,
+        ctx.accounts.bridge.guardian,
+        BridgeError::InvalidCoGuardian
+    );
+
+    let bridge = &mut ctx.accounts.bridge;
+    let previous_co_guardian = bridge.co_guardian;
+    bridge.co_guardian = new_co_guardian;
+
+    emit!(CoGuardianUpdated {
+        previous_co_guardian,
+        new_co_guardian,
+    });
+
+    Ok(())
+}
+
+/// Cancel a pending guardian proposal.
+/// Only the current guardian can call this function.
+pub fn cancel_guardian_transfer_handler(ctx: Context<SetBridgeConfigFromGuardian>) -> Result<()> {
+    let pending_guardian = ctx.accounts.bridge.pending_guardian.take();
+
+    emit!(GuardianTransferCancelled {
+        guardian: ctx.accounts.guardian.key(),
+        pending_guardian,
+    });
 
     Ok(())
 }
@@ -27,12 +115,71 @@ mod tests {
 
     use crate::{
         accounts,
-        common::bridge::Bridge,
-        instruction::TransferGuardian as TransferGuardianIx,
+        instruction::{
+            AcceptGuardian as AcceptGuardianIx, CancelGuardianTransfer as CancelGuardianTransferIx,
+            TransferGuardian as TransferGuardianIx,
+        },
         test_utils::{setup_bridge, SetupBridgeResult},
         ID,
     };
 
+    fn send_transfer_guardian(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        new_guardian: Pubkey,
+    ) -> litesvm::types::TransactionMetadata {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: TransferGuardianIx { new_guardian }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send transfer_guardian transaction")
+    }
+
+    fn send_accept_guardian(
+        svm: &mut litesvm::LiteSVM,
+        signer: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::AcceptGuardian {
+                bridge: bridge_pda,
+                new_guardian: signer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: AcceptGuardianIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[signer],
+            Message::new(&[ix], Some(&signer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    fn fetch_bridge(svm: &litesvm::LiteSVM, bridge_pda: Pubkey) -> Bridge {
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap()
+    }
+
     #[test]
     fn test_transfer_guardian_success() {
         let SetupBridgeResult {
@@ -42,45 +189,94 @@ mod tests {
             ..
         } = setup_bridge();
 
-        // Create a new guardian
         let new_guardian = Keypair::new();
+        svm.airdrop(&new_guardian.pubkey(), 1_000_000_000).unwrap();
 
-        // Build the instruction accounts
-        let accounts = accounts::SetBridgeConfigFromGuardian {
-            bridge: bridge_pda,
-            guardian: guardian.pubkey(),
-        }
-        .to_account_metas(None);
+        let meta = send_transfer_guardian(&mut svm, &guardian, bridge_pda, new_guardian.pubkey());
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
+        // Proposing alone does not hand over the role
+        let bridge_data = fetch_bridge(&svm, bridge_pda);
+        assert_eq!(bridge_data.guardian, guardian.pubkey());
+        assert_eq!(bridge_data.pending_guardian, Some(new_guardian.pubkey()));
+
+        let meta = send_accept_guardian(&mut svm, &new_guardian, bridge_pda)
+            .expect("Pending guardian should be able to accept");
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
+        let bridge_data = fetch_bridge(&svm, bridge_pda);
+        assert_eq!(
+            bridge_data.guardian,
+            new_guardian.pubkey(),
+            "Guardian should be updated to new guardian"
+        );
+        assert_eq!(bridge_data.pending_guardian, None);
+    }
+
+    #[test]
+    fn test_accept_guardian_with_wrong_key_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        send_transfer_guardian(&mut svm, &guardian, bridge_pda, Pubkey::new_unique());
+
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 1_000_000_000).unwrap();
+        let result = send_accept_guardian(&mut svm, &impostor, bridge_pda);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedGuardianAcceptance"),
+            "Expected UnauthorizedGuardianAcceptance error, got: {}",
+            error_string
+        );
+
+        assert_eq!(fetch_bridge(&svm, bridge_pda).guardian, guardian.pubkey());
+    }
+
+    #[test]
+    fn test_cancel_guardian_transfer_clears_pending_guardian() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_guardian = Keypair::new();
+        svm.airdrop(&new_guardian.pubkey(), 1_000_000_000).unwrap();
+        send_transfer_guardian(&mut svm, &guardian, bridge_pda, new_guardian.pubkey());
 
-        // Build the instruction
         let ix = Instruction {
             program_id: ID,
-            accounts,
-            data: TransferGuardianIx {
-                new_guardian: new_guardian.pubkey(),
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
             }
-            .data(),
+            .to_account_metas(None),
+            data: CancelGuardianTransferIx {}.data(),
         };
-
-        // Build and send the transaction
         let tx = Transaction::new(
             &[&guardian],
             Message::new(&[ix], Some(&guardian.pubkey())),
             svm.latest_blockhash(),
         );
-
         svm.send_transaction(tx)
-            .expect("Failed to send transfer_guardian transaction");
+            .expect("Current guardian should be able to cancel the transfer");
 
-        // Verify the guardian was updated
-        let bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        let bridge_data = fetch_bridge(&svm, bridge_pda);
+        assert_eq!(bridge_data.guardian, guardian.pubkey());
+        assert_eq!(bridge_data.pending_guardian, None);
 
-        assert_eq!(
-            bridge_data.guardian,
-            new_guardian.pubkey(),
-            "Guardian should be updated to new guardian"
-        );
+        // The cancelled proposal can no longer be accepted
+        let result = send_accept_guardian(&mut svm, &new_guardian, bridge_pda);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("UnauthorizedGuardianAcceptance"));
     }
 
     #[test]
@@ -99,9 +295,10 @@ mod tests {
         let new_guardian = Keypair::new();
 
         // Build the instruction accounts with fake guardian
-        let accounts = accounts::SetBridgeConfigFromGuardian {
+        let accounts = accounts::SetBridgeConfigFromGuardians {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            co_guardian: None,
         }
         .to_account_metas(None);
 
@@ -1,10 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{
-        bridge::{Bridge, Eip1559},
-        Config, BRIDGE_SEED, DISCRIMINATOR_LEN,
-    },
+    common::{bridge::Bridge, Config, BRIDGE_SEED, DISCRIMINATOR_LEN},
     program::Bridge as BridgeProgram,
     BridgeError,
 };
@@ -64,27 +61,10 @@ pub struct Initialize<'info> {
 /// and records the current timestamp as the window start.
 pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Config) -> Result<()> {
     let current_timestamp = Clock::get()?.unix_timestamp;
-    let minimum_base_fee = cfg.eip1559_config.minimum_base_fee;
 
     cfg.validate()?;
 
-    *ctx.accounts.bridge = Bridge {
-        base_block_number: 0,
-        nonce: 0,
-        guardian,
-        paused: false, // Initialize bridge as unpaused
-        eip1559: Eip1559 {
-            config: cfg.eip1559_config,
-            current_base_fee: minimum_base_fee,
-            current_window_gas_used: 0,
-            window_start_time: current_timestamp,
-        },
-        gas_config: cfg.gas_config,
-        protocol_config: cfg.protocol_config,
-        buffer_config: cfg.buffer_config,
-        partner_oracle_config: cfg.partner_oracle_config,
-        base_oracle_config: cfg.base_oracle_config,
-    };
+    *ctx.accounts.bridge = Bridge::new(guardian, cfg, current_timestamp);
 
     Ok(())
 }
@@ -104,7 +84,10 @@ mod tests {
     use crate::{
         accounts,
         common::{
-            bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
+            bridge::{
+                BufferConfig, Eip1559, Eip1559Config, GasConfig, PartnerOracleConfig,
+                ProtocolConfig, RegistrationBucket, VolumeWindow,
+            },
             BaseOracleConfig,
         },
         instruction::Initialize,
@@ -180,20 +163,33 @@ mod tests {
             bridge,
             Bridge {
                 base_block_number: 0,
+                last_registration_time: 0,
+                last_output_root: [0; 32],
+                registration_bucket: RegistrationBucket::new(0, TEST_TIMESTAMP),
                 nonce: 0,
+                highest_relayed_nonce: None,
+                locked_sol: 0,
+                wrapped_token_count: 0,
+                outbound_sol_volume: VolumeWindow::new(TEST_TIMESTAMP),
+                inbound_sol_volume: VolumeWindow::new(TEST_TIMESTAMP),
                 guardian: guardian_pk,
+                pending_guardian: None,
+                co_guardian: Pubkey::default(),
                 paused: false,
+                paused_ops: 0,
                 eip1559: Eip1559 {
                     config: Eip1559Config::test_new(),
                     current_base_fee: 1,
                     current_window_gas_used: 0,
                     window_start_time: TEST_TIMESTAMP,
+                    empty_window_streak: 0,
                 },
                 gas_config: GasConfig::test_new(gas_fee_receiver),
                 protocol_config: ProtocolConfig::test_new(),
                 buffer_config: BufferConfig::test_new(),
                 partner_oracle_config: PartnerOracleConfig::default(),
                 base_oracle_config: BaseOracleConfig::test_new(),
+                base_oracle_epoch: 0,
             }
         );
     }
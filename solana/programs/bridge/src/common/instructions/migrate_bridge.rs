@@ -0,0 +1,358 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, internal::resize::resize_account, Config, BRIDGE_SEED, DISCRIMINATOR_LEN,
+        MAX_SIGNER_COUNT, SOL_VAULT_SEED,
+    },
+    program::Bridge as BridgeProgram,
+    BridgeError,
+};
+
+/// Layout of the `Bridge` account written by the program before `locked_sol`, the volume windows
+/// and the other runtime fields were added.
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyBridge {
+    base_block_number: u64,
+    nonce: u64,
+    guardian: Pubkey,
+    paused: bool,
+    eip1559: LegacyEip1559,
+    gas_config: LegacyGasConfig,
+    protocol_config: LegacyProtocolConfig,
+    buffer_config: LegacyBufferConfig,
+    partner_oracle_config: LegacyPartnerOracleConfig,
+    base_oracle_config: LegacyBaseOracleConfig,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyEip1559 {
+    target: u64,
+    denominator: u64,
+    window_duration_seconds: u64,
+    minimum_base_fee: u64,
+    current_base_fee: u64,
+    current_window_gas_used: u64,
+    window_start_time: i64,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyGasConfig {
+    gas_cost_scaler: u64,
+    gas_cost_scaler_dp: u64,
+    gas_fee_receiver: Pubkey,
+    gas_per_call: u64,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyProtocolConfig {
+    block_interval_requirement: u64,
+    remote_sol_address: [u8; 20],
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyBufferConfig {
+    max_call_buffer_size: u64,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyPartnerOracleConfig {
+    required_threshold: u8,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+struct LegacyBaseOracleConfig {
+    threshold: u8,
+    signer_count: u8,
+    signers: [[u8; 20]; MAX_SIGNER_COUNT as usize],
+}
+
+/// Accounts for the `migrate_bridge` instruction that rewrites a `Bridge` account created by a
+/// previous version of the program into the current layout.
+/// Only the upgrade authority can migrate the bridge.
+#[derive(Accounts)]
+pub struct MigrateBridge<'info> {
+    /// The upgrade authority that is authorized to migrate the bridge.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for the rent of the grown bridge account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The bridge state account still in the legacy layout.
+    /// CHECK: Deserialized manually from the legacy layout in the handler.
+    #[account(mut, owner = crate::ID, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: UncheckedAccount<'info>,
+
+    /// The SOL vault whose balance seeds `locked_sol`.
+    /// CHECK: This is the SOL vault account.
+    #[account(seeds = [SOL_VAULT_SEED], bump)]
+    pub sol_vault: AccountInfo<'info>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ BridgeError::UnauthorizedConfigUpdate
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The bridge program itself.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ BridgeError::IncorrectBridgeProgram
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for funding the additional rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates a legacy `Bridge` account to the current layout.
+///
+/// The legacy account predates most of the configuration, so `cfg` supplies it in full, as for
+/// `initialize`. The guardian, pause flag, latest registered block, nonce and EIP-1559 runtime
+/// state are carried over. `locked_sol` is seeded from the SOL vault balance, which holds every
+/// lamport bridged out before the upgrade. Every other field starts as on a fresh bridge.
+pub fn migrate_bridge_handler(ctx: Context<MigrateBridge>, cfg: Config) -> Result<()> {
+    cfg.validate()?;
+
+    let bridge_info = ctx.accounts.bridge.to_account_info();
+    let legacy = {
+        let data = bridge_info.try_borrow_data()?;
+        require!(
+            data.len() == DISCRIMINATOR_LEN + LegacyBridge::INIT_SPACE
+                && data.starts_with(Bridge::DISCRIMINATOR),
+            BridgeError::AlreadyMigrated
+        );
+        LegacyBridge::deserialize(&mut &data[DISCRIMINATOR_LEN..])?
+    };
+
+    let mut bridge = Bridge::new(legacy.guardian, cfg, Clock::get()?.unix_timestamp);
+    bridge.base_block_number = legacy.base_block_number;
+    bridge.nonce = legacy.nonce;
+    bridge.paused = legacy.paused;
+    bridge.eip1559.current_base_fee = legacy.eip1559.current_base_fee;
+    bridge.eip1559.current_window_gas_used = legacy.eip1559.current_window_gas_used;
+    bridge.eip1559.window_start_time = legacy.eip1559.window_start_time;
+    bridge.locked_sol = ctx.accounts.sol_vault.lamports();
+
+    resize_account(
+        &bridge_info,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        DISCRIMINATOR_LEN + Bridge::INIT_SPACE,
+    )?;
+    bridge.try_serialize(&mut &mut bridge_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            bpf_loader_upgradeable, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+            system_program,
+        },
+        InstructionData,
+    };
+    use solana_account::Account;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{
+            BaseOracleConfig, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig,
+            ProtocolConfig,
+        },
+        instruction::MigrateBridge as MigrateBridgeIx,
+        test_utils::{setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER},
+        ID,
+    };
+
+    fn test_config() -> Config {
+        Config {
+            eip1559_config: Eip1559Config::test_new(),
+            gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
+            protocol_config: ProtocolConfig::test_new(),
+            buffer_config: BufferConfig::test_new(),
+            partner_oracle_config: PartnerOracleConfig::default(),
+            base_oracle_config: BaseOracleConfig::test_new(),
+        }
+    }
+
+    /// Overwrites the bridge account with a legacy-layout bridge guarded by `guardian`.
+    fn write_legacy_bridge(svm: &mut litesvm::LiteSVM, bridge_pda: Pubkey, guardian: Pubkey) {
+        let legacy = LegacyBridge {
+            base_block_number: 600,
+            nonce: 42,
+            guardian,
+            paused: false,
+            eip1559: LegacyEip1559 {
+                target: 5_000_000,
+                denominator: 2,
+                window_duration_seconds: 1,
+                minimum_base_fee: 1,
+                current_base_fee: 7,
+                current_window_gas_used: 1_000,
+                window_start_time: 1747440000,
+            },
+            gas_config: LegacyGasConfig {
+                gas_cost_scaler: 1_000_000,
+                gas_cost_scaler_dp: 10u64.pow(6),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_per_call: 100_000,
+            },
+            protocol_config: LegacyProtocolConfig {
+                block_interval_requirement: 300,
+                remote_sol_address: [2u8; 20],
+            },
+            buffer_config: LegacyBufferConfig {
+                max_call_buffer_size: 8 * 1024,
+            },
+            partner_oracle_config: LegacyPartnerOracleConfig {
+                required_threshold: 0,
+            },
+            base_oracle_config: LegacyBaseOracleConfig {
+                threshold: 1,
+                signer_count: 1,
+                signers: [[1u8; 20]; MAX_SIGNER_COUNT as usize],
+            },
+        };
+
+        let mut data = Bridge::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), DISCRIMINATOR_LEN + LegacyBridge::INIT_SPACE);
+
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            bridge_pda,
+            Account {
+                lamports,
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    fn migrate_bridge(
+        svm: &mut litesvm::LiteSVM,
+        upgrade_authority: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let (program_data, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateBridge {
+                upgrade_authority: upgrade_authority.pubkey(),
+                payer: upgrade_authority.pubkey(),
+                bridge: bridge_pda,
+                sol_vault,
+                program_data,
+                program: ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: MigrateBridgeIx { cfg: test_config() }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[upgrade_authority],
+            Message::new(&[ix], Some(&upgrade_authority.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        svm.expire_blockhash();
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_bridge_carries_state_and_seeds_locked_sol() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        write_legacy_bridge(&mut svm, bridge_pda, guardian.pubkey());
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        svm.airdrop(&sol_vault, 3 * LAMPORTS_PER_SOL).unwrap();
+
+        migrate_bridge(&mut svm, &payer, bridge_pda).expect("migration should succeed");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        assert_eq!(
+            bridge_account.data.len(),
+            DISCRIMINATOR_LEN + Bridge::INIT_SPACE
+        );
+        assert!(
+            bridge_account.lamports
+                >= svm.minimum_balance_for_rent_exemption(bridge_account.data.len())
+        );
+
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.guardian, guardian.pubkey());
+        assert_eq!(bridge.base_block_number, 600);
+        assert_eq!(bridge.nonce, 42);
+        assert_eq!(bridge.eip1559.current_base_fee, 7);
+        assert_eq!(bridge.eip1559.current_window_gas_used, 1_000);
+        assert_eq!(bridge.locked_sol, 3 * LAMPORTS_PER_SOL);
+        assert_eq!(bridge.protocol_config, ProtocolConfig::test_new());
+    }
+
+    #[test]
+    fn test_migrate_bridge_twice_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        write_legacy_bridge(&mut svm, bridge_pda, guardian.pubkey());
+        migrate_bridge(&mut svm, &payer, bridge_pda).expect("migration should succeed");
+
+        let result = migrate_bridge(&mut svm, &payer, bridge_pda);
+        assert!(result.is_err(), "Expected a second migration to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AlreadyMigrated"),
+            "Expected AlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_bridge_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        write_legacy_bridge(&mut svm, bridge_pda, guardian.pubkey());
+
+        let result = migrate_bridge(&mut svm, &guardian, bridge_pda);
+        assert!(result.is_err(), "Expected the guardian to be rejected");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
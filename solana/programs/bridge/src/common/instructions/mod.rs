@@ -1,7 +1,26 @@
 pub mod initialize;
 pub use initialize::*;
 
+pub mod migrate_bridge;
+pub use migrate_bridge::*;
+
+pub mod get_total_locked_sol;
+pub use get_total_locked_sol::*;
+
+pub mod get_base_fee;
+pub use get_base_fee::*;
+
+pub mod get_fee_bounds;
+pub use get_fee_bounds::*;
+
+pub mod get_program_authorities;
+pub use get_program_authorities::*;
+
+pub mod freeze_wrapped_account;
+pub use freeze_wrapped_account::*;
+
 pub mod guardian;
+pub use guardian::*;
 
 pub mod config;
 pub use config::*;
@@ -1,4 +1,9 @@
-use crate::{common::WRAPPED_TOKEN_SEED, BridgeError, ID};
+use crate::{
+    common::{
+        MAX_REMOTE_TOKEN_DECIMALS, MAX_TOKEN_NAME_LEN, MAX_TOKEN_SYMBOL_LEN, WRAPPED_TOKEN_SEED,
+    },
+    BridgeError, ID,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
 use anchor_spl::{
@@ -145,6 +150,30 @@ impl TryFrom<&AccountInfo<'_>> for PartialTokenMetadata {
 }
 
 impl PartialTokenMetadata {
+    /// Validates the metadata for a wrapped mint with the given local `decimals`.
+    ///
+    /// The remote token precision (`decimals + scaler_exponent`) must not exceed
+    /// `MAX_REMOTE_TOKEN_DECIMALS`, otherwise scaled amounts would overflow on Base.
+    /// `name` and `symbol` are bounded by `MAX_TOKEN_NAME_LEN` and `MAX_TOKEN_SYMBOL_LEN` bytes
+    /// to keep the mint's metadata extension (and its rent) small.
+    pub fn validate(&self, decimals: u8) -> Result<()> {
+        require!(
+            self.name.len() <= MAX_TOKEN_NAME_LEN as usize
+                && self.symbol.len() <= MAX_TOKEN_SYMBOL_LEN as usize,
+            BridgeError::MetadataTooLong
+        );
+
+        let remote_decimals = decimals
+            .checked_add(self.scaler_exponent)
+            .ok_or(BridgeError::InvalidScalerExponent)?;
+        require!(
+            remote_decimals <= MAX_REMOTE_TOKEN_DECIMALS,
+            BridgeError::InvalidScalerExponent
+        );
+
+        Ok(())
+    }
+
     /// Computes a keccak256 hash of the metadata fields as:
     /// `keccak(len(name) || name || len(symbol) || symbol || remote_token || scaler_exponent_le)`,
     /// where `scaler_exponent_le` is the little-endian byte representation.
@@ -177,3 +206,54 @@ fn mint_info_to_token_metadata(mint: &AccountInfo<'_>) -> Result<(TokenMetadata,
     let decimals = mint_with_extension.base.decimals;
     Ok((token_metadata, decimals))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partial_token_metadata(scaler_exponent: u8) -> PartialTokenMetadata {
+        PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent,
+        }
+    }
+
+    #[test]
+    fn test_validate_accepts_scaler_exponent_within_range() {
+        assert!(partial_token_metadata(9).validate(9).is_ok());
+        assert!(partial_token_metadata(0).validate(6).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_excessive_scaler_exponent() {
+        assert!(partial_token_metadata(10).validate(9).is_err());
+        assert!(partial_token_metadata(u8::MAX).validate(9).is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_name_and_symbol_at_max_length() {
+        let mut metadata = partial_token_metadata(0);
+        metadata.name = "N".repeat(MAX_TOKEN_NAME_LEN as usize);
+        metadata.symbol = "S".repeat(MAX_TOKEN_SYMBOL_LEN as usize);
+        assert!(metadata.validate(9).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_name_or_symbol_over_max_length() {
+        let mut metadata = partial_token_metadata(0);
+        metadata.name = "N".repeat(MAX_TOKEN_NAME_LEN as usize + 1);
+        assert_eq!(
+            metadata.validate(9).unwrap_err(),
+            BridgeError::MetadataTooLong.into()
+        );
+
+        let mut metadata = partial_token_metadata(0);
+        metadata.symbol = "S".repeat(MAX_TOKEN_SYMBOL_LEN as usize + 1);
+        assert_eq!(
+            metadata.validate(9).unwrap_err(),
+            BridgeError::MetadataTooLong.into()
+        );
+    }
+}
@@ -1,6 +1,7 @@
 pub mod init_config;
 pub mod math;
 pub mod metadata;
+pub mod resize;
 
 pub use init_config::*;
 pub use metadata::*;
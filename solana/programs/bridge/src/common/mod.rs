@@ -1,9 +1,11 @@
 pub mod constants;
+pub mod events;
 pub mod instructions;
 pub mod internal;
 pub mod state;
 
 pub use constants::*;
+pub use events::*;
 pub use instructions::*;
 pub use internal::*;
 pub use state::*;
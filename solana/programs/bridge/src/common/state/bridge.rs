@@ -2,21 +2,47 @@ use anchor_lang::prelude::*;
 
 use crate::common::{
     internal::math::{fixed_pow, SCALE},
+    Config, DAILY_VOLUME_WINDOW_SECONDS, GAS_MULTIPLIER_BPS_DENOMINATOR, MAX_GAS_MULTIPLIER_BPS,
     MAX_PARTNER_VALIDATOR_THRESHOLD, MAX_SIGNER_COUNT,
 };
-use crate::BridgeError;
+use crate::{solana_to_base::CallType, BridgeError};
 
 #[account]
 #[derive(Debug, PartialEq, Eq, InitSpace)]
 pub struct Bridge {
     /// The Base block number associated with the latest registered output root.
     pub base_block_number: u64,
+    /// Unix timestamp of the latest output root registration.
+    pub last_registration_time: i64,
+    /// Output root of the latest registration.
+    pub last_output_root: [u8; 32],
+    /// Leaky bucket limiting the rate of output root registrations.
+    pub registration_bucket: RegistrationBucket,
     /// Incremental nonce assigned to each outgoing message.
     pub nonce: u64,
+    /// Highest Base nonce among the incoming messages relayed so far (`None` before the first
+    /// relay).
+    pub highest_relayed_nonce: Option<u64>,
+    /// Lamports locked in the SOL vault by `bridge_sol` and not yet released by a relayed transfer.
+    pub locked_sol: u64,
+    /// Number of wrapped mints created by `wrap_token`.
+    pub wrapped_token_count: u64,
+    /// Volume of SOL bridged from Solana to Base in the current fixed daily window.
+    pub outbound_sol_volume: VolumeWindow,
+    /// Volume of SOL finalized from Base to Solana in the current fixed daily window.
+    pub inbound_sol_volume: VolumeWindow,
     /// Guardian pubkey authorized to update bridge configuration parameters
     pub guardian: Pubkey,
-    /// Whether the bridge is paused (emergency stop mechanism)
+    /// Guardian proposed by the current guardian, pending acceptance
+    pub pending_guardian: Option<Pubkey>,
+    /// Second guardian that must co-sign critical configuration changes (the default pubkey
+    /// leaves those changes to the guardian alone)
+    pub co_guardian: Pubkey,
+    /// Whether the bridge is paused (emergency stop mechanism). Acts as a master switch over
+    /// `paused_ops`.
     pub paused: bool,
+    /// Bitmask of individually paused operations (`PAUSE_*` flags)
+    pub paused_ops: u16,
     /// EIP-1559 state and configuration for dynamic pricing.
     pub eip1559: Eip1559,
     /// Configuration parameters for outgoing message pricing
@@ -29,6 +55,92 @@ pub struct Bridge {
     pub partner_oracle_config: PartnerOracleConfig,
     /// Configuration parameters for Base oracle signers
     pub base_oracle_config: BaseOracleConfig,
+    /// Incremented on every change to `base_oracle_config`. Output root re-validations sign it,
+    /// so approvals given to one signer set cannot be replayed under another.
+    pub base_oracle_epoch: u64,
+}
+
+impl Bridge {
+    /// Builds the state of a freshly initialized bridge: the given guardian and configs, unpaused,
+    /// zeroed counters, the EIP-1559 base fee at `eip1559_config.minimum_base_fee` and every
+    /// window starting at `current_timestamp`.
+    pub fn new(guardian: Pubkey, cfg: Config, current_timestamp: i64) -> Self {
+        let minimum_base_fee = cfg.eip1559_config.minimum_base_fee;
+
+        Self {
+            base_block_number: 0,
+            last_registration_time: 0,
+            last_output_root: [0; 32],
+            registration_bucket: RegistrationBucket::new(
+                cfg.protocol_config.registration_bucket_capacity,
+                current_timestamp,
+            ),
+            nonce: 0,
+            highest_relayed_nonce: None,
+            locked_sol: 0,
+            wrapped_token_count: 0,
+            outbound_sol_volume: VolumeWindow::new(current_timestamp),
+            inbound_sol_volume: VolumeWindow::new(current_timestamp),
+            guardian,
+            pending_guardian: None,
+            co_guardian: Pubkey::default(),
+            paused: false, // Initialize bridge as unpaused
+            paused_ops: 0,
+            eip1559: Eip1559 {
+                config: cfg.eip1559_config,
+                current_base_fee: minimum_base_fee,
+                current_window_gas_used: 0,
+                window_start_time: current_timestamp,
+                empty_window_streak: 0,
+            },
+            gas_config: cfg.gas_config,
+            protocol_config: cfg.protocol_config,
+            buffer_config: cfg.buffer_config,
+            partner_oracle_config: cfg.partner_oracle_config,
+            base_oracle_config: cfg.base_oracle_config,
+            base_oracle_epoch: 0,
+        }
+    }
+
+    /// Fails with `BridgePaused` if the whole bridge is paused or if any of the `ops` flags are.
+    pub fn require_not_paused(&self, ops: u16) -> Result<()> {
+        require!(
+            !self.paused && self.paused_ops & ops == 0,
+            BridgeError::BridgePaused
+        );
+
+        Ok(())
+    }
+
+    /// Fails with `CoGuardianSignatureRequired` unless `co_guardian` is the configured
+    /// co-guardian. Passes when no co-guardian is configured.
+    pub fn require_co_guardian(&self, co_guardian: Option<Pubkey>) -> Result<()> {
+        require!(
+            self.co_guardian == Pubkey::default() || co_guardian == Some(self.co_guardian),
+            BridgeError::CoGuardianSignatureRequired
+        );
+
+        Ok(())
+    }
+
+    /// Records the relay of the incoming message with Base nonce `nonce`, raising
+    /// `highest_relayed_nonce` if needed.
+    ///
+    /// When `protocol_config.enforce_relay_nonce_order` is set, a nonce that is not above the
+    /// highest one relayed so far fails with `RelayNonceOutOfOrder`.
+    pub fn record_relayed_nonce(&mut self, nonce: u64) -> Result<()> {
+        if let Some(highest_relayed_nonce) = self.highest_relayed_nonce {
+            require!(
+                !self.protocol_config.enforce_relay_nonce_order || nonce > highest_relayed_nonce,
+                BridgeError::RelayNonceOutOfOrder
+            );
+            self.highest_relayed_nonce = Some(highest_relayed_nonce.max(nonce));
+        } else {
+            self.highest_relayed_nonce = Some(nonce);
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -42,6 +154,8 @@ pub struct Eip1559 {
     pub current_window_gas_used: u64,
     /// Unix timestamp when the current window started (runtime state)
     pub window_start_time: i64,
+    /// Number of consecutive expired windows without gas usage (runtime state)
+    pub empty_window_streak: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -56,6 +170,9 @@ pub struct Eip1559Config {
     /// and as an underflow clamp during decreases; not enforced as a strict lower bound
     /// on every step.
     pub minimum_base_fee: u64,
+    /// Number of consecutive empty windows during which the base fee holds before it starts
+    /// to decay (0 decays from the first empty window)
+    pub decay_grace_windows: u64,
 }
 
 impl Eip1559Config {
@@ -72,15 +189,38 @@ impl Eip1559Config {
 impl Eip1559 {
     /// Refresh the base fee if window has expired, reset window tracking
     /// Handles multiple expired windows by processing each empty window
+    ///
+    /// The first `decay_grace_windows` empty windows in a row leave the base fee unchanged;
+    /// only the empty windows after them decay it.
     pub fn refresh_base_fee(&mut self, current_timestamp: i64) -> u64 {
         let expired_windows_count = self.expired_windows_count(current_timestamp);
         if expired_windows_count == 0 {
             return self.current_base_fee;
         }
 
+        let grace_windows = self.config.decay_grace_windows;
+
         // Process the first window with actual gas usage
-        let mut current_base_fee = self.calc_base_fee(self.current_window_gas_used);
-        let remaining_windows_count = expired_windows_count - 1;
+        let mut current_base_fee = if self.current_window_gas_used > 0 {
+            self.empty_window_streak = 0;
+            self.calc_base_fee(self.current_window_gas_used)
+        } else {
+            self.empty_window_streak = self.empty_window_streak.saturating_add(1);
+            if self.empty_window_streak > grace_windows {
+                self.calc_base_fee(0)
+            } else {
+                self.current_base_fee
+            }
+        };
+
+        // Skip the empty windows still covered by the grace period
+        let expired_empty_windows_count = expired_windows_count - 1;
+        let graced_windows_count =
+            expired_empty_windows_count.min(grace_windows.saturating_sub(self.empty_window_streak));
+        self.empty_window_streak = self
+            .empty_window_streak
+            .saturating_add(expired_empty_windows_count);
+        let remaining_windows_count = expired_empty_windows_count - graced_windows_count;
 
         // Process the remaining empty windows (if any)
         //
@@ -119,6 +259,27 @@ impl Eip1559 {
         self.current_base_fee
     }
 
+    /// Settle the fee state at `current_timestamp` so that new configuration applies from a
+    /// clean window
+    ///
+    /// Expired windows are processed with `refresh_base_fee`. Gas already used in the window in
+    /// progress is then priced under the current configuration as if that window ended now, and
+    /// a new window starts at `current_timestamp`.
+    pub fn settle(&mut self, current_timestamp: i64) -> u64 {
+        self.refresh_base_fee(current_timestamp);
+
+        if self.current_window_gas_used > 0 {
+            self.current_base_fee = self
+                .calc_base_fee(self.current_window_gas_used)
+                .max(self.config.minimum_base_fee);
+            self.empty_window_streak = 0;
+        }
+        self.current_window_gas_used = 0;
+        self.window_start_time = current_timestamp;
+
+        self.current_base_fee
+    }
+
     /// Add gas usage to current window
     pub fn add_gas_usage(&mut self, gas_amount: u64) {
         self.current_window_gas_used += gas_amount;
@@ -170,6 +331,14 @@ pub struct GasConfig {
     pub gas_fee_receiver: Pubkey,
     /// Amount of gas per Solana --> Base message
     pub gas_per_call: u64,
+    /// Minimum fee in lamports charged per message, applied after scaling
+    pub minimum_fee_lamports: u64,
+    /// Maximum fee in lamports charged per message, applied after scaling (0 = no ceiling)
+    pub maximum_fee_lamports: u64,
+    /// Fee multiplier in basis points for each `CallType` (indexed by variant), applied to messages
+    /// carrying a call. 10_000 charges the base fee unchanged. Each multiplier must be in
+    /// `1..=MAX_GAS_MULTIPLIER_BPS`.
+    pub call_type_gas_multipliers_bps: [u32; 4],
 }
 
 impl GasConfig {
@@ -178,8 +347,47 @@ impl GasConfig {
             self.gas_cost_scaler_dp > 0,
             BridgeError::InvalidGasCostScalerDp
         );
+        require!(
+            self.maximum_fee_lamports == 0
+                || self.maximum_fee_lamports >= self.minimum_fee_lamports,
+            BridgeError::InvalidFeeBounds
+        );
+        require!(
+            self.call_type_gas_multipliers_bps
+                .iter()
+                .all(|&bps| (1..=MAX_GAS_MULTIPLIER_BPS).contains(&bps)),
+            BridgeError::InvalidGasMultiplier
+        );
         Ok(())
     }
+
+    /// Lamports charged for one message at the given `base_fee`.
+    ///
+    /// Messages carrying a call of type `call_ty` are adjusted by that type's multiplier. The
+    /// result is floored at `minimum_fee_lamports` so that integer division with a small scaler
+    /// cannot round the fee down to zero, and capped at `maximum_fee_lamports` when one is set.
+    ///
+    /// Intermediate products are computed in `u128`; a cost that does not fit back into `u64`
+    /// fails with `GasCostOverflow`.
+    pub fn gas_cost(&self, base_fee: u64, call_ty: Option<CallType>) -> Result<u64> {
+        let mut gas_cost = (self.gas_per_call as u128)
+            .checked_mul(base_fee as u128)
+            .and_then(|cost| cost.checked_mul(self.gas_cost_scaler as u128))
+            .ok_or(BridgeError::GasCostOverflow)?
+            / self.gas_cost_scaler_dp as u128;
+        if let Some(call_ty) = call_ty {
+            gas_cost = gas_cost
+                .checked_mul(self.call_type_gas_multipliers_bps[call_ty as usize] as u128)
+                .ok_or(BridgeError::GasCostOverflow)?
+                / GAS_MULTIPLIER_BPS_DENOMINATOR as u128;
+        }
+        let gas_cost = u64::try_from(gas_cost).map_err(|_| BridgeError::GasCostOverflow)?;
+        let gas_cost = gas_cost.max(self.minimum_fee_lamports);
+        Ok(match self.maximum_fee_lamports {
+            0 => gas_cost,
+            maximum_fee_lamports => gas_cost.min(maximum_fee_lamports),
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -190,6 +398,66 @@ pub struct ProtocolConfig {
 
     /// The Base evm address of SOL
     pub remote_sol_address: [u8; 20],
+
+    /// Minimum number of seconds between two output root registrations (0 disables the check)
+    pub min_registration_interval_seconds: u64,
+
+    /// Maximum age in seconds of an output root that messages can be proven against (0 disables the check)
+    pub max_root_age_seconds: u64,
+
+    /// Maximum number of output root registrations that can be made in a burst (0 disables the limit)
+    pub registration_bucket_capacity: u64,
+
+    /// Number of seconds after which one registration is returned to the bucket
+    pub registration_refill_interval_seconds: u64,
+
+    /// Whether registering the same output root as the previous registration is rejected
+    /// (otherwise it is only flagged with a `DuplicateRootRegistered` event)
+    pub reject_duplicate_roots: bool,
+
+    /// Whether a registration is rejected when a signer counts toward both the Base and partner
+    /// thresholds (otherwise the overlap is only flagged with an `OverlappingSignersApproved`
+    /// event)
+    pub disjoint_signer_sets: bool,
+
+    /// Whether relaying a message that contains two identical instructions is rejected
+    pub reject_duplicate_ixs: bool,
+
+    /// Whether relaying a message with a Base nonce at or below `highest_relayed_nonce` is
+    /// rejected
+    pub enforce_relay_nonce_order: bool,
+
+    /// Whether `bridge_call` and `bridge_call_buffered` require `from` to also be the payer,
+    /// disallowing third-party sponsors
+    pub require_self_call: bool,
+
+    /// Maximum number of wrapped mints that `wrap_token` can create (0 disables the limit)
+    pub max_wrapped_tokens: u64,
+
+    /// SOL finalizations above this many lamports must be co-signed by the guardian (0 disables
+    /// the check). Token transfers are not covered since their amounts have no common unit.
+    pub large_finalize_threshold: u64,
+
+    /// Maximum lamports bridged from Solana to Base per fixed daily window (0 disables the limit).
+    /// Windows do not overlap, so up to twice the limit can pass around a window boundary.
+    pub daily_outbound_sol_limit: u64,
+
+    /// Maximum lamports finalized from Base to Solana per fixed daily window (0 disables the
+    /// limit). Windows do not overlap, so up to twice the limit can pass around a window boundary.
+    pub daily_inbound_sol_limit: u64,
+
+    /// Output roots more than this many Base blocks behind the latest registered block must be
+    /// re-validated by the current oracle set before messages can be proven against them
+    /// (0 disables the check)
+    pub revalidate_old_roots_after_blocks: u64,
+
+    /// Maximum number of Base blocks a registered output root may advance past the latest
+    /// registered one (0 disables the check)
+    pub max_block_advance: u64,
+
+    /// Minimum number of seconds between a message being proven and it being relayed (0 disables
+    /// the check)
+    pub min_prove_to_relay_delay_seconds: u64,
 }
 
 impl ProtocolConfig {
@@ -208,6 +476,117 @@ impl ProtocolConfig {
             self.remote_sol_address != [0u8; 20],
             BridgeError::ZeroAddress
         );
+
+        require!(
+            self.registration_bucket_capacity == 0 || self.registration_refill_interval_seconds > 0,
+            BridgeError::InvalidRegistrationRateLimit
+        );
+        Ok(())
+    }
+}
+
+/// Leaky bucket state for output root registrations.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RegistrationBucket {
+    /// Registrations currently available
+    pub tokens: u64,
+    /// Unix timestamp from which the next refill is measured
+    pub last_refill_time: i64,
+}
+
+impl RegistrationBucket {
+    /// Creates a full bucket.
+    pub fn new(capacity: u64, current_timestamp: i64) -> Self {
+        Self {
+            tokens: capacity,
+            last_refill_time: current_timestamp,
+        }
+    }
+
+    /// Refills the bucket for the time elapsed since the last refill and takes `count` tokens.
+    /// Returns `false`, taking nothing, when fewer than `count` tokens are available. A `capacity`
+    /// of 0 disables the limit.
+    pub fn try_consume(
+        &mut self,
+        count: u64,
+        capacity: u64,
+        refill_interval_seconds: u64,
+        current_timestamp: i64,
+    ) -> bool {
+        if capacity == 0 {
+            return true;
+        }
+
+        let elapsed = current_timestamp
+            .saturating_sub(self.last_refill_time)
+            .max(0) as u64;
+        let refills = elapsed.checked_div(refill_interval_seconds).unwrap_or(0);
+        if refills > 0 {
+            self.tokens = self.tokens.saturating_add(refills).min(capacity);
+            self.last_refill_time = self
+                .last_refill_time
+                .saturating_add(refills.saturating_mul(refill_interval_seconds) as i64);
+        }
+
+        // A full bucket does not bank time towards future refills
+        if self.tokens >= capacity {
+            self.tokens = capacity;
+            self.last_refill_time = current_timestamp;
+        }
+
+        if self.tokens < count {
+            return false;
+        }
+        self.tokens -= count;
+        true
+    }
+}
+
+/// Volume accumulated over a fixed (tumbling) daily window.
+///
+/// A window starts with the first addition after the previous one elapsed and is reset as a
+/// whole, rather than sliding with time. The limit therefore bounds each window, not every
+/// 24-hour span: volume added at the end of one window and at the start of the next can reach
+/// twice the limit within a few seconds.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct VolumeWindow {
+    /// Amount accumulated in the current window
+    pub volume: u64,
+    /// Unix timestamp when the current window started
+    pub window_start_time: i64,
+}
+
+impl VolumeWindow {
+    /// Creates an empty window starting at `current_timestamp`.
+    pub fn new(current_timestamp: i64) -> Self {
+        Self {
+            volume: 0,
+            window_start_time: current_timestamp,
+        }
+    }
+
+    /// Starts a new window if the current one has elapsed, then adds `amount` to it.
+    /// Fails with `DailyLimitExceeded` if the window would exceed `limit`. A `limit` of 0
+    /// disables the check.
+    pub fn add(&mut self, amount: u64, limit: u64, current_timestamp: i64) -> Result<()> {
+        if limit == 0 {
+            return Ok(());
+        }
+
+        let elapsed = current_timestamp
+            .saturating_sub(self.window_start_time)
+            .max(0) as u64;
+        if elapsed >= DAILY_VOLUME_WINDOW_SECONDS {
+            *self = Self::new(current_timestamp);
+        }
+
+        let volume = self
+            .volume
+            .checked_add(amount)
+            .filter(|volume| *volume <= limit)
+            .ok_or(BridgeError::DailyLimitExceeded)?;
+        self.volume = volume;
+
         Ok(())
     }
 }
@@ -216,6 +595,13 @@ impl ProtocolConfig {
 pub struct BufferConfig {
     /// Maximum call buffer size. This caps the max size of a Solana → Base message.
     pub max_call_buffer_size: u64,
+
+    /// Maximum number of MMR proof nodes a prove buffer can be allocated for.
+    pub max_prove_buffer_proof_len: u64,
+
+    /// Maximum number of appends to a single call buffer (0 disables the limit). Captured by
+    /// each call buffer at initialization.
+    pub max_call_buffer_appends: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
@@ -236,24 +622,26 @@ impl PartnerOracleConfig {
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
 pub struct BaseOracleConfig {
-    /// Number of required valid unique signatures
+    /// Required approval weight of valid unique signatures
     pub threshold: u8,
     /// Number of signers in `signers` array
     pub signer_count: u8,
     /// Static list of authorized signer addresses
     pub signers: [[u8; 20]; MAX_SIGNER_COUNT as usize],
+    /// Approval weight of each signer in `signers` (a weight of 0 counts as 1)
+    pub weights: [u8; MAX_SIGNER_COUNT as usize],
 }
 
 impl BaseOracleConfig {
     pub fn validate(&self) -> Result<()> {
-        require!(
-            self.threshold > 0 && self.threshold <= self.signer_count,
-            BridgeError::InvalidThreshold
-        );
         require!(
             self.signer_count as usize <= self.signers.len(),
             BridgeError::TooManySigners
         );
+        require!(
+            self.threshold > 0 && self.threshold as u32 <= self.total_weight(),
+            BridgeError::InvalidThreshold
+        );
 
         // Ensure uniqueness among the provided signer_count entries
         {
@@ -268,25 +656,167 @@ impl BaseOracleConfig {
     }
 
     pub fn contains(&self, evm_addr: &[u8; 20]) -> bool {
-        let active_len = core::cmp::min(self.signer_count as usize, self.signers.len());
-        self.signers[..active_len].iter().any(|s| s == evm_addr)
+        self.position(evm_addr).is_some()
+    }
+
+    /// Appends `evm_addr` as a signer with unit weight.
+    pub fn add_signer(&mut self, evm_addr: [u8; 20]) -> Result<()> {
+        require!(!self.contains(&evm_addr), BridgeError::DuplicateSigner);
+        let index = self.active_len();
+        require!(index < self.signers.len(), BridgeError::TooManySigners);
+
+        self.signers[index] = evm_addr;
+        self.weights[index] = 1;
+        self.signer_count += 1;
+        Ok(())
+    }
+
+    /// Removes `evm_addr`, shifting the following signers (and their weights) down one slot.
+    /// Fails if the remaining signers could no longer meet the threshold.
+    pub fn remove_signer(&mut self, evm_addr: &[u8; 20]) -> Result<()> {
+        let index = self.position(evm_addr).ok_or(BridgeError::SignerNotFound)?;
+        let len = self.active_len();
+
+        self.signers.copy_within(index + 1..len, index);
+        self.weights.copy_within(index + 1..len, index);
+        self.signers[len - 1] = [0u8; 20];
+        self.weights[len - 1] = 0;
+        self.signer_count -= 1;
+
+        self.validate()
     }
 
+    /// Sums the weights of the given signers that are part of this config.
+    /// `signers` is expected to be deduplicated by the caller.
     pub fn count_approvals(&self, signers: &[[u8; 20]]) -> u32 {
         let mut count: u32 = 0;
         for signer in signers.iter() {
-            if self.contains(signer) {
-                count += 1;
+            if let Some(index) = self.position(signer) {
+                count += self.weight_at(index);
             }
         }
         count
     }
+
+    /// Sums the weights of all active signers.
+    pub fn total_weight(&self) -> u32 {
+        (0..self.active_len()).map(|i| self.weight_at(i)).sum()
+    }
+
+    /// The configured signers, excluding unused slots.
+    pub fn active_signers(&self) -> &[[u8; 20]] {
+        &self.signers[..self.active_len()]
+    }
+
+    fn active_len(&self) -> usize {
+        core::cmp::min(self.signer_count as usize, self.signers.len())
+    }
+
+    fn position(&self, evm_addr: &[u8; 20]) -> Option<usize> {
+        self.active_signers().iter().position(|s| s == evm_addr)
+    }
+
+    fn weight_at(&self, index: usize) -> u32 {
+        core::cmp::max(self.weights[index], 1) as u32
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn base_oracle_config(threshold: u8, weights: &[u8]) -> BaseOracleConfig {
+        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        let mut signer_weights = [0u8; MAX_SIGNER_COUNT as usize];
+        for (i, weight) in weights.iter().enumerate() {
+            signers[i] = [(i + 1) as u8; 20];
+            signer_weights[i] = *weight;
+        }
+
+        BaseOracleConfig {
+            threshold,
+            signer_count: weights.len() as u8,
+            signers,
+            weights: signer_weights,
+        }
+    }
+
+    #[test]
+    fn test_count_approvals_defaults_to_unit_weight() {
+        let config = base_oracle_config(2, &[0, 0, 0]);
+
+        assert_eq!(config.count_approvals(&[[1u8; 20], [3u8; 20]]), 2);
+        assert_eq!(config.total_weight(), 3);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_count_approvals_sums_weights() {
+        let config = base_oracle_config(4, &[3, 1, 1]);
+
+        // A single heavy signer plus one regular signer meets the threshold
+        assert_eq!(config.count_approvals(&[[1u8; 20], [2u8; 20]]), 4);
+        // Regular signers alone do not
+        assert_eq!(config.count_approvals(&[[2u8; 20], [3u8; 20]]), 2);
+        // Unknown signers are ignored
+        assert_eq!(config.count_approvals(&[[9u8; 20]]), 0);
+    }
+
+    #[test]
+    fn test_add_signer_appends_with_unit_weight() {
+        let mut config = base_oracle_config(1, &[3]);
+
+        config.add_signer([9u8; 20]).unwrap();
+        assert_eq!(config.signer_count, 2);
+        assert_eq!(config.active_signers(), &[[1u8; 20], [9u8; 20]]);
+        assert_eq!(config.total_weight(), 4);
+
+        assert_eq!(
+            config.add_signer([9u8; 20]).unwrap_err(),
+            BridgeError::DuplicateSigner.into()
+        );
+    }
+
+    #[test]
+    fn test_add_signer_fails_when_full() {
+        let mut config = base_oracle_config(1, &[1; MAX_SIGNER_COUNT as usize]);
+        assert_eq!(
+            config.add_signer([0xFFu8; 20]).unwrap_err(),
+            BridgeError::TooManySigners.into()
+        );
+    }
+
+    #[test]
+    fn test_remove_signer_shifts_remaining_signers() {
+        let mut config = base_oracle_config(2, &[1, 2, 3]);
+
+        config.remove_signer(&[1u8; 20]).unwrap();
+        assert_eq!(config.signer_count, 2);
+        assert_eq!(config.active_signers(), &[[2u8; 20], [3u8; 20]]);
+        assert_eq!(config.count_approvals(&[[3u8; 20]]), 3);
+        assert_eq!(config.signers[2], [0u8; 20]);
+
+        assert_eq!(
+            config.remove_signer(&[1u8; 20]).unwrap_err(),
+            BridgeError::SignerNotFound.into()
+        );
+    }
+
+    #[test]
+    fn test_remove_signer_below_threshold_fails() {
+        let mut config = base_oracle_config(2, &[1, 1]);
+        assert_eq!(
+            config.remove_signer(&[2u8; 20]).unwrap_err(),
+            BridgeError::InvalidThreshold.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_threshold_against_total_weight() {
+        assert!(base_oracle_config(5, &[3, 1, 1]).validate().is_ok());
+        assert!(base_oracle_config(6, &[3, 1, 1]).validate().is_err());
+    }
+
     #[test]
     fn test_new_state_creation() {
         let timestamp = 1234567890;
@@ -295,6 +825,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: timestamp,
+            empty_window_streak: 0,
         };
 
         assert_eq!(state.config, Eip1559Config::test_new());
@@ -310,6 +841,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 5_000_000,
             window_start_time: 0,
+            empty_window_streak: 0,
         };
         let gas_used = state.config.target; // Exactly at target
 
@@ -324,6 +856,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: 0,
+            empty_window_streak: 0,
         };
         let gas_used = state.config.target + 3_000_000; // 3M above target (5M)
 
@@ -341,6 +874,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: 0,
+            empty_window_streak: 0,
         };
         let gas_used = state.config.target - 3_000_000; // 3M below target (5M)
 
@@ -358,6 +892,7 @@ mod tests {
             current_base_fee: 10_000_000, // Large base fee to amplify small changes
             current_window_gas_used: 0,
             window_start_time: 0,
+            empty_window_streak: 0,
         };
         let gas_used = state.config.target + 1; // Just 1 gas above target
 
@@ -376,10 +911,12 @@ mod tests {
                 denominator: 2,
                 window_duration_seconds: 1,
                 minimum_base_fee: 1,
+                decay_grace_windows: 0,
             },
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: start_time,
+            empty_window_streak: 0,
         };
 
         // Window should not be expired at start time
@@ -405,6 +942,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: 0,
+            empty_window_streak: 0,
         };
         assert_eq!(state.current_window_gas_used, 0);
 
@@ -422,6 +960,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: 1000,
+            empty_window_streak: 0,
         };
         let original_base_fee = state.current_base_fee;
         state.add_gas_usage(2_000_000);
@@ -442,6 +981,7 @@ mod tests {
             current_base_fee: 1000,
             current_window_gas_used: 0,
             window_start_time: 1000,
+            empty_window_streak: 0,
         };
         state.add_gas_usage(8_000_000); // Above target, should increase fee
 
@@ -455,6 +995,63 @@ mod tests {
         assert_eq!(state.window_start_time, new_time);
     }
 
+    #[test]
+    fn test_settle_prices_window_under_old_target_before_change() {
+        let new_state = || {
+            let mut state = Eip1559 {
+                config: Eip1559Config::test_new(),
+                current_base_fee: 1000,
+                current_window_gas_used: 0,
+                window_start_time: 1000,
+                empty_window_streak: 0,
+            };
+            state.add_gas_usage(2 * state.config.target);
+            state
+        };
+        let window_end = 1000 + Eip1559Config::test_new().window_duration_seconds as i64;
+
+        let mut expected = new_state();
+        let expected_base_fee = expected.refresh_base_fee(window_end);
+        assert!(expected_base_fee > 1000);
+
+        // Raising the target in place would price the settled window against the new target
+        let mut unsettled = new_state();
+        unsettled.config.target *= 2;
+        assert_eq!(unsettled.refresh_base_fee(window_end), 1000);
+
+        let mut settled = new_state();
+        assert_eq!(settled.settle(window_end), expected_base_fee);
+        settled.config.target *= 2;
+        assert_eq!(settled.current_window_gas_used, 0);
+        assert_eq!(settled.window_start_time, window_end);
+
+        // Usage at the new target keeps the fee where the old configuration left it
+        settled.add_gas_usage(settled.config.target);
+        let next_window_end = window_end + settled.config.window_duration_seconds as i64;
+        assert_eq!(settled.refresh_base_fee(next_window_end), expected_base_fee);
+    }
+
+    #[test]
+    fn test_settle_closes_window_in_progress() {
+        let mut state = Eip1559 {
+            config: Eip1559Config::test_new(),
+            current_base_fee: 1000,
+            current_window_gas_used: 0,
+            window_start_time: 1000,
+            empty_window_streak: 0,
+        };
+        let expected_base_fee = state.calc_base_fee(2 * state.config.target);
+        state.add_gas_usage(2 * state.config.target);
+
+        // Still inside the first window
+        assert_eq!(state.settle(1000), expected_base_fee);
+        assert_eq!(state.current_window_gas_used, 0);
+        assert_eq!(state.window_start_time, 1000);
+
+        // An empty window in progress leaves the fee unchanged
+        assert_eq!(state.settle(1000), expected_base_fee);
+    }
+
     #[test]
     fn test_refresh_base_fee_multiple_empty_windows() {
         let mut state = Eip1559 {
@@ -462,6 +1059,7 @@ mod tests {
             current_base_fee: 8000, // High base fee
             current_window_gas_used: 0,
             window_start_time: 1000,
+            empty_window_streak: 0,
         };
         state.add_gas_usage(10_000_000); // High usage in first window
 
@@ -479,4 +1077,249 @@ mod tests {
         assert_eq!(state.current_window_gas_used, 0);
         assert_eq!(state.window_start_time, new_time);
     }
+
+    #[test]
+    fn test_refresh_base_fee_holds_during_decay_grace_windows() {
+        let mut state = Eip1559 {
+            config: Eip1559Config {
+                decay_grace_windows: 3,
+                ..Eip1559Config::test_new()
+            },
+            current_base_fee: 8000,
+            current_window_gas_used: 0,
+            window_start_time: 0,
+            empty_window_streak: 0,
+        };
+
+        // Empty windows one at a time: the fee holds for the first three
+        for window in 1..=3 {
+            assert_eq!(state.refresh_base_fee(window), 8000);
+        }
+        assert_eq!(state.empty_window_streak, 3);
+
+        // The fourth empty window decays it
+        assert!(state.refresh_base_fee(4) < 8000);
+
+        // Gas usage ends the streak, so the next empty windows are graced again
+        state.add_gas_usage(state.config.target);
+        let fee = state.refresh_base_fee(5);
+        assert_eq!(state.empty_window_streak, 0);
+        assert_eq!(state.refresh_base_fee(6), fee);
+    }
+
+    #[test]
+    fn test_refresh_base_fee_skips_grace_windows_when_jumping() {
+        let grace_windows = 3;
+        let mut graced = Eip1559 {
+            config: Eip1559Config {
+                decay_grace_windows: grace_windows,
+                ..Eip1559Config::test_new()
+            },
+            current_base_fee: 8000,
+            current_window_gas_used: 0,
+            window_start_time: 0,
+            empty_window_streak: 0,
+        };
+        let mut ungraced = graced.clone();
+        ungraced.config.decay_grace_windows = 0;
+
+        // Jumping over the grace period at once holds the fee for exactly `grace_windows`
+        assert_eq!(graced.refresh_base_fee(grace_windows as i64), 8000);
+
+        // Jumping further decays it as if the grace windows had not happened
+        let graced_fee = graced.refresh_base_fee(grace_windows as i64 + 5);
+        let ungraced_fee = ungraced.refresh_base_fee(5);
+        assert_eq!(graced_fee, ungraced_fee);
+        assert_eq!(graced.empty_window_streak, grace_windows + 5);
+    }
+
+    fn gas_config(gas_cost_scaler: u64, minimum_fee_lamports: u64) -> GasConfig {
+        GasConfig {
+            gas_cost_scaler,
+            gas_cost_scaler_dp: 1_000_000,
+            gas_fee_receiver: Pubkey::default(),
+            gas_per_call: 100_000,
+            minimum_fee_lamports,
+            maximum_fee_lamports: 0,
+            call_type_gas_multipliers_bps: [10_000, 10_000, 20_000, 30_000],
+        }
+    }
+
+    #[test]
+    fn test_gas_cost_scales_base_fee() {
+        assert_eq!(gas_config(1_000_000, 0).gas_cost(1, None).unwrap(), 100_000);
+        assert_eq!(
+            gas_config(500_000, 5_000).gas_cost(1, None).unwrap(),
+            50_000
+        );
+    }
+
+    #[test]
+    fn test_gas_cost_applies_minimum_fee_floor() {
+        // 100_000 * 1 * 1 / 1_000_000 rounds down to zero
+        assert_eq!(gas_config(1, 0).gas_cost(1, None).unwrap(), 0);
+        assert_eq!(gas_config(1, 5_000).gas_cost(1, None).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_gas_cost_applies_maximum_fee_ceiling() {
+        let mut config = gas_config(1_000_000, 5_000);
+        config.maximum_fee_lamports = 60_000;
+        assert_eq!(config.gas_cost(1, None).unwrap(), 60_000);
+        assert_eq!(config.gas_cost(0, None).unwrap(), 5_000);
+
+        // A ceiling below the floor is rejected
+        config.maximum_fee_lamports = 4_999;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            BridgeError::InvalidFeeBounds.into()
+        );
+    }
+
+    #[test]
+    fn test_gas_cost_applies_call_type_multiplier() {
+        let config = gas_config(1_000_000, 0);
+
+        assert_eq!(config.gas_cost(1, Some(CallType::Call)).unwrap(), 100_000);
+        assert_eq!(config.gas_cost(1, Some(CallType::Create)).unwrap(), 200_000);
+        assert_eq!(
+            config.gas_cost(1, Some(CallType::Create2)).unwrap(),
+            300_000
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_out_of_range_gas_multiplier() {
+        let mut config = gas_config(1_000_000, 0);
+        assert!(config.validate().is_ok());
+
+        config.call_type_gas_multipliers_bps[CallType::Create as usize] = 0;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            BridgeError::InvalidGasMultiplier.into()
+        );
+
+        config.call_type_gas_multipliers_bps[CallType::Create as usize] = MAX_GAS_MULTIPLIER_BPS;
+        assert!(config.validate().is_ok());
+
+        config.call_type_gas_multipliers_bps[CallType::Create as usize] =
+            MAX_GAS_MULTIPLIER_BPS + 1;
+        assert_eq!(
+            config.validate().unwrap_err(),
+            BridgeError::InvalidGasMultiplier.into()
+        );
+    }
+
+    #[test]
+    fn test_gas_cost_large_multiplicands_do_not_overflow_intermediates() {
+        let config = GasConfig {
+            gas_cost_scaler: 1_000_000_000,
+            gas_cost_scaler_dp: 1_000_000_000,
+            gas_fee_receiver: Pubkey::default(),
+            gas_per_call: 10_000_000,
+            minimum_fee_lamports: 0,
+            maximum_fee_lamports: 0,
+            call_type_gas_multipliers_bps: [u32::MAX; 4],
+        };
+
+        // gas_per_call * base_fee * gas_cost_scaler = 1e7 * 1e9 * 1e9 overflows u64, but the
+        // scaled-down cost fits
+        assert_eq!(
+            config.gas_cost(1_000_000_000, None).unwrap(),
+            10_000_000_000_000_000
+        );
+
+        // The call type multiplier pushes the final cost past u64::MAX
+        assert_eq!(
+            config
+                .gas_cost(1_000_000_000, Some(CallType::Call))
+                .unwrap_err(),
+            BridgeError::GasCostOverflow.into()
+        );
+        assert_eq!(
+            config.gas_cost(u64::MAX, None).unwrap_err(),
+            BridgeError::GasCostOverflow.into()
+        );
+    }
+
+    #[test]
+    fn test_registration_bucket_drains_and_refills() {
+        let mut bucket = RegistrationBucket::new(2, 1_000);
+
+        assert!(bucket.try_consume(1, 2, 60, 1_000));
+        assert!(bucket.try_consume(1, 2, 60, 1_010));
+        assert!(!bucket.try_consume(1, 2, 60, 1_059));
+
+        // One interval after the first drain returns exactly one token
+        assert!(bucket.try_consume(1, 2, 60, 1_060));
+        assert!(!bucket.try_consume(1, 2, 60, 1_060));
+
+        // Long idle periods never exceed capacity
+        assert!(bucket.try_consume(1, 2, 60, 10_000));
+        assert!(bucket.try_consume(1, 2, 60, 10_000));
+        assert!(!bucket.try_consume(1, 2, 60, 10_000));
+    }
+
+    #[test]
+    fn test_registration_bucket_takes_count_tokens_or_none() {
+        let mut bucket = RegistrationBucket::new(3, 1_000);
+
+        assert!(!bucket.try_consume(4, 3, 60, 1_000));
+        assert_eq!(bucket.tokens, 3);
+        assert!(bucket.try_consume(2, 3, 60, 1_000));
+        assert!(!bucket.try_consume(2, 3, 60, 1_000));
+        assert!(bucket.try_consume(1, 3, 60, 1_000));
+    }
+
+    #[test]
+    fn test_registration_bucket_disabled_with_zero_capacity() {
+        let mut bucket = RegistrationBucket::new(0, 0);
+
+        for _ in 0..10 {
+            assert!(bucket.try_consume(1, 0, 0, 0));
+        }
+    }
+
+    #[test]
+    fn test_volume_window_enforces_limit_and_rolls_over() {
+        let mut window = VolumeWindow::new(0);
+
+        assert!(window.add(60, 100, 0).is_ok());
+        assert!(window.add(40, 100, 10).is_ok());
+        assert_eq!(
+            window.add(1, 100, 20).unwrap_err(),
+            BridgeError::DailyLimitExceeded.into()
+        );
+        assert_eq!(window.volume, 100);
+
+        // Still within the same window
+        let last_second = DAILY_VOLUME_WINDOW_SECONDS as i64 - 1;
+        assert!(window.add(1, 100, last_second).is_err());
+
+        // A new window starts once the day has elapsed
+        let next_day = DAILY_VOLUME_WINDOW_SECONDS as i64;
+        assert!(window.add(100, 100, next_day).is_ok());
+        assert_eq!(window.window_start_time, next_day);
+        assert_eq!(window.volume, 100);
+    }
+
+    #[test]
+    fn test_volume_window_is_fixed_not_sliding() {
+        let mut window = VolumeWindow::new(0);
+
+        // The full limit at the end of one window and again at the start of the next
+        let last_second = DAILY_VOLUME_WINDOW_SECONDS as i64 - 1;
+        assert!(window.add(100, 100, last_second).is_ok());
+        assert!(window.add(100, 100, last_second + 1).is_ok());
+        assert_eq!(window.volume, 100);
+    }
+
+    #[test]
+    fn test_volume_window_disabled_with_zero_limit() {
+        let mut window = VolumeWindow::new(0);
+
+        assert!(window.add(u64::MAX, 0, 0).is_ok());
+        assert!(window.add(u64::MAX, 0, 0).is_ok());
+        assert_eq!(window.volume, 0);
+    }
 }
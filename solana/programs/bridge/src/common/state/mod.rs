@@ -1,3 +1,5 @@
 pub mod bridge;
+pub mod remote_token;
 
 pub use bridge::*;
+pub use remote_token::*;
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{DENIED_REMOTE_TOKEN_SEED, RATE_LIMIT_SEED},
+    BridgeError,
+};
+
+/// Guardian-managed denylist entry for a Base token address, at
+/// `[DENIED_REMOTE_TOKEN_SEED, remote_token]`. Tokens with an entry can no longer be bridged or
+/// wrapped.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct DeniedRemoteToken {
+    /// The denied Base token address
+    pub remote_token: [u8; 20],
+}
+
+impl DeniedRemoteToken {
+    /// Returns the address of the denylist entry of `remote_token`.
+    pub fn address(remote_token: &[u8; 20]) -> Pubkey {
+        Pubkey::find_program_address(&[DENIED_REMOTE_TOKEN_SEED, remote_token], &crate::ID).0
+    }
+
+    /// Fails with `RemoteTokenDenied` if `entry`, the denylist entry of `remote_token`, exists.
+    pub fn ensure_allowed(entry: &AccountInfo, remote_token: &[u8; 20]) -> Result<()> {
+        require_keys_eq!(
+            entry.key(),
+            Self::address(remote_token),
+            BridgeError::IncorrectDeniedRemoteToken
+        );
+        require!(!is_initialized(entry), BridgeError::RemoteTokenDenied);
+
+        Ok(())
+    }
+}
+
+/// Guardian-set fixed-window outflow limit for a Base token address, at
+/// `[RATE_LIMIT_SEED, remote_token]`. Tokens without a limit account are unlimited.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct RemoteTokenRateLimit {
+    /// The Base token address the limit applies to
+    pub remote_token: [u8; 20],
+    /// Maximum amount, in local token units, bridged out per window
+    pub max_per_window: u64,
+    /// Window duration in seconds
+    pub window_seconds: u64,
+    /// Unix timestamp when the current window started
+    pub window_start_time: i64,
+    /// Amount bridged out in the current window
+    pub amount_in_window: u64,
+}
+
+impl RemoteTokenRateLimit {
+    /// Returns the address of the rate limit of `remote_token`.
+    pub fn address(remote_token: &[u8; 20]) -> Pubkey {
+        Pubkey::find_program_address(&[RATE_LIMIT_SEED, remote_token], &crate::ID).0
+    }
+
+    /// Sets the limit of `remote_token`, restarting its window at `current_timestamp`.
+    pub fn set(
+        &mut self,
+        remote_token: [u8; 20],
+        max_per_window: u64,
+        window_seconds: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        require!(window_seconds > 0, BridgeError::InvalidRateLimitWindow);
+
+        *self = Self {
+            remote_token,
+            max_per_window,
+            window_seconds,
+            window_start_time: current_timestamp,
+            amount_in_window: 0,
+        };
+
+        Ok(())
+    }
+
+    /// Records `amount` bridged out, starting a new window first if the current one has elapsed.
+    /// Fails with `RateLimitExceeded` if the window would exceed `max_per_window`.
+    pub fn record(&mut self, amount: u64, current_timestamp: i64) -> Result<()> {
+        let elapsed = current_timestamp
+            .saturating_sub(self.window_start_time)
+            .max(0) as u64;
+        if elapsed >= self.window_seconds {
+            self.window_start_time = current_timestamp;
+            self.amount_in_window = 0;
+        }
+
+        self.amount_in_window = self
+            .amount_in_window
+            .checked_add(amount)
+            .filter(|amount_in_window| *amount_in_window <= self.max_per_window)
+            .ok_or(BridgeError::RateLimitExceeded)?;
+
+        Ok(())
+    }
+
+    /// Records `amount` bridged out of `remote_token` against `entry`, its rate limit account,
+    /// if the token has a limit.
+    pub fn record_outflow(
+        entry: &AccountInfo,
+        remote_token: &[u8; 20],
+        amount: u64,
+        current_timestamp: i64,
+    ) -> Result<()> {
+        require_keys_eq!(
+            entry.key(),
+            Self::address(remote_token),
+            BridgeError::IncorrectRateLimit
+        );
+        if !is_initialized(entry) {
+            return Ok(());
+        }
+
+        let mut data = entry.try_borrow_mut_data()?;
+        let mut limit = Self::try_deserialize(&mut &data[..])?;
+        limit.record(amount, current_timestamp)?;
+        limit.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
+
+/// Whether the program-derived `entry` has been created by the program. Anyone can fund a PDA
+/// address, so lamports alone do not count.
+fn is_initialized(entry: &AccountInfo) -> bool {
+    entry.owner == &crate::ID && !entry.data_is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limit(
+        max_per_window: u64,
+        window_seconds: u64,
+        current_timestamp: i64,
+    ) -> RemoteTokenRateLimit {
+        let mut limit = RemoteTokenRateLimit {
+            remote_token: [0u8; 20],
+            max_per_window: 0,
+            window_seconds: 0,
+            window_start_time: 0,
+            amount_in_window: 0,
+        };
+        limit
+            .set([1u8; 20], max_per_window, window_seconds, current_timestamp)
+            .unwrap();
+        limit
+    }
+
+    #[test]
+    fn test_remote_token_rate_limit_resets_lazily() {
+        let mut limit = rate_limit(100, 60, 1_000);
+
+        limit.record(60, 1_000).unwrap();
+        limit.record(40, 1_059).unwrap();
+        assert_eq!(
+            limit.record(1, 1_059).unwrap_err(),
+            BridgeError::RateLimitExceeded.into()
+        );
+
+        // The window elapsed: the full limit is available again
+        limit.record(100, 1_060).unwrap();
+    }
+
+    #[test]
+    fn test_remote_token_rate_limit_set_restarts_window() {
+        let mut limit = rate_limit(100, 60, 0);
+        limit.record(100, 10).unwrap();
+
+        assert_eq!(
+            limit.set([1u8; 20], 200, 0, 20).unwrap_err(),
+            BridgeError::InvalidRateLimitWindow.into()
+        );
+
+        limit.set([1u8; 20], 200, 60, 20).unwrap();
+        assert_eq!(limit.window_start_time, 20);
+        assert_eq!(limit.amount_in_window, 0);
+        limit.record(200, 20).unwrap();
+    }
+}
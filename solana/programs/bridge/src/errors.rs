@@ -12,6 +12,15 @@ pub enum BridgeError {
     #[msg("Incorrect gas fee receiver")]
     IncorrectGasFeeReceiver,
 
+    #[msg("Gas cost does not fit in a u64")]
+    GasCostOverflow,
+
+    #[msg("Quoted total cost does not fit in a u64")]
+    QuoteOverflow,
+
+    #[msg("Account is already migrated or does not have the legacy layout")]
+    AlreadyMigrated,
+
     // Authorization & Access Control (6100-6199)
     #[msg("Only the upgrade authority can initialize the bridge")]
     UnauthorizedInitialization = 6100,
@@ -19,6 +28,24 @@ pub enum BridgeError {
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate,
 
+    #[msg("Guardian approval is required for this finalization")]
+    GuardianApprovalRequired,
+
+    #[msg("Sender must also be the payer")]
+    SponsorNotAllowed,
+
+    #[msg("Only the pending guardian can accept the guardian role")]
+    UnauthorizedGuardianAcceptance,
+
+    #[msg("Partner config account is not owned by the partner program")]
+    InvalidPartnerConfigOwner,
+
+    #[msg("The co-guardian must also sign this configuration change")]
+    CoGuardianSignatureRequired,
+
+    #[msg("The co-guardian must differ from the guardian")]
+    InvalidCoGuardian,
+
     // Buffer Management (6200-6299)
     #[msg("Only the owner can close this buffer")]
     BufferUnauthorizedClose = 6200,
@@ -29,6 +56,24 @@ pub enum BridgeError {
     #[msg("Call buffer size exceeds maximum allowed size")]
     BufferMaxSizeExceeded,
 
+    #[msg("Prove buffer proof length exceeds maximum allowed length")]
+    ProveBufferMaxProofLenExceeded,
+
+    #[msg("Append exceeds the prove buffer's allocated capacity")]
+    ProveBufferCapacityExceeded,
+
+    #[msg("Call buffer has reached its maximum number of appends")]
+    TooManyAppends,
+
+    #[msg("Only the owner can transfer this buffer")]
+    BufferUnauthorizedTransfer,
+
+    #[msg("Call buffer data does not match the committed hash")]
+    BufferChecksumMismatch,
+
+    #[msg("Append would grow the call buffer past its capacity")]
+    BufferOverflow,
+
     // Signature & Cryptography (6300-6399)
     #[msg("Invalid recovery ID")]
     InvalidRecoveryId = 6300,
@@ -42,6 +87,9 @@ pub enum BridgeError {
     #[msg("Insufficient partner oracle signatures to meet threshold")]
     InsufficientPartnerSignatures,
 
+    #[msg("Signer approved as both a Base and a partner oracle")]
+    OverlappingSignerSets,
+
     // MMR Proofs (6400-6499)
     #[msg("Invalid proof")]
     InvalidProof = 6400,
@@ -67,6 +115,9 @@ pub enum BridgeError {
     #[msg("No peaks found for non-empty MMR")]
     NoPeaksFoundForNonEmptyMmr,
 
+    #[msg("Proof is longer than any valid proof for this MMR")]
+    ProofTooLong,
+
     // Message Proving & Relaying (6500-6599)
     #[msg("Invalid message hash")]
     InvalidMessageHash = 6500,
@@ -77,6 +128,60 @@ pub enum BridgeError {
     #[msg("Incorrect block number")]
     IncorrectBlockNumber,
 
+    #[msg("Unsupported message version")]
+    UnsupportedMessageVersion,
+
+    #[msg("Output root registration is too soon after the previous one")]
+    RegistrationTooSoon,
+
+    #[msg("Output root is older than the maximum allowed age")]
+    OutputRootTooOld,
+
+    #[msg("Output root registrations are rate limited")]
+    RegistrationRateLimited,
+
+    #[msg("Output root is identical to the previously registered root")]
+    DuplicateOutputRoot,
+
+    #[msg("Relayed instruction targets a protected bridge account")]
+    ProtectedAccountMutation,
+
+    #[msg("Output root is not within the registered range")]
+    RootNotRegistered,
+
+    #[msg("Relay slice is empty or extends past the message instructions")]
+    InvalidRelaySlice,
+
+    #[msg("The latest output root cannot be closed")]
+    CannotCloseLatestOutputRoot,
+
+    #[msg("Output root is too recent to be closed")]
+    OutputRootCloseTimelocked,
+
+    #[msg("Output root is within the close margin of the latest registered block")]
+    OutputRootWithinCloseMargin,
+
+    #[msg("Output root must be re-validated by the current oracle set")]
+    OutputRootRequiresRevalidation,
+
+    #[msg("Base block number is too far ahead of the latest registered block")]
+    BlockNumberTooFarAhead,
+
+    #[msg("Output root batch is empty, too large or does not match the provided root accounts")]
+    InvalidOutputRootBatch,
+
+    #[msg("Message was proven too recently to be relayed")]
+    RelayTooSoon,
+
+    #[msg("Message contains duplicate instructions")]
+    DuplicateRelayedInstruction,
+
+    #[msg("Message nonce is not above the highest relayed nonce")]
+    RelayNonceOutOfOrder,
+
+    #[msg("Message has no instructions to relay in slices, use relay_message instead")]
+    NoInstructionsToRelay,
+
     // Token Validation (6600-6699)
     #[msg("Mint does not match local token")]
     MintDoesNotMatchLocalToken = 6600,
@@ -96,6 +201,33 @@ pub enum BridgeError {
     #[msg("Incorrect sol vault")]
     IncorrectSolVault,
 
+    #[msg("SOL release exceeds locked amount")]
+    SolReleaseExceedsLocked,
+
+    #[msg("Bridge would leave the sender below the requested reserve")]
+    WouldDrainPayer,
+
+    #[msg("Maximum number of wrapped tokens reached")]
+    WrappedTokenLimitReached,
+
+    #[msg("Remote token is denied")]
+    RemoteTokenDenied,
+
+    #[msg("Incorrect denied remote token account")]
+    IncorrectDeniedRemoteToken,
+
+    #[msg("Invalid bridge transfer payload")]
+    InvalidBridgePayload,
+
+    #[msg("Daily bridged volume limit exceeded")]
+    DailyLimitExceeded,
+
+    #[msg("Remote token rate limit exceeded")]
+    RateLimitExceeded,
+
+    #[msg("Incorrect rate limit account")]
+    IncorrectRateLimit,
+
     // Token Metadata (6700-6799)
     #[msg("Remote token not found")]
     RemoteTokenNotFound = 6700,
@@ -115,6 +247,9 @@ pub enum BridgeError {
     #[msg("Mint is not a valid wrapped token PDA")]
     MintIsNotWrappedTokenPda,
 
+    #[msg("Token name or symbol exceeds the maximum length")]
+    MetadataTooLong,
+
     // Bridge Configuration (6800-6899)
     #[msg("Threshold must be <= number of signers")]
     InvalidThreshold = 6800,
@@ -140,6 +275,30 @@ pub enum BridgeError {
     #[msg("Invalid block interval requirement")]
     InvalidBlockIntervalRequirement,
 
+    #[msg("Block interval requirement is incompatible with the latest registered block")]
+    IncompatibleBlockInterval,
+
+    #[msg("Registration rate limit requires a non-zero refill interval")]
+    InvalidRegistrationRateLimit,
+
+    #[msg("Base fee is outside [minimum_base_fee, MAX_BASE_FEE]")]
+    InvalidBaseFee,
+
+    #[msg("Signer is not part of the oracle set")]
+    SignerNotFound,
+
+    #[msg("Rate limit window must be non-zero")]
+    InvalidRateLimitWindow,
+
+    #[msg("Paused operations mask contains unknown flags")]
+    InvalidPausedOps,
+
+    #[msg("Maximum fee must be zero or at least the minimum fee")]
+    InvalidFeeBounds,
+
+    #[msg("Gas multiplier must be non-zero and at most MAX_GAS_MULTIPLIER_BPS")]
+    InvalidGasMultiplier,
+
     // Call Type Validation (6900-6999)
     #[msg("Creation with non-zero target")]
     CreationWithNonZeroTarget = 6900,
@@ -8,18 +8,36 @@ mod errors;
 mod solana_to_base;
 
 use base_to_solana::*;
+pub use base_to_solana::{
+    compute_output_root_batch_message_hash, compute_output_root_message_hash,
+};
 use common::*;
 pub use errors::*;
 
 use common::{
     config::{
-        set_adjustment_denominator_handler, set_block_interval_requirement_handler,
-        set_gas_cost_scaler_dp_handler, set_gas_cost_scaler_handler, set_gas_fee_receiver_handler,
-        set_gas_target_handler, set_max_call_buffer_size_handler, set_minimum_base_fee_handler,
-        set_pause_status_handler, set_window_duration_handler,
+        add_denied_remote_token_handler, remove_denied_remote_token_handler,
+        reset_base_fee_handler, set_adjustment_denominator_handler,
+        set_block_interval_requirement_handler, set_daily_sol_volume_limits_handler,
+        set_decay_grace_windows_handler, set_disjoint_signer_sets_handler,
+        set_enforce_relay_nonce_order_handler, set_gas_cost_scaler_dp_handler,
+        set_gas_cost_scaler_handler, set_gas_fee_receiver_handler, set_gas_target_handler,
+        set_large_finalize_threshold_handler, set_max_block_advance_handler,
+        set_max_call_buffer_appends_handler, set_max_call_buffer_size_handler,
+        set_max_prove_buffer_proof_len_handler, set_max_root_age_handler,
+        set_max_wrapped_tokens_handler, set_min_prove_to_relay_delay_handler,
+        set_min_registration_interval_handler, set_minimum_base_fee_handler,
+        set_pause_status_handler, set_paused_ops_handler, set_rate_limit_handler,
+        set_registration_rate_limit_handler, set_reject_duplicate_ixs_handler,
+        set_reject_duplicate_roots_handler, set_require_self_call_handler,
+        set_revalidate_old_roots_after_blocks_handler, set_window_duration_handler,
+    },
+    guardian::{
+        accept_guardian_handler, cancel_guardian_transfer_handler, set_co_guardian_handler,
+        transfer_guardian_handler,
     },
-    guardian::transfer_guardian_handler,
     initialize::initialize_handler,
+    migrate_bridge::migrate_bridge_handler,
 };
 use solana_to_base::*;
 
@@ -46,6 +64,60 @@ pub mod bridge {
         initialize_handler(ctx, guardian, cfg)
     }
 
+    /// Migrates a bridge account created by a previous version of the program to the current
+    /// layout. Must be called once, right after upgrading a deployed program, before any other
+    /// instruction can load the bridge. Only the upgrade authority can call this function.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the legacy bridge account, the SOL vault seeding
+    ///   `locked_sol` and the upgrade authority accounts
+    /// * `cfg` - All the configuration parameters of the migrated bridge
+    pub fn migrate_bridge(ctx: Context<MigrateBridge>, cfg: Config) -> Result<()> {
+        migrate_bridge_handler(ctx, cfg)
+    }
+
+    /// Returns the SOL locked in the bridge alongside the SOL vault's actual balance.
+    /// The result is written to the transaction return data as a `TotalLockedSol`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge and SOL vault accounts
+    pub fn get_total_locked_sol(ctx: Context<GetTotalLockedSol>) -> Result<TotalLockedSol> {
+        get_total_locked_sol_handler(ctx)
+    }
+
+    /// Returns the EIP-1559 base fee refreshed for the current timestamp, without persisting the
+    /// refresh. The result is written to the transaction return data as a `BaseFee`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account
+    pub fn get_base_fee(ctx: Context<GetBaseFee>) -> Result<BaseFee> {
+        get_base_fee_handler(ctx)
+    }
+
+    /// Returns the minimum, current and maximum lamport fee for a standard call under the current
+    /// gas config, without persisting the base fee refresh. The result is written to the
+    /// transaction return data as a `FeeBounds`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account
+    pub fn get_fee_bounds(ctx: Context<GetFeeBounds>) -> Result<FeeBounds> {
+        get_fee_bounds_handler(ctx)
+    }
+
+    /// Returns the bridge's program-derived addresses and their bumps, including the CPI
+    /// authority that signs instructions relayed from `sender`.
+    /// The result is written to the transaction return data as a `ProgramAuthorities`.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context (no accounts are required)
+    /// * `sender` - The Base sender whose bridge CPI authority is derived
+    pub fn get_program_authorities(
+        ctx: Context<GetProgramAuthorities>,
+        sender: [u8; 20],
+    ) -> Result<ProgramAuthorities> {
+        get_program_authorities_handler(ctx, sender)
+    }
+
     // Base -> Solana
 
     /// Registers an output root from Base to enable message verification.
@@ -76,6 +148,24 @@ pub mod bridge {
         )
     }
 
+    /// Registers several consecutive output roots from Base under a single set of oracle
+    /// signatures, e.g. to catch up after registrations were delayed.
+    /// Each entry is checked as if registered with `register_output_root`. The batch counts as one
+    /// registration against the cooldown, but each entry takes its own token from the registration
+    /// rate limit.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account; the output root PDAs are passed as remaining accounts in entry order
+    /// * `entries`    - The output roots to register, in strictly increasing block order
+    /// * `signatures` - A list of ECDSA signatures from authorized oracles over the whole batch
+    pub fn register_output_roots_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterOutputRootsBatch<'info>>,
+        entries: Vec<OutputRootEntry>,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        register_output_roots_batch_handler(ctx, entries, signatures)
+    }
+
     /// Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.
     /// This function verifies the message was included in a previously registered output root
     /// and stores the proven message state for later relay execution.
@@ -169,10 +259,29 @@ pub mod bridge {
         prove_message_buffered_handler(ctx, nonce, sender, message_hash)
     }
 
+    /// Migrates an incoming message proven by a previous version of the program to the current
+    /// layout, so it can be relayed after an upgrade.
+    /// Anyone can call this function; the nonce is checked against the message hash.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the legacy incoming message account
+    /// * `nonce`        - The nonce the message was proven with
+    /// * `message_hash` - The hash the incoming message account is derived from
+    pub fn migrate_incoming_message(
+        ctx: Context<MigrateIncomingMessage>,
+        nonce: u64,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        migrate_incoming_message_handler(ctx, nonce, message_hash)
+    }
+
     /// Executes a previously proven cross-chain message on Solana.
     /// This function takes a message that has been proven via `prove_message` and executes
     /// its payload using a bridge CPI authority derived from the message sender.
     ///
+    /// The `guardian` account always precedes the remaining accounts; pass the bridge program ID
+    /// when the guardian does not co-sign. See `CHANGELOG.md` for clients built before this slot.
+    ///
     /// # Arguments
     /// * `ctx` - The transaction context
     pub fn relay_message<'a, 'info>(
@@ -181,6 +290,107 @@ pub mod bridge {
         relay_message_handler(ctx)
     }
 
+    /// Begins relaying a proven message whose instructions do not fit in a single transaction.
+    /// Finalizes the message's transfer (if any), marks the message as executed and creates a
+    /// relay cursor from which `continue_relay` executes the instructions in slices. Messages
+    /// without instructions are rejected and must be relayed with `relay_message`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the message, bridge and relay cursor accounts
+    pub fn begin_relay<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, BeginRelay<'info>>,
+    ) -> Result<()> {
+        begin_relay_handler(ctx)
+    }
+
+    /// Executes the next `ix_count` instructions of a message relayed with `begin_relay`,
+    /// starting at the relay cursor. The relay is complete once the cursor reaches the end
+    /// of the instruction list.
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context containing the message, bridge and relay cursor accounts
+    /// * `ix_count` - Number of instructions to execute in this slice
+    pub fn continue_relay<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, ContinueRelay<'info>>,
+        ix_count: u32,
+    ) -> Result<()> {
+        continue_relay_handler(ctx, ix_count)
+    }
+
+    /// Closes an erroneously created or stale output root and returns its rent to a receiver.
+    /// The latest output root can never be closed, and other roots only once they trail the
+    /// latest block by `OUTPUT_ROOT_CLOSE_MARGIN_INTERVALS` block intervals and are older than
+    /// `OUTPUT_ROOT_CLOSE_DELAY_SECONDS`.
+    /// Only the guardian can call this function, co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the guardians, rent receiver, bridge and output root accounts
+    /// * `base_block_number` - The Base block number of the output root to close
+    pub fn close_output_root(ctx: Context<CloseOutputRoot>, base_block_number: u64) -> Result<()> {
+        close_output_root_handler(ctx, base_block_number)
+    }
+
+    /// Migrates an output root created by a previous version of the program to the current
+    /// layout, so it can be proven against again after an upgrade.
+    /// Only the upgrade authority can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the legacy output root and the upgrade authority accounts
+    /// * `base_block_number` - The Base block number of the output root to migrate
+    pub fn migrate_output_root(
+        ctx: Context<MigrateOutputRoot>,
+        base_block_number: u64,
+    ) -> Result<()> {
+        migrate_output_root_handler(ctx, base_block_number)
+    }
+
+    /// Records that the current Base oracle set co-signed an already registered output root.
+    /// Required before proving against roots older than `revalidate_old_roots_after_blocks`.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the bridge and output root accounts
+    /// * `base_block_number` - The Base block number of the output root to re-validate
+    /// * `signatures`        - Oracle EVM signatures over the root's registration message
+    pub fn revalidate_output_root(
+        ctx: Context<RevalidateOutputRoot>,
+        base_block_number: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        revalidate_output_root_handler(ctx, base_block_number, signatures)
+    }
+
+    /// Returns the stored data and execution status of a proven message.
+    /// The result is written to the transaction return data as a `ProvenMessage`; the message
+    /// payload is only included inline when it fits, its keccak256 hash is always included.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the incoming message account
+    /// * `message_hash` - The 32-byte hash of the proven message
+    pub fn get_proven_message(
+        ctx: Context<GetProvenMessage>,
+        message_hash: [u8; 32],
+    ) -> Result<ProvenMessage> {
+        get_proven_message_handler(ctx, message_hash)
+    }
+
+    /// Returns the current base oracle signer set and approval threshold.
+    /// The result is written to the transaction return data as an `OracleSigners`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account
+    pub fn get_oracle_signers(ctx: Context<GetOracleSigners>) -> Result<OracleSigners> {
+        get_oracle_signers_handler(ctx)
+    }
+
+    /// Returns the highest Base nonce relayed so far, or `None` before the first relay, so
+    /// monitoring can detect skipped nonces.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account
+    pub fn get_highest_relayed_nonce(ctx: Context<GetHighestRelayedNonce>) -> Result<Option<u64>> {
+        get_highest_relayed_nonce_handler(ctx)
+    }
+
     // Solana -> Base
 
     /// Creates a wrapped version of a Base token.
@@ -188,6 +398,9 @@ pub mod bridge {
     /// enabling users to bridge the token between the two chains. It will also trigger a message
     /// to Base to register the wrapped token in the Base Bridge contract.
     ///
+    /// The `denied_remote_token` PDA of the remote token follows `bridge`; see `CHANGELOG.md` for
+    /// clients built before this account.
+    ///
     /// # Arguments
     /// * `ctx`                    - The transaction context
     /// * `outgoing_message_salt`  - The salt for the outgoing message account
@@ -214,10 +427,24 @@ pub mod bridge {
         ctx: Context<BridgeCall>,
         outgoing_message_salt: [u8; 32],
         call: Call,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_call_handler(ctx, outgoing_message_salt, call)
     }
 
+    /// Quotes the lamports a `bridge_sol`, `bridge_spl` or `bridge_call` would cost right now:
+    /// gas fee at the current base fee, locked SOL value and outgoing message rent.
+    /// The result is written to the transaction return data as a `TotalCostQuote`.
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the bridge account
+    /// * `operation` - The operation to quote, including the shape of its optional call
+    pub fn quote_total_cost(
+        ctx: Context<QuoteTotalCost>,
+        operation: QuotedOperation,
+    ) -> Result<TotalCostQuote> {
+        quote_total_cost_handler(ctx, operation)
+    }
+
     /// Bridges a call using data from a call buffer account.
     /// This instruction consumes the call buffer and creates an outgoing message
     /// for execution on Base.
@@ -228,7 +455,7 @@ pub mod bridge {
     pub fn bridge_call_buffered<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, BridgeCallBuffered<'info>>,
         outgoing_message_salt: [u8; 32],
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_call_buffered_handler(ctx, outgoing_message_salt)
     }
 
@@ -236,26 +463,42 @@ pub mod bridge {
     /// This function locks SOL on Solana and initiates a message to mint equivalent
     /// tokens on Base for the specified recipient.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the SOL bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
     /// * `amount`                - Amount of SOL to bridge (in lamports)
     /// * `call`                  - Optional additional contract call to execute with the token transfer
+    /// * `reserve_lamports`      - Optional minimum balance `from` must keep after the bridge; a
+    ///   sponsoring `payer` is not checked
     pub fn bridge_sol(
         ctx: Context<BridgeSol>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
-        bridge_sol_handler(ctx, outgoing_message_salt, to, amount, call)
+        reserve_lamports: Option<u64>,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_sol_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            amount,
+            call,
+            reserve_lamports,
+        )
     }
 
     /// Bridges native SOL tokens from Solana to Base with a call using buffered data.
     /// This function locks SOL on Solana and initiates a message to mint equivalent
     /// tokens on Base, then executes a call using data from a call buffer.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the SOL bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
@@ -266,7 +509,7 @@ pub mod bridge {
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_sol_with_buffered_call_handler(ctx, outgoing_message_salt, to, amount)
     }
 
@@ -274,6 +517,9 @@ pub mod bridge {
     /// This function burns or locks SPL tokens on Solana and initiates a message to mint
     /// equivalent ERC20 tokens on Base for the specified recipient.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the SPL token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
@@ -288,7 +534,7 @@ pub mod bridge {
         remote_token: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_spl_handler(ctx, outgoing_message_salt, to, remote_token, amount, call)
     }
 
@@ -296,6 +542,9 @@ pub mod bridge {
     /// This function locks SPL tokens on Solana and initiates a message to mint equivalent
     /// tokens on Base, then executes a call using data from a call buffer.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the SPL token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
@@ -308,7 +557,7 @@ pub mod bridge {
         to: [u8; 20],
         remote_token: [u8; 20],
         amount: u64,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_spl_with_buffered_call_handler(ctx, outgoing_message_salt, to, remote_token, amount)
     }
 
@@ -316,6 +565,9 @@ pub mod bridge {
     /// This function burns wrapped tokens on Solana and initiates a message to release
     /// or mint the original tokens on Base for the specified recipient.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the wrapped token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
@@ -328,7 +580,7 @@ pub mod bridge {
         to: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_wrapped_token_handler(ctx, outgoing_message_salt, to, amount, call)
     }
 
@@ -336,6 +588,9 @@ pub mod bridge {
     /// This function burns wrapped tokens on Solana and initiates a message to release
     /// the original tokens on Base, then executes a call using data from a call buffer.
     ///
+    /// The `denied_remote_token` and `rate_limit` PDAs of the remote token follow `bridge`; see
+    /// `CHANGELOG.md` for clients built before these accounts.
+    ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the wrapped token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
@@ -346,7 +601,7 @@ pub mod bridge {
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
-    ) -> Result<()> {
+    ) -> Result<OutgoingMessageReceipt> {
         bridge_wrapped_token_with_buffered_call_handler(ctx, outgoing_message_salt, to, amount)
     }
 
@@ -361,6 +616,7 @@ pub mod bridge {
     /// * `value`        - The amount of ETH to send with the call (in wei)
     /// * `initial_data` - Initial call data to store
     /// * `max_data_len` - Maximum total length of data that will be stored
+    /// * `expected_hash` - Optional keccak-256 hash the complete data must match when bridged
     pub fn initialize_call_buffer(
         ctx: Context<InitializeCallBuffer>,
         ty: CallType,
@@ -368,8 +624,17 @@ pub mod bridge {
         value: u128,
         initial_data: Vec<u8>,
         max_data_len: u64,
+        expected_hash: Option<[u8; 32]>,
     ) -> Result<()> {
-        initialize_call_buffer_handler(ctx, ty, to, value, initial_data, max_data_len)
+        initialize_call_buffer_handler(
+            ctx,
+            ty,
+            to,
+            value,
+            initial_data,
+            max_data_len,
+            expected_hash,
+        )
     }
 
     /// Appends data to an existing call buffer account.
@@ -382,6 +647,19 @@ pub mod bridge {
         append_to_call_buffer_handler(ctx, data)
     }
 
+    /// Appends several chunks of data to an existing call buffer account in a single instruction.
+    /// Only the owner of the call buffer can append data to it.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the call buffer account
+    /// * `chunks` - Chunks of data appended to the buffer in order
+    pub fn append_chunks_to_call_buffer(
+        ctx: Context<AppendToCallBuffer>,
+        chunks: Vec<Vec<u8>>,
+    ) -> Result<()> {
+        append_chunks_to_call_buffer_handler(ctx, chunks)
+    }
+
     /// Closes a call buffer account and returns the rent to the specified receiver.
     /// Only the owner of the call buffer can close it. This is useful if the user
     /// changed their mind or made a mistake and wants to recover the rent.
@@ -392,25 +670,87 @@ pub mod bridge {
         close_call_buffer_handler(ctx)
     }
 
-    /// Transfer guardian authority to a new pubkey
-    /// Only the current guardian can call this function
+    /// Transfers ownership of a call buffer account to a new owner.
+    /// Only the current owner of the call buffer can transfer it. This lets a service build
+    /// large call data and hand the buffer to the wallet that will bridge it.
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the call buffer and its current owner
+    /// * `new_owner` - The account that will own the call buffer (must not be the zero pubkey)
+    pub fn transfer_call_buffer(ctx: Context<TransferCallBuffer>, new_owner: Pubkey) -> Result<()> {
+        transfer_call_buffer_handler(ctx, new_owner)
+    }
+
+    /// Propose a new guardian. The role is only handed over once the proposed key accepts it
+    /// with `accept_guardian`.
+    /// Only the current guardian can call this function, co-signed by the co-guardian if set
     ///
     /// # Arguments
     /// * `ctx` - The context containing the bridge account and current guardian
-    /// * `new_guardian` - The pubkey of the new guardian
+    /// * `new_guardian` - The pubkey of the proposed guardian, recorded as `pending_guardian`
     pub fn transfer_guardian(
-        ctx: Context<SetBridgeConfigFromGuardian>,
+        ctx: Context<SetBridgeConfigFromGuardians>,
         new_guardian: Pubkey,
     ) -> Result<()> {
         transfer_guardian_handler(ctx, new_guardian)
     }
 
+    /// Accept a pending guardian proposal, making the signer the guardian
+    /// Only the pending guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and the pending guardian
+    pub fn accept_guardian(ctx: Context<AcceptGuardian>) -> Result<()> {
+        accept_guardian_handler(ctx)
+    }
+
+    /// Set the co-guardian that must co-sign critical configuration changes (unpausing,
+    /// guardian transfers and co-guardian changes)
+    /// Only the current guardian can call this function, co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`             - The context containing the bridge account and guardians
+    /// * `new_co_guardian` - The new co-guardian (the default pubkey removes it)
+    pub fn set_co_guardian(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_co_guardian: Pubkey,
+    ) -> Result<()> {
+        set_co_guardian_handler(ctx, new_co_guardian)
+    }
+
+    /// Cancel a pending guardian proposal
+    /// Only the current guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and current guardian
+    pub fn cancel_guardian_transfer(ctx: Context<SetBridgeConfigFromGuardian>) -> Result<()> {
+        cancel_guardian_transfer_handler(ctx)
+    }
+
+    /// Freeze a wrapped token account, blocking transfers and bridging back to Base
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge, guardian, wrapped mint and token account
+    pub fn freeze_wrapped_account(ctx: Context<FreezeWrappedAccount>) -> Result<()> {
+        freeze_wrapped_account_handler(ctx)
+    }
+
+    /// Thaw a wrapped token account frozen by `freeze_wrapped_account`
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge, guardian, wrapped mint and token account
+    pub fn thaw_wrapped_account(ctx: Context<FreezeWrappedAccount>) -> Result<()> {
+        thaw_wrapped_account_handler(ctx)
+    }
+
     /// Sets the authorized oracle EVM signer addresses and the signature threshold used
-    /// when registering output roots. This function updates the `OracleSigners` account
-    /// and can only be called by the guardian.
+    /// when registering output roots. Only the upgrade authority can call this function, together
+    /// with the co-guardian when one is configured.
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge, guardian signer, and oracle signers accounts
+    /// * `ctx` - The context containing the bridge account, upgrade authority and co-guardian
     /// * `cfg` - Configuration parameters for Base oracle signers
     pub fn set_oracle_signers(
         ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
@@ -419,6 +759,49 @@ pub mod bridge {
         set_oracle_signers_handler(ctx, cfg)
     }
 
+    /// Adds a single Base oracle signer with unit weight, keeping the rest of the set.
+    /// Only the upgrade authority can call this function, together with the co-guardian when one
+    /// is configured
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account, upgrade authority and co-guardian
+    /// * `new_signer` - The EVM address of the signer to add
+    pub fn add_oracle_signer(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        new_signer: [u8; 20],
+    ) -> Result<()> {
+        add_oracle_signer_handler(ctx, new_signer)
+    }
+
+    /// Removes a single Base oracle signer. Fails if the remaining signers could no longer
+    /// meet the threshold.
+    /// Only the upgrade authority can call this function, together with the co-guardian when one
+    /// is configured
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the bridge account, upgrade authority and co-guardian
+    /// * `signer` - The EVM address of the signer to remove
+    pub fn remove_oracle_signer(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        signer: [u8; 20],
+    ) -> Result<()> {
+        remove_oracle_signer_handler(ctx, signer)
+    }
+
+    /// Sets the approval threshold of the Base oracle signer set.
+    /// Only the upgrade authority can call this function, together with the co-guardian when one
+    /// is configured
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the bridge account, upgrade authority and co-guardian
+    /// * `threshold` - The new required approval weight (> 0 and <= total signer weight)
+    pub fn set_oracle_threshold(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        threshold: u8,
+    ) -> Result<()> {
+        set_oracle_threshold_handler(ctx, threshold)
+    }
+
     // EIP-1559 Configuration Management
 
     /// Set the minimum base fee for EIP-1559 pricing
@@ -473,6 +856,43 @@ pub mod bridge {
         set_adjustment_denominator_handler(ctx, new_denominator)
     }
 
+    /// Set the number of consecutive empty windows before the base fee decays for EIP-1559 pricing
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the bridge account and guardian
+    /// * `new_grace_windows` - The new number of empty windows during which the base fee holds
+    pub fn set_decay_grace_windows(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_grace_windows: u64,
+    ) -> Result<()> {
+        set_decay_grace_windows_handler(ctx, new_grace_windows)
+    }
+
+    /// Replace all EIP-1559 pricing parameters at once, settling the current fee window under the
+    /// old parameters first
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account and guardian
+    /// * `new_config` - The new EIP-1559 configuration
+    pub fn set_eip1559_params(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_config: Eip1559Config,
+    ) -> Result<()> {
+        set_eip1559_params_handler(ctx, new_config)
+    }
+
+    /// Reset the current EIP-1559 base fee in an emergency
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the bridge account and guardian
+    /// * `new_fee` - The new base fee, in `[minimum_base_fee, MAX_BASE_FEE]`
+    pub fn reset_base_fee(ctx: Context<SetBridgeConfigFromGuardian>, new_fee: u64) -> Result<()> {
+        reset_base_fee_handler(ctx, new_fee)
+    }
+
     /// Set the gas cost scaler for Gas Cost Config
     /// Only the guardian can call this function
     ///
@@ -522,11 +942,55 @@ pub mod bridge {
         set_gas_per_call_handler(ctx, new_val)
     }
 
-    /// Set the block interval requirement for Protocol Config
+    /// Set the minimum fee per message for Gas Config
     /// Only the guardian can call this function
     ///
     /// # Arguments
     /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_minimum_fee` - The new minimum fee in lamports charged per message
+    pub fn set_minimum_fee_lamports(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_minimum_fee: u64,
+    ) -> Result<()> {
+        set_minimum_fee_lamports_handler(ctx, new_minimum_fee)
+    }
+
+    /// Set the maximum fee per message for Gas Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_maximum_fee` - The new maximum fee in lamports charged per message (0 removes the
+    ///   ceiling)
+    pub fn set_maximum_fee_lamports(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_maximum_fee: u64,
+    ) -> Result<()> {
+        set_maximum_fee_lamports_handler(ctx, new_maximum_fee)
+    }
+
+    /// Set the fee multiplier for a call type in Gas Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `ty` - The call type the multiplier applies to
+    /// * `multiplier_bps` - The new multiplier in basis points (10_000 leaves the fee unchanged),
+    ///   in `1..=MAX_GAS_MULTIPLIER_BPS`
+    pub fn set_call_type_gas_multiplier(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        ty: CallType,
+        multiplier_bps: u32,
+    ) -> Result<()> {
+        set_call_type_gas_multiplier_handler(ctx, ty, multiplier_bps)
+    }
+
+    /// Set the block interval requirement for Protocol Config
+    /// Only the guardian can call this function. The interval only constrains which Base blocks
+    /// can be registered, not how often, so it does not need the co-guardian
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
     /// * `new_interval` - The new block interval requirement value
     pub fn set_block_interval_requirement(
         ctx: Context<SetBridgeConfigFromGuardian>,
@@ -535,6 +999,258 @@ pub mod bridge {
         set_block_interval_requirement_handler(ctx, new_interval)
     }
 
+    /// Set the minimum wall-clock interval between output root registrations for Protocol Config
+    /// Only the guardian can call this function. Lowering or disabling the interval must be
+    /// co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                  - The context containing the bridge account and guardians
+    /// * `new_interval_seconds` - The new minimum interval in seconds (0 disables the check)
+    pub fn set_min_registration_interval(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_interval_seconds: u64,
+    ) -> Result<()> {
+        set_min_registration_interval_handler(ctx, new_interval_seconds)
+    }
+
+    /// Set the maximum age of output roots that messages can be proven against for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling the maximum age must be
+    /// co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the bridge account and guardians
+    /// * `new_max_age_seconds` - The new maximum root age in seconds (0 disables the check)
+    pub fn set_max_root_age(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_max_age_seconds: u64,
+    ) -> Result<()> {
+        set_max_root_age_handler(ctx, new_max_age_seconds)
+    }
+
+    /// Set how many Base blocks an output root may fall behind before it must be re-validated
+    /// for Protocol Config
+    /// Only the guardian can call this function. Raising the threshold or disabling re-validation
+    /// must be co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account and guardians
+    /// * `new_blocks` - The new block threshold (0 disables re-validation)
+    pub fn set_revalidate_old_roots_after_blocks(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_blocks: u64,
+    ) -> Result<()> {
+        set_revalidate_old_roots_after_blocks_handler(ctx, new_blocks)
+    }
+
+    /// Set how many Base blocks a new output root may advance past the latest registered one
+    /// for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling the bound must be co-signed
+    /// by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing the bridge account and guardians
+    /// * `new_max_block_advance` - The new maximum advance in blocks (0 disables the check)
+    pub fn set_max_block_advance(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_max_block_advance: u64,
+    ) -> Result<()> {
+        set_max_block_advance_handler(ctx, new_max_block_advance)
+    }
+
+    /// Set how long a proven message must wait before it can be relayed for Protocol Config
+    /// Only the guardian can call this function. Shortening or disabling the delay must be
+    /// co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the bridge account and guardians
+    /// * `new_delay_seconds` - The new minimum delay in seconds (0 disables the check)
+    pub fn set_min_prove_to_relay_delay(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_delay_seconds: u64,
+    ) -> Result<()> {
+        set_min_prove_to_relay_delay_handler(ctx, new_delay_seconds)
+    }
+
+    /// Set whether registering an output root identical to the previous one is rejected for Protocol Config
+    /// Only the guardian can call this function. Turning the check off must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                    - The context containing the bridge account and guardians
+    /// * `reject_duplicate_roots` - If set, duplicate roots fail instead of only emitting an event
+    pub fn set_reject_duplicate_roots(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        reject_duplicate_roots: bool,
+    ) -> Result<()> {
+        set_reject_duplicate_roots_handler(ctx, reject_duplicate_roots)
+    }
+
+    /// Set whether relaying a message with identical instructions is rejected for Protocol Config
+    /// Only the guardian can call this function. Turning the check off must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                  - The context containing the bridge account and guardians
+    /// * `reject_duplicate_ixs` - If set, messages containing two identical instructions fail to relay
+    pub fn set_reject_duplicate_ixs(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        reject_duplicate_ixs: bool,
+    ) -> Result<()> {
+        set_reject_duplicate_ixs_handler(ctx, reject_duplicate_ixs)
+    }
+
+    /// Set whether `bridge_call` and `bridge_call_buffered` require `from` to be the payer for
+    /// Protocol Config
+    /// Only the guardian can call this function. Turning the check off must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the bridge account and guardians
+    /// * `require_self_call` - If set, calls paid for by a third-party sponsor are rejected
+    pub fn set_require_self_call(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        require_self_call: bool,
+    ) -> Result<()> {
+        set_require_self_call_handler(ctx, require_self_call)
+    }
+
+    /// Set whether relaying a message at or below the highest relayed nonce is rejected for
+    /// Protocol Config
+    /// Only the guardian can call this function. Turning the check off must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                       - The context containing the bridge account and guardians
+    /// * `enforce_relay_nonce_order` - If set, messages must be relayed in increasing nonce order
+    pub fn set_enforce_relay_nonce_order(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        enforce_relay_nonce_order: bool,
+    ) -> Result<()> {
+        set_enforce_relay_nonce_order_handler(ctx, enforce_relay_nonce_order)
+    }
+
+    /// Set whether the Base and partner oracle sets must approve output roots independently for
+    /// Protocol Config
+    /// Only the guardian can call this function. Turning the check off must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                  - The context containing the bridge account and guardians
+    /// * `disjoint_signer_sets` - If set, a signer in both sets fails registration instead of only
+    ///   emitting an event
+    pub fn set_disjoint_signer_sets(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        disjoint_signer_sets: bool,
+    ) -> Result<()> {
+        set_disjoint_signer_sets_handler(ctx, disjoint_signer_sets)
+    }
+
+    /// Set the maximum number of wrapped mints for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling the limit must be co-signed
+    /// by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the bridge account and guardians
+    /// * `new_max` - The new maximum number of wrapped tokens (0 disables the limit)
+    pub fn set_max_wrapped_tokens(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_max: u64,
+    ) -> Result<()> {
+        set_max_wrapped_tokens_handler(ctx, new_max)
+    }
+
+    /// Add a Base token address to the remote token denylist
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the bridge account, guardian and denylist entry
+    /// * `remote_token` - The Base token address that can no longer be bridged or wrapped
+    pub fn add_denied_remote_token(
+        ctx: Context<AddDeniedRemoteToken>,
+        remote_token: [u8; 20],
+    ) -> Result<()> {
+        add_denied_remote_token_handler(ctx, remote_token)
+    }
+
+    /// Remove a Base token address from the remote token denylist
+    /// Only the guardian can call this function, co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the bridge account, guardians and denylist entry
+    /// * `remote_token` - The Base token address to allow again
+    pub fn remove_denied_remote_token(
+        ctx: Context<RemoveDeniedRemoteToken>,
+        remote_token: [u8; 20],
+    ) -> Result<()> {
+        remove_denied_remote_token_handler(ctx, remote_token)
+    }
+
+    /// Set the outflow limit of a Base token address, checked by every bridge of that token
+    /// Only the guardian can call this function. Removing a limit, raising its maximum or
+    /// shortening its window must be co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the bridge account, guardians and rate limit
+    /// * `remote_token`   - The Base token address to limit
+    /// * `max_per_window` - The maximum amount, in local token units, bridged per window (0 removes the limit)
+    /// * `window_seconds` - The window duration in seconds
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        remote_token: [u8; 20],
+        max_per_window: u64,
+        window_seconds: u64,
+    ) -> Result<()> {
+        set_rate_limit_handler(ctx, remote_token, max_per_window, window_seconds)
+    }
+
+    /// Set the lamport amount above which SOL finalizations require a guardian co-signature for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling the threshold must be
+    /// co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`           - The context containing the bridge account and guardians
+    /// * `new_threshold` - The new threshold in lamports (0 disables the check)
+    pub fn set_large_finalize_threshold(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        new_threshold: u64,
+    ) -> Result<()> {
+        set_large_finalize_threshold_handler(ctx, new_threshold)
+    }
+
+    /// Set the daily limits on SOL bridged in each direction for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling either limit must be
+    /// co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the bridge account and guardians
+    /// * `outbound_limit` - Maximum lamports bridged to Base per fixed daily window (0 disables
+    ///   the limit)
+    /// * `inbound_limit`  - Maximum lamports finalized from Base per fixed daily window (0
+    ///   disables the limit)
+    pub fn set_daily_sol_volume_limits(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        outbound_limit: u64,
+        inbound_limit: u64,
+    ) -> Result<()> {
+        set_daily_sol_volume_limits_handler(ctx, outbound_limit, inbound_limit)
+    }
+
+    /// Set the leaky bucket rate limit on output root registrations for Protocol Config
+    /// Only the guardian can call this function. Raising or disabling the capacity, or shortening
+    /// the refill interval, must be co-signed by the co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`                     - The context containing the bridge account and guardians
+    /// * `capacity`                - Maximum registrations in a burst (0 disables the limit)
+    /// * `refill_interval_seconds` - Seconds after which one registration is returned to the bucket
+    pub fn set_registration_rate_limit(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        capacity: u64,
+        refill_interval_seconds: u64,
+    ) -> Result<()> {
+        set_registration_rate_limit_handler(ctx, capacity, refill_interval_seconds)
+    }
+
     /// Set the max call buffer size for Buffer Config
     /// Only the guardian can call this function
     ///
@@ -548,23 +1264,67 @@ pub mod bridge {
         set_max_call_buffer_size_handler(ctx, new_size)
     }
 
-    /// Set the pause status for the bridge
+    /// Set the max prove buffer proof length for Buffer Config
     /// Only the guardian can call this function
     ///
     /// # Arguments
     /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_len` - The new max number of proof nodes a prove buffer can hold
+    pub fn set_max_prove_buffer_proof_len(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_len: u64,
+    ) -> Result<()> {
+        set_max_prove_buffer_proof_len_handler(ctx, new_len)
+    }
+
+    /// Set the max number of appends per call buffer for Buffer Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the bridge account and guardian
+    /// * `new_max` - The new max number of appends to a single call buffer (0 disables the limit)
+    pub fn set_max_call_buffer_appends(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_max: u64,
+    ) -> Result<()> {
+        set_max_call_buffer_appends_handler(ctx, new_max)
+    }
+
+    /// Set the pause status for the bridge
+    /// Only the guardian can call this function. Unpausing must be co-signed by the co-guardian if
+    /// set
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
     /// * `new_paused` - The new pause status (true for paused, false for unpaused)
     pub fn set_pause_status(
-        ctx: Context<SetBridgeConfigFromGuardian>,
+        ctx: Context<SetBridgeConfigFromGuardians>,
         new_paused: bool,
     ) -> Result<()> {
         set_pause_status_handler(ctx, new_paused)
     }
 
+    /// Pause individual bridge operations without stopping the whole bridge
+    /// Only the guardian can call this function. Clearing a flag must be co-signed by the
+    /// co-guardian if set
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account and guardian
+    /// * `paused_ops` - Bitmask of paused operations (`PAUSE_PROVE`, `PAUSE_RELAY`,
+    ///   `PAUSE_BRIDGE_TOKEN`, `PAUSE_BRIDGE_CALL`, `PAUSE_REGISTER_ROOT`)
+    pub fn set_paused_ops(
+        ctx: Context<SetBridgeConfigFromGuardians>,
+        paused_ops: u16,
+    ) -> Result<()> {
+        set_paused_ops_handler(ctx, paused_ops)
+    }
+
     /// Update the partner oracle configuration containing the required signature threshold
+    /// Only the upgrade authority can call this function, together with the co-guardian when one
+    /// is configured
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `ctx` - The context containing the bridge account, upgrade authority and co-guardian
     /// * `new_config` - The new partner oracle config
     pub fn set_partner_oracle_config(
         ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
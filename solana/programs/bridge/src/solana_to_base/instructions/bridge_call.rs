@@ -1,9 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_CALL},
     solana_to_base::{
-        internal::bridge_call::bridge_call_internal, Call, OutgoingMessage, OUTGOING_MESSAGE_SEED,
+        internal::bridge_call::bridge_call_internal, Call, OutgoingMessage, OutgoingMessageReceipt,
+        OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -59,6 +60,7 @@ pub struct BridgeCall<'info> {
 
 /// Handler for `bridge_call`.
 /// - Fails if the bridge is paused
+/// - Fails if the protocol requires self calls and `from` is not the payer
 /// - Validates the call
 /// - Charges gas and updates EIP-1559 state
 /// - Persists the `OutgoingMessage` and increments the nonce
@@ -66,9 +68,18 @@ pub fn bridge_call_handler(
     ctx: Context<BridgeCall>,
     _outgoing_message_salt: [u8; 32],
     call: Call,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_CALL)?;
+
+    // Optionally disallow a third-party sponsor paying for the call
+    if ctx.accounts.bridge.protocol_config.require_self_call {
+        require_keys_eq!(
+            ctx.accounts.from.key(),
+            ctx.accounts.payer.key(),
+            BridgeError::SponsorNotAllowed
+        );
+    }
     bridge_call_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
@@ -95,11 +106,17 @@ mod tests {
 
     use crate::{
         accounts,
-        common::bridge::Bridge,
-        instruction::BridgeCall as BridgeCallIx,
-        solana_to_base::CallType,
+        common::{bridge::Bridge, FeeBounds, PAUSE_ALL_OPS},
+        instruction::{
+            BridgeCall as BridgeCallIx, GetFeeBounds as GetFeeBoundsIx,
+            SetCallTypeGasMultiplier as SetCallTypeGasMultiplierIx,
+            SetMaximumFeeLamports as SetMaximumFeeLamportsIx,
+            SetRequireSelfCall as SetRequireSelfCallIx,
+        },
+        solana_to_base::{CallType, OUTGOING_MESSAGE_SEED},
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, setup_bridge, update_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -162,7 +179,8 @@ mod tests {
         );
 
         // Send the transaction
-        svm.send_transaction(tx)
+        let meta = svm
+            .send_transaction(tx)
             .expect("Failed to send bridge_call transaction");
 
         // Assert the OutgoingMessage account was created correctly
@@ -172,6 +190,18 @@ mod tests {
         let outgoing_message_data =
             OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
 
+        // The returned receipt identifies the persisted message
+        assert_eq!(meta.return_data.program_id, ID);
+        let receipt = OutgoingMessageReceipt::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            receipt,
+            OutgoingMessageReceipt {
+                nonce: outgoing_message_data.nonce,
+                outgoing_message,
+                message_hash: outgoing_message_data.message_hash(&outgoing_message),
+            }
+        );
+
         // Verify the message fields
         assert_eq!(outgoing_message_data.nonce, 0);
         assert_eq!(outgoing_message_data.sender, from.pubkey());
@@ -277,13 +307,7 @@ mod tests {
         } = setup_bridge();
 
         // Pause the bridge first
-        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_account.data = new_data;
-        svm.set_account(bridge_pda, bridge_account).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         // Create from account
         let from = Keypair::new();
@@ -344,4 +368,489 @@ mod tests {
             error_string
         );
     }
+
+    fn send_bridge_call_with_paused_ops(
+        paused_ops: u16,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        update_bridge(&mut svm, |bridge| bridge.paused_ops = paused_ops);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCall {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                bridge: bridge_pda,
+                outgoing_message,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: BridgeCallIx {
+                outgoing_message_salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![1, 2, 3, 4],
+                },
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bridge_call_only_blocked_by_its_paused_op() {
+        // Pausing every other operation leaves bridge_call available
+        send_bridge_call_with_paused_ops(PAUSE_ALL_OPS & !PAUSE_BRIDGE_CALL)
+            .expect("bridge_call should succeed when only other operations are paused");
+
+        let error_string = format!(
+            "{:?}",
+            send_bridge_call_with_paused_ops(PAUSE_BRIDGE_CALL).unwrap_err()
+        );
+        assert!(
+            error_string.contains("BridgePaused"),
+            "Expected BridgePaused error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_call_charges_minimum_fee_when_scaled_fee_rounds_to_zero() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // A scaler this small rounds the fee down to zero; the floor must still be charged
+        let minimum_fee_lamports = 5_000;
+        update_bridge(&mut svm, |bridge| {
+            bridge.gas_config.gas_cost_scaler = 1;
+            bridge.gas_config.minimum_fee_lamports = minimum_fee_lamports;
+        });
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeCall {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeCallIx {
+                outgoing_message_salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![0x12, 0x34],
+                },
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let receiver_initial_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_call transaction");
+
+        let receiver_final_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert_eq!(
+            receiver_final_balance - receiver_initial_balance,
+            minimum_fee_lamports
+        );
+    }
+
+    /// Sends a `bridge_call` and returns the fee received by the gas fee receiver.
+    fn bridge_call_fee(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        outgoing_message_salt: [u8; 32],
+        call: Call,
+    ) -> u64 {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let outgoing_message = Pubkey::find_program_address(
+            &[OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let accounts = accounts::BridgeCall {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeCallIx {
+                outgoing_message_salt,
+                call,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let receiver_initial_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_call transaction");
+        svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports - receiver_initial_balance
+    }
+
+    #[test]
+    fn test_bridge_call_create2_charged_more_than_call() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        // Charge Create2 deployments three times the base fee
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: SetCallTypeGasMultiplierIx {
+                ty: CallType::Create2,
+                multiplier_bps: 30_000,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to set call type gas multiplier");
+
+        let data = vec![0x12, 0x34, 0x56, 0x78];
+        let call_fee = bridge_call_fee(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [1u8; 32],
+            Call {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0,
+                data: data.clone(),
+            },
+        );
+        let create2_fee = bridge_call_fee(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [2u8; 32],
+            Call {
+                ty: CallType::Create2,
+                to: [0u8; 20],
+                value: 0,
+                data,
+            },
+        );
+
+        assert!(call_fee > 0);
+        assert_eq!(create2_fee, call_fee * 3);
+    }
+
+    fn get_fee_bounds(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> FeeBounds {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetFeeBounds { bridge: bridge_pda }.to_account_metas(None),
+            data: GetFeeBoundsIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("get_fee_bounds should succeed");
+        svm.expire_blockhash();
+
+        FeeBounds::try_from_slice(&meta.return_data.data).unwrap()
+    }
+
+    #[test]
+    fn test_bridge_call_fee_within_fee_bounds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let set_maximum_fee = |svm: &mut litesvm::LiteSVM, new_maximum_fee: u64| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::SetBridgeConfigFromGuardian {
+                    bridge: bridge_pda,
+                    guardian: guardian.pubkey(),
+                }
+                .to_account_metas(None),
+                data: SetMaximumFeeLamportsIx { new_maximum_fee }.data(),
+            };
+            let tx = Transaction::new(
+                &[&guardian],
+                Message::new(&[ix], Some(&guardian.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).expect("Failed to set maximum fee");
+            svm.expire_blockhash();
+        };
+        let call = Call {
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            data: vec![0x12, 0x34],
+        };
+
+        set_maximum_fee(&mut svm, LAMPORTS_PER_SOL);
+        let bounds = get_fee_bounds(&mut svm, &payer, bridge_pda);
+        assert_eq!(bounds.maximum_fee, Some(LAMPORTS_PER_SOL));
+
+        let fee = bridge_call_fee(&mut svm, &payer, bridge_pda, [1u8; 32], call.clone());
+        assert_eq!(fee, bounds.current_fee);
+        assert!(bounds.minimum_fee <= fee && fee <= LAMPORTS_PER_SOL);
+
+        // A ceiling below the current fee caps what is charged
+        let ceiling = fee - 1;
+        set_maximum_fee(&mut svm, ceiling);
+        let bounds = get_fee_bounds(&mut svm, &payer, bridge_pda);
+        assert_eq!(bounds.maximum_fee, Some(ceiling));
+        assert_eq!(bounds.current_fee, ceiling);
+
+        let fee = bridge_call_fee(&mut svm, &payer, bridge_pda, [2u8; 32], call);
+        assert_eq!(fee, ceiling);
+        assert!(bounds.minimum_fee <= fee);
+    }
+
+    fn set_require_self_call(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        require_self_call: bool,
+    ) {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: SetRequireSelfCallIx { require_self_call }.data(),
+        };
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to set require_self_call");
+    }
+
+    fn send_bridge_call_from(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        from: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeCall {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeCallIx {
+                outgoing_message_salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![0x12, 0x34],
+                },
+            }
+            .data(),
+        };
+
+        let mut signers = vec![payer];
+        if from.pubkey() != payer.pubkey() {
+            signers.push(from);
+        }
+        let tx = Transaction::new(
+            &signers,
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bridge_call_require_self_call_rejects_sponsor() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        // A sponsored call is allowed unless the guardian requires self calls
+        send_bridge_call_from(&mut svm, &payer, &from, bridge_pda)
+            .expect("sponsored bridge_call should succeed without require_self_call");
+
+        set_require_self_call(&mut svm, &guardian, bridge_pda, true);
+
+        let result = send_bridge_call_from(&mut svm, &payer, &from, bridge_pda);
+        assert!(result.is_err(), "Expected sponsored bridge_call to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("SponsorNotAllowed"),
+            "Expected SponsorNotAllowed error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_call_require_self_call_allows_self_bridging() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        set_require_self_call(&mut svm, &guardian, bridge_pda, true);
+
+        send_bridge_call_from(&mut svm, &payer, &payer, bridge_pda)
+            .expect("self-bridging call should succeed with require_self_call");
+    }
+
+    #[test]
+    fn test_set_require_self_call_with_non_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardians {
+                bridge: bridge_pda,
+                guardian: payer.pubkey(),
+                co_guardian: None,
+            }
+            .to_account_metas(None),
+            data: SetRequireSelfCallIx {
+                require_self_call: true,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected non-guardian setter to fail");
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
 }
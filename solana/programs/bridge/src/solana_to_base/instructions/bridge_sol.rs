@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN, SOL_VAULT_SEED},
     solana_to_base::{
-        internal::bridge_sol::bridge_sol_internal, Call, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::bridge_sol::bridge_sol_internal, Call, OutgoingMessage, OutgoingMessageReceipt,
+        Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -47,6 +47,19 @@ pub struct BridgeSol<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+    /// if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+    /// exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The outgoing message account that stores cross-chain transfer details.
     /// - Created fresh for each bridge operation
     /// - Payer funds the account creation
@@ -71,22 +84,36 @@ pub fn bridge_sol_handler(
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    reserve_lamports: Option<u64>,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
-    bridge_sol_internal(
+    let receipt = bridge_sol_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
         &ctx.accounts.sol_vault,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         to,
         amount,
         call,
-    )
+    )?;
+
+    // Opt-in guard against bridging away the balance needed for future fees. Only `from` is
+    // checked: a sponsoring payer only pays rent and fees, which it already had to cover
+    if let Some(reserve_lamports) = reserve_lamports {
+        require!(
+            ctx.accounts.from.lamports() >= reserve_lamports,
+            BridgeError::WouldDrainPayer
+        );
+    }
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -104,11 +131,16 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, SOL_VAULT_SEED},
+        common::{
+            bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit, DAILY_VOLUME_WINDOW_SECONDS,
+            SOL_VAULT_SEED,
+        },
         instruction::BridgeSol as BridgeSolIx,
         solana_to_base::{Call, CallType, NATIVE_SOL_PUBKEY},
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, deny_remote_token, mock_clock, set_remote_token_rate_limit,
+            setup_bridge, update_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_REMOTE_SOL_ADDRESS,
         },
         ID,
     };
@@ -143,6 +175,8 @@ mod tests {
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             outgoing_message,
             system_program: system_program::ID,
         }
@@ -157,6 +191,7 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                reserve_lamports: None,
             }
             .data(),
         };
@@ -219,6 +254,7 @@ mod tests {
         let bridge_account = svm.get_account(&bridge_pda).unwrap();
         let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
         assert_eq!(bridge_data.nonce, 1);
+        assert_eq!(bridge_data.locked_sol, amount);
     }
 
     #[test]
@@ -259,6 +295,8 @@ mod tests {
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             outgoing_message,
             system_program: system_program::ID,
         }
@@ -273,6 +311,7 @@ mod tests {
                 to,
                 amount,
                 call: Some(call.clone()),
+                reserve_lamports: None,
             }
             .data(),
         };
@@ -352,6 +391,8 @@ mod tests {
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             outgoing_message,
             system_program: system_program::ID,
         }
@@ -366,6 +407,7 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                reserve_lamports: None,
             }
             .data(),
         };
@@ -403,13 +445,7 @@ mod tests {
         } = setup_bridge();
 
         // Pause the bridge first
-        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_account.data = new_data;
-        svm.set_account(bridge_pda, bridge_account).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         // Create from account
         let from = Keypair::new();
@@ -432,6 +468,8 @@ mod tests {
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             outgoing_message,
             system_program: system_program::ID,
         }
@@ -446,6 +484,7 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                reserve_lamports: None,
             }
             .data(),
         };
@@ -472,4 +511,303 @@ mod tests {
             error_string
         );
     }
+
+    fn send_bridge_sol_with_reserve(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        from: &Keypair,
+        bridge_pda: Pubkey,
+        amount: u64,
+        reserve_lamports: Option<u64>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            sol_vault,
+            bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
+            outgoing_message,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: None,
+                reserve_lamports,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bridge_sol_respects_reserve_lamports() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            LAMPORTS_PER_SOL * 4,
+            Some(LAMPORTS_PER_SOL),
+        )
+        .expect("bridge leaving exactly the reserve should succeed");
+
+        let from_balance = svm.get_account(&from.pubkey()).unwrap().lamports;
+        assert_eq!(from_balance, LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn test_bridge_sol_fails_when_it_would_drain_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let result = send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            LAMPORTS_PER_SOL * 4 + 1,
+            Some(LAMPORTS_PER_SOL),
+        );
+        assert!(result.is_err(), "Expected draining bridge to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("WouldDrainPayer"), "Unexpected error: {}", err);
+
+        // Without a reserve the same bridge goes through
+        svm.expire_blockhash();
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            LAMPORTS_PER_SOL * 4 + 1,
+            None,
+        )
+        .expect("bridge without reserve should succeed");
+    }
+
+    #[test]
+    fn test_bridge_sol_reserve_ignores_sponsoring_payer_balance() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The sponsor holds less than the reserve, `from` keeps exactly the reserve
+        let sponsor = Keypair::new();
+        svm.airdrop(&sponsor.pubkey(), LAMPORTS_PER_SOL / 2)
+            .unwrap();
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &sponsor,
+            &from,
+            bridge_pda,
+            LAMPORTS_PER_SOL * 4,
+            Some(LAMPORTS_PER_SOL),
+        )
+        .expect("a sponsored bridge leaving `from` at the reserve should succeed");
+
+        let from_balance = svm.get_account(&from.pubkey()).unwrap().lamports;
+        assert_eq!(from_balance, LAMPORTS_PER_SOL);
+        let sponsor_balance = svm.get_account(&sponsor.pubkey()).unwrap().lamports;
+        assert!(sponsor_balance < LAMPORTS_PER_SOL);
+    }
+
+    #[test]
+    fn test_bridge_sol_daily_limit_resets_after_window() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let now = 1747440000;
+        mock_clock(&mut svm, now);
+
+        // Allow at most 3 SOL to be bridged out per day
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.daily_outbound_sol_limit = 3 * LAMPORTS_PER_SOL;
+        });
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 10).unwrap();
+
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        )
+        .expect("bridge_sol within the daily limit should succeed");
+
+        let result = send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        );
+        assert!(
+            result.is_err(),
+            "Expected bridge_sol over the daily limit to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("DailyLimitExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+
+        // Once the window rolls over the full limit is available again
+        mock_clock(&mut svm, now + DAILY_VOLUME_WINDOW_SECONDS as i64);
+        svm.expire_blockhash();
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        )
+        .expect("bridge_sol should succeed after the window rolls over");
+    }
+
+    #[test]
+    fn test_bridge_sol_fails_when_remote_sol_address_denied() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        deny_remote_token(&mut svm, TEST_REMOTE_SOL_ADDRESS);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let result = send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            LAMPORTS_PER_SOL,
+            None,
+        );
+        assert!(result.is_err(), "Expected bridge of denied SOL to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("RemoteTokenDenied"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_fails_when_remote_token_rate_limit_exceeded() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let now = 1747440000;
+        mock_clock(&mut svm, now);
+
+        set_remote_token_rate_limit(
+            &mut svm,
+            TEST_REMOTE_SOL_ADDRESS,
+            3 * LAMPORTS_PER_SOL,
+            3_600,
+            now,
+        );
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 10).unwrap();
+
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        )
+        .expect("bridge_sol within the rate limit should succeed");
+
+        let result = send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        );
+        assert!(
+            result.is_err(),
+            "Expected bridge over the rate limit to fail"
+        );
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("RateLimitExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+
+        mock_clock(&mut svm, now + 3_600);
+        svm.expire_blockhash();
+        send_bridge_sol_with_reserve(
+            &mut svm,
+            &payer,
+            &from,
+            bridge_pda,
+            2 * LAMPORTS_PER_SOL,
+            None,
+        )
+        .expect("bridge_sol should succeed once the rate limit window elapses");
+    }
 }
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN, TOKEN_VAULT_SEED,
+    },
     solana_to_base::{
-        internal::bridge_spl::bridge_spl_internal, Call, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::bridge_spl::bridge_spl_internal, Call, OutgoingMessage, OutgoingMessageReceipt,
+        Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -53,6 +55,17 @@ pub struct BridgeSpl<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of the bridged remote token; the instruction fails if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of the bridged remote token, recorded against when it exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The token vault account that holds locked SPL tokens during the bridge process.
     /// - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
     /// - Created if it doesn't exist for this mint/remote_token pair
@@ -98,9 +111,9 @@ pub fn bridge_spl_handler(
     remote_token: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
     bridge_spl_internal(
         &ctx.accounts.payer,
@@ -109,6 +122,8 @@ pub fn bridge_spl_handler(
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.token_vault,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
@@ -136,12 +151,13 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, TOKEN_VAULT_SEED},
+        common::{bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit, TOKEN_VAULT_SEED},
         instruction::BridgeSpl as BridgeSplIx,
         solana_to_base::{Call, CallType},
         test_utils::{
-            create_mock_mint, create_mock_token_account, create_outgoing_message, setup_bridge,
-            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            deny_remote_token, setup_bridge, update_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -202,6 +218,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
@@ -340,6 +358,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
@@ -454,6 +474,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
@@ -508,13 +530,7 @@ mod tests {
         } = setup_bridge();
 
         // Pause the bridge first
-        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_account.data = new_data;
-        svm.set_account(bridge_pda, bridge_account).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         // Create from account
         let from = Keypair::new();
@@ -564,6 +580,8 @@ mod tests {
             token_vault,
             mint,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             outgoing_message,
             token_program: anchor_spl::token_interface::spl_token_2022::ID,
             system_program: system_program::ID,
@@ -606,4 +624,86 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_spl_fails_for_denied_remote_token() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let remote_token = [2u8; 20];
+
+        deny_remote_token(&mut svm, remote_token);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(&mut svm, from_token_account, mint, from.pubkey(), 1_000_000);
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        // The denylist entry of another token does not bypass the check
+        let wrong_denied_remote_token = DeniedRemoteToken::address(&[3u8; 20]);
+        for (denied_remote_token, expected_error) in [
+            (
+                DeniedRemoteToken::address(&remote_token),
+                "RemoteTokenDenied",
+            ),
+            (wrong_denied_remote_token, "IncorrectDeniedRemoteToken"),
+        ] {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::BridgeSpl {
+                    payer: payer.pubkey(),
+                    from: from.pubkey(),
+                    gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                    from_token_account,
+                    token_vault,
+                    mint,
+                    bridge: bridge_pda,
+                    denied_remote_token,
+                    rate_limit: RemoteTokenRateLimit::address(&remote_token),
+                    outgoing_message,
+                    token_program: anchor_spl::token_interface::spl_token_2022::ID,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: BridgeSplIx {
+                    outgoing_message_salt,
+                    to: [1u8; 20],
+                    remote_token,
+                    amount: 500_000,
+                    call: None,
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer, &from],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            let result = svm.send_transaction(tx);
+            assert!(result.is_err(), "Expected bridge of a denied token to fail");
+            let err = format!("{:?}", result.unwrap_err());
+            assert!(err.contains(expected_error), "Unexpected error: {}", err);
+        }
+    }
 }
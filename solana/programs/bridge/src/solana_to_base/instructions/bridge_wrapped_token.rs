@@ -5,10 +5,10 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN},
     solana_to_base::{
         internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, OutgoingMessage,
-        Transfer, OUTGOING_MESSAGE_SEED,
+        OutgoingMessageReceipt, Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -53,6 +53,17 @@ pub struct BridgeWrappedToken<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of the remote token of `mint`, recorded against when it exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The outgoing message account being created to store bridge transfer data.
     /// - Contains transfer details and optional call data for Base execution
     /// - Space allocated based on call data size
@@ -81,9 +92,9 @@ pub fn bridge_wrapped_token_handler(
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
     bridge_wrapped_token_internal(
         &ctx.accounts.payer,
@@ -92,6 +103,8 @@ pub fn bridge_wrapped_token_handler(
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
@@ -117,12 +130,12 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, PartialTokenMetadata},
+        common::{bridge::Bridge, DeniedRemoteToken, PartialTokenMetadata, RemoteTokenRateLimit},
         instruction::BridgeWrappedToken as BridgeWrappedTokenIx,
         solana_to_base::{Call, CallType},
         test_utils::{
             create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
-            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            setup_bridge, update_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -178,6 +191,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
@@ -303,6 +318,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
@@ -410,6 +427,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
@@ -462,13 +481,7 @@ mod tests {
         } = setup_bridge();
 
         // Pause the bridge first
-        let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
-        let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
-        bridge.paused = true;
-        let mut new_data = Vec::new();
-        bridge.try_serialize(&mut new_data).unwrap();
-        bridge_account.data = new_data;
-        svm.set_account(bridge_pda, bridge_account).unwrap();
+        update_bridge(&mut svm, |bridge| bridge.paused = true);
 
         // Create from account
         let from = Keypair::new();
@@ -512,6 +525,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             outgoing_message,
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
@@ -553,4 +568,98 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_wrapped_token_fails_with_foreign_mint() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Create from account
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 0,
+        };
+
+        // Build a mint that looks exactly like a wrapped token but lives at an address that
+        // was not derived by wrap_token
+        let initial_amount = 1_000_000u64;
+        let wrapped_mint =
+            create_mock_wrapped_mint(&mut svm, initial_amount, 6, &partial_token_metadata);
+        let foreign_mint = Keypair::new().pubkey();
+        let mint_account = svm.get_account(&wrapped_mint).unwrap();
+        svm.set_account(foreign_mint, mint_account).unwrap();
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            from_token_account,
+            foreign_mint,
+            from.pubkey(),
+            initial_amount,
+        );
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeWrappedToken {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            mint: foreign_mint,
+            from_token_account,
+            bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
+            outgoing_message,
+            token_program: anchor_spl::token_2022::ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeWrappedTokenIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: 500_000,
+                call: None,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(
+            result.is_err(),
+            "Expected transaction to fail with a foreign mint"
+        );
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("MintIsNotWrappedTokenPda"),
+            "Expected MintIsNotWrappedTokenPda error, got: {}",
+            error_string
+        );
+
+        // Nothing was burned
+        let from_account = svm.get_account(&from_token_account).unwrap();
+        let from_amount = TokenAccount::try_deserialize(&mut &from_account.data[..])
+            .unwrap()
+            .amount;
+        assert_eq!(from_amount, initial_amount);
+    }
 }
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::AppendToCallBuffer, BridgeError};
+
+/// Appends several chunks of raw bytes to `call_buffer.data`, in order, within a single
+/// instruction.
+///
+/// The combined length of all chunks is checked once against the buffer's capacity (see
+/// `AppendToCallBuffer::capacity`), and `data` is grown once for the total. Each chunk is folded
+/// into `call_buffer.running_hash` separately, so appending chunks `[a, b]` leaves the same
+/// running hash as appending `a` and then `b`.
+pub fn append_chunks_to_call_buffer_handler(
+    ctx: Context<AppendToCallBuffer>,
+    chunks: Vec<Vec<u8>>,
+) -> Result<()> {
+    let capacity = ctx.accounts.capacity();
+
+    let call_buffer = &mut ctx.accounts.call_buffer;
+    call_buffer.record_append()?;
+    let total_len: usize = chunks.iter().map(Vec::len).sum();
+    require!(
+        call_buffer.data.len() + total_len <= capacity,
+        BridgeError::BufferOverflow
+    );
+
+    call_buffer.data.reserve(total_len);
+    for chunk in &chunks {
+        call_buffer.append(chunk);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::BRIDGE_SEED,
+        instruction::{
+            AppendChunksToCallBuffer as AppendChunksToCallBufferIx, InitializeCallBuffer,
+        },
+        solana_to_base::{CallBuffer, CallType},
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn setup_call_buffer(
+        svm: &mut litesvm::LiteSVM,
+        owner: &Keypair,
+        call_buffer: &Keypair,
+        initial_data: Vec<u8>,
+        max_data_len: u64,
+    ) {
+        let bridge_pda = Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0;
+        let init_accounts = accounts::InitializeCallBuffer {
+            payer: owner.pubkey(),
+            bridge: bridge_pda,
+            call_buffer: call_buffer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: init_accounts,
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0u128,
+                initial_data,
+                max_data_len,
+                expected_hash: None,
+            }
+            .data(),
+        };
+
+        let init_tx = Transaction::new(
+            &[owner, call_buffer],
+            Message::new(&[init_ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(init_tx)
+            .expect("Failed to initialize call buffer");
+    }
+
+    fn append_chunks_tx(
+        svm: &litesvm::LiteSVM,
+        owner: &Keypair,
+        call_buffer: &Keypair,
+        chunks: Vec<Vec<u8>>,
+    ) -> Transaction {
+        let accounts = accounts::AppendToCallBuffer {
+            owner: owner.pubkey(),
+            call_buffer: call_buffer.pubkey(),
+            bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: AppendChunksToCallBufferIx { chunks }.data(),
+        };
+
+        Transaction::new(
+            &[owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        )
+    }
+
+    #[test]
+    fn test_append_chunks_to_call_buffer_appends_in_order() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &owner, &call_buffer, vec![0x01], 1024);
+
+        let chunks = vec![vec![0x02, 0x03], vec![0x04], vec![0x05, 0x06, 0x07]];
+        let tx = append_chunks_tx(&svm, &owner, &call_buffer, chunks);
+        svm.send_transaction(tx)
+            .expect("Failed to send append_chunks_to_call_buffer transaction");
+
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(
+            call_buffer_data.data,
+            vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]
+        );
+
+        let expected_running_hash = [vec![0x02, 0x03], vec![0x04], vec![0x05, 0x06, 0x07]]
+            .iter()
+            .fold(
+                CallBuffer::chain_hash(&[0u8; 32], &[0x01]),
+                |hash, chunk| CallBuffer::chain_hash(&hash, chunk),
+            );
+        assert_eq!(call_buffer_data.running_hash, expected_running_hash);
+    }
+
+    #[test]
+    fn test_append_chunks_to_call_buffer_exceeding_capacity_fails() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &owner, &call_buffer, vec![], 8);
+
+        // 4 + 4 + 1 bytes: one byte more than the allocated capacity
+        let chunks = vec![vec![0xAA; 4], vec![0xBB; 4], vec![0xCC]];
+        let tx = append_chunks_tx(&svm, &owner, &call_buffer, chunks);
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err(), "Expected append over capacity to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("BufferOverflow"), "Unexpected error: {}", err);
+    }
+}
@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::{solana_to_base::CallBuffer, BridgeError};
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    solana_to_base::CallBuffer,
+    BridgeError,
+};
 
 /// Accounts struct for appending data to an existing call buffer account.
 /// This allows building up large call data over multiple transactions.
@@ -13,26 +17,50 @@ pub struct AppendToCallBuffer<'info> {
 
     /// The call buffer account to append data to.
     /// Must have been initialized with enough space to hold the resulting
-    /// data; this instruction does not reallocate and will revert if
-    /// serialization would exceed the account's allocated size.
+    /// data; this instruction does not reallocate.
     #[account(
         mut,
         has_one = owner @ BridgeError::BufferUnauthorizedAppend,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
+
+    /// The bridge account providing `buffer_config.max_call_buffer_size`
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
 }
 
-/// Appends raw bytes to `call_buffer.data`.
+impl AppendToCallBuffer<'_> {
+    /// Maximum length `call_buffer.data` may reach: the `max_data_len` the account was allocated
+    /// with, further capped by the bridge's current `max_call_buffer_size`.
+    pub fn capacity(&self) -> usize {
+        let allocated = self
+            .call_buffer
+            .to_account_info()
+            .data_len()
+            .saturating_sub(DISCRIMINATOR_LEN + CallBuffer::space(0));
+        let max_call_buffer_size =
+            usize::try_from(self.bridge.buffer_config.max_call_buffer_size).unwrap_or(usize::MAX);
+
+        allocated.min(max_call_buffer_size)
+    }
+}
+
+/// Appends raw bytes to `call_buffer.data` and folds them into `call_buffer.running_hash`.
 ///
-/// Note: No explicit max-length checks are performed here. The account must
-/// have sufficient space allocated during initialization; otherwise the
-/// transaction will fail during serialization.
+/// Fails with `BufferOverflow` if the resulting data would exceed the buffer's capacity.
 pub fn append_to_call_buffer_handler(
     ctx: Context<AppendToCallBuffer>,
     data: Vec<u8>,
 ) -> Result<()> {
+    let capacity = ctx.accounts.capacity();
+
     let call_buffer = &mut ctx.accounts.call_buffer;
-    call_buffer.data.extend_from_slice(&data);
+    call_buffer.record_append()?;
+    require!(
+        call_buffer.data.len() + data.len() <= capacity,
+        BridgeError::BufferOverflow
+    );
+    call_buffer.append(&data);
 
     Ok(())
 }
@@ -41,7 +69,10 @@ pub fn append_to_call_buffer_handler(
 mod tests {
     use super::*;
 
-    use crate::{common::BRIDGE_SEED, test_utils::SetupBridgeResult};
+    use crate::{
+        common::BRIDGE_SEED,
+        test_utils::{update_bridge, SetupBridgeResult},
+    };
     use anchor_lang::{
         solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
         system_program, InstructionData,
@@ -84,6 +115,7 @@ mod tests {
                 value: 0u128,
                 initial_data,
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -100,7 +132,11 @@ mod tests {
 
     #[test]
     fn test_append_to_call_buffer_success() {
-        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
         // Create owner account
         let owner = Keypair::new();
@@ -120,6 +156,7 @@ mod tests {
         let accounts = accounts::AppendToCallBuffer {
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
+            bridge: bridge_pda,
         }
         .to_account_metas(None);
 
@@ -149,6 +186,18 @@ mod tests {
         let call_buffer_data =
             CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
 
+        // The running hash chains the segments in the order they were written
+        let running_hash = CallBuffer::chain_hash(
+            &CallBuffer::chain_hash(&[0u8; 32], &initial_data),
+            &append_data,
+        );
+        assert_eq!(call_buffer_data.running_hash, running_hash);
+        let reordered_hash = CallBuffer::chain_hash(
+            &CallBuffer::chain_hash(&[0u8; 32], &append_data),
+            &initial_data,
+        );
+        assert_ne!(call_buffer_data.running_hash, reordered_hash);
+
         let mut expected_data = initial_data;
         expected_data.extend_from_slice(&append_data);
         assert_eq!(call_buffer_data.data, expected_data);
@@ -156,7 +205,11 @@ mod tests {
 
     #[test]
     fn test_append_to_call_buffer_unauthorized() {
-        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
 
         // Create owner account
         let owner = Keypair::new();
@@ -181,6 +234,7 @@ mod tests {
         let accounts = accounts::AppendToCallBuffer {
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
+            bridge: bridge_pda,
         }
         .to_account_metas(None);
 
@@ -213,4 +267,110 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_append_to_call_buffer_fails_after_max_appends() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Allow two appends per call buffer
+        update_bridge(&mut svm, |bridge| {
+            bridge.buffer_config.max_call_buffer_appends = 2;
+        });
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &owner, &call_buffer, vec![]);
+
+        let append = |svm: &mut litesvm::LiteSVM, byte: u8| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::AppendToCallBuffer {
+                    owner: owner.pubkey(),
+                    call_buffer: call_buffer.pubkey(),
+                    bridge: bridge_pda,
+                }
+                .to_account_metas(None),
+                data: AppendToCallBufferIx { data: vec![byte] }.data(),
+            };
+            let tx = Transaction::new(
+                &[&owner],
+                Message::new(&[ix], Some(&owner.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).map_err(Box::new)
+        };
+
+        append(&mut svm, 0x01).expect("first append should succeed");
+        append(&mut svm, 0x02).expect("second append should succeed");
+
+        let result = append(&mut svm, 0x03);
+        assert!(result.is_err(), "Expected append over the cap to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("TooManyAppends"), "Unexpected error: {}", err);
+
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(call_buffer_data.append_count, 2);
+        assert_eq!(call_buffer_data.data, vec![0x01, 0x02]);
+    }
+
+    #[test]
+    fn test_append_to_call_buffer_capped_by_max_call_buffer_size() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &owner, &call_buffer, vec![0x01; 4]);
+
+        // Lower the bridge cap below the 1024 bytes the buffer was allocated with
+        update_bridge(&mut svm, |bridge| {
+            bridge.buffer_config.max_call_buffer_size = 8;
+        });
+
+        let append = |svm: &mut litesvm::LiteSVM, data: Vec<u8>| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::AppendToCallBuffer {
+                    owner: owner.pubkey(),
+                    call_buffer: call_buffer.pubkey(),
+                    bridge: bridge_pda,
+                }
+                .to_account_metas(None),
+                data: AppendToCallBufferIx { data }.data(),
+            };
+            let tx = Transaction::new(
+                &[&owner],
+                Message::new(&[ix], Some(&owner.pubkey())),
+                svm.latest_blockhash(),
+            );
+            let result = svm.send_transaction(tx).map_err(Box::new);
+            svm.expire_blockhash();
+            result
+        };
+
+        // One byte over the cap
+        let err = format!("{:?}", append(&mut svm, vec![0x02; 5]).unwrap_err());
+        assert!(err.contains("BufferOverflow"), "Unexpected error: {}", err);
+
+        // Exactly up to the cap
+        append(&mut svm, vec![0x02; 4]).expect("append up to the cap should succeed");
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(call_buffer_data.data.len(), 8);
+
+        let err = format!("{:?}", append(&mut svm, vec![0x03]).unwrap_err());
+        assert!(err.contains("BufferOverflow"), "Unexpected error: {}", err);
+    }
 }
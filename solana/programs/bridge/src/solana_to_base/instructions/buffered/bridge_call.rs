@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_CALL},
     solana_to_base::{
         internal::bridge_call::bridge_call_internal, Call, CallBuffer, OutgoingMessage,
-        OUTGOING_MESSAGE_SEED,
+        OutgoingMessageReceipt, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -76,11 +76,21 @@ pub struct BridgeCallBuffered<'info> {
 pub fn bridge_call_buffered_handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, BridgeCallBuffered<'info>>,
     _outgoing_message_salt: [u8; 32],
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_CALL)?;
+
+    // Optionally disallow a third-party sponsor paying for the call
+    if ctx.accounts.bridge.protocol_config.require_self_call {
+        require_keys_eq!(
+            ctx.accounts.from.key(),
+            ctx.accounts.payer.key(),
+            BridgeError::SponsorNotAllowed
+        );
+    }
 
     let call_buffer = &ctx.accounts.call_buffer;
+    call_buffer.verify_checksum()?;
     let call = Call {
         ty: call_buffer.ty,
         to: call_buffer.to,
@@ -104,7 +114,7 @@ mod tests {
     use super::*;
 
     use anchor_lang::{
-        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        solana_program::{instruction::Instruction, keccak, native_token::LAMPORTS_PER_SOL},
         system_program, InstructionData,
     };
     use solana_keypair::Keypair;
@@ -115,7 +125,10 @@ mod tests {
     use crate::{
         accounts,
         common::bridge::Bridge,
-        instruction::{BridgeCallBuffered as BridgeCallBufferedIx, InitializeCallBuffer},
+        instruction::{
+            AppendToCallBuffer as AppendToCallBufferIx, BridgeCallBuffered as BridgeCallBufferedIx,
+            InitializeCallBuffer,
+        },
         solana_to_base::CallType,
         test_utils::{
             create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
@@ -123,6 +136,122 @@ mod tests {
         ID,
     };
 
+    /// Initializes a call buffer committed to the keccak hash of `expected_data`, appends
+    /// `chunks` one transaction at a time, then bridges it.
+    fn bridge_call_buffered_with_checksum(
+        expected_data: &[u8],
+        chunks: Vec<Vec<u8>>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0,
+                initial_data: vec![],
+                max_data_len: 1024,
+                expected_hash: Some(keccak::hash(expected_data).to_bytes()),
+            }
+            .data(),
+        };
+        let init_tx = Transaction::new(
+            &[&owner, &call_buffer],
+            Message::new(&[init_ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(init_tx)
+            .expect("Failed to initialize call buffer");
+
+        for data in chunks {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::AppendToCallBuffer {
+                    owner: owner.pubkey(),
+                    call_buffer: call_buffer.pubkey(),
+                    bridge: bridge_pda,
+                }
+                .to_account_metas(None),
+                data: AppendToCallBufferIx { data }.data(),
+            };
+            let tx = Transaction::new(
+                &[&owner],
+                Message::new(&[ix], Some(&owner.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .expect("Failed to append to call buffer");
+        }
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCallBuffered {
+                payer: payer.pubkey(),
+                from: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                bridge: bridge_pda,
+                owner: owner.pubkey(),
+                call_buffer: call_buffer.pubkey(),
+                outgoing_message,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: BridgeCallBufferedIx {
+                outgoing_message_salt,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer, &owner],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bridge_call_buffered_with_matching_checksum() {
+        bridge_call_buffered_with_checksum(
+            &[0x01, 0x02, 0x03, 0x04],
+            vec![vec![0x01, 0x02], vec![0x03, 0x04]],
+        )
+        .expect("bridge_call_buffered should succeed when data matches the committed hash");
+    }
+
+    #[test]
+    fn test_bridge_call_buffered_fails_on_reordered_appends() {
+        let result = bridge_call_buffered_with_checksum(
+            &[0x01, 0x02, 0x03, 0x04],
+            vec![vec![0x03, 0x04], vec![0x01, 0x02]],
+        );
+        assert!(result.is_err(), "Expected checksum mismatch to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("BufferChecksumMismatch"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
     #[test]
     fn test_bridge_call_buffered_success() {
         let SetupBridgeResult {
@@ -164,6 +293,7 @@ mod tests {
                 value: call_value,
                 initial_data: call_data.clone(),
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -301,6 +431,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -403,6 +534,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN, SOL_VAULT_SEED},
     solana_to_base::{
-        internal::bridge_sol::bridge_sol_internal, Call, CallBuffer, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::bridge_sol::bridge_sol_internal, Call, CallBuffer, OutgoingMessage,
+        OutgoingMessageReceipt, Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -48,6 +48,19 @@ pub struct BridgeSolWithBufferedCall<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of `bridge.protocol_config.remote_sol_address`; the instruction fails
+    /// if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of `bridge.protocol_config.remote_sol_address`, recorded against when it
+    /// exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The owner of the call buffer who will receive the rent refund.
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -84,11 +97,12 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
     let call_buffer = &ctx.accounts.call_buffer;
+    call_buffer.verify_checksum()?;
     let call = Some(Call {
         ty: call_buffer.ty,
         to: call_buffer.to,
@@ -102,6 +116,8 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         &ctx.accounts.gas_fee_receiver,
         &ctx.accounts.sol_vault,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         to,
@@ -125,13 +141,14 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, SOL_VAULT_SEED},
+        common::{bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit, SOL_VAULT_SEED},
         instruction::{
             BridgeSolWithBufferedCall as BridgeSolWithBufferedCallIx, InitializeCallBuffer,
         },
         solana_to_base::{CallType, NATIVE_SOL_PUBKEY},
         test_utils::{
             create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_REMOTE_SOL_ADDRESS,
         },
         ID,
     };
@@ -185,6 +202,7 @@ mod tests {
                 value: call_value,
                 initial_data: call_data.clone(),
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -211,6 +229,8 @@ mod tests {
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -355,6 +375,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -384,6 +405,8 @@ mod tests {
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -467,6 +490,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -496,6 +520,8 @@ mod tests {
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
             sol_vault,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+            rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -2,10 +2,12 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN, TOKEN_VAULT_SEED,
+    },
     solana_to_base::{
-        internal::bridge_spl::bridge_spl_internal, Call, CallBuffer, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::bridge_spl::bridge_spl_internal, Call, CallBuffer, OutgoingMessage,
+        OutgoingMessageReceipt, Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -54,6 +56,17 @@ pub struct BridgeSplWithBufferedCall<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of the bridged remote token; the instruction fails if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of the bridged remote token, recorded against when it exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The token vault account that holds locked SPL tokens during the bridge process.
     /// - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
     /// - Created if it doesn't exist for this mint/remote_token pair
@@ -107,11 +120,12 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     to: [u8; 20],
     remote_token: [u8; 20],
     amount: u64,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
     let call_buffer = &ctx.accounts.call_buffer;
+    call_buffer.verify_checksum()?;
     let call = Some(Call {
         ty: call_buffer.ty,
         to: call_buffer.to,
@@ -126,6 +140,8 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.token_vault,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
@@ -153,7 +169,7 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, TOKEN_VAULT_SEED},
+        common::{bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit, TOKEN_VAULT_SEED},
         instruction::{
             BridgeSplWithBufferedCall as BridgeSplWithBufferedCallIx, InitializeCallBuffer,
         },
@@ -235,6 +251,7 @@ mod tests {
                 value: call_value,
                 initial_data: call_data.clone(),
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -266,6 +283,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
@@ -426,6 +445,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -461,6 +481,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
@@ -567,6 +589,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -602,6 +625,8 @@ mod tests {
             mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&remote_token),
             token_vault,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
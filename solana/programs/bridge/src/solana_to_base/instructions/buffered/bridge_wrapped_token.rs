@@ -5,10 +5,10 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, PAUSE_BRIDGE_TOKEN},
     solana_to_base::{
         internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, CallBuffer,
-        OutgoingMessage, Transfer, OUTGOING_MESSAGE_SEED,
+        OutgoingMessage, OutgoingMessageReceipt, Transfer, OUTGOING_MESSAGE_SEED,
     },
     BridgeError,
 };
@@ -58,6 +58,17 @@ pub struct BridgeWrappedTokenWithBufferedCall<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of the remote token of `mint`; the instruction fails if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
+    /// The rate limit of the remote token of `mint`, recorded against when it exists.
+    /// CHECK: Checked in the handler against the PDA with RATE_LIMIT_SEED and the remote token,
+    /// and deserialized if created
+    #[account(mut)]
+    pub rate_limit: AccountInfo<'info>,
+
     /// The owner of the call buffer who will receive the rent refund.
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -94,11 +105,12 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
 
     let call_buffer = &ctx.accounts.call_buffer;
+    call_buffer.verify_checksum()?;
     let call = Some(Call {
         ty: call_buffer.ty,
         to: call_buffer.to,
@@ -113,6 +125,8 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
+        &ctx.accounts.denied_remote_token,
+        &ctx.accounts.rate_limit,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
@@ -138,7 +152,7 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, PartialTokenMetadata},
+        common::{bridge::Bridge, DeniedRemoteToken, PartialTokenMetadata, RemoteTokenRateLimit},
         instruction::{
             BridgeWrappedTokenWithBufferedCall as BridgeWrappedTokenWithBufferedCallIx,
             InitializeCallBuffer,
@@ -223,6 +237,7 @@ mod tests {
                 value: call_value,
                 initial_data: call_data.clone(),
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -247,6 +262,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -402,6 +419,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -429,6 +447,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -536,6 +556,7 @@ mod tests {
                 value: 0,
                 initial_data: vec![0x12, 0x34],
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
@@ -563,6 +584,8 @@ mod tests {
             mint: wrapped_mint,
             from_token_account,
             bridge: bridge_pda,
+            denied_remote_token: DeniedRemoteToken::address(&partial_token_metadata.remote_token),
+            rate_limit: RemoteTokenRateLimit::address(&partial_token_metadata.remote_token),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
@@ -70,6 +70,7 @@ mod tests {
                 value: 0u128,
                 initial_data,
                 max_data_len: 1024,
+                expected_hash: None,
             }
             .data(),
         };
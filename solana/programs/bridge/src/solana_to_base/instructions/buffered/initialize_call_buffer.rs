@@ -41,6 +41,8 @@ pub struct InitializeCallBuffer<'info> {
 }
 
 /// Initializes a `CallBuffer` with the provided parameters.
+/// When `expected_hash` is set, the buffered bridge instructions only accept the buffer once the
+/// keccak-256 hash of its complete `data` matches it.
 /// Note: `max_data_len` is used only for account allocation (via the accounts macro) and is not
 /// stored in the account state. If `initial_data.len()` exceeds the allocated capacity, the
 /// transaction will fail due to insufficient account space.
@@ -51,12 +53,17 @@ pub fn initialize_call_buffer_handler(
     value: u128,
     initial_data: Vec<u8>,
     _max_data_len: u64,
+    expected_hash: Option<[u8; 32]>,
 ) -> Result<()> {
     *ctx.accounts.call_buffer = CallBuffer {
         owner: ctx.accounts.payer.key(),
         ty,
         to,
         value,
+        max_appends: ctx.accounts.bridge.buffer_config.max_call_buffer_appends,
+        append_count: 0,
+        expected_hash,
+        running_hash: CallBuffer::chain_hash(&[0u8; 32], &initial_data),
         data: initial_data,
     };
 
@@ -125,6 +132,7 @@ mod tests {
                 value,
                 initial_data: initial_data.clone(),
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -196,6 +204,7 @@ mod tests {
                 value,
                 initial_data,
                 max_data_len,
+                expected_hash: None,
             }
             .data(),
         };
@@ -222,4 +231,59 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_initialize_call_buffer_max_size_boundary() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let initialize = |svm: &mut litesvm::LiteSVM, max_data_len: u64| {
+            let call_buffer = Keypair::new();
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::InitializeCallBuffer {
+                    payer: payer.pubkey(),
+                    bridge: bridge_pda,
+                    call_buffer: call_buffer.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: InitializeCallBufferIx {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    initial_data: vec![],
+                    max_data_len,
+                    expected_hash: None,
+                }
+                .data(),
+            };
+            let tx = Transaction::new(
+                &[&payer, &call_buffer],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).map_err(Box::new)
+        };
+
+        let max_call_buffer_size = 8 * 1024;
+        let err = format!(
+            "{:?}",
+            initialize(&mut svm, max_call_buffer_size + 1).unwrap_err()
+        );
+        assert!(
+            err.contains("BufferMaxSizeExceeded"),
+            "Unexpected error: {}",
+            err
+        );
+
+        initialize(&mut svm, max_call_buffer_size)
+            .expect("max_data_len equal to the bridge cap should be accepted");
+    }
 }
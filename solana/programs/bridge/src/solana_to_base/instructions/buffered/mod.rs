@@ -1,9 +1,13 @@
+pub mod append_chunks_to_call_buffer;
+pub use append_chunks_to_call_buffer::*;
 pub mod append_to_call_buffer;
 pub use append_to_call_buffer::*;
 pub mod close_call_buffer;
 pub use close_call_buffer::*;
 pub mod initialize_call_buffer;
 pub use initialize_call_buffer::*;
+pub mod transfer_call_buffer;
+pub use transfer_call_buffer::*;
 
 pub mod bridge_call;
 pub use bridge_call::*;
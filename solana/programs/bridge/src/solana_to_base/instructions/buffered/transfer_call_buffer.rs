@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::CallBuffer, BridgeError};
+
+/// Accounts struct for handing a call buffer over to a new owner.
+/// Ownership is enforced via `has_one = owner` on the `call_buffer` account.
+#[derive(Accounts)]
+pub struct TransferCallBuffer<'info> {
+    /// The current owner of the call buffer.
+    /// Must match `call_buffer.owner`.
+    pub owner: Signer<'info>,
+
+    /// The call buffer account being transferred
+    #[account(
+        mut,
+        has_one = owner @ BridgeError::BufferUnauthorizedTransfer,
+    )]
+    pub call_buffer: Account<'info, CallBuffer>,
+}
+
+/// Sets `call_buffer.owner` to `new_owner`.
+///
+/// After the transfer only `new_owner` can append to, close, or bridge the buffer. This lets a
+/// service build large call data and hand the buffer to the user who will send it.
+pub fn transfer_call_buffer_handler(
+    ctx: Context<TransferCallBuffer>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    require_keys_neq!(new_owner, Pubkey::default(), BridgeError::ZeroAddress);
+
+    ctx.accounts.call_buffer.owner = new_owner;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::BRIDGE_SEED,
+        instruction::{
+            AppendToCallBuffer as AppendToCallBufferIx, CloseCallBuffer as CloseCallBufferIx,
+            InitializeCallBuffer, TransferCallBuffer as TransferCallBufferIx,
+        },
+        solana_to_base::CallType,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn setup_call_buffer(svm: &mut litesvm::LiteSVM, owner: &Keypair, call_buffer: &Keypair) {
+        let bridge_pda = Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0;
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0u128,
+                initial_data: vec![0x01],
+                max_data_len: 1024,
+                expected_hash: None,
+            }
+            .data(),
+        };
+
+        let init_tx = Transaction::new(
+            &[owner, call_buffer],
+            Message::new(&[init_ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(init_tx)
+            .expect("Failed to initialize call buffer");
+    }
+
+    fn send_ix(
+        svm: &mut litesvm::LiteSVM,
+        signer: &Keypair,
+        ix: Instruction,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let tx = Transaction::new(
+            &[signer],
+            Message::new(&[ix], Some(&signer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)?;
+        Ok(())
+    }
+
+    fn transfer_ix(owner: &Keypair, call_buffer: &Keypair, new_owner: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::TransferCallBuffer {
+                owner: owner.pubkey(),
+                call_buffer: call_buffer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: TransferCallBufferIx { new_owner }.data(),
+        }
+    }
+
+    fn append_ix(owner: &Keypair, call_buffer: &Keypair, data: Vec<u8>) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::AppendToCallBuffer {
+                owner: owner.pubkey(),
+                call_buffer: call_buffer.pubkey(),
+                bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+            }
+            .to_account_metas(None),
+            data: AppendToCallBufferIx { data }.data(),
+        }
+    }
+
+    fn close_ix(owner: &Keypair, call_buffer: &Keypair) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::CloseCallBuffer {
+                owner: owner.pubkey(),
+                call_buffer: call_buffer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: CloseCallBufferIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_transfer_call_buffer_hands_over_ownership() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let old_owner = Keypair::new();
+        svm.airdrop(&old_owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let new_owner = Keypair::new();
+        svm.airdrop(&new_owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &old_owner, &call_buffer);
+
+        let ix = transfer_ix(&old_owner, &call_buffer, new_owner.pubkey());
+        send_ix(&mut svm, &old_owner, ix).expect("transfer_call_buffer should succeed");
+
+        let account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let buffer = CallBuffer::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(buffer.owner, new_owner.pubkey());
+
+        // The previous owner can no longer append or close
+        let ix = append_ix(&old_owner, &call_buffer, vec![0x02]);
+        let err = format!("{:?}", send_ix(&mut svm, &old_owner, ix).unwrap_err());
+        assert!(
+            err.contains("BufferUnauthorizedAppend"),
+            "Unexpected error: {}",
+            err
+        );
+
+        let ix = close_ix(&old_owner, &call_buffer);
+        let err = format!("{:?}", send_ix(&mut svm, &old_owner, ix).unwrap_err());
+        assert!(
+            err.contains("BufferUnauthorizedClose"),
+            "Unexpected error: {}",
+            err
+        );
+
+        // The new owner can
+        let ix = append_ix(&new_owner, &call_buffer, vec![0x02]);
+        send_ix(&mut svm, &new_owner, ix).expect("new owner should be able to append");
+
+        let account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let buffer = CallBuffer::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(buffer.data, vec![0x01, 0x02]);
+
+        let ix = close_ix(&new_owner, &call_buffer);
+        send_ix(&mut svm, &new_owner, ix).expect("new owner should be able to close");
+        assert_eq!(svm.get_account(&call_buffer.pubkey()).unwrap().lamports, 0);
+    }
+
+    #[test]
+    fn test_transfer_call_buffer_rejects_zero_owner() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, &owner, &call_buffer);
+
+        let ix = transfer_ix(&owner, &call_buffer, Pubkey::default());
+        let err = format!("{:?}", send_ix(&mut svm, &owner, ix).unwrap_err());
+        assert!(err.contains("ZeroAddress"), "Unexpected error: {}", err);
+    }
+}
@@ -21,6 +21,9 @@ pub use bridge_wrapped_token::*;
 pub mod buffered;
 pub use buffered::*;
 
+pub mod quote_total_cost;
+pub use quote_total_cost::*;
+
 pub fn check_call(call: &Call) -> Result<()> {
     require!(
         matches!(call.ty, CallType::Call | CallType::DelegateCall) || call.to == [0; 20],
@@ -34,6 +37,7 @@ pub fn pay_for_gas<'info>(
     payer: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
     bridge: &mut Bridge,
+    call_ty: Option<CallType>,
 ) -> Result<()> {
     // Get the base fee for the current window
     let current_timestamp = Clock::get()?.unix_timestamp;
@@ -42,8 +46,7 @@ pub fn pay_for_gas<'info>(
     // Record gas usage for this transaction
     bridge.eip1559.add_gas_usage(bridge.gas_config.gas_per_call);
 
-    let gas_cost = bridge.gas_config.gas_per_call * base_fee * bridge.gas_config.gas_cost_scaler
-        / bridge.gas_config.gas_cost_scaler_dp;
+    let gas_cost = bridge.gas_config.gas_cost(base_fee, call_ty)?;
 
     let cpi_ctx = CpiContext::new(
         system_program.to_account_info(),
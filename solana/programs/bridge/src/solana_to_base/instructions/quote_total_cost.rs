@@ -0,0 +1,299 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    solana_to_base::{Call, CallType, OutgoingMessage, Transfer},
+    BridgeError,
+};
+
+/// Accounts struct for the `quote_total_cost` view instruction that estimates the lamports a
+/// bridge operation will cost its payer.
+#[derive(Accounts)]
+pub struct QuoteTotalCost<'info> {
+    /// The main bridge state account holding the gas and EIP-1559 configuration.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Read-only: quoting never records gas usage
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+/// Shape of the optional call attached to a quoted operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct QuotedCall {
+    /// The type of the call, which selects its gas multiplier.
+    pub ty: CallType,
+    /// Length in bytes of the call data, which sizes the outgoing message account.
+    pub data_len: u32,
+}
+
+/// The bridge operation to quote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum QuotedOperation {
+    /// `bridge_sol` of `amount` lamports, with an optional call.
+    Sol {
+        amount: u64,
+        call: Option<QuotedCall>,
+    },
+    /// `bridge_spl` (or `bridge_wrapped_token`), with an optional call. Token amounts are not
+    /// lamports and therefore not part of the quote.
+    Spl { call: Option<QuotedCall> },
+    /// `bridge_call`.
+    Call { call: QuotedCall },
+}
+
+impl QuotedOperation {
+    fn call(&self) -> Option<QuotedCall> {
+        match *self {
+            QuotedOperation::Sol { call, .. } | QuotedOperation::Spl { call } => call,
+            QuotedOperation::Call { call } => Some(call),
+        }
+    }
+
+    fn outgoing_message_space(&self) -> usize {
+        let data_len = self.call().map(|c| c.data_len as usize).unwrap_or_default();
+        let message_space = match self {
+            QuotedOperation::Call { .. } => OutgoingMessage::space::<Call>(data_len),
+            _ => OutgoingMessage::space::<Transfer>(data_len),
+        };
+        DISCRIMINATOR_LEN + message_space
+    }
+
+    fn locked_lamports(&self) -> u64 {
+        match *self {
+            QuotedOperation::Sol { amount, .. } => amount,
+            _ => 0,
+        }
+    }
+}
+
+/// Lamport cost of a bridge operation, returned to the caller via return data.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct TotalCostQuote {
+    /// Gas fee paid to the gas fee receiver at the current base fee.
+    pub gas_fee: u64,
+    /// Lamports locked in the SOL vault (only non-zero for `bridge_sol`).
+    pub value: u64,
+    /// Rent-exempt balance of the outgoing message account created by the operation.
+    pub rent: u64,
+    /// Sum of `gas_fee`, `value` and `rent`. Quoting fails with `QuoteOverflow` if it does not
+    /// fit in a u64.
+    pub total: u64,
+}
+
+/// Quotes the lamports `operation` would cost if executed now.
+///
+/// The base fee is refreshed for the current timestamp exactly as `pay_for_gas` does, but on a
+/// copy of the EIP-1559 state so the bridge account is left untouched. Transaction fees and the
+/// one-off rent of a token vault created by the first `bridge_spl` of a mint are not included.
+pub fn quote_total_cost_handler(
+    ctx: Context<QuoteTotalCost>,
+    operation: QuotedOperation,
+) -> Result<TotalCostQuote> {
+    let bridge = &ctx.accounts.bridge;
+
+    let mut eip1559 = bridge.eip1559.clone();
+    let base_fee = eip1559.refresh_base_fee(Clock::get()?.unix_timestamp);
+    let gas_fee = bridge
+        .gas_config
+        .gas_cost(base_fee, operation.call().map(|c| c.ty))?;
+
+    let value = operation.locked_lamports();
+    let rent = Rent::get()?.minimum_balance(operation.outgoing_message_space());
+    let total = gas_fee
+        .checked_add(value)
+        .and_then(|total| total.checked_add(rent))
+        .ok_or(BridgeError::QuoteOverflow)?;
+
+    Ok(TotalCostQuote {
+        gas_fee,
+        value,
+        rent,
+        total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{DeniedRemoteToken, RemoteTokenRateLimit, SOL_VAULT_SEED},
+        instruction::{BridgeSol as BridgeSolIx, QuoteTotalCost as QuoteTotalCostIx},
+        test_utils::{
+            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_REMOTE_SOL_ADDRESS,
+        },
+        ID,
+    };
+
+    /// Fee for a single-signature transaction at LiteSVM's default lamports per signature.
+    const TX_FEE: u64 = 5_000;
+
+    fn try_quote_total_cost(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        operation: QuotedOperation,
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::QuoteTotalCost { bridge: bridge_pda }.to_account_metas(None),
+            data: QuoteTotalCostIx { operation }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx).map_err(Box::new);
+        svm.expire_blockhash();
+        result
+    }
+
+    fn quote_total_cost(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        operation: QuotedOperation,
+    ) -> TotalCostQuote {
+        let meta = try_quote_total_cost(svm, payer, bridge_pda, operation)
+            .expect("quote_total_cost should succeed");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        TotalCostQuote::try_from_slice(&meta.return_data.data).unwrap()
+    }
+
+    #[test]
+    fn test_quote_total_cost_matches_bridge_sol_lamport_changes() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let amount = LAMPORTS_PER_SOL;
+        let call = Call {
+            ty: CallType::Call,
+            to: [2u8; 20],
+            value: 0,
+            data: vec![0xAB; 64],
+        };
+        let quote = quote_total_cost(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            QuotedOperation::Sol {
+                amount,
+                call: Some(QuotedCall {
+                    ty: call.ty,
+                    data_len: call.data.len() as u32,
+                }),
+            },
+        );
+        assert_eq!(quote.value, amount);
+        assert_eq!(quote.total, quote.gas_fee + quote.value + quote.rent);
+
+        let payer_before = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        let receiver_before = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSol {
+                payer: payer.pubkey(),
+                from: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                sol_vault,
+                bridge: bridge_pda,
+                denied_remote_token: DeniedRemoteToken::address(&TEST_REMOTE_SOL_ADDRESS),
+                rate_limit: RemoteTokenRateLimit::address(&TEST_REMOTE_SOL_ADDRESS),
+                outgoing_message,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: Some(call),
+                reserve_lamports: None,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("bridge_sol should succeed");
+
+        let payer_after = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        let receiver_after = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        let message_rent = svm.get_account(&outgoing_message).unwrap().lamports;
+
+        assert_eq!(receiver_after - receiver_before, quote.gas_fee);
+        assert_eq!(message_rent, quote.rent);
+        assert_eq!(payer_before - payer_after - TX_FEE, quote.total);
+    }
+
+    #[test]
+    fn test_quote_total_cost_does_not_mutate_bridge() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let bridge_before = svm.get_account(&bridge_pda).unwrap().data;
+        let quote = quote_total_cost(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            QuotedOperation::Spl { call: None },
+        );
+        assert_eq!(quote.value, 0);
+        assert_eq!(svm.get_account(&bridge_pda).unwrap().data, bridge_before);
+    }
+
+    #[test]
+    fn test_quote_total_cost_rejects_overflowing_total() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let result = try_quote_total_cost(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            QuotedOperation::Sol {
+                amount: u64::MAX,
+                call: None,
+            },
+        );
+        assert!(result.is_err(), "Expected overflowing quote to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(err.contains("QuoteOverflow"), "Unexpected error: {}", err);
+    }
+}
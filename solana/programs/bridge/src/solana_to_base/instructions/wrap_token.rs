@@ -15,8 +15,13 @@ use anchor_spl::token_interface::{
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 use crate::common::DISCRIMINATOR_LEN;
-use crate::common::{bridge::Bridge, PartialTokenMetadata, BRIDGE_SEED, WRAPPED_TOKEN_SEED};
-use crate::solana_to_base::{pay_for_gas, Call, CallType, OutgoingMessage, OUTGOING_MESSAGE_SEED};
+use crate::common::{
+    bridge::Bridge, DeniedRemoteToken, PartialTokenMetadata, WrappedTokenCreated, BRIDGE_SEED,
+    PAUSE_BRIDGE_TOKEN, WRAPPED_TOKEN_INFO_SEED, WRAPPED_TOKEN_SEED,
+};
+use crate::solana_to_base::{
+    pay_for_gas, Call, CallType, OutgoingMessage, WrappedTokenInfo, OUTGOING_MESSAGE_SEED,
+};
 use crate::solana_to_base::{REMOTE_TOKEN_METADATA_KEY, SCALER_EXPONENT_METADATA_KEY};
 use crate::BridgeError;
 use crate::ID;
@@ -44,7 +49,8 @@ pub struct WrapToken<'info> {
 
     /// The new SPL Token-2022 mint being created for the wrapped token.
     /// - Uses PDA with token metadata hash and decimals for deterministic address
-    /// - Mint authority set to itself (mint account) for controlled minting
+    /// - Mint and freeze authority set to itself (mint account) for controlled minting and
+    ///   guardian-initiated freezes
     /// - Includes metadata pointer extension to store token information onchain
     #[account(
         init,
@@ -58,16 +64,34 @@ pub struct WrapToken<'info> {
         bump,
         mint::decimals = decimals,
         mint::authority = mint,
+        mint::freeze_authority = mint,
         extensions::metadata_pointer::metadata_address = mint,
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// Record of the wrapped mint's creation, queryable without replaying events.
+    /// - Uses PDA with WRAPPED_TOKEN_INFO_SEED and the mint address
+    /// - Payer funds the account creation
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + WrappedTokenInfo::INIT_SPACE,
+        seeds = [WRAPPED_TOKEN_INFO_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub wrapped_token_info: Account<'info, WrappedTokenInfo>,
+
     /// The main bridge state account that tracks cross-chain operations.
     /// Used to increment the nonce counter and manage EIP-1559 gas pricing.
     /// Must be mutable to update the nonce after creating the outgoing message.
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The denylist entry of the remote token being wrapped; the instruction fails if it exists.
+    /// CHECK: Checked in the handler against the PDA with DENIED_REMOTE_TOKEN_SEED and the remote
+    /// token; only its existence is read
+    pub denied_remote_token: AccountInfo<'info>,
+
     /// The outgoing message account that stores the cross-chain call to register
     /// the wrapped token on the Base blockchain. Contains the encoded function call
     /// with token address, local mint address, and scaling parameters.
@@ -96,10 +120,45 @@ pub fn wrap_token_handler(
     partial_token_metadata: PartialTokenMetadata,
 ) -> Result<()> {
     // Check if bridge is paused
-    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+    ctx.accounts.bridge.require_not_paused(PAUSE_BRIDGE_TOKEN)?;
+
+    partial_token_metadata.validate(decimals)?;
+
+    DeniedRemoteToken::ensure_allowed(
+        &ctx.accounts.denied_remote_token,
+        &partial_token_metadata.remote_token,
+    )?;
+
+    let bridge = &mut ctx.accounts.bridge;
+
+    // Enforce the global cap on wrapped mints
+    let max_wrapped_tokens = bridge.protocol_config.max_wrapped_tokens;
+    require!(
+        max_wrapped_tokens == 0 || bridge.wrapped_token_count < max_wrapped_tokens,
+        BridgeError::WrappedTokenLimitReached
+    );
+    bridge.wrapped_token_count += 1;
 
     initialize_metadata(&ctx, decimals, &partial_token_metadata)?;
 
+    *ctx.accounts.wrapped_token_info = WrappedTokenInfo {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.payer.key(),
+        created_at: Clock::get()?.unix_timestamp,
+        remote_token: partial_token_metadata.remote_token,
+        decimals,
+        scaler_exponent: partial_token_metadata.scaler_exponent,
+    };
+
+    emit!(WrappedTokenCreated {
+        mint: ctx.accounts.mint.key(),
+        remote_token: partial_token_metadata.remote_token,
+        decimals,
+        name: partial_token_metadata.name.clone(),
+        symbol: partial_token_metadata.symbol.clone(),
+        scaler_exponent: partial_token_metadata.scaler_exponent,
+    });
+
     register_remote_token(
         ctx,
         &partial_token_metadata.remote_token,
@@ -218,6 +277,7 @@ fn register_remote_token(
         &ctx.accounts.payer,
         &ctx.accounts.gas_fee_receiver,
         &mut ctx.accounts.bridge,
+        Some(CallType::Call),
     )?;
 
     *ctx.accounts.outgoing_message = message;
@@ -234,3 +294,254 @@ const fn add_type_and_length_to_len(value_len: usize) -> usize {
         .saturating_add(std::mem::size_of::<ExtensionType>())
         .saturating_add(pod_get_packed_len::<Length>())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::WrapToken as WrapTokenIx,
+        test_utils::{
+            create_outgoing_message, deny_remote_token, emitted_events, mock_clock, setup_bridge,
+            update_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+    };
+
+    fn wrapped_token_info_pda(mint: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[WRAPPED_TOKEN_INFO_SEED, mint.as_ref()], &ID).0
+    }
+
+    fn send_wrap_token(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        remote_token: [u8; 20],
+    ) -> std::result::Result<
+        litesvm::types::TransactionMetadata,
+        Box<litesvm::types::FailedTransactionMetadata>,
+    > {
+        let decimals = 6u8;
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Wrapped Token".to_string(),
+            symbol: "WTKN".to_string(),
+            remote_token,
+            scaler_exponent: 12,
+        };
+        let mint = Pubkey::find_program_address(
+            &[
+                WRAPPED_TOKEN_SEED,
+                decimals.to_le_bytes().as_ref(),
+                partial_token_metadata.hash().as_ref(),
+            ],
+            &ID,
+        )
+        .0;
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::WrapToken {
+                payer: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                mint,
+                wrapped_token_info: wrapped_token_info_pda(mint),
+                bridge: bridge_pda,
+                denied_remote_token: DeniedRemoteToken::address(&remote_token),
+                outgoing_message,
+                token_program: anchor_spl::token_2022::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: WrapTokenIx {
+                outgoing_message_salt,
+                decimals,
+                partial_token_metadata,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).map_err(Box::new)
+    }
+
+    #[test]
+    fn test_wrap_token_fails_when_wrapped_token_limit_reached() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        // Allow at most two wrapped mints
+        update_bridge(&mut svm, |bridge| {
+            bridge.protocol_config.max_wrapped_tokens = 2;
+        });
+
+        send_wrap_token(&mut svm, &payer, bridge_pda, [1u8; 20])
+            .expect("first wrap_token should succeed");
+        send_wrap_token(&mut svm, &payer, bridge_pda, [2u8; 20])
+            .expect("second wrap_token should succeed");
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.wrapped_token_count, 2);
+
+        let result = send_wrap_token(&mut svm, &payer, bridge_pda, [3u8; 20]);
+        assert!(result.is_err(), "Expected wrap over the cap to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("WrappedTokenLimitReached"),
+            "Unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_wrap_token_charges_refreshed_base_fee_for_stale_window() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        // Leave an elevated base fee behind in a window that expired long ago
+        let bridge = update_bridge(&mut svm, |bridge| {
+            bridge.eip1559.current_base_fee = 1_000 * bridge.eip1559.config.minimum_base_fee.max(1);
+        });
+
+        let now = bridge.eip1559.window_start_time
+            + 50 * bridge.eip1559.config.window_duration_seconds as i64;
+        mock_clock(&mut svm, now);
+
+        let stale_fee = bridge
+            .gas_config
+            .gas_cost(bridge.eip1559.current_base_fee, Some(CallType::Call))
+            .unwrap();
+        let refreshed_base_fee = bridge.eip1559.clone().refresh_base_fee(now);
+        let expected_fee = bridge
+            .gas_config
+            .gas_cost(refreshed_base_fee, Some(CallType::Call))
+            .unwrap();
+        assert!(expected_fee < stale_fee);
+
+        let receiver_initial_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        send_wrap_token(&mut svm, &payer, bridge_pda, [5u8; 20])
+            .expect("wrap_token should succeed");
+        let receiver_final_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert_eq!(
+            receiver_final_balance - receiver_initial_balance,
+            expected_fee
+        );
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.eip1559.current_base_fee, refreshed_base_fee);
+    }
+
+    #[test]
+    fn test_wrap_token_emits_wrapped_token_created() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let remote_token = [7u8; 20];
+        let meta = send_wrap_token(&mut svm, &payer, bridge_pda, remote_token)
+            .expect("wrap_token should succeed");
+
+        let event = emitted_events::<WrappedTokenCreated>(&meta)
+            .pop()
+            .expect("WrappedTokenCreated event not emitted");
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.wrapped_token_count, 1);
+
+        assert_eq!(event.remote_token, remote_token);
+        assert_eq!(event.decimals, 6);
+        assert_eq!(event.name, "Wrapped Token");
+        assert_eq!(event.symbol, "WTKN");
+        assert_eq!(event.scaler_exponent, 12);
+        assert!(svm.get_account(&event.mint).is_some());
+    }
+
+    #[test]
+    fn test_wrap_token_creates_wrapped_token_info() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let remote_token = [9u8; 20];
+        let meta = send_wrap_token(&mut svm, &payer, bridge_pda, remote_token)
+            .expect("wrap_token should succeed");
+        let mint = emitted_events::<WrappedTokenCreated>(&meta)
+            .pop()
+            .expect("WrappedTokenCreated event not emitted")
+            .mint;
+
+        let info_account = svm.get_account(&wrapped_token_info_pda(mint)).unwrap();
+        assert_eq!(info_account.owner, ID);
+        let info = WrappedTokenInfo::try_deserialize(&mut &info_account.data[..]).unwrap();
+        assert_eq!(info.mint, mint);
+        assert_eq!(info.creator, payer.pubkey());
+        assert_eq!(info.created_at, svm.get_sysvar::<Clock>().unix_timestamp);
+        assert_eq!(info.remote_token, remote_token);
+        assert_eq!(info.decimals, 6);
+        assert_eq!(info.scaler_exponent, 12);
+    }
+
+    #[test]
+    fn test_wrap_token_fails_for_denied_remote_token() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        deny_remote_token(&mut svm, [1u8; 20]);
+
+        let result = send_wrap_token(&mut svm, &payer, bridge_pda, [1u8; 20]);
+        assert!(result.is_err(), "Expected wrap of a denied token to fail");
+        let err = format!("{:?}", result.unwrap_err());
+        assert!(
+            err.contains("RemoteTokenDenied"),
+            "Unexpected error: {}",
+            err
+        );
+
+        send_wrap_token(&mut svm, &payer, bridge_pda, [2u8; 20])
+            .expect("wrap of another token should succeed");
+    }
+}
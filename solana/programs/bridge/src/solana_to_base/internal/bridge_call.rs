@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::{
     common::bridge::Bridge,
-    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage},
+    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage, OutgoingMessageReceipt},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -14,15 +14,28 @@ pub fn bridge_call_internal<'info>(
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     system_program: &Program<'info, System>,
     call: Call,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     check_call(&call)?;
 
+    let call_ty = call.ty;
     let message = OutgoingMessage::new_call(bridge.nonce, from.key(), call);
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        bridge,
+        Some(call_ty),
+    )?;
+
+    let receipt = OutgoingMessageReceipt {
+        nonce: message.nonce,
+        outgoing_message: outgoing_message.key(),
+        message_hash: message.message_hash(&outgoing_message.key()),
+    };
 
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
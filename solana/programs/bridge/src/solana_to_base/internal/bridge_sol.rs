@@ -4,9 +4,10 @@ use anchor_lang::{
 };
 
 use crate::{
-    common::bridge::Bridge,
+    common::{bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit},
     solana_to_base::{
-        check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp, NATIVE_SOL_PUBKEY,
+        check_call, pay_for_gas, Call, OutgoingMessage, OutgoingMessageReceipt,
+        Transfer as TransferOp, NATIVE_SOL_PUBKEY,
     },
 };
 
@@ -17,16 +18,29 @@ pub fn bridge_sol_internal<'info>(
     gas_fee_receiver: &AccountInfo<'info>,
     sol_vault: &AccountInfo<'info>,
     bridge: &mut Account<'info, Bridge>,
+    denied_remote_token: &AccountInfo<'info>,
+    rate_limit: &AccountInfo<'info>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     system_program: &Program<'info, System>,
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
+    let call_ty = call.as_ref().map(|call| call.ty);
+
+    let remote_sol_address = bridge.protocol_config.remote_sol_address;
+    DeniedRemoteToken::ensure_allowed(denied_remote_token, &remote_sol_address)?;
+    RemoteTokenRateLimit::record_outflow(
+        rate_limit,
+        &remote_sol_address,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     let message = OutgoingMessage::new_transfer(
         bridge.nonce,
         from.key(),
@@ -39,7 +53,12 @@ pub fn bridge_sol_internal<'info>(
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    pay_for_gas(system_program, payer, gas_fee_receiver, bridge, call_ty)?;
+
+    let daily_limit = bridge.protocol_config.daily_outbound_sol_limit;
+    bridge
+        .outbound_sol_volume
+        .add(amount, daily_limit, Clock::get()?.unix_timestamp)?;
 
     // Lock the sol from the user into the SOL vault.
     let cpi_ctx = CpiContext::new(
@@ -50,9 +69,19 @@ pub fn bridge_sol_internal<'info>(
         },
     );
     system_program::transfer(cpi_ctx, amount)?;
+    bridge.locked_sol = bridge
+        .locked_sol
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let receipt = OutgoingMessageReceipt {
+        nonce: message.nonce,
+        outgoing_message: outgoing_message.key(),
+        message_hash: message.message_hash(&outgoing_message.key()),
+    };
 
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
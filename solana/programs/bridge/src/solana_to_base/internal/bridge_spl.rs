@@ -4,8 +4,11 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::common::PartialTokenMetadata;
 use crate::{
-    common::bridge::Bridge,
-    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp},
+    common::{bridge::Bridge, DeniedRemoteToken, RemoteTokenRateLimit},
+    solana_to_base::{
+        check_call, pay_for_gas, Call, OutgoingMessage, OutgoingMessageReceipt,
+        Transfer as TransferOp,
+    },
     BridgeError,
 };
 
@@ -17,6 +20,8 @@ pub fn bridge_spl_internal<'info>(
     mint: &InterfaceAccount<'info, Mint>,
     from_token_account: &InterfaceAccount<'info, TokenAccount>,
     bridge: &mut Account<'info, Bridge>,
+    denied_remote_token: &AccountInfo<'info>,
+    rate_limit: &AccountInfo<'info>,
     token_vault: &mut InterfaceAccount<'info, TokenAccount>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     token_program: &Interface<'info, TokenInterface>,
@@ -25,11 +30,21 @@ pub fn bridge_spl_internal<'info>(
     remote_token: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
+    let call_ty = call.as_ref().map(|call| call.ty);
+
+    DeniedRemoteToken::ensure_allowed(denied_remote_token, &remote_token)?;
+    RemoteTokenRateLimit::record_outflow(
+        rate_limit,
+        &remote_token,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     // Check that the provided mint is not a wrapped token.
     // Wrapped tokens should be handled by the wrapped_token_transfer_operation branch which burns the token from the user.
     require!(
@@ -71,10 +86,16 @@ pub fn bridge_spl_internal<'info>(
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    pay_for_gas(system_program, payer, gas_fee_receiver, bridge, call_ty)?;
+
+    let receipt = OutgoingMessageReceipt {
+        nonce: message.nonce,
+        outgoing_message: outgoing_message.key(),
+        message_hash: message.message_hash(&outgoing_message.key()),
+    };
 
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
@@ -6,8 +6,8 @@ use anchor_spl::{
 
 use crate::solana_to_base::{check_call, pay_for_gas};
 use crate::{
-    common::{bridge::Bridge, PartialTokenMetadata},
-    solana_to_base::{Call, OutgoingMessage, Transfer as TransferOp},
+    common::{bridge::Bridge, DeniedRemoteToken, PartialTokenMetadata, RemoteTokenRateLimit},
+    solana_to_base::{Call, OutgoingMessage, OutgoingMessageReceipt, Transfer as TransferOp},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -18,20 +18,32 @@ pub fn bridge_wrapped_token_internal<'info>(
     mint: &InterfaceAccount<'info, Mint>,
     from_token_account: &InterfaceAccount<'info, TokenAccount>,
     bridge: &mut Account<'info, Bridge>,
+    denied_remote_token: &AccountInfo<'info>,
+    rate_limit: &AccountInfo<'info>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     token_program: &Program<'info, Token2022>,
     system_program: &Program<'info, System>,
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
 
+    let call_ty = call.as_ref().map(|call| call.ty);
+
     // Get the token metadata from the mint.
     let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
 
+    DeniedRemoteToken::ensure_allowed(denied_remote_token, &partial_token_metadata.remote_token)?;
+    RemoteTokenRateLimit::record_outflow(
+        rate_limit,
+        &partial_token_metadata.remote_token,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
     let message = OutgoingMessage::new_transfer(
         bridge.nonce,
         from.key(),
@@ -44,7 +56,7 @@ pub fn bridge_wrapped_token_internal<'info>(
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    pay_for_gas(system_program, payer, gas_fee_receiver, bridge, call_ty)?;
 
     // Burn the token from the user.
     let cpi_ctx = CpiContext::new(
@@ -57,8 +69,14 @@ pub fn bridge_wrapped_token_internal<'info>(
     );
     token_interface::burn_checked(cpi_ctx, amount, mint.decimals)?;
 
+    let receipt = OutgoingMessageReceipt {
+        nonce: message.nonce,
+        outgoing_message: outgoing_message.key(),
+        message_hash: message.message_hash(&outgoing_message.key()),
+    };
+
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
@@ -1,6 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::keccak};
 
-use crate::solana_to_base::CallType;
+use crate::{solana_to_base::CallType, BridgeError};
 
 /// A buffer account that stores call parameters which can be built up over multiple transactions
 /// to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and
@@ -23,6 +23,22 @@ pub struct CallBuffer {
     /// The amount of Base native currency (ETH) to send with this call, in wei.
     pub value: u128,
 
+    /// Maximum number of appends allowed on this buffer (0 means unlimited), copied from
+    /// `bridge.buffer_config.max_call_buffer_appends` at initialization.
+    pub max_appends: u64,
+
+    /// Number of appends made to this buffer so far.
+    pub append_count: u64,
+
+    /// Keccak-256 hash `data` must have when the buffer is bridged, committed at initialization
+    /// so a dropped or reordered append fails on Solana instead of on Base.
+    pub expected_hash: Option<[u8; 32]>,
+
+    /// Keccak-256 hash chain over the segments written to `data`, folded as
+    /// `keccak(running_hash || segment)` starting from zero with `initial_data`. Clients recompute
+    /// it after each append to catch a dropped or reordered append before bridging.
+    pub running_hash: [u8; 32],
+
     /// The encoded function call data or contract bytecode.
     /// For regular calls: ABI-encoded function signature and parameters.
     /// For contract creation: the contract's initialization bytecode.
@@ -40,6 +56,46 @@ impl CallBuffer {
         1 + // ty (CallType enum)
         20 + // to
         16 + // value
+        8 + // max_appends
+        8 + // append_count
+        1 + 32 + // expected_hash
+        32 + // running_hash
         4 + max_data_len // data vec (length prefix + max data)
     }
+
+    /// Counts one append against `max_appends`, failing with `TooManyAppends` once the cap
+    /// is reached.
+    pub fn record_append(&mut self) -> Result<()> {
+        require!(
+            self.max_appends == 0 || self.append_count < self.max_appends,
+            BridgeError::TooManyAppends
+        );
+        self.append_count += 1;
+
+        Ok(())
+    }
+
+    /// Returns the running hash after folding `segment` into `running_hash`.
+    pub fn chain_hash(running_hash: &[u8; 32], segment: &[u8]) -> [u8; 32] {
+        keccak::hashv(&[running_hash, segment]).to_bytes()
+    }
+
+    /// Appends `segment` to `data` and folds it into `running_hash`.
+    pub fn append(&mut self, segment: &[u8]) {
+        self.running_hash = Self::chain_hash(&self.running_hash, segment);
+        self.data.extend_from_slice(segment);
+    }
+
+    /// Checks that `data` hashes to `expected_hash`, if one was committed, failing with
+    /// `BufferChecksumMismatch` otherwise.
+    pub fn verify_checksum(&self) -> Result<()> {
+        if let Some(expected_hash) = self.expected_hash {
+            require!(
+                keccak::hash(&self.data).to_bytes() == expected_hash,
+                BridgeError::BufferChecksumMismatch
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 pub mod call_buffer;
 pub mod outgoing_message;
+pub mod wrapped_token_info;
 
 pub use call_buffer::*;
 pub use outgoing_message::*;
+pub use wrapped_token_info::*;
@@ -1,4 +1,6 @@
-use anchor_lang::prelude::*;
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_sol_types::SolValue;
+use anchor_lang::{prelude::*, solana_program::keccak};
 
 /// Trait for calculating the space required for a message.
 pub trait MessageSpace {
@@ -31,6 +33,23 @@ pub struct Transfer {
     pub call: Option<Call>,
 }
 
+impl Transfer {
+    /// ABI tuple of `TokenLib.Transfer` as seen from Base:
+    /// `(address localToken, bytes32 remoteToken, bytes32 to, uint64 remoteAmount)`.
+    /// Base's local token is this transfer's remote token and vice versa, and `to` is the EVM
+    /// address left-aligned in 32 bytes.
+    fn to_sol(&self) -> (Address, FixedBytes<32>, FixedBytes<32>, U256) {
+        let mut to = [0u8; 32];
+        to[..20].copy_from_slice(&self.to);
+        (
+            Address::from(self.remote_token),
+            FixedBytes::from(self.local_token.to_bytes()),
+            FixedBytes::from(to),
+            U256::from(self.amount),
+        )
+    }
+}
+
 impl MessageSpace for Transfer {
     fn space(data_len: usize) -> usize {
         20 + // to
@@ -71,6 +90,18 @@ pub struct Call {
     pub data: Vec<u8>,
 }
 
+impl Call {
+    /// ABI tuple of `CallLib.Call` on Base: `(uint8 ty, address to, uint128 value, bytes data)`.
+    fn to_sol(&self) -> (U256, Address, U256, Bytes) {
+        (
+            U256::from(self.ty as u8),
+            Address::from(self.to),
+            U256::from(self.value),
+            Bytes::from(self.data.clone()),
+        )
+    }
+}
+
 impl MessageSpace for Call {
     fn space(data_len: usize) -> usize {
         CallType::INIT_SPACE + // call type
@@ -94,6 +125,26 @@ pub enum Message {
     Transfer(Transfer),
 }
 
+/// Mirrors `MessageType` in `MessageLib.sol` on Base.
+#[derive(Clone, Copy)]
+enum BaseMessageType {
+    Call,
+    Transfer,
+    TransferAndCall,
+}
+
+/// Identifies an outgoing message created by a `bridge_*` instruction, returned to the caller via
+/// return data so it does not have to predict the nonce or account address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutgoingMessageReceipt {
+    /// Nonce assigned to the message
+    pub nonce: u64,
+    /// Address of the `OutgoingMessage` account
+    pub outgoing_message: Pubkey,
+    /// Hash Base validators register the message under (see `OutgoingMessage::message_hash`)
+    pub message_hash: [u8; 32],
+}
+
 /// Represents a message being sent from Solana to Base through the bridge.
 /// This struct contains all the necessary information to execute a cross-chain operation
 /// on the Base side, including the message content and execution parameters.
@@ -131,6 +182,47 @@ impl OutgoingMessage {
         }
     }
 
+    /// Computes the hash Base validators register this message under, mirroring
+    /// `MessageLib.getMessageHash` on Base:
+    ///
+    /// message_hash = keccak256(nonce || outgoing_message || keccak256(abi.encode(sender, ty, data)))
+    ///
+    /// where `ty` and `data` are the `MessageType` and ABI-encoded payload the relayer submits.
+    pub fn message_hash(&self, outgoing_message: &Pubkey) -> [u8; 32] {
+        let (ty, data) = match &self.message {
+            Message::Call(call) => (BaseMessageType::Call, (call.to_sol(),).abi_encode_params()),
+            Message::Transfer(transfer) => match &transfer.call {
+                None => (
+                    BaseMessageType::Transfer,
+                    (transfer.to_sol(),).abi_encode_params(),
+                ),
+                Some(call) => (
+                    BaseMessageType::TransferAndCall,
+                    (transfer.to_sol(), call.to_sol()).abi_encode_params(),
+                ),
+            },
+        };
+
+        let inner_hash = keccak::hash(
+            &(
+                FixedBytes::<32>::from(self.sender.to_bytes()),
+                U256::from(ty as u8),
+                Bytes::from(data),
+            )
+                .abi_encode_params(),
+        );
+
+        keccak::hash(
+            &(
+                U256::from(self.nonce),
+                FixedBytes::<32>::from(outgoing_message.to_bytes()),
+                FixedBytes::<32>::from(inner_hash.to_bytes()),
+            )
+                .abi_encode_params(),
+        )
+        .to_bytes()
+    }
+
     /// Returns the serialized size of an `OutgoingMessage` payload, excluding the DISCRIMINATOR_LEN-byte Anchor
     /// account discriminator.
     pub fn space<T: MessageSpace>(data_len: usize) -> usize {
@@ -139,3 +231,65 @@ impl OutgoingMessage {
         1 + T::space(data_len) // message (variant + space)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(value: u64) -> [u8; 32] {
+        let mut word = [0u8; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn test_message_hash_matches_base_encoding_for_call() {
+        let sender = Pubkey::new_unique();
+        let outgoing_message = Pubkey::new_unique();
+        let message = OutgoingMessage::new_call(
+            5,
+            sender,
+            Call {
+                ty: CallType::DelegateCall,
+                to: [0xAB; 20],
+                value: 7,
+                data: vec![0xDE, 0xAD, 0xBE, 0xEF],
+            },
+        );
+
+        let mut padded_data = [0u8; 32];
+        padded_data[..4].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        let mut to = [0u8; 32];
+        to[12..].copy_from_slice(&[0xAB; 20]);
+
+        // abi.encode(Call): offset to the tuple, then (ty, to, value, offset to data, data)
+        let call_data = [
+            word(0x20),
+            word(1),
+            to,
+            word(7),
+            word(0x80),
+            word(4),
+            padded_data,
+        ]
+        .concat();
+
+        // abi.encode(bytes32 sender, uint8 MessageType.Call, bytes data)
+        let inner = [
+            sender.to_bytes().to_vec(),
+            word(0).to_vec(),
+            word(0x60).to_vec(),
+            word(call_data.len() as u64).to_vec(),
+            call_data,
+        ]
+        .concat();
+        let inner_hash = keccak::hash(&inner).to_bytes();
+
+        let outer = [word(5), outgoing_message.to_bytes(), inner_hash].concat();
+
+        assert_eq!(
+            message.message_hash(&outgoing_message),
+            keccak::hash(&outer).to_bytes()
+        );
+    }
+}
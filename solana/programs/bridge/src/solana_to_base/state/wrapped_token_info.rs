@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Durable record of a wrapped mint created by `wrap_token`.
+///
+/// Created alongside the mint at `[WRAPPED_TOKEN_INFO_SEED, mint]` so integrators can look up
+/// when, by whom and with which configuration a wrapped token was created without replaying
+/// `WrappedTokenCreated` events.
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct WrappedTokenInfo {
+    /// The wrapped Token-2022 mint this record describes.
+    pub mint: Pubkey,
+
+    /// The payer of the `wrap_token` transaction that created the mint.
+    pub creator: Pubkey,
+
+    /// Solana unix timestamp at which the mint was created.
+    pub created_at: i64,
+
+    /// The 20-byte address of the Base token the mint wraps.
+    pub remote_token: [u8; 20],
+
+    /// Decimals of the wrapped mint.
+    pub decimals: u8,
+
+    /// Exponent registered on Base to scale amounts between the mint and the remote token.
+    pub scaler_exponent: u8,
+}
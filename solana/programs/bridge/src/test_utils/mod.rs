@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program::{
         bpf_loader_upgradeable, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
     },
-    system_program, InstructionData,
+    system_program, Event, InstructionData,
 };
 use anchor_spl::{
     token_2022::spl_token_2022::{
@@ -21,8 +21,9 @@ use anchor_spl::{
         spl_token_metadata_interface::state::TokenMetadata,
     },
 };
+use base64::{engine::general_purpose::STANDARD, Engine};
 use hex_literal::hex;
-use litesvm::LiteSVM;
+use litesvm::{types::TransactionMetadata, LiteSVM};
 use solana_account::Account;
 use solana_keypair::Keypair;
 use solana_loader_v3_interface::state::UpgradeableLoaderState;
@@ -32,17 +33,20 @@ use solana_transaction::Transaction;
 
 use crate::{
     accounts,
-    base_to_solana::signers::PartnerSigner,
+    base_to_solana::{signers::PartnerSigner, state::OutputRoot},
     common::{
-        bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
-        BaseOracleConfig, Config, PartialTokenMetadata, BRIDGE_SEED, MAX_SIGNER_COUNT,
-        WRAPPED_TOKEN_SEED,
+        bridge::{
+            Bridge, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig,
+        },
+        BaseOracleConfig, Config, DeniedRemoteToken, PartialTokenMetadata, RemoteTokenRateLimit,
+        BRIDGE_SEED, MAX_SIGNER_COUNT, WRAPPED_TOKEN_SEED,
     },
     instruction::Initialize,
     solana_to_base::OUTGOING_MESSAGE_SEED,
     ID,
 };
 pub const TEST_GAS_FEE_RECEIVER: Pubkey = pubkey!("eEwCrQLBdQchykrkYitkYUZskd7MPrU2YxBXcPDPnMt");
+pub const TEST_REMOTE_SOL_ADDRESS: [u8; 20] = hex!("C5b9112382f3c87AFE8e1A28fa52452aF81085AD");
 
 impl Eip1559Config {
     pub fn test_new() -> Self {
@@ -51,6 +55,7 @@ impl Eip1559Config {
             denominator: 2,
             window_duration_seconds: 1,
             minimum_base_fee: 1,
+            decay_grace_windows: 0,
         }
     }
 }
@@ -62,6 +67,9 @@ impl GasConfig {
             gas_cost_scaler_dp: 10u64.pow(6),
             gas_fee_receiver,
             gas_per_call: 100_000,
+            minimum_fee_lamports: 0,
+            maximum_fee_lamports: 0,
+            call_type_gas_multipliers_bps: [10_000; 4],
         }
     }
 }
@@ -70,7 +78,23 @@ impl ProtocolConfig {
     pub fn test_new() -> Self {
         Self {
             block_interval_requirement: 300,
-            remote_sol_address: hex!("C5b9112382f3c87AFE8e1A28fa52452aF81085AD"),
+            remote_sol_address: TEST_REMOTE_SOL_ADDRESS,
+            min_registration_interval_seconds: 0,
+            max_root_age_seconds: 0,
+            registration_bucket_capacity: 0,
+            registration_refill_interval_seconds: 0,
+            reject_duplicate_roots: false,
+            disjoint_signer_sets: false,
+            reject_duplicate_ixs: false,
+            enforce_relay_nonce_order: false,
+            require_self_call: false,
+            max_wrapped_tokens: 0,
+            large_finalize_threshold: 0,
+            daily_outbound_sol_limit: 0,
+            daily_inbound_sol_limit: 0,
+            revalidate_old_roots_after_blocks: 0,
+            max_block_advance: 0,
+            min_prove_to_relay_delay_seconds: 0,
         }
     }
 }
@@ -79,6 +103,8 @@ impl BufferConfig {
     pub fn test_new() -> Self {
         Self {
             max_call_buffer_size: 8 * 1024, // 8KB
+            max_prove_buffer_proof_len: 64,
+            max_call_buffer_appends: 0,
         }
     }
 }
@@ -92,6 +118,7 @@ impl BaseOracleConfig {
             threshold: 1,
             signer_count: 1,
             signers: signer_addrs,
+            weights: [1u8; MAX_SIGNER_COUNT as usize],
         }
     }
 }
@@ -274,6 +301,70 @@ pub fn setup_bridge() -> SetupBridgeResult {
     }
 }
 
+/// Sends a guardian configuration instruction signed by `signers`, the first of which is the
+/// guardian paying for the transaction.
+pub fn send_guardian_config_ix(
+    svm: &mut LiteSVM,
+    signers: &[&Keypair],
+    accounts: impl ToAccountMetas,
+    data: Vec<u8>,
+) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+    let guardian = signers[0];
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data,
+    };
+
+    let tx = Transaction::new(
+        signers,
+        Message::new(&[ix], Some(&guardian.pubkey())),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).map_err(Box::new)?;
+    svm.expire_blockhash();
+    Ok(())
+}
+
+/// Applies `update` to the bridge account, bypassing the instructions that would normally
+/// guard the change, and returns the updated bridge.
+pub fn update_bridge(svm: &mut LiteSVM, update: impl FnOnce(&mut Bridge)) -> Bridge {
+    let bridge_pda = Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0;
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    update(&mut bridge);
+
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+    bridge
+}
+
+/// Writes an `OutputRoot` registered now at Base block 1, advances the bridge to that block and
+/// returns the root's address.
+pub fn create_output_root_account(
+    svm: &mut LiteSVM,
+    root: [u8; 32],
+    total_leaf_count: u64,
+) -> Pubkey {
+    update_bridge(svm, |bridge| bridge.base_block_number = 1);
+
+    let output_root_pk = Keypair::new().pubkey();
+    set_program_account(
+        svm,
+        output_root_pk,
+        &OutputRoot {
+            root,
+            total_leaf_count,
+            registered_at: svm.get_sysvar::<Clock>().unix_timestamp,
+            base_block_number: 1,
+            revalidated_epoch: OutputRoot::NOT_REVALIDATED,
+        },
+    );
+    output_root_pk
+}
+
 pub fn create_outgoing_message() -> ([u8; 32], Pubkey) {
     let outgoing_message_salt = [42u8; 32];
     (
@@ -286,6 +377,66 @@ pub fn create_outgoing_message() -> ([u8; 32], Pubkey) {
     )
 }
 
+/// Writes the denylist entry of `remote_token`, as `add_denied_remote_token` would.
+pub fn deny_remote_token(svm: &mut LiteSVM, remote_token: [u8; 20]) {
+    set_program_account(
+        svm,
+        DeniedRemoteToken::address(&remote_token),
+        &DeniedRemoteToken { remote_token },
+    );
+}
+
+/// Writes the rate limit of `remote_token` with a window starting at `current_timestamp`, as
+/// `set_rate_limit` would.
+pub fn set_remote_token_rate_limit(
+    svm: &mut LiteSVM,
+    remote_token: [u8; 20],
+    max_per_window: u64,
+    window_seconds: u64,
+    current_timestamp: i64,
+) {
+    set_program_account(
+        svm,
+        RemoteTokenRateLimit::address(&remote_token),
+        &RemoteTokenRateLimit {
+            remote_token,
+            max_per_window,
+            window_seconds,
+            window_start_time: current_timestamp,
+            amount_in_window: 0,
+        },
+    );
+}
+
+fn set_program_account(svm: &mut LiteSVM, address: Pubkey, state: &impl AccountSerialize) {
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        address,
+        Account {
+            lamports,
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Decodes the `E` events emitted by a transaction, in emission order.
+pub fn emitted_events<E: Event>(meta: &TransactionMetadata) -> Vec<E> {
+    meta.logs
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter(|data| data.starts_with(E::DISCRIMINATOR))
+        .map(|data| E::try_from_slice(&data[E::DISCRIMINATOR.len()..]).unwrap())
+        .collect()
+}
+
 pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     let mut clock = svm.get_sysvar::<Clock>();
     clock.unix_timestamp = timestamp;
@@ -397,7 +548,7 @@ pub fn create_mock_wrapped_mint(
         supply: initial_supply,
         decimals,
         is_initialized: true,
-        freeze_authority: COption::None,
+        freeze_authority: COption::Some(wrapped_mint),
     };
     mint_with_extension.pack_base();
     mint_with_extension.init_account_type().unwrap();